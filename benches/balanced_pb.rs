@@ -1,7 +1,8 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use livesplit_core::{
-    comparison::balanced_pb::BalancedPB, run::parser::livesplit, Run, Segment, TimeSpan, Timer,
+    comparison::balanced_pb::BalancedPB, run::parser::livesplit, timing::GameTimeAheadPolicy, Run,
+    Segment, TimeSpan, Timer,
 };
 use std::fs;
 
@@ -9,6 +10,7 @@ criterion_main!(benches);
 criterion_group!(benches, fake_splits, actual_splits);
 
 fn run_with_splits(timer: &mut Timer, splits: &[f64]) {
+    timer.set_game_time_ahead_policy(GameTimeAheadPolicy::Allow);
     timer.start().unwrap();
     timer.initialize_game_time().unwrap();
     timer.pause_game_time().unwrap();
@@ -9,6 +9,7 @@ cfg_if::cfg_if! {
             },
             run::parser::livesplit,
             settings::{Font, ImageCache},
+            timing::GameTimeAheadPolicy,
             Run, Segment, TimeSpan, Timer, TimingMethod,
         };
         use std::fs;
@@ -145,6 +146,7 @@ cfg_if::cfg_if! {
         }
 
         fn start_run(timer: &mut Timer) {
+            timer.set_game_time_ahead_policy(GameTimeAheadPolicy::Allow);
             timer.set_current_timing_method(TimingMethod::GameTime);
             timer.start().unwrap();
             timer.initialize_game_time().unwrap();
@@ -3,17 +3,18 @@ cfg_if::cfg_if! {
         use {
             criterion::{criterion_group, criterion_main, Criterion},
             livesplit_core::{
-                layout::{self, Layout},
+                layout::{self, Layout, LayoutState},
                 rendering::software::Renderer,
                 run::parser::livesplit,
                 settings::ImageCache,
+                timing::GameTimeAheadPolicy,
                 Run, Segment, TimeSpan, Timer, TimingMethod,
             },
             std::fs,
         };
 
         criterion_main!(benches);
-        criterion_group!(benches, default, subsplits_layout);
+        criterion_group!(benches, default, subsplits_layout, running_timer);
 
         fn default(c: &mut Criterion) {
             let mut run = create_run(&["A", "B", "C", "D"]);
@@ -52,6 +53,61 @@ cfg_if::cfg_if! {
             });
         }
 
+        fn running_timer(c: &mut Criterion) {
+            let mut timer = Timer::new(create_run(&["A", "B", "C", "D"])).unwrap();
+            let mut layout = Layout::default_layout();
+            let mut image_cache = ImageCache::new();
+
+            start_run(&mut timer);
+
+            let mut state = layout.state(&mut image_cache, &timer.snapshot());
+            let mut renderer = Renderer::new();
+
+            let rendered = render_frames(
+                &mut timer,
+                &mut layout,
+                &mut image_cache,
+                &mut state,
+                &mut renderer,
+            );
+            println!("Rendered {rendered} out of 1000 frames of a running timer");
+
+            c.bench_function("Software Rendering (Running Timer, Only Changes)", move |b| {
+                b.iter(|| {
+                    render_frames(
+                        &mut timer,
+                        &mut layout,
+                        &mut image_cache,
+                        &mut state,
+                        &mut renderer,
+                    )
+                })
+            });
+        }
+
+        fn render_frames(
+            timer: &mut Timer,
+            layout: &mut Layout,
+            image_cache: &mut ImageCache,
+            state: &mut LayoutState,
+            renderer: &mut Renderer,
+        ) -> usize {
+            let mut rendered = 0;
+            for frame in 0..1000 {
+                timer
+                    .set_game_time(TimeSpan::from_milliseconds(frame as f64))
+                    .unwrap();
+                layout.update_state(state, image_cache, &timer.snapshot());
+                if renderer
+                    .render_if_changed(state, image_cache, [300, 500])
+                    .is_some()
+                {
+                    rendered += 1;
+                }
+            }
+            rendered
+        }
+
         fn file(path: &str) -> String {
             fs::read_to_string(path).unwrap()
         }
@@ -73,6 +129,7 @@ cfg_if::cfg_if! {
         }
 
         fn start_run(timer: &mut Timer) {
+            timer.set_game_time_ahead_policy(GameTimeAheadPolicy::Allow);
             timer.set_current_timing_method(TimingMethod::GameTime);
             timer.start().unwrap();
             timer.initialize_game_time().unwrap();
@@ -7,6 +7,7 @@ cfg_if::cfg_if! {
                 rendering::svg::Renderer,
                 run::parser::livesplit,
                 settings::ImageCache,
+                timing::GameTimeAheadPolicy,
                 Run, Segment, TimeSpan, Timer, TimingMethod,
             },
             std::fs,
@@ -81,6 +82,7 @@ cfg_if::cfg_if! {
         }
 
         fn start_run(timer: &mut Timer) {
+            timer.set_game_time_ahead_policy(GameTimeAheadPolicy::Allow);
             timer.set_current_timing_method(TimingMethod::GameTime);
             timer.start().unwrap();
             timer.initialize_game_time().unwrap();
@@ -25,7 +25,8 @@ export type Gradient =
     "Transparent" |
     { Plain: Color } |
     { Vertical: Color[] } |
-    { Horizontal: Color[] };
+    { Horizontal: Color[] } |
+    { Angular: [Color, Color, number] };
 
 /**
  * Describes an extended form of a gradient, specifically made for use with
@@ -109,6 +110,16 @@ export interface LayoutStateJson {
     separators_color: Color,
     /** The text color to use for text that doesn't specify its own color. */
     text_color: Color,
+    /**
+     * The color of the outline drawn around all the text. `null` means no
+     * outline is drawn.
+     */
+    text_outline_color: Color | null,
+    /**
+     * The width of the outline drawn around the text in percent of the size
+     * of the text.
+     */
+    text_outline_width: number,
 }
 
 /**
@@ -216,6 +227,21 @@ export enum TimerPhase {
     Paused = 3,
 }
 
+/**
+ * Describes what the timer does when a split is to be undone while the attempt
+ * is still on the first split, so there is no split to undo.
+ */
+export enum FirstSplitUndoPolicy {
+    /** Nothing happens and the split can't be undone. */
+    Ignore = 0,
+    /**
+     * The start of the attempt is undone. The attempt is discarded without
+     * storing it in the Run's history and the attempt count is reverted, as if
+     * the timer had never been started.
+     */
+    UndoStart = 1,
+}
+
 /** An event informs you about a change in the timer. */
 export enum Event {
     /** The timer has been started. */
@@ -259,6 +285,15 @@ export enum Event {
     LoadingTimesSet = 16,
     /** A custom variable has been set. */
     CustomVariableSet = 17,
+    /** A bookmark has been added to the current attempt. */
+    BookmarkAdded = 18,
+    /** The most recent reset has been undone. */
+    ResetUndone = 19,
+    /**
+     * The start of the attempt has been undone, because the first split was
+     * undone.
+     */
+    StartUndone = 20,
 }
 
 /** An error that occurred when a command was being processed. */
@@ -297,6 +332,8 @@ export enum CommandError {
     TimerPaused = -16,
     /** The runner decided to not reset the run. */
     RunnerDecidedAgainstReset = -17,
+    /** There is no reset that can be undone. */
+    NoResetToUndo = -18,
 }
 
 /** The result of a command that was processed. */
@@ -743,6 +780,7 @@ export type SettingsDescriptionValueJson =
     { OptionalString: string | null } |
     { Accuracy: AccuracyJson } |
     { DigitsFormat: DigitsFormatJson } |
+    { DeltaSigns: DeltaSignsJson } |
     { OptionalTimingMethod: TimingMethodJson | null } |
     { Color: Color } |
     { OptionalColor: Color | null } |
@@ -833,6 +871,13 @@ export type DigitsFormatJson =
     "SingleDigitHours" |
     "DoubleDigitHours";
 
+/**
+ * Describes which signs are shown in front of deltas. A positive delta means
+ * that time was lost compared to the comparison, while a negative delta means
+ * that time was saved.
+ */
+export type DeltaSignsJson = "Both" | "OnlyPlus" | "OnlyMinus";
+
 /**
  * Represents the current state of the Run Editor in order to visualize it
  * properly.
@@ -858,6 +903,11 @@ export interface RunEditorStateJson {
      * is mostly just a visual number and has no effect on any history.
      */
     attempts: number,
+    /**
+     * The index of the segment at which the game time stops. If this is
+     * `null`, the game time keeps running until the final split.
+     */
+    game_time_end_index: number | null,
     /**
      * The timing method that is currently selected to be visualized and
      * edited.
@@ -963,6 +1013,10 @@ export interface RunEditorButtonsJson {
      * moved.
      */
     can_move_down: boolean,
+    /** Describes whether there is an edit that can be undone. */
+    can_undo: boolean,
+    /** Describes whether there is an undone edit that can be redone. */
+    can_redo: boolean,
 }
 
 /** Describes the current state of a segment. */
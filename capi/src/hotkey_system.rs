@@ -19,19 +19,20 @@ pub type NullableOwnedHotkeySystem = Option<OwnedHotkeySystem>;
 /// Creates a new Hotkey System for a Timer with the default hotkeys.
 #[no_mangle]
 pub extern "C" fn HotkeySystem_new(command_sink: &CommandSink) -> NullableOwnedHotkeySystem {
-    HotkeySystem::new(command_sink.clone()).ok().map(Box::new)
+    HotkeySystem_with_config(command_sink, Default::default())
 }
 
 /// Creates a new Hotkey System for a Timer with a custom configuration for the
-/// hotkeys.
+/// hotkeys. This includes the hotkeys for switching to a comparison by its
+/// index.
 #[no_mangle]
 pub extern "C" fn HotkeySystem_with_config(
     command_sink: &CommandSink,
     config: OwnedHotkeyConfig,
 ) -> NullableOwnedHotkeySystem {
-    HotkeySystem::with_config(command_sink.clone(), *config)
-        .ok()
-        .map(Box::new)
+    let mut hotkey_system = HotkeySystem::with_config(command_sink.clone(), *config).ok()?;
+    hotkey_system.enable_select_comparison().ok()?;
+    Some(Box::new(hotkey_system))
 }
 
 /// drop
@@ -44,6 +44,14 @@ pub extern "C" fn LayoutState_as_json(this: &LayoutState) -> Json {
     })
 }
 
+/// Returns a counter that advances whenever updating the Layout State changed
+/// any of the information to visualize. You can skip rendering for as long as
+/// it stays the same as the last one you rendered.
+#[no_mangle]
+pub extern "C" fn LayoutState_generation(this: &LayoutState) -> u64 {
+    this.generation()
+}
+
 /// Gets the number of Components in the Layout State.
 #[no_mangle]
 pub extern "C" fn LayoutState_len(this: &LayoutState) -> usize {
@@ -63,6 +63,7 @@ pub mod segment_history;
 pub mod segment_history_element;
 pub mod segment_history_iter;
 pub mod segment_time_component;
+pub mod segments_remaining_component;
 pub mod separator_component;
 pub mod separator_component_state;
 #[cfg(all(target_family = "wasm", feature = "wasm-web"))]
@@ -74,10 +75,12 @@ pub mod splits_component;
 pub mod splits_component_state;
 pub mod sum_of_best_cleaner;
 pub mod sum_of_best_component;
+pub mod sum_of_best_delta_component;
 pub mod text_component;
 pub mod text_component_state;
 pub mod time;
 pub mod time_span;
+pub mod time_to_next_split_component;
 pub mod timer;
 pub mod timer_component;
 pub mod timer_component_state;
@@ -117,6 +117,22 @@ pub unsafe extern "C" fn RunEditor_parse_and_set_attempt_count(
     this.parse_and_set_attempt_count(str(attempts)).is_ok()
 }
 
+/// Sets the index of the segment at which the game time stops. Once this
+/// segment is split, the game time is frozen for the remainder of the attempt,
+/// while the real time keeps running. Returns <FALSE> if the index doesn't
+/// refer to any of the segments, in which case nothing changes.
+#[no_mangle]
+pub extern "C" fn RunEditor_set_game_time_end_index(this: &mut RunEditor, index: usize) -> bool {
+    this.set_game_time_end_index(Some(index)).is_ok()
+}
+
+/// Removes the game time end, so that the game time keeps running until the
+/// final split.
+#[no_mangle]
+pub extern "C" fn RunEditor_remove_game_time_end_index(this: &mut RunEditor) {
+    let _ = this.set_game_time_end_index(None);
+}
+
 /// Sets the game's icon.
 #[no_mangle]
 pub unsafe extern "C" fn RunEditor_set_game_icon(
@@ -263,6 +279,21 @@ pub extern "C" fn RunEditor_remove_segments(this: &mut RunEditor) {
     this.remove_segments();
 }
 
+/// Undoes the most recent edit. The segments that were selected before the
+/// edit are selected again. If there is no edit to undo, nothing happens.
+#[no_mangle]
+pub extern "C" fn RunEditor_undo(this: &mut RunEditor) {
+    this.undo();
+}
+
+/// Redoes the most recently undone edit. Doing any other edit in the meantime
+/// discards the edits that can be redone. If there is no edit to redo, nothing
+/// happens.
+#[no_mangle]
+pub extern "C" fn RunEditor_redo(this: &mut RunEditor) {
+    this.redo();
+}
+
 /// Moves all the selected segments up, unless the first segment is
 /// selected. The run's information is automatically adjusted properly. The
 /// active segment stays the active segment.
@@ -383,6 +414,15 @@ pub unsafe extern "C" fn RunEditor_remove_comparison(
     this.remove_comparison(str(comparison));
 }
 
+/// Merges the custom comparisons whose names only differ in their casing or in
+/// leading and trailing whitespace. The first of these comparisons is kept and
+/// receives the times of the others wherever it doesn't have a time itself.
+/// The Personal Best is never merged with any other comparison.
+#[no_mangle]
+pub extern "C" fn RunEditor_deduplicate_comparisons(this: &mut RunEditor) {
+    this.deduplicate_comparisons();
+}
+
 /// Renames a comparison. The comparison can't be renamed if the new name of
 /// the comparison starts with `[Race]` or it already exists.
 #[no_mangle]
@@ -57,3 +57,16 @@ pub extern "C" fn RunMetadata_custom_variables(
 ) -> OwnedRunMetadataCustomVariablesIter {
     Box::new(this.custom_variables())
 }
+
+/// Returns the amount of runners participating in the run.
+#[no_mangle]
+pub extern "C" fn RunMetadata_runners_len(this: &RunMetadata) -> usize {
+    this.runners().len()
+}
+
+/// Accesses the name of the runner at the index specified. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunMetadata_runner(this: &RunMetadata, index: usize) -> *const c_char {
+    output_str(&this.runners()[index])
+}
@@ -0,0 +1,53 @@
+//! The Segments Remaining Component is a component that shows how many
+//! segments are left in the current attempt out of the total amount of
+//! segments.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::segments_remaining::Component as SegmentsRemainingComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedSegmentsRemainingComponent = Box<SegmentsRemainingComponent>;
+
+/// Creates a new Segments Remaining Component.
+#[no_mangle]
+pub extern "C" fn SegmentsRemainingComponent_new() -> OwnedSegmentsRemainingComponent {
+    Box::new(SegmentsRemainingComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentsRemainingComponent_drop(this: OwnedSegmentsRemainingComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SegmentsRemainingComponent_into_generic(
+    this: OwnedSegmentsRemainingComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SegmentsRemainingComponent_state_as_json(
+    this: &mut SegmentsRemainingComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn SegmentsRemainingComponent_state(
+    this: &mut SegmentsRemainingComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(timer))
+}
@@ -12,7 +12,7 @@ use livesplit_core::{
         Alignment, BackgroundImage, Color, ColumnKind, Font, FontStretch, FontStyle, FontWeight,
         Gradient, ImageId, LayoutBackground, ListGradient, Value as SettingValue,
     },
-    timing::formatter::{Accuracy, DigitsFormat},
+    timing::formatter::{Accuracy, DeltaSigns, DigitsFormat},
     TimingMethod,
 };
 use std::{os::raw::c_char, str::FromStr};
@@ -115,6 +115,22 @@ pub unsafe extern "C" fn SettingValue_from_digits_format(
     Some(Box::new(value.into()))
 }
 
+/// Creates a new setting value from the name of the signs to show in front of
+/// deltas. If it doesn't match a known value, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_delta_signs(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Both" => DeltaSigns::Both,
+        "OnlyPlus" => DeltaSigns::OnlyPlus,
+        "OnlyMinus" => DeltaSigns::OnlyMinus,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
 /// Creates a new setting value from a timing method name with the type
 /// `optional timing method`. If it doesn't match a known timing method, <NULL>
 /// is returned.
@@ -201,6 +217,30 @@ pub extern "C" fn SettingValue_from_horizontal_gradient(
     Box::new(Gradient::Horizontal(Color::rgba(r1, g1, b1, a1), Color::rgba(r2, g2, b2, a2)).into())
 }
 
+/// Creates a new setting value from the angular gradient provided as two RGBA
+/// colors and the angle of the gradient in degrees.
+#[no_mangle]
+pub extern "C" fn SettingValue_from_angular_gradient(
+    r1: f32,
+    g1: f32,
+    b1: f32,
+    a1: f32,
+    r2: f32,
+    g2: f32,
+    b2: f32,
+    a2: f32,
+    angle_degrees: f32,
+) -> OwnedSettingValue {
+    Box::new(
+        Gradient::Angular(
+            Color::rgba(r1, g1, b1, a1),
+            Color::rgba(r2, g2, b2, a2),
+            angle_degrees,
+        )
+        .into(),
+    )
+}
+
 /// Creates a new setting value from the alternating gradient provided as two RGBA colors.
 #[no_mangle]
 pub extern "C" fn SettingValue_from_alternating_gradient(
@@ -20,6 +20,33 @@ pub extern "C" fn SplitsComponentState_final_separator_shown(this: &SplitsCompon
     this.show_final_separator
 }
 
+/// Describes whether grid lines should be shown between the columns.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_column_grid_lines_shown(
+    this: &SplitsComponentState,
+) -> bool {
+    this.show_column_grid_lines
+}
+
+/// Returns the amount of boundaries between adjacent columns. There is one
+/// boundary for each gap between two columns.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_column_boundaries_len(this: &SplitsComponentState) -> usize {
+    this.column_boundaries.len()
+}
+
+/// Returns the boundary with the specified index. The boundaries are specified
+/// from right to left. Each boundary is the combined width of all the columns
+/// to the right of it, measured in the number of characters of the longest
+/// value of each column. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_column_boundary(
+    this: &SplitsComponentState,
+    index: usize,
+) -> usize {
+    this.column_boundaries[index]
+}
+
 /// Returns the amount of segments to visualize.
 #[no_mangle]
 pub extern "C" fn SplitsComponentState_len(this: &SplitsComponentState) -> usize {
@@ -0,0 +1,54 @@
+//! The Sum of Best Delta Component shows how far the predicted final time of
+//! the current attempt is away from the Sum of Best Segments, which is the
+//! fastest time that is theoretically possible based on all the previous
+//! attempts.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::sum_of_best_delta::Component as SumOfBestDeltaComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedSumOfBestDeltaComponent = Box<SumOfBestDeltaComponent>;
+
+/// Creates a new Sum of Best Delta Component.
+#[no_mangle]
+pub extern "C" fn SumOfBestDeltaComponent_new() -> OwnedSumOfBestDeltaComponent {
+    Box::new(SumOfBestDeltaComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SumOfBestDeltaComponent_drop(this: OwnedSumOfBestDeltaComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SumOfBestDeltaComponent_into_generic(
+    this: OwnedSumOfBestDeltaComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SumOfBestDeltaComponent_state_as_json(
+    this: &SumOfBestDeltaComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot()).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn SumOfBestDeltaComponent_state(
+    this: &SumOfBestDeltaComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(&timer.snapshot()))
+}
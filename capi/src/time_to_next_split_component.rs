@@ -0,0 +1,53 @@
+//! The Time to Next Split Component shows a live countdown to the time at
+//! which the current split is expected to happen, based on the chosen
+//! comparison.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::time_to_next_split::Component as TimeToNextSplitComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedTimeToNextSplitComponent = Box<TimeToNextSplitComponent>;
+
+/// Creates a new Time to Next Split Component.
+#[no_mangle]
+pub extern "C" fn TimeToNextSplitComponent_new() -> OwnedTimeToNextSplitComponent {
+    Box::new(TimeToNextSplitComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn TimeToNextSplitComponent_drop(this: OwnedTimeToNextSplitComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn TimeToNextSplitComponent_into_generic(
+    this: OwnedTimeToNextSplitComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn TimeToNextSplitComponent_state_as_json(
+    this: &TimeToNextSplitComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot()).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn TimeToNextSplitComponent_state(
+    this: &TimeToNextSplitComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(&timer.snapshot()))
+}
@@ -8,6 +8,7 @@ use crate::{
 use livesplit_core::{
     event::{Error, Event},
     run::saver::{self, livesplit::IoWrite},
+    timing::FirstSplitUndoPolicy,
     Run, Time, TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 use std::{os::raw::c_char, ptr};
@@ -128,7 +129,8 @@ pub extern "C" fn Timer_skip_split(this: &mut Timer) -> i32 {
 
 /// Removes the split time from the last split if an attempt is in progress
 /// and there is a previous split. The Timer Phase also switches to
-/// `Running` if it previously was `Ended`.
+/// `Running` if it previously was `Ended`. If the attempt is still on the
+/// first split, the first split undo policy decides what happens.
 #[no_mangle]
 pub extern "C" fn Timer_undo_split(this: &mut Timer) -> i32 {
     convert(this.undo_split())
@@ -218,6 +220,24 @@ pub extern "C" fn Timer_toggle_timing_method(this: &mut Timer) {
     this.toggle_timing_method();
 }
 
+/// Returns what happens when undoing a split while the attempt is still on the
+/// first split.
+#[no_mangle]
+pub extern "C" fn Timer_first_split_undo_policy(this: &Timer) -> FirstSplitUndoPolicy {
+    this.first_split_undo_policy()
+}
+
+/// Sets what happens when undoing a split while the attempt is still on the
+/// first split. By default nothing happens. Alternatively the start of the
+/// attempt can be undone, as if the Timer had never been started.
+#[no_mangle]
+pub extern "C" fn Timer_set_first_split_undo_policy(
+    this: &mut Timer,
+    policy: FirstSplitUndoPolicy,
+) {
+    this.set_first_split_undo_policy(policy);
+}
+
 /// Returns the current comparison that is being compared against. This may
 /// be a custom comparison or one of the Comparison Generators.
 #[no_mangle]
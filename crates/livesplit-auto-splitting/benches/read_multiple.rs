@@ -0,0 +1,41 @@
+//! Compares reading many values from a process one at a time with reading them
+//! in a single batch. Each individual read crosses the WebAssembly boundary and
+//! issues a syscall, while the batch does both only once.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use livesplit_auto_splitting::{AutoSplitter, Config, Runtime};
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::{build, spawn_helper, TestTimer};
+
+criterion_main!(benches);
+criterion_group!(benches, individual_reads, batched_read);
+
+fn auto_splitter(crate_name: &str) -> AutoSplitter<TestTimer> {
+    Runtime::new(Config::default())
+        .unwrap()
+        .compile(&build(crate_name))
+        .unwrap()
+        .instantiate(TestTimer::default(), None, None)
+        .unwrap()
+}
+
+fn individual_reads(c: &mut Criterion) {
+    let _helper = spawn_helper();
+    let auto_splitter = auto_splitter("bench-read-individually");
+
+    c.bench_function("64 Individual Reads", |b| {
+        b.iter(|| auto_splitter.lock().update().unwrap())
+    });
+}
+
+fn batched_read(c: &mut Criterion) {
+    let _helper = spawn_helper();
+    let auto_splitter = auto_splitter("bench-read-batched");
+
+    c.bench_function("64 Batched Reads", |b| {
+        b.iter(|| auto_splitter.lock().update().unwrap())
+    });
+}
@@ -39,6 +39,13 @@
 //! #[repr(transparent)]
 //! pub struct ProcessId(u64);
 //!
+//! #[repr(C, packed)]
+//! pub struct ReadDescriptor {
+//!     pub address: Address,
+//!     pub buf_offset: u32,
+//!     pub len: u32,
+//! }
+//!
 //! #[repr(transparent)]
 //! pub struct SettingsMap(NonZeroU64);
 //!
@@ -99,6 +106,34 @@
 //! extern "C" {
 //!     /// Gets the state that the timer currently is in.
 //!     pub fn timer_get_state() -> TimerState;
+//!     /// Gets the real time that has elapsed in the current attempt in
+//!     /// nanoseconds. This is `-1` if there is no attempt in progress.
+//!     pub fn timer_get_elapsed_real_time() -> i64;
+//!     /// Gets the total number of attempts of the run. If there is an attempt in
+//!     /// progress, it is included in the count.
+//!     pub fn timer_get_attempt_count() -> u64;
+//!     /// Gets the number of attempts of the run that have been finished.
+//!     pub fn timer_get_finished_runs_count() -> u64;
+//!     /// Gets the name of the comparison that the timer is currently comparing
+//!     /// against by storing it into the buffer provided. Returns `false` if the
+//!     /// buffer is too small. After this call, no matter whether it was
+//!     /// successful or not, the `buf_len_ptr` will be set to the required buffer
+//!     /// size. The name is guaranteed to be valid UTF-8 and is not
+//!     /// nul-terminated.
+//!     pub fn timer_get_comparison(buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
+//!     /// Gets the name of the segment that the timer is currently on by storing
+//!     /// it into the buffer provided. Returns `false` if there is no attempt in
+//!     /// progress, the attempt has ended or the buffer is too small. After this
+//!     /// call, no matter whether it was successful or not, the `buf_len_ptr`
+//!     /// will be set to the required buffer size, which is `0` if there is no
+//!     /// current segment. The name is guaranteed to be valid UTF-8 and is not
+//!     /// nul-terminated.
+//!     pub fn timer_current_split_name(buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
+//!     /// Queries whether the segment at the index provided was split or skipped
+//!     /// in the current attempt. Returns `1` if the segment was split, `0` if it
+//!     /// was skipped and `-1` if the timer didn't get past the segment yet,
+//!     /// which is the case for every index if there is no attempt in progress.
+//!     pub fn timer_segment_splitted(idx: u64) -> i32;
 //!
 //!     /// Starts the timer.
 //!     pub fn timer_start();
@@ -110,6 +145,9 @@
 //!     pub fn timer_undo_split();
 //!     /// Resets the timer.
 //!     pub fn timer_reset();
+//!     /// Removes all the pause times from the current attempt. If the attempt
+//!     /// is paused, it also gets resumed.
+//!     pub fn timer_undo_all_pauses();
 //!     /// Sets a custom key value pair. This may be arbitrary information that the
 //!     /// auto splitter wants to provide for visualization. The pointers need to
 //!     /// point to valid UTF-8 encoded text with the respective given length.
@@ -122,6 +160,10 @@
 //!
 //!     /// Sets the game time.
 //!     pub fn timer_set_game_time(secs: i64, nanos: i32);
+//!     /// Sets the total amount of time the game has been loading in the
+//!     /// current attempt. The game time is then real time minus the loading
+//!     /// times.
+//!     pub fn timer_set_loading_times(secs: i64, nanos: i32);
 //!     /// Pauses the game time. This does not pause the timer, only the
 //!     /// automatic flow of time for the game time.
 //!     pub fn timer_pause_game_time();
@@ -156,9 +198,31 @@
 //!         list_ptr: *mut ProcessId,
 //!         list_len_ptr: *mut usize,
 //!     ) -> bool;
+//!     /// Lists the processes that the auto splitter is currently attached to.
+//!     /// Multiple processes can be attached to at the same time, such as a
+//!     /// launcher and the game itself. Returns `false` if listing the processes
+//!     /// failed. If it was successful, the buffer is now filled with the
+//!     /// handles of the attached processes. They are in no specific order. The
+//!     /// `list_len_ptr` will be updated to the amount of attached processes. If
+//!     /// this is larger than the original value provided, the buffer provided
+//!     /// was too small and not all handles could be stored. This is still
+//!     /// considered successful and can optionally be treated as an error
+//!     /// condition by the caller by checking if the length increased and
+//!     /// potentially reallocating a larger buffer. If the length decreased after
+//!     /// the call, the buffer was larger than needed and the remaining entries
+//!     /// are untouched.
+//!     pub fn process_list_attached(
+//!         list_ptr: *mut AttachedProcess,
+//!         list_len_ptr: *mut usize,
+//!     ) -> bool;
 //!     /// Checks whether a process is still open. You should detach from a
 //!     /// process and stop using it if this returns `false`.
 //!     pub fn process_is_open(process: AttachedProcess) -> bool;
+//!     /// Gets the generation of a process. It starts at `0` and is incremented
+//!     /// whenever the process got relaunched within the grace period and the
+//!     /// runtime reattached to it. Any addresses found in the process before
+//!     /// are no longer valid once its generation changes.
+//!     pub fn process_get_generation(process: AttachedProcess) -> u64;
 //!     /// Reads memory from a process at the address given. This will write
 //!     /// the memory to the buffer given. Returns `false` if this fails.
 //!     pub fn process_read(
@@ -167,6 +231,22 @@
 //!         buf_ptr: *mut u8,
 //!         buf_len: usize,
 //!     ) -> bool;
+//!     /// Reads memory from a process at multiple addresses at once. The memory
+//!     /// at the address of each descriptor is written into the buffer given at
+//!     /// the offset specified by the descriptor. Reads close to each other are
+//!     /// coalesced by the runtime. For each descriptor a status word is written
+//!     /// to the status buffer, which is `1` if the read succeeded and `0`
+//!     /// otherwise. The part of the buffer of a failed read is zeroed out.
+//!     /// Returns `true` even if some or all of the reads failed, as their
+//!     /// status is reported through the status buffer.
+//!     pub fn process_read_multiple(
+//!         process: AttachedProcess,
+//!         descriptors_ptr: *const ReadDescriptor,
+//!         descriptors_len: usize,
+//!         buf_ptr: *mut u8,
+//!         buf_len: usize,
+//!         status_ptr: *mut u32,
+//!     ) -> bool;
 //!     /// Gets the address of a module in a process. The pointer needs to point to
 //!     /// valid UTF-8 encoded text with the given length.
 //!     pub fn process_get_module_address(
@@ -223,10 +303,31 @@
 //!     pub fn process_get_memory_range_size(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
 //!     /// Gets the flags of a memory range by its index.
 //!     pub fn process_get_memory_range_flags(process: AttachedProcess, idx: u64) -> Option<MemoryRangeFlags>;
+//!     /// Scans the memory of a process for the first occurrence of a byte
+//!     /// pattern within the given range and returns its address. The mask has
+//!     /// the same length as the pattern. Only the bits that are set in the mask
+//!     /// need to match, so a mask byte of `0` is a wildcard. The range is
+//!     /// clamped to the readable memory ranges of the process and parts of it
+//!     /// that can't be read are skipped. Use the memory ranges of the process to
+//!     /// only scan specific ranges, such as the executable ones. Returns `None`
+//!     /// if the pattern is not found or the pattern is empty.
+//!     pub fn process_scan_signature(
+//!         process: AttachedProcess,
+//!         range_address: Address,
+//!         range_len: u64,
+//!         pattern_ptr: *const u8,
+//!         pattern_len: usize,
+//!         mask_ptr: *const u8,
+//!     ) -> Option<NonZeroAddress>;
 //!
 //!     /// Sets the tick rate of the runtime. This influences the amount of
 //!     /// times the `update` function is called per second.
 //!     pub fn runtime_set_tick_rate(ticks_per_second: f64);
+//!     /// Requests the next `update` to only be called after the given amount of
+//!     /// seconds. This only affects a single tick. Afterwards the runtime goes
+//!     /// back to the regular tick rate. This is useful for doing expensive work,
+//!     /// such as scanning memory, and then idling for a while.
+//!     pub fn runtime_request_slow_tick(seconds: f64);
 //!     /// Prints a log message for debugging purposes. The pointer needs to point
 //!     /// to valid UTF-8 encoded text with the given length.
 //!     pub fn runtime_print_message(text_ptr: *const u8, text_len: usize);
@@ -257,6 +358,22 @@
 //!         description_len: usize,
 //!         default_value: bool,
 //!     ) -> bool;
+//!     /// Adds a new integer setting that the user can modify. This will return
+//!     /// either the specified default value or the value that the user has set.
+//!     /// The value is always clamped to the inclusive range from `min` to `max`.
+//!     /// The minimum must not be larger than the maximum. The key is used to
+//!     /// store the setting in the settings map and needs to be unique across all
+//!     /// types of settings. The pointers need to point to valid UTF-8 encoded
+//!     /// text with the respective given length.
+//!     pub fn user_settings_add_int(
+//!         key_ptr: *const u8,
+//!         key_len: usize,
+//!         description_ptr: *const u8,
+//!         description_len: usize,
+//!         default_value: i64,
+//!         min: i64,
+//!         max: i64,
+//!     ) -> i64;
 //!     /// Adds a new title to the user settings. This is used to group settings
 //!     /// together. The heading level determines the size of the title. The top
 //!     /// level titles use a heading level of 0. The key needs to be unique across
@@ -451,6 +568,12 @@
 //!     /// happens, you still retain ownership of the setting value, which means
 //!     /// you still need to free it.
 //!     pub fn settings_list_insert(list: SettingsList, idx: u64, value: SettingValue) -> bool;
+//!     /// Removes the setting value at the index given from the settings list.
+//!     /// Returns `false` if the index is out of bounds, in which case the
+//!     /// settings list is left unchanged.
+//!     pub fn settings_list_remove(list: SettingsList, idx: u64) -> bool;
+//!     /// Removes all the setting values from the settings list.
+//!     pub fn settings_list_clear(list: SettingsList);
 //!
 //!     /// Creates a new setting value from a settings map. The value is a copy of
 //!     /// the settings map. Any changes to the original settings map afterwards
@@ -572,7 +695,7 @@ pub mod wasi_path;
 pub use process::Process;
 pub use runtime::{
     AutoSplitter, CompiledAutoSplitter, Config, CreationError, ExecutionGuard, InterruptHandle,
-    Runtime,
+    RequiredFeatures, Runtime,
 };
 pub use time;
 pub use timer::{LogLevel, Timer, TimerState};
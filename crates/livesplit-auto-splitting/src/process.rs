@@ -2,6 +2,7 @@
 
 use std::{
     io,
+    ops::Range,
     time::{Duration, Instant},
 };
 
@@ -27,6 +28,157 @@ pub enum ModuleError {
 
 pub type Address = u64;
 
+/// The size of a page of memory. A read starting on the same page that another
+/// read ends on is coalesced with it, as reading the gap is cheaper than
+/// issuing another read.
+const PAGE_SIZE: Address = 4096;
+
+/// The maximum length of a coalesced read. Reads are only coalesced as long as
+/// the merged range stays within this length, so the auto splitter can't cause
+/// arbitrarily large buffers to be allocated by spreading its reads out.
+const MAX_COALESCED_READ_LEN: Address = 64 << 10;
+
+/// A single read of a batched memory read. The memory at the address is
+/// supposed to be read into the output buffer at the offset specified.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) struct ReadDescriptor {
+    pub address: Address,
+    pub buf_offset: u32,
+    pub len: u32,
+}
+
+impl ReadDescriptor {
+    fn end(&self) -> Option<Address> {
+        self.address.checked_add(self.len as Address)
+    }
+}
+
+/// A byte pattern to scan memory for. Only the bits set in the mask need to
+/// match.
+pub(super) struct Signature {
+    pattern: Vec<u8>,
+    mask: Vec<u8>,
+    shifts: [usize; 256],
+}
+
+impl Signature {
+    /// Creates a new signature. The pattern and the mask need to have the same
+    /// length, which can't be empty.
+    pub fn new(pattern: &[u8], mask: &[u8]) -> Option<Self> {
+        if pattern.is_empty() || pattern.len() != mask.len() {
+            return None;
+        }
+
+        let pattern = pattern
+            .iter()
+            .zip(mask)
+            .map(|(p, m)| p & m)
+            .collect::<Vec<_>>();
+        let last = pattern.len() - 1;
+
+        // Boyer-Moore-Horspool: The shift for each byte is determined by the
+        // last position before the end of the pattern that the byte could
+        // match. With wildcards, a byte may match at more positions, which
+        // only ever makes the shifts smaller.
+        let mut shifts = [pattern.len(); 256];
+        for (byte, shift) in shifts.iter_mut().enumerate() {
+            if let Some(index) = (0..last)
+                .rev()
+                .find(|&i| byte as u8 & mask[i] == pattern[i])
+            {
+                *shift = last - index;
+            }
+        }
+
+        Some(Self {
+            pattern,
+            mask: mask.to_vec(),
+            shifts,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    fn matches_at(&self, haystack: &[u8]) -> bool {
+        haystack
+            .iter()
+            .zip(&self.mask)
+            .zip(&self.pattern)
+            .all(|((h, m), p)| h & m == *p)
+    }
+
+    /// Finds the offset of the first occurrence of the signature.
+    fn find(&self, haystack: &[u8]) -> Option<usize> {
+        let last = self.len() - 1;
+        let mut offset = 0;
+        while offset + last < haystack.len() {
+            if self.matches_at(&haystack[offset..]) {
+                return Some(offset);
+            }
+            offset += self.shifts[haystack[offset + last] as usize];
+        }
+        None
+    }
+}
+
+/// The contiguous memory that is currently being scanned for a signature. It
+/// keeps the end of the previously read memory around, so matches spanning
+/// across multiple reads are found as well.
+struct Window {
+    bytes: Vec<u8>,
+    start: Address,
+}
+
+impl Window {
+    /// Reads the range into the window and scans it for the signature. The
+    /// address of the match is returned, if there is one. Otherwise the window
+    /// only keeps the bytes needed for the next read.
+    fn read(
+        &mut self,
+        process: &Process,
+        range: Range<Address>,
+        signature: &Signature,
+    ) -> io::Result<Option<Address>> {
+        let previous_len = self.bytes.len();
+        self.bytes
+            .resize(previous_len + (range.end - range.start) as usize, 0);
+
+        if let Err(e) = process.read_mem(range.start, &mut self.bytes[previous_len..]) {
+            self.bytes.truncate(previous_len);
+            return Err(e);
+        }
+
+        if let Some(offset) = signature.find(&self.bytes) {
+            return Ok(Some(self.start + offset as Address));
+        }
+
+        let keep = self.bytes.len().min(signature.len() - 1);
+        self.bytes.drain(..self.bytes.len() - keep);
+        self.start = range.end - keep as Address;
+
+        Ok(None)
+    }
+
+    fn reset(&mut self, start: Address) {
+        self.bytes.clear();
+        self.start = start;
+    }
+
+    /// The address right after the memory that was read into the window.
+    fn end(&self) -> Address {
+        self.start + self.bytes.len() as Address
+    }
+}
+
+/// A group of reads that can be done as a single read of the memory range.
+#[derive(Debug, PartialEq, Eq)]
+struct CoalescedRead {
+    range: Range<Address>,
+    descriptors: Vec<usize>,
+}
+
 // FIXME: Temporary workaround until this is merged and released:
 // https://github.com/rbspy/read-process-memory/pull/21
 struct UnsafeSendSync<T>(T);
@@ -35,6 +187,35 @@ unsafe impl<T> Send for UnsafeSendSync<T> {}
 // SAFETY: Temporary
 unsafe impl<T> Sync for UnsafeSendSync<T> {}
 
+/// Tracks since when a process is closed, so that it can still be reported as
+/// open for a grace period. This gives the process a chance to get relaunched
+/// and reattached to.
+#[derive(Default)]
+struct ClosedTracker {
+    closed_since: Option<Instant>,
+}
+
+impl ClosedTracker {
+    /// Returns whether the process closed and the grace period didn't run out
+    /// yet. This is also the case if the process is only now found to be
+    /// closed.
+    fn is_tracking(&self, now: Instant, grace_period: Duration) -> bool {
+        self.closed_since
+            .is_none_or(|since| now.saturating_duration_since(since) < grace_period)
+    }
+
+    /// Updates the tracker with whether the process is actually open and
+    /// returns whether it should be reported as open.
+    fn update(&mut self, is_open: bool, now: Instant, grace_period: Duration) -> bool {
+        if is_open {
+            self.closed_since = None;
+            return true;
+        }
+        let since = *self.closed_since.get_or_insert(now);
+        now.saturating_duration_since(since) < grace_period
+    }
+}
+
 /// A process that an auto splitter is attached to.
 pub struct Process {
     handle: UnsafeSendSync<ProcessHandle>,
@@ -43,6 +224,13 @@ pub struct Process {
     next_memory_range_check: Instant,
     next_open_check: Instant,
     path: Option<Box<str>>,
+    /// The name the process was attached by. This is used to reattach to the
+    /// process if it gets relaunched.
+    name: Option<Box<str>>,
+    closed: ClosedTracker,
+    /// The number of times the process got reattached to after it got
+    /// relaunched.
+    generation: u64,
 }
 
 impl std::fmt::Debug for Process {
@@ -82,6 +270,9 @@ impl Process {
             next_memory_range_check: now,
             next_open_check: now + Duration::from_secs(1),
             path,
+            name: Some(name.into()),
+            closed: ClosedTracker::default(),
+            generation: 0,
         })
     }
 
@@ -105,6 +296,9 @@ impl Process {
             next_memory_range_check: now,
             next_open_check: now + Duration::from_secs(1),
             path,
+            name: None,
+            closed: ClosedTracker::default(),
+            generation: 0,
         })
     }
 
@@ -118,14 +312,49 @@ impl Process {
             .map(|p| p.pid().as_u32())
     }
 
-    pub(super) fn is_open(&mut self, process_list: &mut ProcessList) -> bool {
+    pub(super) fn is_open(
+        &mut self,
+        process_list: &mut ProcessList,
+        grace_period: Duration,
+    ) -> bool {
         let now = Instant::now();
         let pid = sysinfo::Pid::from_u32(self.pid as u32);
-        if now >= self.next_open_check {
+        let check = now >= self.next_open_check;
+        if check {
             process_list.refresh_single_process(pid);
             self.next_open_check = now + Duration::from_secs(1);
         }
-        process_list.is_open(pid)
+        let mut is_open = process_list.is_open(pid);
+
+        if !is_open
+            && check
+            && !grace_period.is_zero()
+            && self.closed.is_tracking(now, grace_period)
+        {
+            is_open = self.reattach(process_list);
+        }
+
+        self.closed.update(is_open, now, grace_period)
+    }
+
+    /// Tries to attach to a relaunched process with the same name.
+    fn reattach(&mut self, process_list: &mut ProcessList) -> bool {
+        let Some(name) = &self.name else {
+            return false;
+        };
+        match Process::with_name(name, process_list) {
+            Ok(process) if process.pid != self.pid => {
+                // The memory of the relaunched process is laid out differently,
+                // so the new generation tells the auto splitter that any
+                // addresses it found before are no longer valid.
+                *self = Process {
+                    generation: self.generation + 1,
+                    ..process
+                };
+                true
+            }
+            _ => false,
+        }
     }
 
     pub(super) fn module_address(&mut self, module: &str) -> Result<Address, ModuleError> {
@@ -160,6 +389,145 @@ impl Process {
         self.handle.0.copy_address(address as usize, buf)
     }
 
+    /// Reads all the descriptors into the buffer. Reads that are close to each
+    /// other are coalesced into a single read. Any read that fails is zeroed
+    /// out in the buffer. The status of each read is stored in `success`. The
+    /// caller needs to ensure that all the descriptors are within the bounds
+    /// of the buffer.
+    pub(super) fn read_mem_multiple(
+        &self,
+        descriptors: &[ReadDescriptor],
+        buf: &mut [u8],
+        success: &mut [bool],
+    ) {
+        let mut scratch = Vec::new();
+
+        for read in coalesce_reads(descriptors) {
+            if let [index] = read.descriptors[..] {
+                success[index] = self.read_into(&descriptors[index], buf);
+                continue;
+            }
+
+            scratch.clear();
+            scratch.resize((read.range.end - read.range.start) as usize, 0);
+
+            if self.read_mem(read.range.start, &mut scratch).is_ok() {
+                for index in read.descriptors {
+                    let descriptor = &descriptors[index];
+                    let start = (descriptor.address - read.range.start) as usize;
+                    let dst = sub_buffer(descriptor, buf);
+                    dst.copy_from_slice(&scratch[start..][..dst.len()]);
+                    success[index] = true;
+                }
+            } else {
+                // Parts of the range may not be mapped, so fall back to reading
+                // each of them individually.
+                for index in read.descriptors {
+                    success[index] = self.read_into(&descriptors[index], buf);
+                }
+            }
+        }
+    }
+
+    /// Scans the memory range for the first occurrence of the signature. Each
+    /// byte of the memory is compared with the pattern only for the bits set
+    /// in the mask. So a mask byte of `0` is a wildcard. The range is clamped
+    /// to the readable memory ranges of the process, so unmapped parts of the
+    /// range are never read. The memory is read in chunks. Pages that still
+    /// can't be read are skipped.
+    pub(super) fn scan_signature(
+        &mut self,
+        range: Range<Address>,
+        signature: &Signature,
+    ) -> Option<Address> {
+        self.refresh_memory_ranges().ok()?;
+
+        let mut mapped = self
+            .memory_ranges
+            .iter()
+            .filter(|m| m.is_read())
+            .filter_map(|m| {
+                let start = m.start() as Address;
+                let end = start.saturating_add(m.size() as Address);
+                let clamped = start.max(range.start)..end.min(range.end);
+                (clamped.start < clamped.end).then_some(clamped)
+            })
+            .collect::<Vec<_>>();
+        mapped.sort_unstable_by_key(|range| range.start);
+
+        let mut window = Window {
+            bytes: Vec::new(),
+            start: range.start,
+        };
+
+        for range in mapped {
+            // Matches may only span across memory ranges that are directly
+            // next to each other.
+            if window.end() != range.start {
+                window.reset(range.start);
+            }
+            if let Some(found) = self.scan_mapped_range(range, &mut window, signature) {
+                return Some(found);
+            }
+        }
+
+        None
+    }
+
+    fn scan_mapped_range(
+        &self,
+        range: Range<Address>,
+        window: &mut Window,
+        signature: &Signature,
+    ) -> Option<Address> {
+        const CHUNK_SIZE: Address = 64 << 10;
+
+        let mut address = range.start;
+
+        while address < range.end {
+            let chunk_end = (address - address % PAGE_SIZE)
+                .saturating_add(CHUNK_SIZE)
+                .min(range.end);
+
+            match window.read(self, address..chunk_end, signature) {
+                Ok(Some(found)) => return Some(found),
+                Ok(None) => {
+                    address = chunk_end;
+                    continue;
+                }
+                Err(_) => {}
+            }
+
+            // Parts of the chunk can't be read, so read it page by page to
+            // still scan the pages that are readable.
+            while address < chunk_end {
+                let page_end = (address - address % PAGE_SIZE)
+                    .saturating_add(PAGE_SIZE)
+                    .min(chunk_end);
+                match window.read(self, address..page_end, signature) {
+                    Ok(Some(found)) => return Some(found),
+                    Ok(None) => {}
+                    Err(_) => window.reset(page_end),
+                }
+                address = page_end;
+            }
+        }
+
+        None
+    }
+
+    fn read_into(&self, descriptor: &ReadDescriptor, buf: &mut [u8]) -> bool {
+        let dst = sub_buffer(descriptor, buf);
+        if dst.is_empty() {
+            return true;
+        }
+        let is_ok = descriptor.end().is_some() && self.read_mem(descriptor.address, dst).is_ok();
+        if !is_ok {
+            dst.fill(0);
+        }
+        is_ok
+    }
+
     pub(super) fn get_memory_range_count(&mut self) -> Result<usize, ModuleError> {
         self.refresh_memory_ranges()?;
         Ok(self.memory_ranges.len())
@@ -208,6 +576,12 @@ impl Process {
         self.pid
     }
 
+    /// Returns the generation of the process. It starts at `0` and is
+    /// incremented whenever the process got relaunched and reattached to.
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// Returns the path of the executable of the process.
     pub fn path(&self) -> Option<&str> {
         self.path.as_deref()
@@ -237,3 +611,205 @@ impl Process {
         Ok(())
     }
 }
+
+fn sub_buffer<'b>(descriptor: &ReadDescriptor, buf: &'b mut [u8]) -> &'b mut [u8] {
+    &mut buf[descriptor.buf_offset as usize..][..descriptor.len as usize]
+}
+
+/// Groups the reads by sorting them by their address and merging reads that
+/// either overlap, are adjacent or start on the same page the previous read
+/// ends on. A read is not merged if the merged range would exceed
+/// [`MAX_COALESCED_READ_LEN`].
+fn coalesce_reads(descriptors: &[ReadDescriptor]) -> Vec<CoalescedRead> {
+    let mut order = (0..descriptors.len()).collect::<Vec<_>>();
+    order.sort_unstable_by_key(|&index| descriptors[index].address);
+
+    let mut reads = Vec::<CoalescedRead>::new();
+
+    for index in order {
+        let descriptor = &descriptors[index];
+        let Some(end) = descriptor.end().filter(|_| descriptor.len != 0) else {
+            // Empty and overflowing reads are handled individually.
+            reads.push(CoalescedRead {
+                range: descriptor.address..descriptor.address,
+                descriptors: vec![index],
+            });
+            continue;
+        };
+
+        if let Some(read) = reads.last_mut().filter(|read| {
+            read.range.start != read.range.end
+                && (descriptor.address <= read.range.end
+                    || descriptor.address / PAGE_SIZE == (read.range.end - 1) / PAGE_SIZE)
+                && read.range.end.max(end) - read.range.start <= MAX_COALESCED_READ_LEN
+        }) {
+            read.range.end = read.range.end.max(end);
+            read.descriptors.push(index);
+        } else {
+            reads.push(CoalescedRead {
+                range: descriptor.address..end,
+                descriptors: vec![index],
+            });
+        }
+    }
+
+    reads
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn descriptor(address: Address, len: u32) -> ReadDescriptor {
+        ReadDescriptor {
+            address,
+            buf_offset: 0,
+            len,
+        }
+    }
+
+    #[test]
+    fn closed_process_is_reported_immediately_without_grace_period() {
+        let mut tracker = ClosedTracker::default();
+        let now = Instant::now();
+
+        assert!(tracker.update(true, now, Duration::ZERO));
+        assert!(!tracker.update(false, now, Duration::ZERO));
+    }
+
+    #[test]
+    fn closed_process_can_be_reattached_within_grace_period() {
+        let grace_period = Duration::from_secs(5);
+        let mut tracker = ClosedTracker::default();
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        assert!(tracker.update(true, at(0), grace_period));
+
+        // The process closes, but it's still reported as open, as it may get
+        // relaunched.
+        assert!(tracker.is_tracking(at(1), grace_period));
+        assert!(tracker.update(false, at(1), grace_period));
+        assert!(tracker.is_tracking(at(3), grace_period));
+        assert!(tracker.update(false, at(3), grace_period));
+
+        // The process got relaunched and was reattached to.
+        assert!(tracker.update(true, at(4), grace_period));
+
+        // Closing it again starts a new grace period.
+        assert!(tracker.update(false, at(5), grace_period));
+        assert!(tracker.update(false, at(9), grace_period));
+        assert!(!tracker.is_tracking(at(10), grace_period));
+        assert!(!tracker.update(false, at(10), grace_period));
+    }
+
+    #[test]
+    fn coalesces_reads_on_the_same_page() {
+        let reads = coalesce_reads(&[
+            descriptor(0x1010, 4),
+            descriptor(0x1000, 8),
+            descriptor(0x1008, 8),
+            descriptor(0x1FF0, 0x20),
+        ]);
+        assert_eq!(
+            reads,
+            [CoalescedRead {
+                range: 0x1000..0x2010,
+                descriptors: vec![1, 2, 0, 3],
+            }]
+        );
+    }
+
+    #[test]
+    fn separates_reads_on_different_pages() {
+        let reads = coalesce_reads(&[descriptor(0x1000, 8), descriptor(0x3000, 8)]);
+        assert_eq!(
+            reads,
+            [
+                CoalescedRead {
+                    range: 0x1000..0x1008,
+                    descriptors: vec![0],
+                },
+                CoalescedRead {
+                    range: 0x3000..0x3008,
+                    descriptors: vec![1],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn limits_the_length_of_coalesced_reads() {
+        let reads = coalesce_reads(&[
+            descriptor(0x1000, 0x8000),
+            descriptor(0x9000, 0x8000),
+            descriptor(0x1_1000, 8),
+        ]);
+        assert_eq!(
+            reads,
+            [
+                CoalescedRead {
+                    range: 0x1000..0x1_1000,
+                    descriptors: vec![0, 1],
+                },
+                CoalescedRead {
+                    range: 0x1_1000..0x1_1008,
+                    descriptors: vec![2],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_signature_with_wildcards() {
+        let mut haystack = vec![0xAA; 300];
+        haystack[100..105].copy_from_slice(&[0x48, 0x8B, 0x05, 0x12, 0xC3]);
+        haystack[200..205].copy_from_slice(&[0x48, 0x8B, 0x0D, 0x34, 0xC3]);
+
+        let signature = Signature::new(
+            &[0x48, 0x8B, 0x0D, 0x00, 0xC3],
+            &[0xFF, 0xFF, 0xFF, 0x00, 0xFF],
+        )
+        .unwrap();
+        assert_eq!(signature.find(&haystack), Some(200));
+
+        let signature = Signature::new(
+            &[0x48, 0x8B, 0x00, 0x00, 0xC3],
+            &[0xFF, 0xFF, 0x00, 0x00, 0xFF],
+        )
+        .unwrap();
+        assert_eq!(signature.find(&haystack), Some(100));
+
+        let signature = Signature::new(&[0x48, 0x8B, 0x0F], &[0xFF, 0xFF, 0xFF]).unwrap();
+        assert_eq!(signature.find(&haystack), None);
+    }
+
+    #[test]
+    fn finds_signature_at_the_edges() {
+        let haystack = [1, 2, 3, 4, 5];
+        let signature = Signature::new(&[1, 2], &[0xFF, 0xFF]).unwrap();
+        assert_eq!(signature.find(&haystack), Some(0));
+        let signature = Signature::new(&[4, 5], &[0xFF, 0xFF]).unwrap();
+        assert_eq!(signature.find(&haystack), Some(3));
+        let signature = Signature::new(&[5, 6], &[0xFF, 0xFF]).unwrap();
+        assert_eq!(signature.find(&haystack), None);
+    }
+
+    #[test]
+    fn partial_mask_bits() {
+        let signature = Signature::new(&[0x40], &[0xF0]).unwrap();
+        assert_eq!(signature.find(&[0x12, 0x4F]), Some(1));
+    }
+
+    #[test]
+    fn invalid_signatures() {
+        assert!(Signature::new(&[], &[]).is_none());
+        assert!(Signature::new(&[1, 2], &[0xFF]).is_none());
+    }
+
+    #[test]
+    fn overflowing_reads_are_not_coalesced() {
+        let reads = coalesce_reads(&[descriptor(u64::MAX - 4, 8), descriptor(u64::MAX - 8, 4)]);
+        assert_eq!(reads.len(), 2);
+    }
+}
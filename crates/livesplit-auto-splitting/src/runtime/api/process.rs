@@ -1,16 +1,19 @@
 use std::str;
 
-use anyhow::{format_err, Context as _, Result};
+use anyhow::{ensure, format_err, Context as _, Result};
 use slotmap::{Key, KeyData};
 use wasmtime::{Caller, Linker};
 
 use crate::{
+    process::{ReadDescriptor, Signature},
     runtime::{Context, ProcessKey},
     timer::LogLevel,
     CreationError, Process, Timer,
 };
 
-use super::{get_arr_mut, get_slice_mut, get_str, get_two_slice_mut, memory_and_context};
+use super::{
+    get_arr_mut, get_slice, get_slice_mut, get_str, get_two_slice_mut, memory_and_context,
+};
 
 pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationError> {
     linker
@@ -130,6 +133,40 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
             source,
             name: "process_list_by_name",
         })?
+        .func_wrap("env", "process_list_attached", {
+            |mut caller: Caller<'_, Context<T>>, list_ptr: u32, list_len_ptr: u32| {
+                let (memory, context) = memory_and_context(&mut caller);
+
+                let list_len_bytes = get_arr_mut(memory, list_len_ptr)?;
+                let list_len = u32::from_le_bytes(*list_len_bytes);
+
+                let list = get_slice_mut(
+                    memory,
+                    list_ptr,
+                    list_len
+                        .checked_mul(8)
+                        .context("The list length overflows the size of the address space.")?,
+                )?;
+
+                for (key, list_element) in context
+                    .processes
+                    .keys()
+                    .zip(bytemuck::cast_slice_mut::<u8, [u8; 8]>(list))
+                {
+                    *list_element = key.data().as_ffi().to_le_bytes();
+                }
+
+                let count = u32::try_from(context.processes.len()).unwrap_or(u32::MAX);
+                let list_len_bytes = get_arr_mut(memory, list_len_ptr)?;
+                *list_len_bytes = count.to_le_bytes();
+
+                Ok(1u32)
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "process_list_attached",
+        })?
         .func_wrap("env", "process_is_open", {
             |mut caller: Caller<'_, Context<T>>, process: u64| {
                 let ctx = caller.data_mut();
@@ -137,13 +174,38 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
                     .processes
                     .get_mut(ProcessKey::from(KeyData::from_ffi(process)))
                     .ok_or_else(|| format_err!("Invalid process handle: {process}"))?;
-                Ok(proc.is_open(&mut ctx.process_list) as u32)
+                let generation = proc.generation();
+                let is_open = proc.is_open(&mut ctx.process_list, ctx.process_grace_period);
+                if proc.generation() != generation {
+                    ctx.timer.log_runtime(
+                        format_args!(
+                            "Reattached to a relaunched process: {}",
+                            proc.name().unwrap_or("<Unnamed Process>")
+                        ),
+                        LogLevel::Debug,
+                    );
+                }
+                Ok(is_open as u32)
             }
         })
         .map_err(|source| CreationError::LinkFunction {
             source,
             name: "process_is_open",
         })?
+        .func_wrap("env", "process_get_generation", {
+            |mut caller: Caller<'_, Context<T>>, process: u64| {
+                Ok(caller
+                    .data_mut()
+                    .processes
+                    .get(ProcessKey::from(KeyData::from_ffi(process)))
+                    .ok_or_else(|| format_err!("Invalid process handle: {process}"))?
+                    .generation())
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "process_get_generation",
+        })?
         .func_wrap("env", "process_read", {
             |mut caller: Caller<'_, Context<T>>,
              process: u64,
@@ -163,6 +225,68 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
             source,
             name: "process_read",
         })?
+        .func_wrap("env", "process_read_multiple", {
+            |mut caller: Caller<'_, Context<T>>,
+             process: u64,
+             descriptors_ptr: u32,
+             descriptors_len: u32,
+             buf_ptr: u32,
+             buf_len: u32,
+             status_ptr: u32| {
+                const DESCRIPTOR_SIZE: u32 = 16;
+
+                let (memory, context) = memory_and_context(&mut caller);
+                let process = context
+                    .processes
+                    .get(ProcessKey::from(KeyData::from_ffi(process)))
+                    .ok_or_else(|| format_err!("Invalid process handle: {process}"))?;
+
+                let descriptors = get_slice(
+                    memory,
+                    descriptors_ptr,
+                    descriptors_len
+                        .checked_mul(DESCRIPTOR_SIZE)
+                        .context("Too many descriptors.")?,
+                )?
+                .chunks_exact(DESCRIPTOR_SIZE as usize)
+                .map(|descriptor| {
+                    let descriptor = ReadDescriptor {
+                        address: u64::from_le_bytes(descriptor[..8].try_into().unwrap()),
+                        buf_offset: u32::from_le_bytes(descriptor[8..12].try_into().unwrap()),
+                        len: u32::from_le_bytes(descriptor[12..].try_into().unwrap()),
+                    };
+                    ensure!(
+                        descriptor
+                            .buf_offset
+                            .checked_add(descriptor.len)
+                            .is_some_and(|end| end <= buf_len),
+                        "Out of bounds descriptor."
+                    );
+                    Ok(descriptor)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+                let mut success = vec![false; descriptors.len()];
+                process.read_mem_multiple(
+                    &descriptors,
+                    get_slice_mut(memory, buf_ptr, buf_len)?,
+                    &mut success,
+                );
+
+                let status = get_slice_mut(memory, status_ptr, descriptors_len * 4)?;
+                for (status, &success) in status.chunks_exact_mut(4).zip(&success) {
+                    status.copy_from_slice(&(success as u32).to_le_bytes());
+                }
+
+                // The individual failures are reported through the status
+                // words, so the batch as a whole always succeeds.
+                Ok(1u32)
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "process_read_multiple",
+        })?
         .func_wrap("env", "process_get_module_address", {
             |mut caller: Caller<'_, Context<T>>, process: u64, ptr: u32, len: u32| {
                 let (memory, context) = memory_and_context(&mut caller);
@@ -319,6 +443,34 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
         .map_err(|source| CreationError::LinkFunction {
             source,
             name: "process_get_memory_range_flags",
+        })?
+        .func_wrap("env", "process_scan_signature", {
+            |mut caller: Caller<'_, Context<T>>,
+             process: u64,
+             range_address: u64,
+             range_len: u64,
+             pattern_ptr: u32,
+             pattern_len: u32,
+             mask_ptr: u32| {
+                let (memory, context) = memory_and_context(&mut caller);
+                let process = context
+                    .processes
+                    .get_mut(ProcessKey::from(KeyData::from_ffi(process)))
+                    .ok_or_else(|| format_err!("Invalid process handle: {process}"))?;
+                let pattern = get_slice(memory, pattern_ptr, pattern_len)?;
+                let mask = get_slice(memory, mask_ptr, pattern_len)?;
+                let Some(signature) = Signature::new(pattern, mask) else {
+                    return Ok(0);
+                };
+                let range_end = range_address.saturating_add(range_len);
+                Ok(process
+                    .scan_signature(range_address..range_end, &signature)
+                    .unwrap_or_default())
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "process_scan_signature",
         })?;
     Ok(())
 }
@@ -41,6 +41,31 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
             source,
             name: "runtime_set_tick_rate",
         })?
+        .func_wrap("env", "runtime_request_slow_tick", {
+            |mut caller: Caller<'_, Context<T>>, seconds: f64| -> Result<()> {
+                caller
+                    .data_mut()
+                    .timer
+                    .log_runtime(format_args!("Next Tick In: {seconds}s"), LogLevel::Debug);
+
+                ensure!(seconds > 0.0, "The interval needs to be larger than 0.");
+
+                const MAX_DURATION: f64 = u64::MAX as f64;
+                ensure!(seconds < MAX_DURATION, "The interval is too large.");
+
+                caller
+                    .data_mut()
+                    .shared_data
+                    .next_tick_interval
+                    .store(seconds.to_bits(), atomic::Ordering::Relaxed);
+
+                Ok(())
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "runtime_request_slow_tick",
+        })?
         .func_wrap("env", "runtime_print_message", {
             |mut caller: Caller<'_, Context<T>>, ptr: u32, len: u32| {
                 let (memory, context) = memory_and_context(&mut caller);
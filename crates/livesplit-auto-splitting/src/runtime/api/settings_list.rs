@@ -149,6 +149,39 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
         .map_err(|source| CreationError::LinkFunction {
             source,
             name: "settings_list_insert",
+        })?
+        .func_wrap("env", "settings_list_remove", {
+            |mut caller: Caller<'_, Context<T>>, settings_list: u64, index: u64| {
+                let settings_list = caller
+                    .data_mut()
+                    .settings_lists
+                    .get_mut(SettingsListKey::from(KeyData::from_ffi(settings_list)))
+                    .ok_or_else(|| format_err!("Invalid settings list handle: {settings_list}"))?;
+
+                Ok(settings_list
+                    .remove(index.try_into().unwrap_or(usize::MAX))
+                    .is_some() as u32)
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "settings_list_remove",
+        })?
+        .func_wrap("env", "settings_list_clear", {
+            |mut caller: Caller<'_, Context<T>>, settings_list: u64| {
+                caller
+                    .data_mut()
+                    .settings_lists
+                    .get_mut(SettingsListKey::from(KeyData::from_ffi(settings_list)))
+                    .ok_or_else(|| format_err!("Invalid settings list handle: {settings_list}"))?
+                    .clear();
+
+                Ok(())
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "settings_list_clear",
         })?;
     Ok(())
 }
@@ -3,7 +3,7 @@ use wasmtime::{Caller, Linker};
 
 use crate::{runtime::Context, CreationError, Timer};
 
-use super::{get_str, memory_and_context};
+use super::{get_arr_mut, get_slice_mut, get_str, memory_and_context};
 
 pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationError> {
     linker
@@ -14,6 +14,88 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
             source,
             name: "timer_get_state",
         })?
+        .func_wrap("env", "timer_get_elapsed_real_time", {
+            |caller: Caller<'_, Context<T>>| match caller.data().timer.elapsed_real_time() {
+                Some(time) => time.whole_nanoseconds().try_into().unwrap_or(i64::MAX),
+                None => -1,
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_get_elapsed_real_time",
+        })?
+        .func_wrap("env", "timer_get_attempt_count", {
+            |caller: Caller<'_, Context<T>>| caller.data().timer.attempt_count()
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_get_attempt_count",
+        })?
+        .func_wrap("env", "timer_get_finished_runs_count", {
+            |caller: Caller<'_, Context<T>>| caller.data().timer.finished_runs_count()
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_get_finished_runs_count",
+        })?
+        .func_wrap("env", "timer_get_comparison", {
+            |mut caller: Caller<'_, Context<T>>, ptr: u32, len_ptr: u32| {
+                let (memory, context) = memory_and_context(&mut caller);
+                let comparison = context.timer.current_comparison();
+                let len_bytes = get_arr_mut(memory, len_ptr)?;
+                let len = u32::from_le_bytes(*len_bytes) as usize;
+                *len_bytes = (comparison.len() as u32).to_le_bytes();
+                if len < comparison.len() {
+                    return Ok(0u32);
+                }
+                let buf = get_slice_mut(memory, ptr, comparison.len() as _)?;
+                buf.copy_from_slice(comparison.as_bytes());
+                Ok(1u32)
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_get_comparison",
+        })?
+        .func_wrap("env", "timer_current_split_name", {
+            |mut caller: Caller<'_, Context<T>>, ptr: u32, len_ptr: u32| {
+                let (memory, context) = memory_and_context(&mut caller);
+                let name = context.timer.current_split_name();
+                let len_bytes = get_arr_mut(memory, len_ptr)?;
+                let Some(name) = name else {
+                    *len_bytes = 0u32.to_le_bytes();
+                    return Ok(0u32);
+                };
+                let len = u32::from_le_bytes(*len_bytes) as usize;
+                *len_bytes = (name.len() as u32).to_le_bytes();
+                if len < name.len() {
+                    return Ok(0u32);
+                }
+                let buf = get_slice_mut(memory, ptr, name.len() as _)?;
+                buf.copy_from_slice(name.as_bytes());
+                Ok(1u32)
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_current_split_name",
+        })?
+        .func_wrap("env", "timer_segment_splitted", {
+            |caller: Caller<'_, Context<T>>, index: u64| {
+                let splitted = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| caller.data().timer.segment_splitted(index));
+                match splitted {
+                    Some(true) => 1i32,
+                    Some(false) => 0,
+                    None => -1,
+                }
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_segment_splitted",
+        })?
         .func_wrap(
             "env",
             "timer_start",
@@ -69,6 +151,17 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
             source,
             name: "timer_reset",
         })?
+        .func_wrap(
+            "env",
+            "timer_undo_all_pauses",
+            |mut caller: Caller<'_, Context<T>>| {
+                caller.data_mut().timer.undo_all_pauses();
+            },
+        )
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_undo_all_pauses",
+        })?
         .func_wrap("env", "timer_set_variable", {
             |mut caller: Caller<'_, Context<T>>,
              name_ptr: u32,
@@ -99,6 +192,18 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
             source,
             name: "timer_set_game_time",
         })?
+        .func_wrap("env", "timer_set_loading_times", {
+            |mut caller: Caller<'_, Context<T>>, secs: i64, nanos: i32| {
+                caller
+                    .data_mut()
+                    .timer
+                    .set_loading_times(time::Duration::new(secs, nanos));
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "timer_set_loading_times",
+        })?
         .func_wrap("env", "timer_pause_game_time", {
             |mut caller: Caller<'_, Context<T>>| caller.data_mut().timer.pause_game_time()
         })
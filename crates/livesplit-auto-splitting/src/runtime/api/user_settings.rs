@@ -37,6 +37,43 @@ pub fn bind<T: Timer>(linker: &mut Linker<Context<T>>) -> Result<(), CreationErr
             source,
             name: "user_settings_add_bool",
         })?
+        .func_wrap("env", "user_settings_add_int", {
+            |mut caller: Caller<'_, Context<T>>,
+             key_ptr: u32,
+             key_len: u32,
+             description_ptr: u32,
+             description_len: u32,
+             default_value: i64,
+             min: i64,
+             max: i64| {
+                if min > max {
+                    bail!("The minimum value is larger than the maximum value.");
+                }
+                let (memory, context) = memory_and_context(&mut caller);
+                let key = Arc::<str>::from(get_str(memory, key_ptr, key_len)?);
+                let description = get_str(memory, description_ptr, description_len)?.into();
+                let default_value = default_value.clamp(min, max);
+                let value_in_map = match context.shared_data.get_settings_map().get(&key) {
+                    Some(settings::Value::I64(v)) => (*v).clamp(min, max),
+                    _ => default_value,
+                };
+                Arc::make_mut(&mut context.settings_widgets).push(settings::Widget {
+                    key,
+                    description,
+                    tooltip: None,
+                    kind: settings::WidgetKind::Int {
+                        default_value,
+                        min,
+                        max,
+                    },
+                });
+                Ok(value_in_map)
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "user_settings_add_int",
+        })?
         .func_wrap("env", "user_settings_add_title", {
             |mut caller: Caller<'_, Context<T>>,
              key_ptr: u32,
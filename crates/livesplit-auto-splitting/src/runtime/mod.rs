@@ -13,7 +13,7 @@ use indexmap::IndexMap;
 use slotmap::SlotMap;
 use snafu::Snafu;
 use std::{
-    path::Path,
+    path::{Path, PathBuf},
     sync::{
         atomic::{self, AtomicU64},
         Arc, Mutex, MutexGuard,
@@ -27,6 +27,7 @@ use wasmtime::{
 use wasmtime_wasi::preview1::WasiP1Ctx;
 
 mod api;
+mod module_cache;
 
 /// An error that is returned when the creation of a new runtime fails.
 #[derive(Debug, Snafu)]
@@ -94,6 +95,7 @@ pub struct Context<T> {
     timer: T,
     memory: Option<Memory>,
     process_list: ProcessList,
+    process_grace_period: Duration,
     wasi: WasiP1Ctx,
     stderr: StdErr,
 }
@@ -201,6 +203,23 @@ pub struct Config {
     /// occurs more details are printed in the backtrace. By default this is
     /// `true` if the feature `enhanced-backtrace` is enabled.
     pub backtrace_details: bool,
+    /// The directory to cache compiled auto splitters in. Compiling an auto
+    /// splitter can take a while, so if a directory is specified, the compiled
+    /// auto splitter is stored in there and loaded the next time the same
+    /// auto splitter is compiled. Entries that were compiled by a different
+    /// version of the runtime or with a different configuration are compiled
+    /// again. Only specify a directory that is not writable by untrusted
+    /// parties, as the cached code is executed without being validated. By
+    /// default this is [`None`].
+    pub module_cache: Option<PathBuf>,
+    /// The duration for which a process that closed is still reported as open
+    /// to the auto splitter. If a process with the same name shows up again
+    /// during this time, the auto splitter is reattached to it and the
+    /// generation of the process is incremented. This allows auto splitters to
+    /// keep their state when a game crashes and is relaunched quickly. This
+    /// only applies to processes that were attached to by name. By default
+    /// this is zero, so closed processes are reported immediately.
+    pub process_grace_period: Duration,
 }
 
 impl Default for Config {
@@ -209,6 +228,8 @@ impl Default for Config {
             debug_info: cfg!(feature = "debugger-support"),
             optimize: true,
             backtrace_details: cfg!(feature = "enhanced-backtrace"),
+            module_cache: None,
+            process_grace_period: Duration::ZERO,
         }
     }
 }
@@ -216,6 +237,9 @@ impl Default for Config {
 struct SharedData {
     settings_map: ArcSwap<IndexMap<Arc<str>, settings::Value>>,
     tick_rate: AtomicU64,
+    /// A one-shot interval to wait until the next execution, overriding the
+    /// tick rate. A value of `0` means that no such interval was requested.
+    next_tick_interval: AtomicU64,
 }
 
 struct ExclusiveData<T> {
@@ -320,11 +344,32 @@ impl SharedData {
 /// module to control a timer.
 pub struct Runtime {
     engine: Engine,
+    module_cache: Option<PathBuf>,
+    process_grace_period: Duration,
 }
 
 /// A compiled auto splitter that can be instantiated.
 pub struct CompiledAutoSplitter {
     module: Module,
+    process_grace_period: Duration,
+}
+
+/// The features of the runtime that a compiled auto splitter requires. This is
+/// determined by inspecting the imports of the WebAssembly module, so it can be
+/// queried before the auto splitter gets instantiated. This allows warning the
+/// user or refusing to load the auto splitter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequiredFeatures {
+    /// The auto splitter imports functions from the WebAssembly System
+    /// Interface (WASI).
+    pub wasi: bool,
+    /// The auto splitter imports functions for attaching to and reading from
+    /// processes.
+    pub processes: bool,
+    /// The names of all the host functions provided by the runtime that the
+    /// auto splitter imports. This does not include any of the WASI functions.
+    pub host_functions: Vec<String>,
 }
 
 impl Runtime {
@@ -349,20 +394,66 @@ impl Runtime {
         let engine = Engine::new(&engine_config)
             .map_err(|source| CreationError::EngineCreation { source })?;
 
-        Ok(Self { engine })
+        Ok(Self {
+            engine,
+            module_cache: config.module_cache,
+            process_grace_period: config.process_grace_period,
+        })
     }
 
     /// Compiles the given auto splitter that is provided as a WebAssembly
-    /// module.
+    /// module. If a module cache is configured, the compiled auto splitter is
+    /// loaded from the cache instead if possible.
     pub fn compile(&self, module: &[u8]) -> Result<CompiledAutoSplitter, CreationError> {
+        let cache_path = self
+            .module_cache
+            .as_deref()
+            .map(|dir| module_cache::entry_path(dir, &self.engine, module));
+
+        if let Some(cache_path) = &cache_path {
+            if let Some(module) = module_cache::load(&self.engine, cache_path) {
+                return Ok(CompiledAutoSplitter {
+                    module,
+                    process_grace_period: self.process_grace_period,
+                });
+            }
+        }
+
+        let module = Module::from_binary(&self.engine, module)
+            .map_err(|source| CreationError::ModuleLoading { source })?;
+
+        if let Some(cache_path) = &cache_path {
+            module_cache::store(&module, cache_path);
+        }
+
         Ok(CompiledAutoSplitter {
-            module: Module::from_binary(&self.engine, module)
-                .map_err(|source| CreationError::ModuleLoading { source })?,
+            module,
+            process_grace_period: self.process_grace_period,
         })
     }
 }
 
 impl CompiledAutoSplitter {
+    /// Queries the features of the runtime that the auto splitter requires.
+    pub fn required_features(&self) -> RequiredFeatures {
+        let mut features = RequiredFeatures::default();
+
+        for import in self.module.imports() {
+            match import.module() {
+                "wasi_snapshot_preview1" => features.wasi = true,
+                "env" => {
+                    if import.name().starts_with("process_") {
+                        features.processes = true;
+                    }
+                    features.host_functions.push(import.name().to_owned());
+                }
+                _ => {}
+            }
+        }
+
+        features
+    }
+
     /// Instantiates the auto splitter with the given timer.
     pub fn instantiate<T: Timer>(
         &self,
@@ -377,6 +468,7 @@ impl CompiledAutoSplitter {
         let shared_data = Arc::new(SharedData {
             settings_map: ArcSwap::new(settings_map.unwrap_or_default().values),
             tick_rate: AtomicU64::new(f64::to_bits(1.0 / 120.0)),
+            next_tick_interval: AtomicU64::new(0),
         });
 
         let (wasi, stderr) = api::wasi::build(interpreter_script_path);
@@ -393,6 +485,7 @@ impl CompiledAutoSplitter {
                 timer,
                 memory: None,
                 process_list: ProcessList::new(),
+                process_grace_period: self.process_grace_period,
                 wasi,
                 stderr,
             },
@@ -507,6 +600,22 @@ impl<T: Timer> AutoSplitter<T> {
         ))
     }
 
+    /// Returns the duration to wait until the next execution and consumes any
+    /// one-shot interval the auto splitter may have requested during the last
+    /// update. If no such interval was requested, this is the same as the
+    /// [`tick_rate`](Self::tick_rate). You should call this function exactly
+    /// once after every update to sleep for the correct amount of time.
+    pub fn next_tick_interval(&self) -> Duration {
+        match self
+            .shared_data
+            .next_tick_interval
+            .swap(0, atomic::Ordering::Relaxed)
+        {
+            0 => self.tick_rate(),
+            bits => Duration::from_secs_f64(f64::from_bits(bits)),
+        }
+    }
+
     /// Accesses a copy of the currently stored settings. The auto splitter can
     /// change these at any time. If you intend to make modifications to the
     /// settings, you need to set them again via
@@ -0,0 +1,113 @@
+use std::{
+    fmt::Write,
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use sha2::{Digest, Sha256};
+use wasmtime::{Engine, Module};
+
+/// Determines the path of the cache entry for the WebAssembly module. The
+/// entry is keyed by a SHA-256 hash of the module's contents and of the
+/// engine's compatibility hash, which covers the version of Wasmtime and the
+/// configuration of the engine. A cache entry is deserialized into native code
+/// that gets executed, so a collision must not be feasible.
+pub fn entry_path(dir: &Path, engine: &Engine, module: &[u8]) -> PathBuf {
+    let mut hasher = Sha256Hasher(Sha256::new());
+    engine.precompile_compatibility_hash().hash(&mut hasher);
+    hasher.0.update(module);
+
+    let mut file_name = String::with_capacity(2 * 32 + ".cwasm".len());
+    for byte in hasher.0.finalize() {
+        let _ = write!(file_name, "{byte:02x}");
+    }
+    file_name.push_str(".cwasm");
+
+    dir.join(file_name)
+}
+
+/// Loads the compiled module from the cache. If there is no such entry or it
+/// is incompatible with the engine, [`None`] is returned.
+pub fn load(engine: &Engine, path: &Path) -> Option<Module> {
+    if !path.is_file() {
+        return None;
+    }
+    // SAFETY: The cache directory is trusted by the embedder to only contain
+    // modules that were serialized by the runtime. Wasmtime additionally
+    // verifies that the module was compiled by the same version of Wasmtime
+    // with a compatible configuration.
+    unsafe { Module::deserialize_file(engine, path) }.ok()
+}
+
+/// Stores the compiled module in the cache. Failing to do so is not an error,
+/// as the module simply gets compiled again the next time.
+pub fn store(module: &Module, path: &Path) {
+    let Ok(serialized) = module.serialize() else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    // Write to a temporary file first, so other instances never observe a
+    // partially written entry.
+    let temp_path = temp_path(path);
+    if fs::write(&temp_path, serialized).is_err() || fs::rename(&temp_path, path).is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+}
+
+/// Determines the path of a temporary file next to the cache entry. No other
+/// store uses the same path, neither in this process nor in any other one, so
+/// storing the same entry concurrently doesn't mix up the written contents.
+fn temp_path(path: &Path) -> PathBuf {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    let mut file_name = path.file_name().unwrap_or_default().to_owned();
+    file_name.push(format!(
+        ".{}.{}.tmp",
+        process::id(),
+        NEXT_ID.fetch_add(1, Ordering::Relaxed),
+    ));
+    path.with_file_name(file_name)
+}
+
+/// Feeds everything that gets hashed into a SHA-256 hash.
+struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn finish(&self) -> u64 {
+        // Only the SHA-256 hash itself is used.
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temp_paths_are_unique() {
+        let path = Path::new("cache").join("entry.cwasm");
+        let first = temp_path(&path);
+        let second = temp_path(&path);
+
+        assert_ne!(first, second);
+        for temp_path in [first, second] {
+            assert_eq!(temp_path.parent(), path.parent());
+            assert_eq!(temp_path.extension().unwrap(), "tmp");
+            assert!(temp_path
+                .file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .starts_with("entry.cwasm."));
+        }
+    }
+}
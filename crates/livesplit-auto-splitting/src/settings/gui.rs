@@ -35,6 +35,17 @@ pub enum WidgetKind {
         /// settings [`Map`](super::Map) yet.
         default_value: bool,
     },
+    /// An integer setting with an inclusive range of allowed values. This
+    /// could be shown as a slider or a number input.
+    Int {
+        /// The default value of the setting, if it's not available in the
+        /// settings [`Map`](super::Map) yet.
+        default_value: i64,
+        /// The minimum value that the setting can take on.
+        min: i64,
+        /// The maximum value that the setting can take on.
+        max: i64,
+    },
     /// A choice setting. This could be shown as a dropdown or radio buttons.
     Choice {
         /// The default value of the setting, if it's not available in the
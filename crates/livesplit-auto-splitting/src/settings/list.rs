@@ -68,14 +68,27 @@ impl List {
     }
 
     /// Removes the setting value at the given index and returns it. If the
-    /// index is larger than the length of the list, [`None`] will be returned.
+    /// index is out of bounds, [`None`] will be returned and the list is left
+    /// untouched.
     #[inline]
     pub fn remove(&mut self, index: usize) -> Option<Value> {
-        let list = Arc::make_mut(&mut self.list);
-        if index >= list.len() {
+        if index >= self.list.len() {
             return None;
         }
-        Some(list.remove(index))
+        Some(Arc::make_mut(&mut self.list).remove(index))
+    }
+
+    /// Removes all the setting values from the list. Clearing an empty list
+    /// leaves it untouched.
+    #[inline]
+    pub fn clear(&mut self) {
+        if self.list.is_empty() {
+            return;
+        }
+        match Arc::get_mut(&mut self.list) {
+            Some(list) => list.clear(),
+            None => self.list = Default::default(),
+        }
     }
 
     /// Iterates over all the setting values in the list.
@@ -133,6 +146,58 @@ mod tests {
         assert_eq!(list.get(3).unwrap(), &Value::Bool(true));
     }
 
+    #[test]
+    fn test_remove() {
+        let mut list = List::new();
+        list.push(Value::Bool(true));
+        list.push(Value::Bool(false));
+        list.push(Value::I64(3));
+        assert_eq!(list.remove(1), Some(Value::Bool(false)));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get(0).unwrap(), &Value::Bool(true));
+        assert_eq!(list.get(1).unwrap(), &Value::I64(3));
+
+        assert_eq!(list.remove(1), Some(Value::I64(3)));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.remove(0), Some(Value::Bool(true)));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_remove_out_of_bounds() {
+        let mut list = List::new();
+        list.push(Value::Bool(true));
+        let before = list.clone();
+        assert_eq!(list.remove(1), None);
+        assert_eq!(list.remove(usize::MAX), None);
+        assert!(list.is_unchanged(&before));
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list = List::new();
+        list.push(Value::Bool(true));
+        list.push(Value::Bool(false));
+        let shared = list.clone();
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(shared.len(), 2);
+
+        list.push(Value::Bool(true));
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_clear_empty() {
+        let mut list = List::new();
+        let before = list.clone();
+        list.clear();
+        assert!(list.is_empty());
+        assert!(list.is_unchanged(&before));
+    }
+
     #[test]
     fn test_get() {
         let mut list = List::new();
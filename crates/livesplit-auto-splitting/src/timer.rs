@@ -45,8 +45,33 @@ pub trait Timer: Send {
     fn undo_split(&mut self);
     /// Resets the timer.
     fn reset(&mut self);
+    /// Removes all the pause times from the current attempt. If the attempt is
+    /// paused, it also gets resumed.
+    fn undo_all_pauses(&mut self);
+    /// Returns the real time that has elapsed in the current attempt. This is
+    /// `None` if there is no attempt in progress.
+    fn elapsed_real_time(&self) -> Option<time::Duration>;
+    /// Returns the name of the comparison that the timer is currently
+    /// comparing against.
+    fn current_comparison(&self) -> String;
+    /// Returns the name of the segment that the timer is currently on. This is
+    /// `None` if there is no attempt in progress or the attempt has ended.
+    fn current_split_name(&self) -> Option<String>;
+    /// Returns whether the segment at the index provided was split (`true`)
+    /// or skipped (`false`) in the current attempt. This is `None` if the
+    /// timer didn't get past the segment yet or there is no attempt in
+    /// progress.
+    fn segment_splitted(&self, index: usize) -> Option<bool>;
+    /// Returns the total number of attempts of the run, including the current
+    /// one, if there is an attempt in progress.
+    fn attempt_count(&self) -> u64;
+    /// Returns the number of attempts of the run that have been finished.
+    fn finished_runs_count(&self) -> u64;
     /// Sets the game time.
     fn set_game_time(&mut self, time: time::Duration);
+    /// Sets the total amount of time the game has been loading in the current
+    /// attempt. The game time is then real time minus the loading times.
+    fn set_loading_times(&mut self, time: time::Duration);
     /// Pauses the game time. This does not pause the timer, only the automatic
     /// flow of time for the game time.
     fn pause_game_time(&mut self);
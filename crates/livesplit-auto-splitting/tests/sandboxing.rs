@@ -1,11 +1,14 @@
-use livesplit_auto_splitting::{AutoSplitter, Config, LogLevel, Runtime, Timer, TimerState};
+use livesplit_auto_splitting::{
+    settings, AutoSplitter, CompiledAutoSplitter, Config, LogLevel, Runtime, Timer, TimerState,
+};
 use std::{
     ffi::OsStr,
     fmt, fs,
     path::PathBuf,
     process::{Command, Stdio},
+    sync::{Arc, Mutex},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 struct DummyTimer;
@@ -19,7 +22,215 @@ impl Timer for DummyTimer {
     fn skip_split(&mut self) {}
     fn undo_split(&mut self) {}
     fn reset(&mut self) {}
+    fn undo_all_pauses(&mut self) {}
+    fn elapsed_real_time(&self) -> Option<time::Duration> {
+        None
+    }
+    fn current_comparison(&self) -> String {
+        "Personal Best".into()
+    }
+    fn current_split_name(&self) -> Option<String> {
+        None
+    }
+    fn segment_splitted(&self, _index: usize) -> Option<bool> {
+        None
+    }
+    fn attempt_count(&self) -> u64 {
+        0
+    }
+    fn finished_runs_count(&self) -> u64 {
+        0
+    }
+    fn set_game_time(&mut self, _time: time::Duration) {}
+    fn set_loading_times(&mut self, _time: time::Duration) {}
+    fn pause_game_time(&mut self) {}
+    fn resume_game_time(&mut self) {}
+    fn set_variable(&mut self, _key: &str, _value: &str) {}
+    fn log_auto_splitter(&mut self, _message: fmt::Arguments<'_>) {}
+    fn log_runtime(&mut self, _message: fmt::Arguments<'_>, _log_level: LogLevel) {}
+}
+
+struct RunningTimer(Instant);
+
+impl Timer for RunningTimer {
+    fn state(&self) -> TimerState {
+        TimerState::Running
+    }
+    fn start(&mut self) {}
+    fn split(&mut self) {}
+    fn skip_split(&mut self) {}
+    fn undo_split(&mut self) {}
+    fn reset(&mut self) {}
+    fn undo_all_pauses(&mut self) {}
+    fn elapsed_real_time(&self) -> Option<time::Duration> {
+        self.0.elapsed().try_into().ok()
+    }
+    fn current_comparison(&self) -> String {
+        "Personal Best".into()
+    }
+    fn current_split_name(&self) -> Option<String> {
+        None
+    }
+    fn segment_splitted(&self, _index: usize) -> Option<bool> {
+        None
+    }
+    fn attempt_count(&self) -> u64 {
+        0
+    }
+    fn finished_runs_count(&self) -> u64 {
+        0
+    }
+    fn set_game_time(&mut self, _time: time::Duration) {}
+    fn set_loading_times(&mut self, _time: time::Duration) {}
+    fn pause_game_time(&mut self) {}
+    fn resume_game_time(&mut self) {}
+    fn set_variable(&mut self, _key: &str, _value: &str) {}
+    fn log_auto_splitter(&mut self, _message: fmt::Arguments<'_>) {}
+    fn log_runtime(&mut self, _message: fmt::Arguments<'_>, _log_level: LogLevel) {}
+}
+
+struct AttemptsTimer {
+    attempt_count: u64,
+    finished_runs_count: u64,
+}
+
+impl Timer for AttemptsTimer {
+    fn state(&self) -> TimerState {
+        TimerState::NotRunning
+    }
+    fn start(&mut self) {}
+    fn split(&mut self) {}
+    fn skip_split(&mut self) {}
+    fn undo_split(&mut self) {}
+    fn reset(&mut self) {}
+    fn undo_all_pauses(&mut self) {}
+    fn elapsed_real_time(&self) -> Option<time::Duration> {
+        None
+    }
+    fn current_comparison(&self) -> String {
+        "Personal Best".into()
+    }
+    fn current_split_name(&self) -> Option<String> {
+        None
+    }
+    fn segment_splitted(&self, _index: usize) -> Option<bool> {
+        None
+    }
+    fn attempt_count(&self) -> u64 {
+        self.attempt_count
+    }
+    fn finished_runs_count(&self) -> u64 {
+        self.finished_runs_count
+    }
     fn set_game_time(&mut self, _time: time::Duration) {}
+    fn set_loading_times(&mut self, _time: time::Duration) {}
+    fn pause_game_time(&mut self) {}
+    fn resume_game_time(&mut self) {}
+    fn set_variable(&mut self, _key: &str, _value: &str) {}
+    fn log_auto_splitter(&mut self, _message: fmt::Arguments<'_>) {}
+    fn log_runtime(&mut self, _message: fmt::Arguments<'_>, _log_level: LogLevel) {}
+}
+
+struct SplitsTimer {
+    segments: &'static [&'static str],
+    current_split_index: Option<usize>,
+    splitted: Vec<bool>,
+}
+
+impl Timer for SplitsTimer {
+    fn state(&self) -> TimerState {
+        match self.current_split_index {
+            None => TimerState::NotRunning,
+            Some(index) if index < self.segments.len() => TimerState::Running,
+            Some(_) => TimerState::Ended,
+        }
+    }
+    fn start(&mut self) {
+        if self.current_split_index.is_none() {
+            self.current_split_index = Some(0);
+        }
+    }
+    fn split(&mut self) {
+        if self.state() == TimerState::Running {
+            self.splitted.push(true);
+            self.current_split_index = Some(self.splitted.len());
+        }
+    }
+    fn skip_split(&mut self) {
+        if self.state() == TimerState::Running {
+            self.splitted.push(false);
+            self.current_split_index = Some(self.splitted.len());
+        }
+    }
+    fn undo_split(&mut self) {}
+    fn reset(&mut self) {
+        self.current_split_index = None;
+        self.splitted.clear();
+    }
+    fn undo_all_pauses(&mut self) {}
+    fn elapsed_real_time(&self) -> Option<time::Duration> {
+        None
+    }
+    fn current_comparison(&self) -> String {
+        "Personal Best".into()
+    }
+    fn current_split_name(&self) -> Option<String> {
+        self.segments
+            .get(self.current_split_index?)
+            .map(|&name| name.into())
+    }
+    fn segment_splitted(&self, index: usize) -> Option<bool> {
+        self.splitted.get(index).copied()
+    }
+    fn attempt_count(&self) -> u64 {
+        0
+    }
+    fn finished_runs_count(&self) -> u64 {
+        0
+    }
+    fn set_game_time(&mut self, _time: time::Duration) {}
+    fn set_loading_times(&mut self, _time: time::Duration) {}
+    fn pause_game_time(&mut self) {}
+    fn resume_game_time(&mut self) {}
+    fn set_variable(&mut self, _key: &str, _value: &str) {}
+    fn log_auto_splitter(&mut self, _message: fmt::Arguments<'_>) {}
+    fn log_runtime(&mut self, _message: fmt::Arguments<'_>, _log_level: LogLevel) {}
+}
+
+struct LoadingTimesTimer(Arc<Mutex<Option<time::Duration>>>);
+
+impl Timer for LoadingTimesTimer {
+    fn state(&self) -> TimerState {
+        TimerState::Running
+    }
+    fn start(&mut self) {}
+    fn split(&mut self) {}
+    fn skip_split(&mut self) {}
+    fn undo_split(&mut self) {}
+    fn reset(&mut self) {}
+    fn undo_all_pauses(&mut self) {}
+    fn elapsed_real_time(&self) -> Option<time::Duration> {
+        None
+    }
+    fn current_comparison(&self) -> String {
+        "Personal Best".into()
+    }
+    fn current_split_name(&self) -> Option<String> {
+        None
+    }
+    fn segment_splitted(&self, _index: usize) -> Option<bool> {
+        None
+    }
+    fn attempt_count(&self) -> u64 {
+        0
+    }
+    fn finished_runs_count(&self) -> u64 {
+        0
+    }
+    fn set_game_time(&mut self, _time: time::Duration) {}
+    fn set_loading_times(&mut self, time: time::Duration) {
+        *self.0.lock().unwrap() = Some(time);
+    }
     fn pause_game_time(&mut self) {}
     fn resume_game_time(&mut self) {}
     fn set_variable(&mut self, _key: &str, _value: &str) {}
@@ -29,6 +240,21 @@ impl Timer for DummyTimer {
 
 #[track_caller]
 fn compile(crate_name: &str) -> anyhow::Result<AutoSplitter<DummyTimer>> {
+    compile_with_timer(crate_name, DummyTimer)
+}
+
+#[track_caller]
+fn compile_with_timer<T: Timer>(crate_name: &str, timer: T) -> anyhow::Result<AutoSplitter<T>> {
+    Ok(compile_module(crate_name)?.instantiate(timer, None, None)?)
+}
+
+#[track_caller]
+fn compile_module(crate_name: &str) -> anyhow::Result<CompiledAutoSplitter> {
+    Ok(Runtime::new(Config::default())?.compile(&build(crate_name))?)
+}
+
+#[track_caller]
+fn build(crate_name: &str) -> Vec<u8> {
     let mut path = PathBuf::from("tests");
     path.push("test-cases");
     path.push(crate_name);
@@ -63,9 +289,7 @@ fn compile(crate_name: &str) -> anyhow::Result<AutoSplitter<DummyTimer>> {
         })
         .unwrap();
 
-    Ok(Runtime::new(Config::default())?
-        .compile(&fs::read(wasm_path).unwrap())?
-        .instantiate(DummyTimer, None, None)?)
+    fs::read(wasm_path).unwrap()
 }
 
 #[track_caller]
@@ -75,6 +299,17 @@ fn run(crate_name: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// The auto splitters find the helper processes by their name, so they would
+/// find each other's helper processes if the tests using them ran at the same
+/// time. Each of those tests holds this lock while its helper processes run.
+#[cfg(target_os = "linux")]
+fn lock_helper_processes() -> std::sync::MutexGuard<'static, ()> {
+    static HELPER_PROCESSES: Mutex<()> = Mutex::new(());
+    HELPER_PROCESSES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
 #[test]
 fn empty() {
     run("empty").unwrap();
@@ -130,6 +365,306 @@ fn random() {
     run("random").unwrap();
 }
 
+// The helper processes are `sleep` processes, which are only reliably
+// available on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn multiple_processes() {
+    let _lock = lock_helper_processes();
+    let mut helpers = [(); 2].map(|_| {
+        Command::new("sleep")
+            .arg("60")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap()
+    });
+
+    let result = run("multiple-processes");
+
+    for helper in &mut helpers {
+        helper.kill().ok();
+        helper.wait().ok();
+    }
+
+    result.unwrap();
+}
+
+// The helper processes are `sleep` processes, which are only reliably
+// available on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn reattach() {
+    let _lock = lock_helper_processes();
+    let spawn_helper = || {
+        Command::new("sleep")
+            .arg("60")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap()
+    };
+    let mut helper = spawn_helper();
+
+    let mut config = Config::default();
+    config.process_grace_period = Duration::from_secs(5);
+    let runtime = Runtime::new(config)
+        .unwrap()
+        .compile(&build("reattach"))
+        .unwrap()
+        .instantiate(DummyTimer, None, None)
+        .unwrap();
+
+    let result = runtime.lock().update();
+    // The process got attached to during the update. Whether it's still open
+    // is checked for the first time a second after that.
+    let next_open_check = Instant::now() + Duration::from_secs(1);
+    helper.kill().ok();
+    helper.wait().ok();
+    result.unwrap();
+
+    // Relaunch the process. Once the helper got spawned, it's guaranteed to be
+    // running with its new name, so the check is the only thing to wait for.
+    let mut helper = spawn_helper();
+    thread::sleep(next_open_check.saturating_duration_since(Instant::now()));
+
+    let result = runtime.lock().update();
+    helper.kill().ok();
+    helper.wait().ok();
+    result.unwrap();
+}
+
+// The helper process is a `sleep` process, which is only reliably available
+// on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn read_multiple() {
+    let _lock = lock_helper_processes();
+    let mut helper = Command::new("sleep")
+        .arg("60")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let result = run("read-multiple");
+
+    helper.kill().ok();
+    helper.wait().ok();
+
+    result.unwrap();
+}
+
+// The helper process is a `sleep` process, which is only reliably available
+// on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn scan_signature() {
+    let _lock = lock_helper_processes();
+    let mut helper = Command::new("sleep")
+        .arg("60")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let result = run("scan-signature");
+
+    helper.kill().ok();
+    helper.wait().ok();
+
+    result.unwrap();
+}
+
+#[test]
+fn elapsed_time() {
+    run("elapsed-time").unwrap();
+
+    let runtime = compile_with_timer("elapsed-time", RunningTimer(Instant::now())).unwrap();
+    for _ in 0..3 {
+        thread::sleep(Duration::from_millis(10));
+        runtime.lock().update().unwrap();
+    }
+}
+
+#[test]
+fn attempt_count() {
+    let timer = AttemptsTimer {
+        attempt_count: 7,
+        finished_runs_count: 3,
+    };
+    let runtime = compile_with_timer("attempt-count", timer).unwrap();
+    runtime.lock().update().unwrap();
+}
+
+#[test]
+fn comparison() {
+    run("comparison").unwrap();
+}
+
+#[test]
+fn current_split_name() {
+    let timer = SplitsTimer {
+        segments: &["Forest Temple", "Water Temple", "Ganon"],
+        current_split_index: None,
+        splitted: Vec::new(),
+    };
+    let runtime = compile_with_timer("current-split-name", timer).unwrap();
+    runtime.lock().update().unwrap();
+}
+
+#[test]
+fn segment_splitted() {
+    let timer = SplitsTimer {
+        segments: &["Forest Temple", "Water Temple", "Ganon"],
+        current_split_index: None,
+        splitted: Vec::new(),
+    };
+    let runtime = compile_with_timer("segment-splitted", timer).unwrap();
+    runtime.lock().update().unwrap();
+}
+
+#[test]
+fn loading_times() {
+    let loading_times = Arc::new(Mutex::new(None));
+    let timer = LoadingTimesTimer(loading_times.clone());
+    let runtime = compile_with_timer("loading-times", timer).unwrap();
+    runtime.lock().update().unwrap();
+    assert_eq!(
+        *loading_times.lock().unwrap(),
+        Some(time::Duration::new(12, 500_000_000)),
+    );
+}
+
+#[test]
+fn slow_tick() {
+    let runtime = compile("slow-tick").unwrap();
+    let tick_rate = runtime.tick_rate();
+
+    runtime.lock().update().unwrap();
+    assert_eq!(runtime.next_tick_interval(), Duration::from_secs(2));
+    assert_eq!(runtime.next_tick_interval(), tick_rate);
+
+    runtime.lock().update().unwrap();
+    assert_eq!(runtime.next_tick_interval(), tick_rate);
+    assert_eq!(runtime.tick_rate(), tick_rate);
+}
+
+#[test]
+fn int_setting() {
+    let mut map = settings::Map::new();
+    map.insert("stored".into(), settings::Value::I64(7));
+    map.insert("too_large".into(), settings::Value::I64(100));
+    map.insert("too_small".into(), settings::Value::I64(-100));
+    map.insert("wrong_type".into(), settings::Value::Bool(true));
+
+    let runtime = compile_module("int-setting")
+        .unwrap()
+        .instantiate(DummyTimer, Some(map), None)
+        .unwrap();
+
+    let widgets = runtime.settings_widgets();
+    let kinds = widgets
+        .iter()
+        .map(|widget| match widget.kind {
+            settings::WidgetKind::Int {
+                default_value,
+                min,
+                max,
+            } => (&*widget.key, default_value, min, max),
+            _ => panic!("Expected an integer setting."),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            ("stored", 0, 0, 10),
+            ("too_large", 0, 0, 10),
+            ("too_small", 0, -5, 5),
+            ("default", 3, 0, 10),
+            ("default_clamped", 10, 0, 10),
+            ("wrong_type", 4, 0, 10),
+        ],
+    );
+
+    // Clamping only affects the value handed to the auto splitter, the stored
+    // values stay untouched.
+    let map = runtime.settings_map();
+    assert!(matches!(
+        map.get("too_large"),
+        Some(settings::Value::I64(100))
+    ));
+    assert!(matches!(
+        map.get("too_small"),
+        Some(settings::Value::I64(-100))
+    ));
+    assert!(map.get("default").is_none());
+}
+
+#[test]
+fn required_features() {
+    let features = compile_module("no-wasi").unwrap().required_features();
+    assert!(!features.wasi);
+    assert!(features.processes);
+    assert!(features
+        .host_functions
+        .iter()
+        .any(|f| f == "timer_get_state"));
+    assert!(features
+        .host_functions
+        .iter()
+        .any(|f| f == "process_is_open"));
+
+    let features = compile_module("stdout").unwrap().required_features();
+    assert!(features.wasi);
+    assert!(!features.processes);
+}
+
+#[test]
+fn module_cache() {
+    let mut cache_dir = std::env::temp_dir();
+    cache_dir.push("livesplit-auto-splitting-module-cache");
+    fs::remove_dir_all(&cache_dir).ok();
+
+    let cache_entries = || {
+        let mut entries = fs::read_dir(&cache_dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect::<Vec<_>>();
+        entries.sort();
+        entries
+    };
+
+    let mut config = Config::default();
+    config.module_cache = Some(cache_dir.clone());
+    let runtime = Runtime::new(config).unwrap();
+
+    let module = build("no-wasi");
+    let compiled = runtime.compile(&module).unwrap();
+    let entries = cache_entries();
+    assert_eq!(entries.len(), 1);
+
+    let cached = runtime.compile(&module).unwrap();
+    assert_eq!(cache_entries(), entries);
+    assert_eq!(cached.required_features(), compiled.required_features());
+    cached
+        .instantiate(DummyTimer, None, None)
+        .unwrap()
+        .lock()
+        .update()
+        .unwrap();
+
+    // A different module must not use the existing entry.
+    runtime.compile(&build("empty")).unwrap();
+    assert_eq!(cache_entries().len(), 2);
+
+    // A corrupted entry gets replaced.
+    fs::write(&entries[0], b"not a module").unwrap();
+    let recompiled = runtime.compile(&module).unwrap();
+    assert_eq!(recompiled.required_features(), compiled.required_features());
+    assert_ne!(fs::read(&entries[0]).unwrap(), b"not a module");
+}
+
 // #[test]
 // fn poll() {
 //     // FIXME: This is basically what happens at the lower levels of sleeping. You
@@ -0,0 +1,15 @@
+extern "C" {
+    fn timer_get_attempt_count() -> u64;
+    fn timer_get_finished_runs_count() -> u64;
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let (attempt_count, finished_runs_count) =
+        unsafe { (timer_get_attempt_count(), timer_get_finished_runs_count()) };
+
+    assert_eq!(attempt_count, 7);
+    assert_eq!(finished_runs_count, 3);
+}
+
+fn main() {}
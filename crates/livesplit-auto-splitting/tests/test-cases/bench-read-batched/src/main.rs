@@ -0,0 +1,65 @@
+use std::{num::NonZeroU64, sync::OnceLock};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct AttachedProcess(NonZeroU64);
+
+#[repr(C, packed)]
+pub struct ReadDescriptor {
+    pub address: u64,
+    pub buf_offset: u32,
+    pub len: u32,
+}
+
+extern "C" {
+    fn process_attach(name_ptr: *const u8, name_len: usize) -> Option<AttachedProcess>;
+    fn process_get_memory_range_address(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
+    fn process_read_multiple(
+        process: AttachedProcess,
+        descriptors_ptr: *const ReadDescriptor,
+        descriptors_len: usize,
+        buf_ptr: *mut u8,
+        buf_len: usize,
+        status_ptr: *mut u32,
+    ) -> bool;
+}
+
+const READS: usize = 64;
+
+static STATE: OnceLock<(AttachedProcess, Vec<ReadDescriptor>)> = OnceLock::new();
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let (process, descriptors) = STATE.get_or_init(|| {
+        let name = "sleep";
+        let process = unsafe { process_attach(name.as_ptr(), name.len()) }.unwrap();
+        let address = unsafe { process_get_memory_range_address(process, 0) }
+            .unwrap()
+            .get();
+        let descriptors = (0..READS)
+            .map(|i| ReadDescriptor {
+                address: address + 16 * i as u64,
+                buf_offset: 8 * i as u32,
+                len: 8,
+            })
+            .collect();
+        (process, descriptors)
+    });
+
+    let mut buf = [0u8; 8 * READS];
+    let mut status = [0u32; READS];
+    assert!(unsafe {
+        process_read_multiple(
+            *process,
+            descriptors.as_ptr(),
+            descriptors.len(),
+            buf.as_mut_ptr(),
+            buf.len(),
+            status.as_mut_ptr(),
+        )
+    });
+    assert!(status.iter().all(|&status| status == 1));
+    std::hint::black_box(buf);
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+use std::{num::NonZeroU64, sync::OnceLock};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct AttachedProcess(NonZeroU64);
+
+extern "C" {
+    fn process_attach(name_ptr: *const u8, name_len: usize) -> Option<AttachedProcess>;
+    fn process_get_memory_range_address(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
+    fn process_read(
+        process: AttachedProcess,
+        address: u64,
+        buf_ptr: *mut u8,
+        buf_len: usize,
+    ) -> bool;
+}
+
+const READS: usize = 64;
+
+static STATE: OnceLock<(AttachedProcess, u64)> = OnceLock::new();
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let &(process, address) = STATE.get_or_init(|| {
+        let name = "sleep";
+        let process = unsafe { process_attach(name.as_ptr(), name.len()) }.unwrap();
+        let address = unsafe { process_get_memory_range_address(process, 0) }
+            .unwrap()
+            .get();
+        (process, address)
+    });
+
+    let mut values = [0u64; READS];
+    for (i, value) in values.iter_mut().enumerate() {
+        let mut buf = [0; 8];
+        assert!(unsafe {
+            process_read(
+                process,
+                address + 16 * i as u64,
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        });
+        *value = u64::from_le_bytes(buf);
+    }
+    std::hint::black_box(values);
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+extern "C" {
+    fn timer_get_comparison(buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let mut buf = [0; 32];
+
+    let mut len = 4;
+    assert!(!unsafe { timer_get_comparison(buf.as_mut_ptr(), &mut len) });
+    assert_eq!(len, "Personal Best".len());
+    assert_eq!(buf, [0; 32]);
+
+    let mut len = buf.len();
+    assert!(unsafe { timer_get_comparison(buf.as_mut_ptr(), &mut len) });
+    assert_eq!(&buf[..len], b"Personal Best");
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+extern "C" {
+    fn timer_current_split_name(buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
+    fn timer_start();
+    fn timer_split();
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let mut buf = [0; 32];
+
+    let mut len = buf.len();
+    assert!(!unsafe { timer_current_split_name(buf.as_mut_ptr(), &mut len) });
+    assert_eq!(len, 0);
+
+    unsafe {
+        timer_start();
+        timer_split();
+    }
+
+    let mut len = 4;
+    assert!(!unsafe { timer_current_split_name(buf.as_mut_ptr(), &mut len) });
+    assert_eq!(len, "Water Temple".len());
+    assert_eq!(buf, [0; 32]);
+
+    let mut len = buf.len();
+    assert!(unsafe { timer_current_split_name(buf.as_mut_ptr(), &mut len) });
+    assert_eq!(&buf[..len], b"Water Temple");
+}
+
+fn main() {}
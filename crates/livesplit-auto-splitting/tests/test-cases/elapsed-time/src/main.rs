@@ -0,0 +1,21 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
+extern "C" {
+    fn timer_get_state() -> u32;
+    fn timer_get_elapsed_real_time() -> i64;
+}
+
+static LAST_ELAPSED: AtomicI64 = AtomicI64::new(-1);
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let (state, elapsed) = unsafe { (timer_get_state(), timer_get_elapsed_real_time()) };
+
+    if state == 0 {
+        assert_eq!(elapsed, -1);
+    } else {
+        assert!(elapsed > LAST_ELAPSED.swap(elapsed, Ordering::Relaxed));
+    }
+}
+
+fn main() {}
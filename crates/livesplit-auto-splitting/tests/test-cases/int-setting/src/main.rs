@@ -0,0 +1,37 @@
+extern "C" {
+    fn user_settings_add_int(
+        key_ptr: *const u8,
+        key_len: usize,
+        description_ptr: *const u8,
+        description_len: usize,
+        default_value: i64,
+        min: i64,
+        max: i64,
+    ) -> i64;
+}
+
+fn add_int(key: &str, default_value: i64, min: i64, max: i64) -> i64 {
+    unsafe {
+        user_settings_add_int(
+            key.as_ptr(),
+            key.len(),
+            key.as_ptr(),
+            key.len(),
+            default_value,
+            min,
+            max,
+        )
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn update() {}
+
+fn main() {
+    assert_eq!(add_int("stored", 0, 0, 10), 7);
+    assert_eq!(add_int("too_large", 0, 0, 10), 10);
+    assert_eq!(add_int("too_small", 0, -5, 5), -5);
+    assert_eq!(add_int("default", 3, 0, 10), 3);
+    assert_eq!(add_int("default_clamped", 42, 0, 10), 10);
+    assert_eq!(add_int("wrong_type", 4, 0, 10), 4);
+}
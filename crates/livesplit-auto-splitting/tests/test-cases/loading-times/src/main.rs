@@ -0,0 +1,10 @@
+extern "C" {
+    fn timer_set_loading_times(secs: i64, nanos: i32);
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    unsafe { timer_set_loading_times(12, 500_000_000) }
+}
+
+fn main() {}
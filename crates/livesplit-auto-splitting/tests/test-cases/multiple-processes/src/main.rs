@@ -0,0 +1,80 @@
+use std::num::NonZeroU64;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct AttachedProcess(NonZeroU64);
+
+extern "C" {
+    fn process_attach_by_pid(pid: u64) -> Option<AttachedProcess>;
+    fn process_detach(process: AttachedProcess);
+    fn process_list_by_name(
+        name_ptr: *const u8,
+        name_len: usize,
+        list_ptr: *mut u64,
+        list_len_ptr: *mut usize,
+    ) -> bool;
+    fn process_list_attached(list_ptr: *mut AttachedProcess, list_len_ptr: *mut usize) -> bool;
+    fn process_get_memory_range_address(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
+    fn process_read(
+        process: AttachedProcess,
+        address: u64,
+        buf_ptr: *mut u8,
+        buf_len: usize,
+    ) -> bool;
+}
+
+fn list_attached() -> Vec<AttachedProcess> {
+    let mut list = Vec::with_capacity(1);
+    let mut len = list.capacity();
+    unsafe {
+        assert!(process_list_attached(list.as_mut_ptr(), &mut len));
+        if len > list.capacity() {
+            list.reserve_exact(len);
+            len = list.capacity();
+            assert!(process_list_attached(list.as_mut_ptr(), &mut len));
+        }
+        list.set_len(len);
+    }
+    list
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    assert!(list_attached().is_empty());
+
+    let name = "sleep";
+    let mut pids = [0u64; 16];
+    let mut len = pids.len();
+    unsafe {
+        assert!(process_list_by_name(
+            name.as_ptr(),
+            name.len(),
+            pids.as_mut_ptr(),
+            &mut len,
+        ));
+    }
+    assert!(len >= 2);
+
+    let first = unsafe { process_attach_by_pid(pids[0]) }.unwrap();
+    let second = unsafe { process_attach_by_pid(pids[1]) }.unwrap();
+    assert_ne!(first, second);
+
+    let mut attached = list_attached();
+    attached.sort_by_key(|p| p.0);
+    let mut expected = [first, second];
+    expected.sort_by_key(|p| p.0);
+    assert_eq!(attached, expected);
+
+    for process in [first, second] {
+        let address = unsafe { process_get_memory_range_address(process, 0) }.unwrap();
+        let mut buf = [0u8; 4];
+        assert!(unsafe { process_read(process, address.get(), buf.as_mut_ptr(), buf.len()) });
+    }
+
+    unsafe { process_detach(first) };
+    assert_eq!(list_attached(), [second]);
+    unsafe { process_detach(second) };
+    assert!(list_attached().is_empty());
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#![no_std]
+
+extern "C" {
+    fn timer_get_state() -> u32;
+    fn process_is_open(process: u64) -> u32;
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    unsafe {
+        if timer_get_state() == 1 {
+            process_is_open(0);
+        }
+    }
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
@@ -0,0 +1,112 @@
+use std::num::NonZeroU64;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct AttachedProcess(NonZeroU64);
+
+#[repr(C, packed)]
+pub struct ReadDescriptor {
+    pub address: u64,
+    pub buf_offset: u32,
+    pub len: u32,
+}
+
+extern "C" {
+    fn process_attach(name_ptr: *const u8, name_len: usize) -> Option<AttachedProcess>;
+    fn process_detach(process: AttachedProcess);
+    fn process_get_memory_range_address(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
+    fn process_read(
+        process: AttachedProcess,
+        address: u64,
+        buf_ptr: *mut u8,
+        buf_len: usize,
+    ) -> bool;
+    fn process_read_multiple(
+        process: AttachedProcess,
+        descriptors_ptr: *const ReadDescriptor,
+        descriptors_len: usize,
+        buf_ptr: *mut u8,
+        buf_len: usize,
+        status_ptr: *mut u32,
+    ) -> bool;
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let name = "sleep";
+    let process = unsafe { process_attach(name.as_ptr(), name.len()) }.unwrap();
+    let address = unsafe { process_get_memory_range_address(process, 0) }
+        .unwrap()
+        .get();
+
+    let mut expected = [0u8; 12];
+    assert!(unsafe { process_read(process, address, expected.as_mut_ptr(), expected.len()) });
+
+    let descriptors = [
+        ReadDescriptor {
+            address: address + 8,
+            buf_offset: 0,
+            len: 4,
+        },
+        ReadDescriptor {
+            address: 0,
+            buf_offset: 4,
+            len: 4,
+        },
+        ReadDescriptor {
+            address,
+            buf_offset: 8,
+            len: 8,
+        },
+    ];
+    let mut buf = [0xFFu8; 16];
+    let mut status = [2u32; 3];
+    assert!(unsafe {
+        process_read_multiple(
+            process,
+            descriptors.as_ptr(),
+            descriptors.len(),
+            buf.as_mut_ptr(),
+            buf.len(),
+            status.as_mut_ptr(),
+        )
+    });
+
+    assert_eq!(status, [1, 0, 1]);
+    assert_eq!(buf[..4], expected[8..]);
+    assert_eq!(buf[4..8], [0; 4]);
+    assert_eq!(buf[8..], expected[..8]);
+
+    // Even if all of the reads fail, the batch itself succeeds.
+    let descriptors = [
+        ReadDescriptor {
+            address: 0,
+            buf_offset: 0,
+            len: 8,
+        },
+        ReadDescriptor {
+            address: 8,
+            buf_offset: 8,
+            len: 8,
+        },
+    ];
+    let mut buf = [0xFFu8; 16];
+    let mut status = [2u32; 2];
+    assert!(unsafe {
+        process_read_multiple(
+            process,
+            descriptors.as_ptr(),
+            descriptors.len(),
+            buf.as_mut_ptr(),
+            buf.len(),
+            status.as_mut_ptr(),
+        )
+    });
+
+    assert_eq!(status, [0, 0]);
+    assert_eq!(buf, [0; 16]);
+
+    unsafe { process_detach(process) };
+}
+
+fn main() {}
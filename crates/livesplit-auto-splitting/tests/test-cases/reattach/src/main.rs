@@ -0,0 +1,52 @@
+use std::{
+    num::NonZeroU64,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct AttachedProcess(NonZeroU64);
+
+extern "C" {
+    fn process_attach(name_ptr: *const u8, name_len: usize) -> Option<AttachedProcess>;
+    fn process_is_open(process: AttachedProcess) -> bool;
+    fn process_get_generation(process: AttachedProcess) -> u64;
+    fn process_get_memory_range_address(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
+    fn process_read(
+        process: AttachedProcess,
+        address: u64,
+        buf_ptr: *mut u8,
+        buf_len: usize,
+    ) -> bool;
+}
+
+static PROCESS: OnceLock<AttachedProcess> = OnceLock::new();
+static UPDATES: AtomicU64 = AtomicU64::new(0);
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let process = *PROCESS.get_or_init(|| {
+        let name = "sleep";
+        unsafe { process_attach(name.as_ptr(), name.len()) }.unwrap()
+    });
+
+    // The process gets relaunched between the first and the second update, so
+    // the same handle then refers to the new process.
+    assert!(unsafe { process_is_open(process) });
+    assert_eq!(
+        unsafe { process_get_generation(process) },
+        UPDATES.fetch_add(1, Ordering::Relaxed),
+    );
+
+    let address = unsafe { process_get_memory_range_address(process, 0) }
+        .unwrap()
+        .get();
+    let mut magic = [0u8; 4];
+    assert!(unsafe { process_read(process, address, magic.as_mut_ptr(), magic.len()) });
+    assert_eq!(&magic, b"\x7fELF");
+}
+
+fn main() {}
@@ -0,0 +1,101 @@
+use std::num::NonZeroU64;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct AttachedProcess(NonZeroU64);
+
+extern "C" {
+    fn process_attach(name_ptr: *const u8, name_len: usize) -> Option<AttachedProcess>;
+    fn process_detach(process: AttachedProcess);
+    fn process_get_memory_range_address(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
+    fn process_get_memory_range_size(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
+    fn process_read(
+        process: AttachedProcess,
+        address: u64,
+        buf_ptr: *mut u8,
+        buf_len: usize,
+    ) -> bool;
+    fn process_scan_signature(
+        process: AttachedProcess,
+        range_address: u64,
+        range_len: u64,
+        pattern_ptr: *const u8,
+        pattern_len: usize,
+        mask_ptr: *const u8,
+    ) -> Option<NonZeroU64>;
+}
+
+fn scan(
+    process: AttachedProcess,
+    address: u64,
+    len: u64,
+    pattern: &[u8],
+    mask: &[u8],
+) -> Option<u64> {
+    assert_eq!(pattern.len(), mask.len());
+    unsafe {
+        process_scan_signature(
+            process,
+            address,
+            len,
+            pattern.as_ptr(),
+            pattern.len(),
+            mask.as_ptr(),
+        )
+    }
+    .map(NonZeroU64::get)
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    let name = "sleep";
+    let process = unsafe { process_attach(name.as_ptr(), name.len()) }.unwrap();
+    let address = unsafe { process_get_memory_range_address(process, 0) }
+        .unwrap()
+        .get();
+    let size = unsafe { process_get_memory_range_size(process, 0) }
+        .unwrap()
+        .get();
+
+    // The first memory range starts with the ELF header of the executable.
+    assert_eq!(
+        scan(
+            process,
+            address,
+            size,
+            b"\x7f?LF",
+            &[0xFF, 0x00, 0xFF, 0xFF]
+        ),
+        Some(address),
+    );
+
+    // Use bytes from somewhere in the range as the pattern.
+    let mut planted = [0u8; 8];
+    assert!(unsafe {
+        process_read(
+            process,
+            address + 0x100,
+            planted.as_mut_ptr(),
+            planted.len(),
+        )
+    });
+    let found = scan(process, address + 0x80, size - 0x80, &planted, &[0xFF; 8]).unwrap();
+    assert!(found >= address + 0x80 && found <= address + 0x100);
+    let mut actual = [0u8; 8];
+    assert!(unsafe { process_read(process, found, actual.as_mut_ptr(), actual.len()) });
+    assert_eq!(actual, planted);
+
+    // The range is clamped to the memory of the process, so the whole address
+    // space can be scanned.
+    assert_eq!(
+        scan(process, 0, u64::MAX, b"\x7fELF", &[0xFF; 4]),
+        Some(address),
+    );
+
+    // An empty pattern never matches.
+    assert_eq!(scan(process, address, size, &[], &[]), None);
+
+    unsafe { process_detach(process) };
+}
+
+fn main() {}
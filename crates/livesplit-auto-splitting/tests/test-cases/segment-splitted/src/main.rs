@@ -0,0 +1,24 @@
+extern "C" {
+    fn timer_segment_splitted(idx: u64) -> i32;
+    fn timer_start();
+    fn timer_split();
+    fn timer_skip_split();
+}
+
+#[no_mangle]
+pub extern "C" fn update() {
+    assert_eq!(unsafe { timer_segment_splitted(0) }, -1);
+
+    unsafe {
+        timer_start();
+        timer_split();
+        timer_skip_split();
+    }
+
+    assert_eq!(unsafe { timer_segment_splitted(0) }, 1);
+    assert_eq!(unsafe { timer_segment_splitted(1) }, 0);
+    assert_eq!(unsafe { timer_segment_splitted(2) }, -1);
+    assert_eq!(unsafe { timer_segment_splitted(u64::MAX) }, -1);
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+extern "C" {
+    fn runtime_request_slow_tick(seconds: f64);
+}
+
+static SCANNED: AtomicBool = AtomicBool::new(false);
+
+#[no_mangle]
+pub extern "C" fn update() {
+    if !SCANNED.swap(true, Ordering::Relaxed) {
+        unsafe { runtime_request_slow_tick(2.0) }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,93 @@
+use core::{fmt, str::FromStr};
+use serde::{Deserialize, Serialize};
+
+/// A button on a mouse that can be registered as a trigger, in addition to the
+/// keys on a keyboard. The extra buttons, usually found on the side of the
+/// mouse, are commonly used as back and forward buttons.
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Button {
+    /// The primary button, usually the left one.
+    Left,
+    /// The secondary button, usually the right one.
+    Right,
+    /// The middle button, usually the scroll wheel.
+    Middle,
+    /// The first extra button, usually the back button.
+    X1,
+    /// The second extra button, usually the forward button.
+    X2,
+}
+
+impl Button {
+    /// Returns the name of the button.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Left => "MouseLeft",
+            Self::Right => "MouseRight",
+            Self::Middle => "MouseMiddle",
+            Self::X1 => "MouseX1",
+            Self::X2 => "MouseX2",
+        }
+    }
+}
+
+impl fmt::Debug for Button {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Button {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "MouseLeft" => Self::Left,
+            "MouseRight" => Self::Right,
+            "MouseMiddle" => Self::Middle,
+            "MouseX1" => Self::X1,
+            "MouseX2" => Self::X2,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl Serialize for Button {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Button {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ButtonVisitor)
+    }
+}
+
+struct ButtonVisitor;
+
+impl serde::de::Visitor<'_> for ButtonVisitor {
+    type Value = Button;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a valid mouse button")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Button::from_str(v).map_err(|()| serde::de::Error::custom("invalid mouse button"))
+    }
+}
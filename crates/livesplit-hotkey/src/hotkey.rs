@@ -1,3 +1,4 @@
+use alloc::string::String;
 use core::{fmt, str::FromStr};
 
 use serde::{Deserialize, Serialize};
@@ -30,22 +31,64 @@ impl fmt::Display for Hotkey {
 }
 
 impl FromStr for Hotkey {
-    type Err = ();
+    type Err = ParseHotkeyError;
 
+    /// Parses a hotkey from its textual form. The modifiers are separated by
+    /// `+` from each other and from the key code that follows them, such as
+    /// `Shift + Numpad1` or `Ctrl+Alt+KeyR`. The modifiers are matched case
+    /// insensitively, while the key code needs to be one of the names returned
+    /// by [`KeyCode::name`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some((modifiers, key_code)) = s.rsplit_once('+') {
-            let modifiers = modifiers.trim_end().parse()?;
-            let key_code = key_code.trim_start().parse()?;
-            Ok(Self {
-                key_code,
-                modifiers,
-            })
-        } else {
-            let key_code = s.parse()?;
-            Ok(Self {
-                key_code,
-                modifiers: Modifiers::empty(),
-            })
+        let (modifiers, key_code) = match s.rsplit_once('+') {
+            Some((modifiers, key_code)) => (Some(modifiers), key_code),
+            None => (None, s),
+        };
+
+        let key_code = key_code.trim();
+        if key_code.is_empty() {
+            return Err(ParseHotkeyError::MissingKeyCode);
+        }
+        let key_code = key_code
+            .parse::<KeyCode>()
+            .map_err(|()| ParseHotkeyError::UnknownKeyCode(key_code.into()))?;
+
+        let mut hotkey = Self::from(key_code);
+        if let Some(modifiers) = modifiers {
+            for modifier in modifiers.split('+').map(str::trim) {
+                hotkey.modifiers |= Modifiers::parse_single(modifier)
+                    .ok_or_else(|| ParseHotkeyError::UnknownModifier(modifier.into()))?;
+            }
+        }
+
+        Ok(hotkey)
+    }
+}
+
+/// The error that occurs when parsing a [`Hotkey`] from its textual form
+/// fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseHotkeyError {
+    /// There is no key code following the modifiers.
+    MissingKeyCode,
+    /// The key code is not known.
+    UnknownKeyCode(String),
+    /// One of the modifiers is not known.
+    UnknownModifier(String),
+}
+
+// FIXME: Impl core::error::Error once it's stable.
+#[cfg(feature = "std")]
+impl std::error::Error for ParseHotkeyError {}
+
+impl fmt::Display for ParseHotkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingKeyCode => f.write_str("The hotkey is missing its key code."),
+            Self::UnknownKeyCode(key_code) => write!(f, "The key code `{key_code}` is not known."),
+            Self::UnknownModifier(modifier) => {
+                write!(f, "The modifier `{modifier}` is not known.")
+            }
         }
     }
 }
@@ -85,7 +128,7 @@ impl serde::de::Visitor<'_> for HotkeyVisitor {
     where
         E: serde::de::Error,
     {
-        Hotkey::from_str(v).map_err(|()| serde::de::Error::custom("invalid hotkey"))
+        Hotkey::from_str(v).map_err(serde::de::Error::custom)
     }
 }
 
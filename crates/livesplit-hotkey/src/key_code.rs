@@ -1841,6 +1841,23 @@ impl KeyCode {
         }
     }
 
+    /// Returns whether the key code is one of the keys that are used as
+    /// [`Modifiers`].
+    pub const fn is_modifier(self) -> bool {
+        use self::KeyCode::*;
+        matches!(
+            self,
+            AltLeft
+                | AltRight
+                | ControlLeft
+                | ControlRight
+                | MetaLeft
+                | MetaRight
+                | ShiftLeft
+                | ShiftRight
+        )
+    }
+
     /// Resolves the key according to the current keyboard layout.
     pub fn resolve(self, hook: &Hook) -> Cow<'static, str> {
         let class = self.classify();
@@ -39,12 +39,13 @@ cfg_if::cfg_if! {
     }
 }
 
+mod button;
 mod hotkey;
 mod key_code;
 mod modifiers;
 use core::fmt;
 
-pub use self::{hotkey::*, key_code::*, modifiers::*};
+pub use self::{button::*, hotkey::*, key_code::*, modifiers::*};
 
 /// A hook allows you to listen to hotkeys.
 #[repr(transparent)]
@@ -81,7 +82,9 @@ impl Hook {
         Ok(Self(platform::Hook::new(consume)?))
     }
 
-    /// Registers a hotkey to listen to.
+    /// Registers a hotkey to listen to. Buttons of game controllers are
+    /// registered through the `Gamepad` key codes, which trigger regardless of
+    /// the modifiers. Gamepad input is never consumed.
     pub fn register<F>(&self, hotkey: Hotkey, callback: F) -> Result<()>
     where
         F: FnMut() + Send + 'static,
@@ -93,6 +96,75 @@ impl Hook {
     pub fn unregister(&self, hotkey: Hotkey) -> Result<()> {
         self.0.unregister(hotkey)
     }
+
+    /// Returns all the hotkeys that are currently registered, sorted by their
+    /// key code and modifiers.
+    pub fn registered(&self) -> alloc::vec::Vec<Hotkey> {
+        let mut hotkeys = self.0.registered();
+        hotkeys.sort_unstable_by_key(|hotkey| (hotkey.key_code as u8, hotkey.modifiers.bits()));
+        hotkeys
+    }
+
+    /// Captures the next hotkey that gets pressed and passes it to the
+    /// callback, so that the user can choose a hotkey by pressing it. While
+    /// capturing, the callbacks of the registered hotkeys are not called. The
+    /// capture ends once the callback is called, after which the registered
+    /// hotkeys work as usual again. Modifier keys on their own don't end the
+    /// capture, so that hotkeys with modifiers can be captured. The captured
+    /// hotkey is consumed according to the same [`ConsumePreference`] as the
+    /// registered hotkeys. Starting another capture replaces the previous one.
+    /// The callback may be called on a different thread than the registered
+    /// hotkeys, but it is always allowed to register the captured hotkey.
+    pub fn capture_next<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        self.0.capture_next(callback)
+    }
+
+    /// Ends a capture started by [`capture_next`](Self::capture_next) without
+    /// calling its callback. If there is no capture, nothing happens.
+    pub fn cancel_capture(&self) -> Result<()> {
+        self.0.cancel_capture()
+    }
+
+    /// Suspends the hook, so that none of the callbacks are called until the
+    /// hook gets resumed. All the registered hotkeys and mouse buttons stay
+    /// registered. Any events that arrive while the hook is suspended are
+    /// dropped rather than queued up, so they don't fire once the hook is
+    /// resumed. This is useful while text is being entered into the
+    /// application's own user interface. Depending on the platform, the
+    /// hotkeys may still be consumed while the hook is suspended.
+    pub fn suspend(&self) {
+        self.0.set_suspended(true);
+    }
+
+    /// Resumes a previously suspended hook, so that the callbacks get called
+    /// again.
+    pub fn resume(&self) {
+        self.0.set_suspended(false);
+    }
+
+    /// Returns whether the hook is currently suspended.
+    pub fn is_suspended(&self) -> bool {
+        self.0.is_suspended()
+    }
+
+    /// Registers a mouse button to listen to. The callback is called when the
+    /// button is pressed, regardless of the modifiers that are held down. The
+    /// button is consumed according to the same [`ConsumePreference`] as the
+    /// hotkeys.
+    pub fn register_mouse<F>(&self, button: Button, callback: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.0.register_mouse(button, callback)
+    }
+
+    /// Unregisters a previously registered mouse button.
+    pub fn unregister_mouse(&self, button: Button) -> Result<()> {
+        self.0.unregister_mouse(button)
+    }
 }
 
 /// The result type for this crate.
@@ -138,7 +210,14 @@ const _: () = {
 
 #[cfg(test)]
 mod tests {
-    use std::{thread, time::Duration};
+    use std::{
+        sync::{
+            atomic::{self, AtomicBool},
+            Arc,
+        },
+        thread,
+        time::Duration,
+    };
 
     use super::*;
 
@@ -168,6 +247,149 @@ mod tests {
         hook.unregister(KeyCode::Numpad1.into()).unwrap();
     }
 
+    #[test]
+    fn mouse() {
+        let hook = Hook::new().unwrap();
+
+        hook.register_mouse(Button::X1, || println!("X1")).unwrap();
+        println!("Press the back button of your mouse");
+        thread::sleep(Duration::from_secs(5));
+        hook.unregister_mouse(Button::X1).unwrap();
+    }
+
+    #[test]
+    fn button_names() {
+        for button in [
+            Button::Left,
+            Button::Right,
+            Button::Middle,
+            Button::X1,
+            Button::X2,
+        ] {
+            assert_eq!(button.name().parse(), Ok(button));
+        }
+    }
+
+    #[test]
+    fn hotkey_round_trip() {
+        let key_codes = [
+            KeyCode::Backquote,
+            KeyCode::Digit0,
+            KeyCode::KeyA,
+            KeyCode::KeyR,
+            KeyCode::Minus,
+            KeyCode::Space,
+            KeyCode::Enter,
+            KeyCode::ShiftLeft,
+            KeyCode::ArrowUp,
+            KeyCode::Numpad1,
+            KeyCode::NumpadAdd,
+            KeyCode::F1,
+            KeyCode::F24,
+            KeyCode::Escape,
+            KeyCode::MediaPlayPause,
+            KeyCode::Gamepad0,
+            KeyCode::Gamepad19,
+        ];
+
+        for key_code in key_codes {
+            for bits in 0..=Modifiers::all().bits() {
+                let hotkey = key_code.with_modifiers(Modifiers::from_bits_truncate(bits));
+                assert_eq!(hotkey.to_string().parse(), Ok(hotkey));
+            }
+        }
+    }
+
+    #[test]
+    fn hotkey_parsing() {
+        assert_eq!(
+            "Shift+Numpad1".parse(),
+            Ok(KeyCode::Numpad1.with_modifiers(Modifiers::SHIFT)),
+        );
+        assert_eq!(
+            "ctrl+ALT+KeyR".parse(),
+            Ok(KeyCode::KeyR.with_modifiers(Modifiers::CONTROL | Modifiers::ALT)),
+        );
+        assert_eq!("Space".parse(), Ok(Hotkey::from(KeyCode::Space)));
+        assert_eq!(
+            "Hyper+KeyA".parse::<Hotkey>(),
+            Err(ParseHotkeyError::UnknownModifier("Hyper".into())),
+        );
+        assert_eq!(
+            "Ctrl+Foo".parse::<Hotkey>(),
+            Err(ParseHotkeyError::UnknownKeyCode("Foo".into())),
+        );
+        assert_eq!(
+            "Ctrl+".parse::<Hotkey>(),
+            Err(ParseHotkeyError::MissingKeyCode),
+        );
+    }
+
+    #[test]
+    fn suspend() {
+        let hook = Hook::new().unwrap();
+        let hotkey = KeyCode::Numpad1.into();
+
+        hook.register(hotkey, || {}).unwrap();
+        assert!(!hook.is_suspended());
+
+        hook.suspend();
+        assert!(hook.is_suspended());
+        assert_eq!(hook.registered(), [hotkey]);
+
+        hook.suspend();
+        assert!(hook.is_suspended());
+
+        hook.resume();
+        assert!(!hook.is_suspended());
+        assert_eq!(hook.registered(), [hotkey]);
+
+        hook.unregister(hotkey).unwrap();
+    }
+
+    #[test]
+    #[ignore = "needs Numpad1 to be pressed manually"]
+    fn suspend_ignores_key_presses() {
+        let hook = Hook::new().unwrap();
+        let fired = Arc::new(AtomicBool::new(false));
+
+        hook.register(KeyCode::Numpad1.into(), {
+            let fired = fired.clone();
+            move || fired.store(true, atomic::Ordering::Relaxed)
+        })
+        .unwrap();
+
+        hook.suspend();
+        println!("Press Numpad1, it should not be picked up");
+        thread::sleep(Duration::from_secs(5));
+        assert!(!fired.load(atomic::Ordering::Relaxed));
+
+        hook.resume();
+        println!("Press Numpad1 again, it should be picked up now");
+        thread::sleep(Duration::from_secs(5));
+        assert!(fired.load(atomic::Ordering::Relaxed));
+
+        hook.unregister(KeyCode::Numpad1.into()).unwrap();
+    }
+
+    #[test]
+    fn registered() {
+        let hook = Hook::new().unwrap();
+
+        let a = KeyCode::KeyA.with_modifiers(Modifiers::CONTROL);
+        let b = KeyCode::KeyB.into();
+        let c = KeyCode::Numpad1.with_modifiers(Modifiers::SHIFT);
+
+        hook.register(c, || {}).unwrap();
+        hook.register(a, || {}).unwrap();
+        hook.register(b, || {}).unwrap();
+        hook.unregister(b).unwrap();
+
+        let mut expected = [a, c];
+        expected.sort_unstable_by_key(|hotkey| (hotkey.key_code as u8, hotkey.modifiers.bits()));
+        assert_eq!(hook.registered(), expected);
+    }
+
     #[test]
     fn resolve() {
         let hook = Hook::new().unwrap();
@@ -1,11 +1,20 @@
-use std::{collections::hash_map::HashMap, os::unix::prelude::AsRawFd, ptr, thread};
+use std::{
+    collections::hash_map::HashMap,
+    os::unix::prelude::AsRawFd,
+    ptr,
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc,
+    },
+    thread,
+};
 
 use evdev::{Device, EventType, InputEventKind, Key};
 use mio::{unix::SourceFd, Events, Interest, Poll, Token, Waker};
 use x11_dl::xlib::{Xlib, _XDisplay};
 
-use super::{x11_impl, Error, Hook, Message};
-use crate::{KeyCode, Modifiers, Result};
+use super::{x11_impl, CaptureCallback, Error, Hook, Message};
+use crate::{Button, KeyCode, Modifiers, Result};
 
 // Low numbered tokens are allocated to devices.
 const PING_TOKEN: Token = Token(usize::MAX);
@@ -235,6 +244,288 @@ pub const fn code_for(key: KeyCode) -> Option<Key> {
     })
 }
 
+/// The inverse of [`code_for`].
+pub const fn key_code_for(key: Key) -> Option<KeyCode> {
+    use self::KeyCode::*;
+    Some(match key {
+        Key::KEY_ESC => Escape,
+        Key::KEY_1 => Digit1,
+        Key::KEY_2 => Digit2,
+        Key::KEY_3 => Digit3,
+        Key::KEY_4 => Digit4,
+        Key::KEY_5 => Digit5,
+        Key::KEY_6 => Digit6,
+        Key::KEY_7 => Digit7,
+        Key::KEY_8 => Digit8,
+        Key::KEY_9 => Digit9,
+        Key::KEY_0 => Digit0,
+        Key::KEY_MINUS => Minus,
+        Key::KEY_EQUAL => Equal,
+        Key::KEY_BACKSPACE => Backspace,
+        Key::KEY_TAB => Tab,
+        Key::KEY_Q => KeyQ,
+        Key::KEY_W => KeyW,
+        Key::KEY_E => KeyE,
+        Key::KEY_R => KeyR,
+        Key::KEY_T => KeyT,
+        Key::KEY_Y => KeyY,
+        Key::KEY_U => KeyU,
+        Key::KEY_I => KeyI,
+        Key::KEY_O => KeyO,
+        Key::KEY_P => KeyP,
+        Key::KEY_LEFTBRACE => BracketLeft,
+        Key::KEY_RIGHTBRACE => BracketRight,
+        Key::KEY_ENTER => Enter,
+        Key::KEY_LEFTCTRL => ControlLeft,
+        Key::KEY_A => KeyA,
+        Key::KEY_S => KeyS,
+        Key::KEY_D => KeyD,
+        Key::KEY_F => KeyF,
+        Key::KEY_G => KeyG,
+        Key::KEY_H => KeyH,
+        Key::KEY_J => KeyJ,
+        Key::KEY_K => KeyK,
+        Key::KEY_L => KeyL,
+        Key::KEY_SEMICOLON => Semicolon,
+        Key::KEY_APOSTROPHE => Quote,
+        Key::KEY_GRAVE => Backquote,
+        Key::KEY_LEFTSHIFT => ShiftLeft,
+        Key::KEY_BACKSLASH => Backslash,
+        Key::KEY_Z => KeyZ,
+        Key::KEY_X => KeyX,
+        Key::KEY_C => KeyC,
+        Key::KEY_V => KeyV,
+        Key::KEY_B => KeyB,
+        Key::KEY_N => KeyN,
+        Key::KEY_M => KeyM,
+        Key::KEY_COMMA => Comma,
+        Key::KEY_DOT => Period,
+        Key::KEY_SLASH => Slash,
+        Key::KEY_RIGHTSHIFT => ShiftRight,
+        Key::KEY_KPASTERISK => NumpadMultiply,
+        Key::KEY_LEFTALT => AltLeft,
+        Key::KEY_SPACE => Space,
+        Key::KEY_CAPSLOCK => CapsLock,
+        Key::KEY_F1 => F1,
+        Key::KEY_F2 => F2,
+        Key::KEY_F3 => F3,
+        Key::KEY_F4 => F4,
+        Key::KEY_F5 => F5,
+        Key::KEY_F6 => F6,
+        Key::KEY_F7 => F7,
+        Key::KEY_F8 => F8,
+        Key::KEY_F9 => F9,
+        Key::KEY_F10 => F10,
+        Key::KEY_NUMLOCK => NumLock,
+        Key::KEY_SCROLLLOCK => ScrollLock,
+        Key::KEY_KP7 => Numpad7,
+        Key::KEY_KP8 => Numpad8,
+        Key::KEY_KP9 => Numpad9,
+        Key::KEY_KPMINUS => NumpadSubtract,
+        Key::KEY_KP4 => Numpad4,
+        Key::KEY_KP5 => Numpad5,
+        Key::KEY_KP6 => Numpad6,
+        Key::KEY_KPPLUS => NumpadAdd,
+        Key::KEY_KP1 => Numpad1,
+        Key::KEY_KP2 => Numpad2,
+        Key::KEY_KP3 => Numpad3,
+        Key::KEY_KP0 => Numpad0,
+        Key::KEY_KPDOT => NumpadDecimal,
+        Key::KEY_ZENKAKUHANKAKU => Lang5,
+        Key::KEY_102ND => IntlBackslash,
+        Key::KEY_F11 => F11,
+        Key::KEY_F12 => F12,
+        Key::KEY_RO => IntlRo,
+        Key::KEY_KATAKANA => Lang3,
+        Key::KEY_HIRAGANA => Lang4,
+        Key::KEY_HENKAN => Convert,
+        Key::KEY_KATAKANAHIRAGANA => KanaMode,
+        Key::KEY_MUHENKAN => NonConvert,
+        Key::KEY_KPENTER => NumpadEnter,
+        Key::KEY_RIGHTCTRL => ControlRight,
+        Key::KEY_KPSLASH => NumpadDivide,
+        Key::KEY_SYSRQ => PrintScreen,
+        Key::KEY_RIGHTALT => AltRight,
+        Key::KEY_HOME => Home,
+        Key::KEY_UP => ArrowUp,
+        Key::KEY_PAGEUP => PageUp,
+        Key::KEY_LEFT => ArrowLeft,
+        Key::KEY_RIGHT => ArrowRight,
+        Key::KEY_END => End,
+        Key::KEY_DOWN => ArrowDown,
+        Key::KEY_PAGEDOWN => PageDown,
+        Key::KEY_INSERT => Insert,
+        Key::KEY_DELETE => Delete,
+        Key::KEY_MUTE => AudioVolumeMute,
+        Key::KEY_VOLUMEDOWN => AudioVolumeDown,
+        Key::KEY_VOLUMEUP => AudioVolumeUp,
+        Key::KEY_POWER => Power,
+        Key::KEY_KPEQUAL => NumpadEqual,
+        Key::KEY_PAUSE => Pause,
+        Key::KEY_SCALE => ShowAllWindows,
+        Key::KEY_KPCOMMA => NumpadComma,
+        Key::KEY_HANGEUL => Lang1,
+        Key::KEY_HANJA => Lang2,
+        Key::KEY_YEN => IntlYen,
+        Key::KEY_LEFTMETA => MetaLeft,
+        Key::KEY_RIGHTMETA => MetaRight,
+        Key::KEY_COMPOSE => ContextMenu,
+        Key::KEY_STOP => BrowserStop,
+        Key::KEY_AGAIN => Again,
+        Key::KEY_PROPS => Props,
+        Key::KEY_UNDO => Undo,
+        Key::KEY_FRONT => Select,
+        Key::KEY_COPY => Copy,
+        Key::KEY_OPEN => Open,
+        Key::KEY_PASTE => Paste,
+        Key::KEY_FIND => Find,
+        Key::KEY_CUT => Cut,
+        Key::KEY_HELP => Help,
+        Key::KEY_CALC => LaunchApp2,
+        Key::KEY_SLEEP => Sleep,
+        Key::KEY_WAKEUP => WakeUp,
+        Key::KEY_FILE => LaunchApp1,
+        Key::KEY_MAIL => LaunchMail,
+        Key::KEY_BOOKMARKS => BrowserFavorites,
+        Key::KEY_BACK => BrowserBack,
+        Key::KEY_FORWARD => BrowserForward,
+        Key::KEY_EJECTCD => Eject,
+        Key::KEY_NEXTSONG => MediaTrackNext,
+        Key::KEY_PLAYPAUSE => MediaPlayPause,
+        Key::KEY_PREVIOUSSONG => MediaTrackPrevious,
+        Key::KEY_STOPCD => MediaStop,
+        Key::KEY_RECORD => MediaRecord,
+        Key::KEY_REWIND => MediaRewind,
+        Key::KEY_CONFIG => MediaSelect,
+        Key::KEY_HOMEPAGE => BrowserHome,
+        Key::KEY_REFRESH => BrowserRefresh,
+        Key::KEY_KPLEFTPAREN => NumpadParenLeft,
+        Key::KEY_KPRIGHTPAREN => NumpadParenRight,
+        Key::KEY_F13 => F13,
+        Key::KEY_F14 => F14,
+        Key::KEY_F15 => F15,
+        Key::KEY_F16 => F16,
+        Key::KEY_F17 => F17,
+        Key::KEY_F18 => F18,
+        Key::KEY_F19 => F19,
+        Key::KEY_F20 => F20,
+        Key::KEY_F21 => F21,
+        Key::KEY_F22 => F22,
+        Key::KEY_F23 => F23,
+        Key::KEY_F24 => F24,
+        Key::KEY_PAUSECD => MediaPause,
+        Key::KEY_PLAY => MediaPlay,
+        Key::KEY_FASTFORWARD => MediaFastForward,
+        Key::KEY_SEARCH => BrowserSearch,
+        Key::KEY_BRIGHTNESSDOWN => BrightnessDown,
+        Key::KEY_BRIGHTNESSUP => BrightnessUp,
+        Key::KEY_SWITCHVIDEOMODE => DisplayToggleIntExt,
+        Key::KEY_SEND => MailSend,
+        Key::KEY_REPLY => MailReply,
+        Key::KEY_FORWARDMAIL => MailForward,
+        Key::KEY_MICMUTE => MicrophoneMuteToggle,
+        Key::KEY_ZOOM => ZoomToggle,
+        Key::KEY_CONTROLPANEL => LaunchControlPanel,
+        Key::KEY_APPSELECT => SelectTask,
+        Key::KEY_SCREENSAVER => LaunchScreenSaver,
+        Key::KEY_ASSISTANT => LaunchAssistant,
+        Key::KEY_KBD_LAYOUT_NEXT => KeyboardLayoutSelect,
+        Key::KEY_PRIVACY_SCREEN_TOGGLE => PrivacyScreenToggle,
+        Key::BTN_SOUTH => Gamepad0,
+        Key::BTN_EAST => Gamepad1,
+        Key::BTN_WEST => Gamepad2,
+        Key::BTN_NORTH => Gamepad3,
+        Key::BTN_TL => Gamepad4,
+        Key::BTN_TR => Gamepad5,
+        Key::BTN_TL2 => Gamepad6,
+        Key::BTN_TR2 => Gamepad7,
+        Key::BTN_SELECT => Gamepad8,
+        Key::BTN_START => Gamepad9,
+        Key::BTN_THUMBL => Gamepad10,
+        Key::BTN_THUMBR => Gamepad11,
+        Key::BTN_DPAD_UP => Gamepad12,
+        Key::BTN_DPAD_DOWN => Gamepad13,
+        Key::BTN_DPAD_LEFT => Gamepad14,
+        Key::BTN_DPAD_RIGHT => Gamepad15,
+        Key::BTN_MODE => Gamepad16,
+        _ => return None,
+    })
+}
+
+const fn button_code_for(button: Button) -> Key {
+    match button {
+        Button::Left => Key::BTN_LEFT,
+        Button::Right => Key::BTN_RIGHT,
+        Button::Middle => Key::BTN_MIDDLE,
+        Button::X1 => Key::BTN_SIDE,
+        Button::X2 => Key::BTN_EXTRA,
+    }
+}
+
+const fn modifier_for(key: Key) -> Option<Modifiers> {
+    Some(match key {
+        Key::KEY_LEFTALT | Key::KEY_RIGHTALT => Modifiers::ALT,
+        Key::KEY_LEFTCTRL | Key::KEY_RIGHTCTRL => Modifiers::CONTROL,
+        Key::KEY_LEFTMETA | Key::KEY_RIGHTMETA => Modifiers::META,
+        Key::KEY_LEFTSHIFT | Key::KEY_RIGHTSHIFT => Modifiers::SHIFT,
+        _ => return None,
+    })
+}
+
+/// Keeps track of the modifiers that are held down and passes the key presses
+/// on to either the capture or the registered callbacks.
+struct Dispatcher {
+    hotkeys: HashMap<(Key, Modifiers), Box<dyn FnMut() + Send>>,
+    buttons: HashMap<Key, Box<dyn FnMut() + Send>>,
+    capture: Option<CaptureCallback>,
+    modifiers: Modifiers,
+}
+
+impl Dispatcher {
+    fn new() -> Self {
+        Self {
+            hotkeys: HashMap::new(),
+            buttons: HashMap::new(),
+            capture: None,
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    fn press(&mut self, k: Key, suspended: bool) {
+        // The modifiers are still tracked while suspended, only the capture and
+        // the callbacks are skipped.
+        if !suspended {
+            let captured = key_code_for(k)
+                .filter(|key_code| !key_code.is_modifier())
+                .and_then(|key_code| Some((self.capture.take()?, key_code)));
+
+            if let Some((capture, key_code)) = captured {
+                let hotkey = key_code.with_modifiers(self.modifiers);
+                // The capture is most likely going to register the captured
+                // hotkey. That waits for this thread to handle the
+                // registration, so the capture can't run on this thread.
+                thread::spawn(move || capture(hotkey));
+            } else {
+                if let Some(callback) = self.hotkeys.get_mut(&(k, self.modifiers)) {
+                    callback();
+                }
+                if let Some(callback) = self.buttons.get_mut(&k) {
+                    callback();
+                }
+            }
+        }
+        if let Some(modifier) = modifier_for(k) {
+            self.modifiers.insert(modifier);
+        }
+    }
+
+    fn release(&mut self, k: Key) {
+        if let Some(modifier) = modifier_for(k) {
+            self.modifiers.remove(modifier);
+        }
+    }
+}
+
 pub fn new() -> Result<Hook> {
     let (sender, receiver) = crossbeam_channel::unbounded();
     let mut poll = Poll::new().map_err(|_| Error::EPoll)?;
@@ -251,11 +542,13 @@ pub fn new() -> Result<Hook> {
             .map_err(|_| Error::EPoll)?;
     }
 
+    let suspended = Arc::new(AtomicBool::new(false));
+    let is_suspended = suspended.clone();
+
     let join_handle = thread::spawn(move || -> Result<()> {
         let mut result = Ok(());
         let mut events = Events::with_capacity(1024);
-        let mut hotkeys: HashMap<(Key, Modifiers), Box<dyn FnMut() + Send>> = HashMap::new();
-        let mut modifiers = Modifiers::empty();
+        let mut dispatcher = Dispatcher::new();
 
         let (mut xlib, mut display) = (None, None);
 
@@ -273,41 +566,9 @@ pub fn new() -> Result<Hook> {
                             const RELEASED: i32 = 0;
                             const PRESSED: i32 = 1;
                             match ev.value() {
-                                PRESSED => {
-                                    if let Some(callback) = hotkeys.get_mut(&(k, modifiers)) {
-                                        callback();
-                                    }
-                                    match k {
-                                        Key::KEY_LEFTALT | Key::KEY_RIGHTALT => {
-                                            modifiers.insert(Modifiers::ALT);
-                                        }
-                                        Key::KEY_LEFTCTRL | Key::KEY_RIGHTCTRL => {
-                                            modifiers.insert(Modifiers::CONTROL);
-                                        }
-                                        Key::KEY_LEFTMETA | Key::KEY_RIGHTMETA => {
-                                            modifiers.insert(Modifiers::META);
-                                        }
-                                        Key::KEY_LEFTSHIFT | Key::KEY_RIGHTSHIFT => {
-                                            modifiers.insert(Modifiers::SHIFT);
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                                RELEASED => match k {
-                                    Key::KEY_LEFTALT | Key::KEY_RIGHTALT => {
-                                        modifiers.remove(Modifiers::ALT);
-                                    }
-                                    Key::KEY_LEFTCTRL | Key::KEY_RIGHTCTRL => {
-                                        modifiers.remove(Modifiers::CONTROL);
-                                    }
-                                    Key::KEY_LEFTMETA | Key::KEY_RIGHTMETA => {
-                                        modifiers.remove(Modifiers::META);
-                                    }
-                                    Key::KEY_LEFTSHIFT | Key::KEY_RIGHTSHIFT => {
-                                        modifiers.remove(Modifiers::SHIFT);
-                                    }
-                                    _ => {}
-                                },
+                                PRESSED => dispatcher
+                                    .press(k, is_suspended.load(atomic::Ordering::Relaxed)),
+                                RELEASED => dispatcher.release(k),
                                 _ => {} // Ignore repeating
                             }
                         }
@@ -318,7 +579,9 @@ pub fn new() -> Result<Hook> {
                             Message::Register(key, callback, promise) => {
                                 promise.set(
                                     if code_for(key.key_code)
-                                        .and_then(|k| hotkeys.insert((k, key.modifiers), callback))
+                                        .and_then(|k| {
+                                            dispatcher.hotkeys.insert((k, key.modifiers), callback)
+                                        })
                                         .is_some()
                                     {
                                         Err(crate::Error::AlreadyRegistered)
@@ -329,9 +592,35 @@ pub fn new() -> Result<Hook> {
                             }
                             Message::Unregister(key, promise) => promise.set(
                                 code_for(key.key_code)
-                                    .and_then(|k| hotkeys.remove(&(k, key.modifiers)).map(drop))
+                                    .and_then(|k| {
+                                        dispatcher.hotkeys.remove(&(k, key.modifiers)).map(drop)
+                                    })
+                                    .ok_or(crate::Error::NotRegistered),
+                            ),
+                            Message::RegisterMouse(button, callback, promise) => {
+                                promise.set(
+                                    if dispatcher
+                                        .buttons
+                                        .insert(button_code_for(button), callback)
+                                        .is_some()
+                                    {
+                                        Err(crate::Error::AlreadyRegistered)
+                                    } else {
+                                        Ok(())
+                                    },
+                                );
+                            }
+                            Message::UnregisterMouse(button, promise) => promise.set(
+                                dispatcher
+                                    .buttons
+                                    .remove(&button_code_for(button))
+                                    .map(drop)
                                     .ok_or(crate::Error::NotRegistered),
                             ),
+                            Message::Capture(callback, promise) => {
+                                dispatcher.capture = callback;
+                                promise.set(Ok(()));
+                            }
                             Message::Resolve(key_code, promise) => {
                                 promise.set(resolve(&mut xlib, &mut display, key_code))
                             }
@@ -355,6 +644,8 @@ pub fn new() -> Result<Hook> {
         sender,
         waker,
         join_handle: Some(join_handle),
+        registered: Default::default(),
+        suspended,
     })
 }
 
@@ -378,3 +669,90 @@ fn resolve(
     let xdisplay = (*display)?;
     x11_impl::resolve(xlib, xdisplay, key_code)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc::{channel, RecvTimeoutError},
+        },
+        time::Duration,
+    };
+
+    use super::*;
+
+    fn dispatcher_with_hotkey(key: Key) -> (Dispatcher, Arc<AtomicUsize>) {
+        let mut dispatcher = Dispatcher::new();
+        let fired = Arc::new(AtomicUsize::new(0));
+        dispatcher.hotkeys.insert((key, Modifiers::empty()), {
+            let fired = fired.clone();
+            Box::new(move || {
+                fired.fetch_add(1, Ordering::Relaxed);
+            })
+        });
+        (dispatcher, fired)
+    }
+
+    #[test]
+    fn capture_takes_precedence_over_the_hotkeys() {
+        let (mut dispatcher, fired) = dispatcher_with_hotkey(Key::KEY_A);
+        let (tx, rx) = channel();
+        dispatcher.capture = Some(Box::new(move |hotkey| tx.send(hotkey).unwrap()));
+
+        // Modifiers on their own don't end the capture.
+        dispatcher.press(Key::KEY_LEFTSHIFT, false);
+        dispatcher.press(Key::KEY_A, false);
+        dispatcher.release(Key::KEY_LEFTSHIFT);
+
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(KeyCode::KeyA.with_modifiers(Modifiers::SHIFT)),
+        );
+        assert_eq!(fired.load(Ordering::Relaxed), 0);
+
+        dispatcher.press(Key::KEY_A, false);
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn capture_is_suppressed_while_suspended() {
+        let (mut dispatcher, fired) = dispatcher_with_hotkey(Key::KEY_A);
+        let (tx, rx) = channel();
+        dispatcher.capture = Some(Box::new(move |hotkey| tx.send(hotkey).unwrap()));
+
+        dispatcher.press(Key::KEY_A, true);
+        assert!(dispatcher.capture.is_some());
+        assert_eq!(fired.load(Ordering::Relaxed), 0);
+
+        // The modifiers are still tracked while suspended.
+        dispatcher.press(Key::KEY_LEFTCTRL, true);
+        dispatcher.press(Key::KEY_B, false);
+
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(KeyCode::KeyB.with_modifiers(Modifiers::CONTROL)),
+        );
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(100)),
+            Err(RecvTimeoutError::Disconnected),
+        );
+    }
+
+    #[test]
+    fn capture_can_register_the_captured_hotkey() {
+        let mut dispatcher = Dispatcher::new();
+        let (tx, rx) = channel();
+        let (registered_tx, registered_rx) = channel();
+        dispatcher.capture = Some(Box::new(move |_| {
+            // The capture waits for the registration of the captured hotkey,
+            // which only gets handled once the hook's thread gets control
+            // back after the key press.
+            let _ = tx.send(registered_rx.recv_timeout(Duration::from_secs(5)).is_ok());
+        }));
+
+        dispatcher.press(Key::KEY_A, false);
+        let _ = registered_tx.send(());
+        assert_eq!(rx.recv_timeout(Duration::from_secs(10)), Ok(true));
+    }
+}
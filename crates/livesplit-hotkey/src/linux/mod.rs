@@ -1,6 +1,14 @@
-use std::{fmt, thread::JoinHandle};
-
-use crate::{ConsumePreference, Hotkey, KeyCode, Result};
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc, Mutex,
+    },
+    thread::JoinHandle,
+};
+
+use crate::{Button, ConsumePreference, Hotkey, KeyCode, Result};
 use crossbeam_channel::Sender;
 use mio::Waker;
 use nix::unistd::{getgroups, Group};
@@ -14,6 +22,7 @@ mod x11_impl;
 pub enum Error {
     EvDev,
     EPoll,
+    GrabKeyboard,
     NoXLib,
     OpenXServerConnection,
     ThreadStopped,
@@ -30,6 +39,7 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Self::EvDev => "Failed fetching events from evdev.",
             Self::EPoll => "Failed polling the event file descriptors.",
+            Self::GrabKeyboard => "Failed grabbing the keyboard.",
             Self::NoXLib => "Failed dynamically linking to X11.",
             Self::OpenXServerConnection => "Failed opening a connection to the X11 server.",
             Self::ThreadStopped => "The background thread stopped unexpectedly.",
@@ -37,6 +47,8 @@ impl fmt::Display for Error {
     }
 }
 
+type CaptureCallback = Box<dyn FnOnce(Hotkey) + Send + 'static>;
+
 enum Message {
     Register(
         Hotkey,
@@ -44,6 +56,13 @@ enum Message {
         Promise<Result<()>>,
     ),
     Unregister(Hotkey, Promise<Result<()>>),
+    RegisterMouse(
+        Button,
+        Box<dyn FnMut() + Send + 'static>,
+        Promise<Result<()>>,
+    ),
+    UnregisterMouse(Button, Promise<Result<()>>),
+    Capture(Option<CaptureCallback>, Promise<Result<()>>),
     Resolve(KeyCode, Promise<Option<char>>),
     End,
 }
@@ -52,6 +71,10 @@ pub struct Hook {
     sender: Sender<Message>,
     waker: Waker,
     join_handle: Option<JoinHandle<Result<()>>>,
+    // The background threads key their hotkeys by the platform specific key
+    // codes, so we additionally keep track of the registered hotkeys here.
+    registered: Mutex<HashSet<Hotkey>>,
+    suspended: Arc<AtomicBool>,
 }
 
 impl Drop for Hook {
@@ -102,7 +125,9 @@ impl Hook {
 
         self.waker.wake().map_err(|_| Error::ThreadStopped)?;
 
-        future.value().ok_or(Error::ThreadStopped)?
+        future.value().ok_or(Error::ThreadStopped)??;
+        self.registered.lock().unwrap().insert(hotkey);
+        Ok(())
     }
 
     pub fn unregister(&self, hotkey: Hotkey) -> Result<()> {
@@ -114,6 +139,70 @@ impl Hook {
 
         self.waker.wake().map_err(|_| Error::ThreadStopped)?;
 
+        future.value().ok_or(Error::ThreadStopped)??;
+        self.registered.lock().unwrap().remove(&hotkey);
+        Ok(())
+    }
+
+    pub fn registered(&self) -> Vec<Hotkey> {
+        self.registered.lock().unwrap().iter().copied().collect()
+    }
+
+    pub fn capture_next<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        self.capture(Some(Box::new(callback)))
+    }
+
+    pub fn cancel_capture(&self) -> Result<()> {
+        self.capture(None)
+    }
+
+    fn capture(&self, callback: Option<CaptureCallback>) -> Result<()> {
+        let (future, promise) = future_promise();
+
+        self.sender
+            .send(Message::Capture(callback, promise))
+            .map_err(|_| Error::ThreadStopped)?;
+
+        self.waker.wake().map_err(|_| Error::ThreadStopped)?;
+
+        future.value().ok_or(Error::ThreadStopped)?
+    }
+
+    pub fn set_suspended(&self, suspended: bool) {
+        self.suspended.store(suspended, atomic::Ordering::Relaxed);
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(atomic::Ordering::Relaxed)
+    }
+
+    pub fn register_mouse<F>(&self, button: Button, callback: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let (future, promise) = future_promise();
+
+        self.sender
+            .send(Message::RegisterMouse(button, Box::new(callback), promise))
+            .map_err(|_| Error::ThreadStopped)?;
+
+        self.waker.wake().map_err(|_| Error::ThreadStopped)?;
+
+        future.value().ok_or(Error::ThreadStopped)?
+    }
+
+    pub fn unregister_mouse(&self, button: Button) -> Result<()> {
+        let (future, promise) = future_promise();
+
+        self.sender
+            .send(Message::UnregisterMouse(button, promise))
+            .map_err(|_| Error::ThreadStopped)?;
+
+        self.waker.wake().map_err(|_| Error::ThreadStopped)?;
+
         future.value().ok_or(Error::ThreadStopped)?
     }
 
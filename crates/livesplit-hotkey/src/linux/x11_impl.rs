@@ -2,17 +2,24 @@ use std::{
     collections::HashMap,
     mem::MaybeUninit,
     os::raw::{c_int, c_uint},
-    ptr, thread,
+    ptr,
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc,
+    },
+    thread,
 };
 
+use evdev::Key;
 use mio::{unix::SourceFd, Events, Interest, Poll, Token, Waker};
 use x11_dl::xlib::{
-    AnyKey, AnyModifier, ControlMask, Display, GrabModeAsync, KeyPress, LockMask, Mod1Mask,
-    Mod2Mask, Mod3Mask, Mod4Mask, ShiftMask, XErrorEvent, XKeyEvent, Xlib, _XDisplay,
+    AnyKey, AnyModifier, ButtonPress, ButtonPressMask, ControlMask, CurrentTime, Display,
+    GrabModeAsync, GrabModeSync, GrabSuccess, KeyPress, LockMask, Mod1Mask, Mod2Mask, Mod3Mask,
+    Mod4Mask, ReplayPointer, ShiftMask, XButtonEvent, XErrorEvent, XKeyEvent, Xlib, _XDisplay,
 };
 
-use super::{Error, Hook, Message};
-use crate::{KeyCode, Modifiers, Result};
+use super::{CaptureCallback, Error, Hook, Message};
+use crate::{Button, KeyCode, Modifiers, Result};
 
 unsafe fn ungrab_all(xlib: &Xlib, display: *mut Display) {
     let screencount = (xlib.XScreenCount)(display);
@@ -101,6 +108,61 @@ unsafe fn grab_key(
     (xlib.XFlush)(display);
 }
 
+unsafe fn grab_button(xlib: &Xlib, display: *mut Display, button: c_uint, ungrab: bool) {
+    let screencount = (xlib.XScreenCount)(display);
+    for screen in 0..screencount {
+        let window = (xlib.XRootWindow)(display, screen);
+
+        // Mouse buttons trigger regardless of the modifiers, so we don't need
+        // to register every combination of them. The pointer is grabbed
+        // synchronously, so each press can be replayed to the window below the
+        // pointer. Otherwise grabbing a button such as the left button would
+        // swallow every click on the whole screen.
+        if ungrab {
+            (xlib.XUngrabButton)(display, button, AnyModifier, window);
+        } else {
+            (xlib.XGrabButton)(
+                display,
+                button,
+                AnyModifier,
+                window,
+                false as _,
+                ButtonPressMask as c_uint,
+                GrabModeSync,
+                GrabModeAsync,
+                0,
+                0,
+            );
+        }
+    }
+
+    (xlib.XFlush)(display);
+}
+
+// Capturing needs to see every key, not just the grabbed ones, so the whole
+// keyboard is grabbed until the capture ends.
+unsafe fn grab_keyboard(xlib: &Xlib, display: *mut Display, ungrab: bool) -> Result<()> {
+    if ungrab {
+        (xlib.XUngrabKeyboard)(display, CurrentTime);
+    } else {
+        let window = (xlib.XDefaultRootWindow)(display);
+        let status = (xlib.XGrabKeyboard)(
+            display,
+            window,
+            false as _,
+            GrabModeAsync,
+            GrabModeAsync,
+            CurrentTime,
+        );
+        if status != GrabSuccess {
+            return Err(Error::GrabKeyboard.into());
+        }
+    }
+
+    (xlib.XFlush)(display);
+    Ok(())
+}
+
 unsafe extern "C" fn handle_error(_: *mut Display, _: *mut XErrorEvent) -> c_int {
     0
 }
@@ -112,6 +174,20 @@ const fn code_for(key: KeyCode) -> Option<c_uint> {
     }
 }
 
+fn key_code_for(code: c_uint) -> Option<KeyCode> {
+    super::evdev_impl::key_code_for(Key::new(code.checked_sub(8)?.try_into().ok()?))
+}
+
+const fn button_code_for(button: Button) -> c_uint {
+    match button {
+        Button::Left => 1,
+        Button::Middle => 2,
+        Button::Right => 3,
+        Button::X1 => 8,
+        Button::X2 => 9,
+    }
+}
+
 const X_TOKEN: Token = Token(0);
 const PING_TOKEN: Token = Token(1);
 
@@ -144,6 +220,9 @@ pub fn new() -> Result<Hook> {
         unsafe impl Send for XData {}
         let xdata = XData(xlib, display);
 
+        let suspended = Arc::new(AtomicBool::new(false));
+        let is_suspended = suspended.clone();
+
         let join_handle = thread::spawn(move || -> Result<()> {
             // Force the whole XData to be moved.
             let XData(xlib, display) = { xdata };
@@ -151,6 +230,8 @@ pub fn new() -> Result<Hook> {
             let mut result = Ok(());
             let mut events = Events::with_capacity(1024);
             let mut hotkeys = HashMap::new();
+            let mut buttons = HashMap::new();
+            let mut capture: Option<CaptureCallback> = None;
 
             // For some reason we need to call this once for any KeyGrabs to
             // actually do anything.
@@ -194,6 +275,37 @@ pub fn new() -> Result<Hook> {
                                     };
                                     promise.set(res);
                                 }
+                                Message::RegisterMouse(button, callback, promise) => {
+                                    let code = button_code_for(button);
+                                    promise.set(if buttons.insert(code, callback).is_some() {
+                                        Err(crate::Error::AlreadyRegistered)
+                                    } else {
+                                        grab_button(&xlib, display, code, false);
+                                        Ok(())
+                                    });
+                                }
+                                Message::UnregisterMouse(button, promise) => {
+                                    let code = button_code_for(button);
+                                    let res = buttons
+                                        .remove(&code)
+                                        .map(drop)
+                                        .ok_or(crate::Error::NotRegistered);
+                                    if res.is_ok() {
+                                        grab_button(&xlib, display, code, true);
+                                    }
+                                    promise.set(res);
+                                }
+                                Message::Capture(callback, promise) => {
+                                    let res = match (&capture, &callback) {
+                                        (None, Some(_)) => grab_keyboard(&xlib, display, false),
+                                        (Some(_), None) => grab_keyboard(&xlib, display, true),
+                                        _ => Ok(()),
+                                    };
+                                    if res.is_ok() {
+                                        capture = callback;
+                                    }
+                                    promise.set(res);
+                                }
                                 Message::Resolve(key_code, promise) => {
                                     promise.set(resolve(&xlib, display, key_code))
                                 }
@@ -208,6 +320,16 @@ pub fn new() -> Result<Hook> {
                             let err_code = (xlib.XNextEvent)(display, event.as_mut_ptr());
                             if err_code == 0 {
                                 let event = event.assume_init();
+                                if event.get_type() == ButtonPress {
+                                    // The pointer stays frozen until the press
+                                    // is replayed, even while suspended.
+                                    let event: &XButtonEvent = event.as_ref();
+                                    (xlib.XAllowEvents)(display, ReplayPointer, event.time);
+                                    (xlib.XFlush)(display);
+                                }
+                                if is_suspended.load(atomic::Ordering::Relaxed) {
+                                    continue;
+                                }
                                 if event.get_type() == KeyPress {
                                     let event: &XKeyEvent = event.as_ref();
 
@@ -225,11 +347,29 @@ pub fn new() -> Result<Hook> {
                                         modifiers.insert(Modifiers::META);
                                     }
 
-                                    if let Some(callback) =
+                                    let captured = key_code_for(event.keycode)
+                                        .filter(|key_code| !key_code.is_modifier())
+                                        .and_then(|key_code| Some((capture.take()?, key_code)));
+
+                                    if let Some((capture, key_code)) = captured {
+                                        let _ = grab_keyboard(&xlib, display, true);
+                                        // The capture is most likely going to
+                                        // register the captured hotkey, which
+                                        // waits for this thread, so it can't
+                                        // run on this thread.
+                                        let hotkey = key_code.with_modifiers(modifiers);
+                                        thread::spawn(move || capture(hotkey));
+                                    } else if let Some(callback) =
                                         hotkeys.get_mut(&(event.keycode, modifiers))
                                     {
                                         callback();
                                     }
+                                } else if event.get_type() == ButtonPress {
+                                    let event: &XButtonEvent = event.as_ref();
+
+                                    if let Some(callback) = buttons.get_mut(&event.button) {
+                                        callback();
+                                    }
                                 }
                             }
                         }
@@ -238,6 +378,9 @@ pub fn new() -> Result<Hook> {
             }
 
             ungrab_all(&xlib, display);
+            for &code in buttons.keys() {
+                grab_button(&xlib, display, code, true);
+            }
 
             (xlib.XCloseDisplay)(display);
 
@@ -248,6 +391,8 @@ pub fn new() -> Result<Hook> {
             sender,
             waker,
             join_handle: Some(join_handle),
+            registered: Default::default(),
+            suspended,
         })
     }
 }
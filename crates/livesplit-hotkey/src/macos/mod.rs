@@ -20,13 +20,17 @@ use self::{
         EventRef, EventTapLocation, EventTapOptions, EventTapPlacement, EventTapProxy, EventType,
     },
 };
-use crate::{ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
+use crate::{Button, ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
 use core::ptr::null_mut;
 use std::{
     collections::{hash_map::Entry, HashMap},
     ffi::c_void,
     fmt,
-    sync::{mpsc::channel, Arc, Mutex},
+    sync::{
+        atomic::{self, AtomicBool},
+        mpsc::channel,
+        Arc, Mutex,
+    },
     thread,
 };
 
@@ -76,6 +80,9 @@ unsafe impl Sync for RunLoop {}
 
 struct State {
     hotkeys: Mutex<HashMap<Hotkey, Box<dyn FnMut() + Send + 'static>>>,
+    buttons: Mutex<HashMap<Button, Box<dyn FnMut() + Send + 'static>>>,
+    capture: Mutex<Option<Box<dyn FnOnce(Hotkey) + Send + 'static>>>,
+    suspended: AtomicBool,
 }
 
 /// A hook allows you to listen to hotkeys.
@@ -105,6 +112,9 @@ impl Hook {
 
         let state = Arc::new(State {
             hotkeys: Mutex::new(HashMap::new()),
+            buttons: Mutex::new(HashMap::new()),
+            capture: Mutex::new(None),
+            suspended: AtomicBool::new(false),
         });
         let thread_state = state.clone();
 
@@ -131,7 +141,10 @@ impl Hook {
                 } else {
                     EventTapOptions::LISTEN_ONLY
                 },
-                EventMask::KEY_DOWN,
+                EventMask::KEY_DOWN
+                    | EventMask::LEFT_MOUSE_DOWN
+                    | EventMask::RIGHT_MOUSE_DOWN
+                    | EventMask::OTHER_MOUSE_DOWN,
                 Some(callback),
                 state_ptr as *mut c_void,
             );
@@ -196,6 +209,56 @@ impl Hook {
         Ok(())
     }
 
+    pub fn registered(&self) -> Vec<Hotkey> {
+        self.state.hotkeys.lock().unwrap().keys().copied().collect()
+    }
+
+    pub fn capture_next<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        *self.state.capture.lock().unwrap() = Some(Box::new(callback));
+        Ok(())
+    }
+
+    pub fn cancel_capture(&self) -> Result<()> {
+        self.state.capture.lock().unwrap().take();
+        Ok(())
+    }
+
+    pub fn set_suspended(&self, suspended: bool) {
+        self.state
+            .suspended
+            .store(suspended, atomic::Ordering::Relaxed);
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.state.suspended.load(atomic::Ordering::Relaxed)
+    }
+
+    pub fn register_mouse<F>(&self, button: Button, callback: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        if let Entry::Vacant(vacant) = self.state.buttons.lock().unwrap().entry(button) {
+            vacant.insert(Box::new(callback));
+            Ok(())
+        } else {
+            Err(crate::Error::AlreadyRegistered)
+        }
+    }
+
+    pub fn unregister_mouse(&self, button: Button) -> Result<()> {
+        let _ = self
+            .state
+            .buttons
+            .lock()
+            .unwrap()
+            .remove(&button)
+            .ok_or(crate::Error::NotRegistered)?;
+        Ok(())
+    }
+
     pub fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         unsafe {
             let current_keyboard_raw = TISCopyCurrentKeyboardInputSource();
@@ -302,9 +365,35 @@ impl Hook {
     }
 }
 
+unsafe fn mouse_callback(ty: EventType, event: EventRef, state: &State) -> EventRef {
+    let button = match ty {
+        EventType::LEFT_MOUSE_DOWN => Button::Left,
+        EventType::RIGHT_MOUSE_DOWN => Button::Right,
+        EventType::OTHER_MOUSE_DOWN => {
+            match cg::CGEventGetIntegerValueField(event, EventField::MOUSE_EVENT_BUTTON_NUMBER) {
+                2 => Button::Middle,
+                3 => Button::X1,
+                4 => Button::X2,
+                _ => return event,
+            }
+        }
+        _ => return event,
+    };
+
+    if let Some(callback) = state.buttons.lock().unwrap().get_mut(&button) {
+        callback();
+
+        // Same as with the keys, we return null so the event gets deleted if
+        // the hook is consuming.
+        null_mut()
+    } else {
+        event
+    }
+}
+
 unsafe extern "C" fn callback(
     _: EventTapProxy,
-    _: EventType,
+    ty: EventType,
     event: EventRef,
     user_info: *mut c_void,
 ) -> EventRef {
@@ -315,6 +404,18 @@ unsafe extern "C" fn callback(
     //   return event;
     // }
 
+    let state = user_info as *const State;
+    let state = &*state;
+
+    // While suspended, the events are passed on without being consumed.
+    if state.suspended.load(atomic::Ordering::Relaxed) {
+        return event;
+    }
+
+    if ty != EventType::KEY_DOWN {
+        return mouse_callback(ty, event, state);
+    }
+
     let is_repeating =
         cg::CGEventGetIntegerValueField(event, EventField::KEYBOARD_EVENT_AUTOREPEAT);
     if is_repeating != 0 {
@@ -449,9 +550,6 @@ unsafe extern "C" fn callback(
         _ => return event,
     };
 
-    let state = user_info as *const State;
-    let state = &*state;
-
     let modifier_flags = CGEventGetFlags(event);
     let mut modifiers = Modifiers::empty();
 
@@ -479,12 +577,22 @@ unsafe extern "C" fn callback(
         modifiers.insert(Modifiers::META);
     }
 
-    if let Some(callback) = state
-        .hotkeys
-        .lock()
-        .unwrap()
-        .get_mut(&key_code.with_modifiers(modifiers))
-    {
+    let hotkey = key_code.with_modifiers(modifiers);
+
+    if !key_code.is_modifier() {
+        // The capture is taken out first, so the state isn't locked while its
+        // callback runs.
+        let capture = state.capture.lock().unwrap().take();
+        if let Some(capture) = capture {
+            capture(hotkey);
+
+            // The captured key is consumed the same way as the registered
+            // hotkeys.
+            return null_mut();
+        }
+    }
+
+    if let Some(callback) = state.hotkeys.lock().unwrap().get_mut(&hotkey) {
         callback();
 
         // If we handled the event and the hook is consuming, we should return
@@ -17,6 +17,23 @@ bitflags::bitflags! {
     }
 }
 
+impl Modifiers {
+    /// Parses the name of a single modifier, ignoring its case.
+    pub(crate) fn parse_single(name: &str) -> Option<Self> {
+        Some(if name.eq_ignore_ascii_case("Ctrl") {
+            Modifiers::CONTROL
+        } else if name.eq_ignore_ascii_case("Alt") {
+            Modifiers::ALT
+        } else if name.eq_ignore_ascii_case("Meta") {
+            Modifiers::META
+        } else if name.eq_ignore_ascii_case("Shift") {
+            Modifiers::SHIFT
+        } else {
+            return None;
+        })
+    }
+}
+
 impl fmt::Display for Modifiers {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut first = true;
@@ -54,13 +71,7 @@ impl FromStr for Modifiers {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut modifiers = Modifiers::empty();
         for modifier in s.split('+').map(str::trim) {
-            match modifier {
-                "Ctrl" => modifiers.insert(Modifiers::CONTROL),
-                "Alt" => modifiers.insert(Modifiers::ALT),
-                "Meta" => modifiers.insert(Modifiers::META),
-                "Shift" => modifiers.insert(Modifiers::SHIFT),
-                _ => return Err(()),
-            }
+            modifiers.insert(Modifiers::parse_single(modifier).ok_or(())?);
         }
         Ok(modifiers)
     }
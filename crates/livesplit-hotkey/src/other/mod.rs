@@ -1,5 +1,6 @@
-use crate::{ConsumePreference, Hotkey, KeyCode, Result};
-use alloc::{fmt, string::String};
+use crate::{Button, ConsumePreference, Hotkey, KeyCode, Result};
+use alloc::{fmt, string::String, vec::Vec};
+use core::sync::atomic::{self, AtomicBool};
 
 #[derive(Debug)]
 #[non_exhaustive]
@@ -12,12 +13,16 @@ impl fmt::Display for Error {
     }
 }
 
-pub struct Hook;
+pub struct Hook {
+    suspended: AtomicBool,
+}
 
 impl Hook {
     #[inline]
     pub fn new(_: ConsumePreference) -> Result<Self> {
-        Ok(Hook)
+        Ok(Hook {
+            suspended: AtomicBool::new(false),
+        })
     }
 
     #[inline]
@@ -33,6 +38,47 @@ impl Hook {
         Ok(())
     }
 
+    #[inline]
+    pub fn registered(&self) -> Vec<Hotkey> {
+        Vec::new()
+    }
+
+    #[inline]
+    pub fn capture_next<F>(&self, _: F) -> Result<()>
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        Ok(())
+    }
+
+    #[inline]
+    pub fn cancel_capture(&self) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    pub fn set_suspended(&self, suspended: bool) {
+        self.suspended.store(suspended, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(atomic::Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn register_mouse<F>(&self, _: Button, _: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        Ok(())
+    }
+
+    #[inline]
+    pub fn unregister_mouse(&self, _: Button) -> Result<()> {
+        Ok(())
+    }
+
     #[inline]
     pub fn try_resolve(&self, _key_code: KeyCode) -> Option<String> {
         None
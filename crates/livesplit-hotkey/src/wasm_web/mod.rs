@@ -1,14 +1,17 @@
-use crate::{ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
+use crate::{Button, ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
 use js_sys::{Function, Promise, Reflect};
 use wasm_bindgen::{prelude::*, JsCast};
-use web_sys::{window, Event, Gamepad, GamepadButton, KeyboardEvent};
+use web_sys::{window, Event, Gamepad, GamepadButton, KeyboardEvent, MouseEvent};
 
 use std::{
     cell::{Cell, RefCell},
     collections::hash_map::{Entry, HashMap},
     fmt,
     rc::Rc,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc, Mutex,
+    },
 };
 
 #[derive(Debug)]
@@ -27,7 +30,11 @@ impl fmt::Display for Error {
 
 pub struct Hook {
     hotkeys: Arc<Mutex<HashMap<Hotkey, Box<dyn FnMut() + Send + 'static>>>>,
+    buttons: Arc<Mutex<HashMap<Button, Box<dyn FnMut() + Send + 'static>>>>,
+    capture: Arc<Mutex<Option<Box<dyn FnOnce(Hotkey) + Send + 'static>>>>,
+    suspended: Arc<AtomicBool>,
     keyboard_callback: Closure<dyn FnMut(Event)>,
+    mouse_callback: Closure<dyn FnMut(Event)>,
     gamepad_callback: Closure<dyn FnMut()>,
     interval_id: Cell<Option<i32>>,
     keyboard_layout_resolver: Rc<RefCell<Option<(JsValue, Function)>>>,
@@ -41,6 +48,10 @@ impl Drop for Hook {
                 "keydown",
                 self.keyboard_callback.as_ref().unchecked_ref(),
             );
+            let _ = window.remove_event_listener_with_callback(
+                "mousedown",
+                self.mouse_callback.as_ref().unchecked_ref(),
+            );
             if let Some(interval_id) = self.interval_id.get() {
                 window.clear_interval_with_handle(interval_id);
             }
@@ -86,12 +97,20 @@ impl Hook {
 
         let window = window().ok_or(crate::Error::Platform(Error::FailedToCreateHook))?;
 
+        let capture = Arc::new(Mutex::new(None::<Box<dyn FnOnce(Hotkey) + Send + 'static>>));
+        let suspended = Arc::new(AtomicBool::new(false));
+
         let hotkey_map = hotkeys.clone();
+        let capture_slot = capture.clone();
+        let is_suspended = suspended.clone();
         let keyboard_callback = Closure::wrap(Box::new(move |event: Event| {
             // Despite all sorts of documentation claiming that `keydown` events
             // pass you a `KeyboardEvent`, this is not actually always the case
             // in browsers. At least in Chrome selecting an element of an
             // `input` sends a `keydown` event that is not a `KeyboardEvent`.
+            if is_suspended.load(atomic::Ordering::Relaxed) {
+                return;
+            }
             if let Ok(event) = event.dyn_into::<KeyboardEvent>() {
                 if !event.repeat() {
                     if let Ok(code) = event.code().parse::<KeyCode>() {
@@ -116,11 +135,22 @@ impl Hook {
                             modifiers.insert(Modifiers::META);
                         }
 
-                        if let Some(callback) = hotkey_map
-                            .lock()
-                            .unwrap()
-                            .get_mut(&code.with_modifiers(modifiers))
-                        {
+                        let hotkey = code.with_modifiers(modifiers);
+
+                        if !code.is_modifier() {
+                            // The capture is taken out first, so the slot isn't
+                            // locked while its callback runs.
+                            let capture = capture_slot.lock().unwrap().take();
+                            if let Some(capture) = capture {
+                                capture(hotkey);
+                                if prevent_default {
+                                    event.prevent_default();
+                                }
+                                return;
+                            }
+                        }
+
+                        if let Some(callback) = hotkey_map.lock().unwrap().get_mut(&hotkey) {
                             callback();
                             if prevent_default {
                                 event.prevent_default();
@@ -135,7 +165,42 @@ impl Hook {
             .add_event_listener_with_callback("keydown", keyboard_callback.as_ref().unchecked_ref())
             .map_err(|_| crate::Error::Platform(Error::FailedToCreateHook))?;
 
+        let buttons = Arc::new(Mutex::new(HashMap::<
+            Button,
+            Box<dyn FnMut() + Send + 'static>,
+        >::new()));
+
+        let button_map = buttons.clone();
+        let is_suspended = suspended.clone();
+        let mouse_callback = Closure::wrap(Box::new(move |event: Event| {
+            if is_suspended.load(atomic::Ordering::Relaxed) {
+                return;
+            }
+            if let Ok(event) = event.dyn_into::<MouseEvent>() {
+                let button = match event.button() {
+                    0 => Button::Left,
+                    1 => Button::Middle,
+                    2 => Button::Right,
+                    3 => Button::X1,
+                    4 => Button::X2,
+                    _ => return,
+                };
+
+                if let Some(callback) = button_map.lock().unwrap().get_mut(&button) {
+                    callback();
+                    if prevent_default {
+                        event.prevent_default();
+                    }
+                }
+            }
+        }) as Box<dyn FnMut(Event)>);
+
+        window
+            .add_event_listener_with_callback("mousedown", mouse_callback.as_ref().unchecked_ref())
+            .map_err(|_| crate::Error::Platform(Error::FailedToCreateHook))?;
+
         let hotkey_map = hotkeys.clone();
+        let is_suspended = suspended.clone();
 
         let mut states = Vec::new();
         let navigator = window.navigator();
@@ -186,7 +251,12 @@ impl Hook {
                         {
                             if let Ok(button) = button.dyn_into::<GamepadButton>() {
                                 let pressed = button.pressed();
-                                if pressed && !*state {
+                                // The state is still tracked while suspended, so
+                                // held buttons don't fire when resuming.
+                                if pressed
+                                    && !*state
+                                    && !is_suspended.load(atomic::Ordering::Relaxed)
+                                {
                                     if let Some(callback) =
                                         hotkey_map.lock().unwrap().get_mut(&code.into())
                                     {
@@ -203,7 +273,11 @@ impl Hook {
 
         Ok(Hook {
             hotkeys,
+            buttons,
+            capture,
+            suspended,
             keyboard_callback,
+            mouse_callback,
             gamepad_callback,
             interval_id: Cell::new(None),
             keyboard_layout_resolver,
@@ -241,6 +315,51 @@ impl Hook {
         }
     }
 
+    pub fn registered(&self) -> Vec<Hotkey> {
+        self.hotkeys.lock().unwrap().keys().copied().collect()
+    }
+
+    pub fn capture_next<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        *self.capture.lock().unwrap() = Some(Box::new(callback));
+        Ok(())
+    }
+
+    pub fn cancel_capture(&self) -> Result<()> {
+        self.capture.lock().unwrap().take();
+        Ok(())
+    }
+
+    pub fn set_suspended(&self, suspended: bool) {
+        self.suspended.store(suspended, atomic::Ordering::Relaxed);
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(atomic::Ordering::Relaxed)
+    }
+
+    pub fn register_mouse<F>(&self, button: Button, callback: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        if let Entry::Vacant(vacant) = self.buttons.lock().unwrap().entry(button) {
+            vacant.insert(Box::new(callback));
+            Ok(())
+        } else {
+            Err(crate::Error::AlreadyRegistered)
+        }
+    }
+
+    pub fn unregister_mouse(&self, button: Button) -> Result<()> {
+        if self.buttons.lock().unwrap().remove(&button).is_some() {
+            Ok(())
+        } else {
+            Err(crate::Error::NotRegistered)
+        }
+    }
+
     pub fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         let keyboard_layout_resolver = self.keyboard_layout_resolver.borrow();
         let (layout, resolve_fn) = keyboard_layout_resolver.as_ref()?;
@@ -0,0 +1,185 @@
+use std::{
+    mem,
+    sync::{
+        atomic::{self, AtomicBool},
+        mpsc::Sender,
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use windows_sys::Win32::{
+    Foundation::ERROR_SUCCESS,
+    UI::Input::XboxController::{
+        XInputGetState, XINPUT_GAMEPAD, XINPUT_GAMEPAD_A, XINPUT_GAMEPAD_B, XINPUT_GAMEPAD_BACK,
+        XINPUT_GAMEPAD_DPAD_DOWN, XINPUT_GAMEPAD_DPAD_LEFT, XINPUT_GAMEPAD_DPAD_RIGHT,
+        XINPUT_GAMEPAD_DPAD_UP, XINPUT_GAMEPAD_LEFT_SHOULDER, XINPUT_GAMEPAD_LEFT_THUMB,
+        XINPUT_GAMEPAD_RIGHT_SHOULDER, XINPUT_GAMEPAD_RIGHT_THUMB, XINPUT_GAMEPAD_START,
+        XINPUT_GAMEPAD_X, XINPUT_GAMEPAD_Y, XINPUT_STATE,
+    },
+};
+
+use super::Input;
+use crate::KeyCode;
+
+// XInput supports at most four controllers.
+const MAX_CONTROLLERS: u32 = 4;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(1000 / 60);
+
+// Querying a slot without a controller is a lot more expensive than querying
+// a connected one, so empty slots are only checked about every two seconds.
+const DISCONNECTED_RETRY_POLLS: u32 = 120;
+
+// This is the same threshold XInput recommends for treating the analog
+// triggers as digital buttons.
+const TRIGGER_THRESHOLD: u8 = 30;
+
+// The buttons are mapped to the "Standard Gamepad" layout, the same way the
+// browsers do it:
+// https://w3c.github.io/gamepad/#remapping
+// The triggers (Gamepad6 and Gamepad7) are handled separately and XInput does
+// not expose the guide button (Gamepad16).
+const BUTTONS: [(u16, KeyCode); 14] = [
+    (XINPUT_GAMEPAD_A, KeyCode::Gamepad0),
+    (XINPUT_GAMEPAD_B, KeyCode::Gamepad1),
+    (XINPUT_GAMEPAD_X, KeyCode::Gamepad2),
+    (XINPUT_GAMEPAD_Y, KeyCode::Gamepad3),
+    (XINPUT_GAMEPAD_LEFT_SHOULDER, KeyCode::Gamepad4),
+    (XINPUT_GAMEPAD_RIGHT_SHOULDER, KeyCode::Gamepad5),
+    (XINPUT_GAMEPAD_BACK, KeyCode::Gamepad8),
+    (XINPUT_GAMEPAD_START, KeyCode::Gamepad9),
+    (XINPUT_GAMEPAD_LEFT_THUMB, KeyCode::Gamepad10),
+    (XINPUT_GAMEPAD_RIGHT_THUMB, KeyCode::Gamepad11),
+    (XINPUT_GAMEPAD_DPAD_UP, KeyCode::Gamepad12),
+    (XINPUT_GAMEPAD_DPAD_DOWN, KeyCode::Gamepad13),
+    (XINPUT_GAMEPAD_DPAD_LEFT, KeyCode::Gamepad14),
+    (XINPUT_GAMEPAD_DPAD_RIGHT, KeyCode::Gamepad15),
+];
+
+/// Polls the gamepads on a background thread for as long as it is alive.
+/// XInput doesn't provide any events, so the thread compares the state of the
+/// buttons to the previous state to find out which buttons got pressed. The
+/// presses are sent as hotkeys without any modifiers. Gamepad input is never
+/// consumed. Dropping the poller stops the thread and waits for it to finish.
+pub struct Poller {
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for Poller {
+    fn drop(&mut self) {
+        self.running.store(false, atomic::Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Poller {
+    pub fn start(events: Sender<Input>) -> Self {
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread = thread::spawn({
+            let running = running.clone();
+            move || poll(&events, &running)
+        });
+
+        Self {
+            running,
+            thread: Some(thread),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Slot {
+    pressed: Vec<KeyCode>,
+    skipped_polls: u32,
+}
+
+fn pressed_buttons(gamepad: &XINPUT_GAMEPAD) -> Vec<KeyCode> {
+    let mut pressed = Vec::new();
+    for (mask, key_code) in BUTTONS {
+        if gamepad.wButtons & mask != 0 {
+            pressed.push(key_code);
+        }
+    }
+    if gamepad.bLeftTrigger > TRIGGER_THRESHOLD {
+        pressed.push(KeyCode::Gamepad6);
+    }
+    if gamepad.bRightTrigger > TRIGGER_THRESHOLD {
+        pressed.push(KeyCode::Gamepad7);
+    }
+    pressed
+}
+
+fn poll(events: &Sender<Input>, running: &AtomicBool) {
+    let mut slots: [Slot; MAX_CONTROLLERS as usize] = Default::default();
+
+    while running.load(atomic::Ordering::Relaxed) {
+        for (user_index, slot) in (0..MAX_CONTROLLERS).zip(&mut slots) {
+            if slot.skipped_polls > 0 {
+                slot.skipped_polls -= 1;
+                continue;
+            }
+
+            let pressed = unsafe {
+                let mut state: XINPUT_STATE = mem::zeroed();
+                if XInputGetState(user_index, &mut state) == ERROR_SUCCESS {
+                    pressed_buttons(&state.Gamepad)
+                } else {
+                    slot.skipped_polls = DISCONNECTED_RETRY_POLLS;
+                    Vec::new()
+                }
+            };
+
+            for &key_code in &pressed {
+                if !slot.pressed.contains(&key_code)
+                    && events.send(Input::Hotkey(key_code.into())).is_err()
+                {
+                    return;
+                }
+            }
+
+            slot.pressed = pressed;
+        }
+
+        // The poller unparks the thread when it gets dropped, so it doesn't
+        // have to wait for the full interval to stop.
+        thread::park_timeout(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pressed_buttons_follow_the_standard_gamepad_layout() {
+        let mut gamepad: XINPUT_GAMEPAD = unsafe { mem::zeroed() };
+        assert!(pressed_buttons(&gamepad).is_empty());
+
+        gamepad.wButtons = XINPUT_GAMEPAD_A | XINPUT_GAMEPAD_START | XINPUT_GAMEPAD_DPAD_LEFT;
+        gamepad.bLeftTrigger = TRIGGER_THRESHOLD + 1;
+        gamepad.bRightTrigger = TRIGGER_THRESHOLD;
+
+        assert_eq!(
+            pressed_buttons(&gamepad),
+            [
+                KeyCode::Gamepad0,
+                KeyCode::Gamepad9,
+                KeyCode::Gamepad14,
+                KeyCode::Gamepad6
+            ],
+        );
+
+        gamepad.wButtons = 0;
+        gamepad.bLeftTrigger = 0;
+        gamepad.bRightTrigger = u8::MAX;
+
+        assert_eq!(pressed_buttons(&gamepad), [KeyCode::Gamepad7]);
+    }
+}
@@ -1,10 +1,13 @@
-use crate::{ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
+mod gamepad;
+
+use crate::{Button, ConsumePreference, Hotkey, KeyCode, KeyCodeClass, Modifiers, Result};
 use std::{
     cell::RefCell,
     collections::hash_map::{Entry, HashMap},
     fmt, mem, ptr,
     sync::{
-        mpsc::{channel, Sender},
+        atomic::{self, AtomicBool},
+        mpsc::{channel, Receiver, Sender},
         Arc, Mutex,
     },
     thread,
@@ -19,13 +22,19 @@ use windows_sys::Win32::{
         },
         WindowsAndMessaging::{
             CallNextHookEx, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
-            UnhookWindowsHookEx, HHOOK, KBDLLHOOKSTRUCT, LLKHF_EXTENDED, WH_KEYBOARD_LL,
-            WM_KEYDOWN, WM_KEYUP, WM_SYSKEYDOWN, WM_SYSKEYUP,
+            UnhookWindowsHookEx, HHOOK, KBDLLHOOKSTRUCT, LLKHF_EXTENDED, MSLLHOOKSTRUCT,
+            WH_KEYBOARD_LL, WH_MOUSE_LL, WM_KEYDOWN, WM_KEYUP, WM_LBUTTONDOWN, WM_MBUTTONDOWN,
+            WM_RBUTTONDOWN, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_XBUTTONDOWN,
         },
     },
 };
 
 const MSG_EXIT: u32 = 0x400;
+const MSG_INSTALL_MOUSE_HOOK: u32 = 0x401;
+const MSG_UNINSTALL_MOUSE_HOOK: u32 = 0x402;
+
+const XBUTTON1: u32 = 0x0001;
+const XBUTTON2: u32 = 0x0002;
 
 #[derive(Debug)]
 #[non_exhaustive]
@@ -45,11 +54,26 @@ impl fmt::Display for Error {
     }
 }
 
-type Callback = Box<dyn FnMut() + Send + 'static>;
+// The callbacks are reference counted, so they can be cloned out of the maps
+// before they are called. This way the maps aren't locked while the callbacks
+// run, which allows the callbacks to register and unregister hotkeys.
+type Callback = Arc<Mutex<Box<dyn FnMut() + Send + 'static>>>;
+type CaptureCallback = Box<dyn FnOnce(Hotkey) + Send + 'static>;
 
 pub struct Hook {
     thread_id: u32,
     hotkeys: Arc<Mutex<HashMap<Hotkey, Callback>>>,
+    buttons: Arc<Mutex<HashMap<Button, Callback>>>,
+    capture: Arc<Mutex<Option<CaptureCallback>>>,
+    suspended: Arc<AtomicBool>,
+    events: Sender<Input>,
+    mouse_hook_installed: Mutex<Receiver<bool>>,
+    gamepad: Mutex<Option<gamepad::Poller>>,
+}
+
+enum Input {
+    Hotkey(Hotkey),
+    Button(Button),
 }
 
 impl Drop for Hook {
@@ -62,7 +86,8 @@ impl Drop for Hook {
 
 struct State {
     hook: HHOOK,
-    events: Sender<Hotkey>,
+    mouse_hook: HHOOK,
+    events: Sender<Input>,
     modifiers: Modifiers,
     // FIXME: Use variant count when it's stable.
     // https://github.com/rust-lang/rust/issues/73662
@@ -291,10 +316,10 @@ unsafe extern "system" fn callback_proc(code: i32, wparam: WPARAM, lparam: LPARA
 
                         state
                             .events
-                            .send(Hotkey {
+                            .send(Input::Hotkey(Hotkey {
                                 key_code,
                                 modifiers: state.modifiers,
-                            })
+                            }))
                             .expect("Callback Thread disconnected");
 
                         match key_code {
@@ -368,6 +393,60 @@ unsafe extern "system" fn callback_proc(code: i32, wparam: WPARAM, lparam: LPARA
     CallNextHookEx(hook, code, wparam, lparam)
 }
 
+unsafe extern "system" fn mouse_callback_proc(
+    code: i32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    let hook = STATE.with_borrow_mut(|state| {
+        let state = state.as_mut().expect("State should be initialized by now");
+
+        if code >= 0 {
+            let button = match wparam as u32 {
+                WM_LBUTTONDOWN => Some(Button::Left),
+                WM_RBUTTONDOWN => Some(Button::Right),
+                WM_MBUTTONDOWN => Some(Button::Middle),
+                WM_XBUTTONDOWN => {
+                    // The high-order word of the mouse data specifies which of
+                    // the extra buttons was pressed.
+                    let hook_struct = &*(lparam as *const MSLLHOOKSTRUCT);
+                    match hook_struct.mouseData >> 16 {
+                        XBUTTON1 => Some(Button::X1),
+                        XBUTTON2 => Some(Button::X2),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+
+            if let Some(button) = button {
+                state
+                    .events
+                    .send(Input::Button(button))
+                    .expect("Callback Thread disconnected");
+            }
+        }
+
+        state.mouse_hook
+    });
+
+    CallNextHookEx(hook, code, wparam, lparam)
+}
+
+/// Replaces the mouse hook of the current thread, uninstalling the previous one
+/// if there was any.
+fn set_mouse_hook(mouse_hook: HHOOK) {
+    let previous = STATE.with_borrow_mut(|state| {
+        let state = state.as_mut()?;
+        Some(mem::replace(&mut state.mouse_hook, mouse_hook))
+    });
+    if let Some(previous) = previous.filter(|previous| !previous.is_null()) {
+        unsafe {
+            UnhookWindowsHookEx(previous);
+        }
+    }
+}
+
 #[inline]
 fn key_idx(key_code: KeyCode) -> (u8, u8) {
     let value = key_code as u8;
@@ -380,13 +459,14 @@ impl Hook {
             return Err(crate::Error::UnmatchedPreference);
         }
 
-        let hotkeys = Arc::new(Mutex::new(HashMap::<
-            Hotkey,
-            Box<dyn FnMut() + Send + 'static>,
-        >::new()));
+        let hotkeys = Arc::new(Mutex::new(HashMap::<Hotkey, Callback>::new()));
+
+        let buttons = Arc::new(Mutex::new(HashMap::<Button, Callback>::new()));
 
         let (initialized_tx, initialized_rx) = channel();
         let (events_tx, events_rx) = channel();
+        let hook_events = events_tx.clone();
+        let (mouse_hook_installed_tx, mouse_hook_installed_rx) = channel();
 
         thread::spawn(move || {
             let mut hook = ptr::null_mut();
@@ -413,7 +493,8 @@ impl Hook {
 
                 *state.borrow_mut() = Some(State {
                     hook,
-                    events: events_tx,
+                    mouse_hook: ptr::null_mut(),
+                    events: hook_events,
                     modifiers: Modifiers::empty(),
                     key_state: Default::default(),
                 });
@@ -427,11 +508,33 @@ impl Hook {
                 if ret < 0 {
                     return Err(Error::MessageLoop);
                 }
-                if unsafe { msg.assume_init().message } == MSG_EXIT {
-                    break;
+                match unsafe { msg.assume_init().message } {
+                    MSG_EXIT => break,
+                    // The mouse hook is only installed while there are mouse
+                    // buttons registered, as every mouse movement of the
+                    // whole system goes through it. This also means that the
+                    // keyboard hotkeys keep working if it can't be installed.
+                    MSG_INSTALL_MOUSE_HOOK => {
+                        let mouse_hook = unsafe {
+                            SetWindowsHookExW(
+                                WH_MOUSE_LL,
+                                Some(mouse_callback_proc),
+                                GetModuleHandleW(ptr::null()),
+                                0,
+                            )
+                        };
+                        set_mouse_hook(mouse_hook);
+                        let _ = mouse_hook_installed_tx.send(!mouse_hook.is_null());
+                    }
+                    MSG_UNINSTALL_MOUSE_HOOK => {
+                        set_mouse_hook(ptr::null_mut());
+                        let _ = mouse_hook_installed_tx.send(false);
+                    }
+                    _ => {}
                 }
             }
 
+            set_mouse_hook(ptr::null_mut());
             unsafe {
                 UnhookWindowsHookEx(hook);
             }
@@ -439,12 +542,37 @@ impl Hook {
             Ok(())
         });
 
+        let capture = Arc::new(Mutex::new(None::<CaptureCallback>));
+        let suspended = Arc::new(AtomicBool::new(false));
+
         let hotkey_map = hotkeys.clone();
+        let button_map = buttons.clone();
+        let capture_slot = capture.clone();
+        let is_suspended = suspended.clone();
 
         thread::spawn(move || {
-            while let Ok(key) = events_rx.recv() {
-                if let Some(callback) = hotkey_map.lock().unwrap().get_mut(&key) {
-                    callback();
+            while let Ok(input) = events_rx.recv() {
+                if is_suspended.load(atomic::Ordering::Relaxed) {
+                    continue;
+                }
+                let callback = match input {
+                    Input::Hotkey(key) => {
+                        if !key.key_code.is_modifier() {
+                            // The capture is taken out first, so the slot isn't
+                            // locked while its callback runs.
+                            let capture = capture_slot.lock().unwrap().take();
+                            if let Some(capture) = capture {
+                                capture(key);
+                                continue;
+                            }
+                        }
+                        hotkey_map.lock().unwrap().get(&key).cloned()
+                    }
+                    Input::Button(button) => button_map.lock().unwrap().get(&button).cloned(),
+                };
+                if let Some(callback) = callback {
+                    let mut callback = callback.lock().unwrap();
+                    (*callback)();
                 }
             }
         });
@@ -453,7 +581,35 @@ impl Hook {
             .recv()
             .map_err(|_| crate::Error::Platform(Error::ThreadStopped))??;
 
-        Ok(Hook { thread_id, hotkeys })
+        Ok(Hook {
+            thread_id,
+            hotkeys,
+            buttons,
+            capture,
+            suspended,
+            events: events_tx,
+            mouse_hook_installed: Mutex::new(mouse_hook_installed_rx),
+            gamepad: Mutex::new(None),
+        })
+    }
+
+    /// Installs or uninstalls the mouse hook on the thread of the keyboard
+    /// hook and waits for it to be done.
+    fn set_mouse_hook_installed(&self, install: bool) -> Result<()> {
+        let installed = self.mouse_hook_installed.lock().unwrap();
+        let message = if install {
+            MSG_INSTALL_MOUSE_HOOK
+        } else {
+            MSG_UNINSTALL_MOUSE_HOOK
+        };
+        if unsafe { PostThreadMessageW(self.thread_id, message, 0, 0) } == 0 {
+            return Err(crate::Error::Platform(Error::ThreadStopped));
+        }
+        match installed.recv() {
+            Ok(is_installed) if is_installed == install => Ok(()),
+            Ok(_) => Err(crate::Error::Platform(Error::WindowsHook)),
+            Err(_) => Err(crate::Error::Platform(Error::ThreadStopped)),
+        }
     }
 
     pub fn register<F>(&self, hotkey: Hotkey, callback: F) -> Result<()>
@@ -461,7 +617,15 @@ impl Hook {
         F: FnMut() + Send + 'static,
     {
         if let Entry::Vacant(vacant) = self.hotkeys.lock().unwrap().entry(hotkey) {
-            vacant.insert(Box::new(callback));
+            if hotkey.key_code.classify() == KeyCodeClass::Gamepad {
+                // The gamepads only get polled once the first gamepad button
+                // is registered.
+                self.gamepad
+                    .lock()
+                    .unwrap()
+                    .get_or_insert_with(|| gamepad::Poller::start(self.events.clone()));
+            }
+            vacant.insert(Arc::new(Mutex::new(Box::new(callback))));
             Ok(())
         } else {
             Err(crate::Error::AlreadyRegistered)
@@ -469,7 +633,70 @@ impl Hook {
     }
 
     pub fn unregister(&self, hotkey: Hotkey) -> Result<()> {
-        if self.hotkeys.lock().unwrap().remove(&hotkey).is_some() {
+        let mut hotkeys = self.hotkeys.lock().unwrap();
+        if hotkeys.remove(&hotkey).is_some() {
+            if hotkey.key_code.classify() == KeyCodeClass::Gamepad
+                && !hotkeys
+                    .keys()
+                    .any(|hotkey| hotkey.key_code.classify() == KeyCodeClass::Gamepad)
+            {
+                // Stop polling the gamepads once the last gamepad button is
+                // unregistered.
+                self.gamepad.lock().unwrap().take();
+            }
+            Ok(())
+        } else {
+            Err(crate::Error::NotRegistered)
+        }
+    }
+
+    pub fn registered(&self) -> Vec<Hotkey> {
+        self.hotkeys.lock().unwrap().keys().copied().collect()
+    }
+
+    pub fn capture_next<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        *self.capture.lock().unwrap() = Some(Box::new(callback));
+        Ok(())
+    }
+
+    pub fn cancel_capture(&self) -> Result<()> {
+        self.capture.lock().unwrap().take();
+        Ok(())
+    }
+
+    pub fn set_suspended(&self, suspended: bool) {
+        self.suspended.store(suspended, atomic::Ordering::Relaxed);
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(atomic::Ordering::Relaxed)
+    }
+
+    pub fn register_mouse<F>(&self, button: Button, callback: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let mut buttons = self.buttons.lock().unwrap();
+        if buttons.is_empty() {
+            self.set_mouse_hook_installed(true)?;
+        }
+        if let Entry::Vacant(vacant) = buttons.entry(button) {
+            vacant.insert(Arc::new(Mutex::new(Box::new(callback))));
+            Ok(())
+        } else {
+            Err(crate::Error::AlreadyRegistered)
+        }
+    }
+
+    pub fn unregister_mouse(&self, button: Button) -> Result<()> {
+        let mut buttons = self.buttons.lock().unwrap();
+        if buttons.remove(&button).is_some() {
+            if buttons.is_empty() {
+                self.set_mouse_hook_installed(false)?;
+            }
             Ok(())
         } else {
             Err(crate::Error::NotRegistered)
@@ -550,3 +777,93 @@ impl Hook {
         Some(char::from_u32(char)?.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, KEYEVENTF_SCANCODE,
+    };
+
+    fn send_scan_code(scan_code: u16, flags: u32) {
+        let input = INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: 0,
+                    wScan: scan_code,
+                    dwFlags: KEYEVENTF_SCANCODE | flags,
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        };
+        unsafe {
+            SendInput(1, &input, mem::size_of::<INPUT>() as i32);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs an interactive desktop to send the simulated input to"]
+    fn register_from_callback() {
+        let hook = Arc::new(crate::Hook::new().unwrap());
+        let (tx, rx) = channel();
+
+        hook.register(KeyCode::F24.into(), {
+            let hook = Arc::downgrade(&hook);
+            move || {
+                if let Some(hook) = hook.upgrade() {
+                    let _ = tx.send(hook.register(KeyCode::F23.into(), || {}).is_ok());
+                }
+            }
+        })
+        .unwrap();
+
+        // F24 is rarely found on keyboards, so simulating it doesn't interfere
+        // with anything else.
+        send_scan_code(0x76, 0);
+        send_scan_code(0x76, KEYEVENTF_KEYUP);
+
+        let registered = rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("The hotkey didn't fire");
+        assert!(registered);
+        assert_eq!(
+            hook.registered(),
+            [KeyCode::F23.into(), KeyCode::F24.into()],
+        );
+    }
+
+    #[test]
+    #[ignore = "needs an interactive desktop to send the simulated input to"]
+    fn capture_next() {
+        let hook = crate::Hook::new().unwrap();
+        let (tx, rx) = channel();
+
+        hook.register(KeyCode::F24.into(), {
+            let tx = tx.clone();
+            move || {
+                let _ = tx.send(None);
+            }
+        })
+        .unwrap();
+        hook.capture_next(move |hotkey| {
+            let _ = tx.send(Some(hotkey));
+        })
+        .unwrap();
+
+        for _ in 0..2 {
+            send_scan_code(0x76, 0);
+            send_scan_code(0x76, KEYEVENTF_KEYUP);
+        }
+
+        // The first press is captured instead of triggering the registered
+        // hotkey, the second one triggers it again.
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(Some(KeyCode::F24.into())),
+        );
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok(None));
+    }
+}
@@ -34,6 +34,34 @@ fn ends_with_numeric(name: &str) -> bool {
     name.chars().last().is_some_and(|c| c.is_numeric())
 }
 
+/// Splits off a trailing `Part N`, `Episode N` or `Chapter N`, where `N` is
+/// either a number or a roman numeral. The part before it may be empty.
+fn split_numbering(name: &str) -> Option<(&str, &str)> {
+    let (rest, number) = name.rsplit_once(char::is_whitespace)?;
+    if number.is_empty()
+        || !(number.chars().all(|c| c.is_numeric())
+            || number.bytes().all(|c| matches!(c, b'I' | b'V' | b'X')))
+    {
+        return None;
+    }
+
+    let rest = rest.trim_end();
+    let (base, marker) = match rest.rsplit_once(char::is_whitespace) {
+        Some((base, marker)) => (base.trim_end(), marker),
+        None => ("", rest),
+    };
+
+    if [&b"part"[..], b"episode", b"chapter"]
+        .iter()
+        .any(|key| matches_ascii_key(marker, key))
+    {
+        let index = marker.as_ptr() as usize - name.as_ptr() as usize;
+        Some((base, &name[index..]))
+    } else {
+        None
+    }
+}
+
 fn series_subtitle_handling(
     name: &str,
     split_token: &str,
@@ -50,6 +78,12 @@ fn series_subtitle_handling(
 
         let is_there_only_one_series_abbreviation = series_abbreviations.len() == 1;
 
+        // A subtitle that is just a numbering like `Episode 3` doesn't tell
+        // anything about the game on its own, so the series always needs to
+        // stay alongside it.
+        let is_subtitle_representative =
+            split_numbering(subtitle.trim()).map_or(true, |(base, _)| !base.is_empty());
+
         for subtitle_abbreviation in &subtitle_abbreviations {
             for series_abbreviation in &series_abbreviations {
                 if is_series_representative
@@ -65,10 +99,12 @@ fn series_subtitle_handling(
             }
         }
 
-        if is_series_representative {
-            list.extend(series_abbreviations);
+        if is_subtitle_representative {
+            if is_series_representative {
+                list.extend(series_abbreviations);
+            }
+            list.extend(subtitle_abbreviations);
         }
-        list.extend(subtitle_abbreviations);
 
         true
     } else {
@@ -102,6 +138,16 @@ fn left_right_handling(
     }
 }
 
+fn numbering_handling(name: &str, list: &mut Vec<Box<str>>, depth: usize) {
+    if let Some((base, numbering)) = split_numbering(name) {
+        if !base.is_empty() {
+            for base_abbreviation in abbreviate_recurse(base, depth) {
+                list.push([&*base_abbreviation, numbering].join(" ").into());
+            }
+        }
+    }
+}
+
 fn matches_ascii_key(value: &str, ascii_key_lower: &[u8]) -> bool {
     value
         .bytes()
@@ -165,6 +211,8 @@ fn abbreviate_recurse(name: &str, depth: usize) -> Vec<Box<str>> {
         || and_handling(name, &mut list, depth)
     {
     } else {
+        numbering_handling(name, &mut list, depth);
+
         if let Some(rest) =
             remove_prefix_word(name, b"the").or_else(|| remove_prefix_word(name, b"a"))
         {
@@ -217,7 +265,40 @@ fn abbreviate_recurse(name: &str, depth: usize) -> Vec<Box<str>> {
 }
 
 pub fn abbreviate(name: &str) -> Vec<Box<str>> {
-    abbreviate_recurse(name, 0)
+    abbreviate_iter(name).collect()
+}
+
+/// Yields the same abbreviations as [`abbreviate`] in the same order, with the
+/// original title being the last one.
+pub fn abbreviate_iter(name: &str) -> impl Iterator<Item = Box<str>> {
+    // FIXME: The abbreviations are still all calculated up front, as the
+    // recursive algorithm doesn't lend itself well to being lazy.
+    abbreviate_recurse(name, 0).into_iter()
+}
+
+/// Chooses the longest abbreviation of the name that is at most `max_len`
+/// characters long. If none of the abbreviations fit, the shortest one is
+/// chosen instead. If multiple abbreviations have the same length, the one
+/// that comes first in the list returned by [`abbreviate`] is chosen.
+pub fn abbreviate_to_fit(name: &str, max_len: usize) -> Box<str> {
+    let mut longest_fitting: Option<(usize, Box<str>)> = None;
+    let mut shortest: Option<(usize, Box<str>)> = None;
+
+    for abbreviation in abbreviate_iter(name) {
+        let len = abbreviation.chars().count();
+        if len <= max_len {
+            if longest_fitting.as_ref().map_or(true, |(l, _)| len > *l) {
+                longest_fitting = Some((len, abbreviation));
+            }
+        } else if longest_fitting.is_none() && shortest.as_ref().map_or(true, |(l, _)| len < *l) {
+            shortest = Some((len, abbreviation));
+        }
+    }
+
+    longest_fitting
+        .or(shortest)
+        .map(|(_, abbreviation)| abbreviation)
+        .unwrap_or_default()
 }
 
 pub fn abbreviate_category(category: &str) -> Vec<Box<str>> {
@@ -265,7 +346,7 @@ pub fn abbreviate_category(category: &str) -> Vec<Box<str>> {
 
 #[cfg(test)]
 mod tests {
-    use super::abbreviate;
+    use super::{abbreviate, abbreviate_iter, abbreviate_to_fit};
     use alloc::{boxed::Box, string::String, vec};
     use core::iter::FromIterator;
 
@@ -338,6 +419,45 @@ mod tests {
         assert_eq!(abbreviations, expected);
     }
 
+    #[test]
+    fn game_test_5() {
+        let abbreviations = abbreviate("Final Fantasy VII Remake Part 1");
+
+        let expected = vec![
+            Box::from("FF VIIR Part 1"),
+            Box::from("FF VIIRP1"),
+            Box::from("Final Fantasy VII Remake Part 1"),
+        ];
+
+        assert_eq!(abbreviations, expected);
+    }
+
+    #[test]
+    fn game_test_6() {
+        let abbreviations = abbreviate("Life is Strange: Episode 3");
+
+        let expected = vec![
+            Box::from("LiS: E3"),
+            Box::from("LiS: Episode 3"),
+            Box::from("Life is Strange: Episode 3"),
+        ];
+
+        assert_eq!(abbreviations, expected);
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn game_test_7() {
+        let abbreviations = abbreviate("Part Time UFO");
+
+        let expected = vec![
+            Box::from("PT UFO"),
+            Box::from("Part Time UFO"),
+        ];
+
+        assert_eq!(abbreviations, expected);
+    }
+
     #[test]
     #[rustfmt::skip]
     fn doesnt_overflow_stack() {
@@ -364,4 +484,40 @@ mod tests {
 
         assert_eq!("test title: the game", last.as_ref())
     }
+
+    #[test]
+    fn iter_matches_vec() {
+        let name = "The Legend of Zelda: The Wind Waker";
+        let abbreviations = abbreviate_iter(name).collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(abbreviations, abbreviate(name));
+        assert_eq!(abbreviations.last().unwrap().as_ref(), name);
+    }
+
+    #[test]
+    fn fit_burnout() {
+        let name = "Burnout 3: Takedown";
+
+        assert_eq!(abbreviate_to_fit(name, 10).as_ref(), "Burnout 3");
+        assert_eq!(abbreviate_to_fit(name, 20).as_ref(), "Burnout 3: Takedown");
+        assert_eq!(abbreviate_to_fit(name, 40).as_ref(), "Burnout 3: Takedown");
+    }
+
+    #[test]
+    fn fit_zelda() {
+        let name = "The Legend of Zelda: The Wind Waker";
+
+        assert_eq!(abbreviate_to_fit(name, 10).as_ref(), "Wind Waker");
+        assert_eq!(abbreviate_to_fit(name, 20).as_ref(), "Legend of Zelda: TWW");
+        assert_eq!(
+            abbreviate_to_fit(name, 40).as_ref(),
+            "The Legend of Zelda: The Wind Waker",
+        );
+    }
+
+    #[test]
+    fn fit_falls_back_to_shortest() {
+        assert_eq!(abbreviate_to_fit("Burnout 3: Takedown", 1).as_ref(), "B3");
+        assert_eq!(abbreviate_to_fit("", 10).as_ref(), "");
+    }
 }
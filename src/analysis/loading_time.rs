@@ -0,0 +1,38 @@
+use crate::{Run, TimeSpan};
+
+/// Calculates the time spent loading in the comparison provided, which is the
+/// difference between the final real time and the final game time. This is
+/// meant for load removed runs, where the game time is the real time without
+/// the loading times. There is no loading time if the comparison is missing
+/// either of the two times for the last split.
+pub fn loading_time(run: &Run, comparison: &str) -> Option<TimeSpan> {
+    let time = run.segments().last()?.comparison(comparison);
+    Some(time.real_time? - time.game_time?)
+}
+
+/// Checks whether the game times of the comparison provided could plausibly
+/// have been derived from its real times by removing loading times. For this
+/// the game time of each split must not be negative, and the loading time up
+/// to that split may never be smaller than the loading time up to any earlier
+/// split, as loading can never remove time. This especially means that the
+/// game time can't ever be larger than the real time. Splits without both a
+/// real time and a game time are not considered. If no split has both times,
+/// there is nothing to check and [`None`] is returned.
+pub fn is_load_removal_consistent(run: &Run, comparison: &str) -> Option<bool> {
+    let mut previous_loading_time = TimeSpan::zero();
+    let mut any_checked = false;
+
+    for segment in run.segments() {
+        let time = segment.comparison(comparison);
+        if let (Some(real_time), Some(game_time)) = (time.real_time, time.game_time) {
+            let loading_time = real_time - game_time;
+            if game_time < TimeSpan::zero() || loading_time < previous_loading_time {
+                return Some(false);
+            }
+            previous_loading_time = loading_time;
+            any_checked = true;
+        }
+    }
+
+    any_checked.then_some(true)
+}
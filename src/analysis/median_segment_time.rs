@@ -0,0 +1,47 @@
+use crate::{platform::prelude::*, Run, TimeSpan, TimingMethod};
+
+/// Calculates the median of the segment times of the segment at the index
+/// provided for the timing method provided. Unlike the Median Segments
+/// comparison, all the segment times are weighted equally. Only the segment
+/// times of actual attempts are considered and attempts without a segment time
+/// for the timing method are skipped, as are the combined segment times of
+/// attempts that skipped the previous split. If there is an even number of
+/// segment times, the median is the mean of the two segment times in the
+/// middle.
+///
+/// The median is [`None`] if the segment doesn't exist or if it doesn't have
+/// any segment times.
+pub fn median_segment_time(
+    run: &Run,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let segment = run.segments().get(segment_index)?;
+    let previous = segment_index.checked_sub(1).map(|i| &run.segments()[i]);
+
+    let mut times: Vec<TimeSpan> = segment
+        .segment_history()
+        .iter_actual_runs()
+        .filter(|&&(id, _)| {
+            // Skip all the combined segments
+            let combined = catch! {
+                previous?.segment_history().get(id)?[method].is_none()
+            };
+            combined != Some(true)
+        })
+        .filter_map(|(_, time)| time[method])
+        .collect();
+
+    if times.is_empty() {
+        return None;
+    }
+    times.sort_unstable();
+
+    let middle = times.len() / 2;
+    Some(if times.len() % 2 == 0 {
+        let (lower, upper) = (times[middle - 1], times[middle]);
+        TimeSpan::from_seconds(0.5 * (lower.total_seconds() + upper.total_seconds()))
+    } else {
+        times[middle]
+    })
+}
@@ -3,15 +3,27 @@
 
 pub mod current_pace;
 pub mod delta;
+mod loading_time;
+mod median_segment_time;
+mod most_relevant_comparison;
 pub mod pb_chance;
 pub mod possible_time_save;
+pub mod report;
 mod skill_curve;
+mod sob_improvement_potential;
 pub mod state_helper;
 pub mod sum_of_segments;
 pub mod total_playtime;
+mod total_time_save;
 
+pub use self::loading_time::{is_load_removal_consistent, loading_time};
+pub use self::median_segment_time::median_segment_time;
+pub use self::most_relevant_comparison::most_relevant_comparison;
+pub use self::report::{full_report, Report, SegmentReport};
 pub use self::skill_curve::SkillCurve;
+pub use self::sob_improvement_potential::sob_improvement_potential;
 pub use self::state_helper::*;
+pub use self::total_time_save::total_time_save;
 
 #[cfg(test)]
 mod tests;
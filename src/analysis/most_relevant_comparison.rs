@@ -0,0 +1,67 @@
+use crate::{
+    analysis, comparison::personal_best, timing::Snapshot, TimeSpan, TimerPhase, TimingMethod,
+};
+
+/// Determines the comparison that is the most relevant to the active attempt,
+/// which is the comparison the runner is the closest to beating. For every
+/// comparison that has a final time, the current delta to it is determined the
+/// same way the current pace is. If the runner is ahead of any of those
+/// comparisons, the one with the smallest lead is chosen, as that's the
+/// tightest comparison that is still on pace to be beaten. Otherwise the runner
+/// is behind all of them and the one with the smallest deficit is chosen. If
+/// there's no active attempt or none of the comparisons have any times, the
+/// Personal Best is returned.
+pub fn most_relevant_comparison<'a>(timer: &'a Snapshot<'_>, method: TimingMethod) -> &'a str {
+    let Some(split_index) = timer.current_split_index() else {
+        return personal_best::NAME;
+    };
+
+    let mut closest_ahead = None::<(TimeSpan, &str)>;
+    let mut closest_behind = None::<(TimeSpan, &str)>;
+
+    for comparison in timer.run().comparisons() {
+        let Some(delta) = current_delta(timer, split_index, comparison, method) else {
+            continue;
+        };
+
+        if delta <= TimeSpan::zero() {
+            if closest_ahead.is_none_or(|(closest, _)| delta > closest) {
+                closest_ahead = Some((delta, comparison));
+            }
+        } else if closest_behind.is_none_or(|(closest, _)| delta < closest) {
+            closest_behind = Some((delta, comparison));
+        }
+    }
+
+    closest_ahead
+        .or(closest_behind)
+        .map_or(personal_best::NAME, |(_, comparison)| comparison)
+}
+
+fn current_delta(
+    timer: &Snapshot<'_>,
+    split_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let last_segment = timer.run().segments().last()?;
+    let final_time = last_segment.comparison(comparison)[method]?;
+
+    if timer.current_phase() == TimerPhase::Ended {
+        return Some(last_segment.split_time()[method]? - final_time);
+    }
+
+    let mut delta =
+        analysis::last_delta(timer.run(), split_index, comparison, method).unwrap_or_default();
+
+    catch! {
+        let live_delta = timer.current_time()[method]?
+            - timer.current_split()?.comparison(comparison)[method]?;
+
+        if live_delta > delta {
+            delta = live_delta;
+        }
+    };
+
+    Some(delta)
+}
@@ -0,0 +1,188 @@
+//! Provides a report that bundles the most important statistics of a
+//! [`Run`](crate::Run), such as the Sum of Best Segments and statistics about
+//! each segment. The report can be serialized, for example as JSON, to be
+//! consumed by other tools.
+
+use super::sum_of_segments;
+use crate::{
+    comparison::{
+        average_segments::{self, AverageSegments},
+        median_segments::{self, MedianSegments},
+        ComparisonGenerator,
+    },
+    platform::{math::f64::sqrt, prelude::*},
+    Run, Segment, TimeSpan, TimingMethod,
+};
+use serde::{Serialize as _, Serializer};
+use serde_derive::Serialize;
+
+/// A report of the statistics of a [`Run`](crate::Run) for a single timing
+/// method. All the times are serialized as a number of seconds or `null` if
+/// they are not known.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    /// The timing method the statistics are calculated for.
+    pub timing_method: TimingMethod,
+    /// The final time of the Personal Best.
+    #[serde(serialize_with = "seconds")]
+    pub personal_best: Option<TimeSpan>,
+    /// The Sum of Best Segments.
+    #[serde(serialize_with = "seconds")]
+    pub sum_of_best: Option<TimeSpan>,
+    /// The Sum of Worst Segments.
+    #[serde(serialize_with = "seconds")]
+    pub sum_of_worst: Option<TimeSpan>,
+    /// The final time of the Average Segments comparison.
+    #[serde(serialize_with = "seconds")]
+    pub sum_of_averages: Option<TimeSpan>,
+    /// The total number of golds achieved in all of the segments.
+    pub gold_count: u32,
+    /// The statistics of each segment.
+    pub segments: Vec<SegmentReport>,
+}
+
+/// The statistics of a single segment in a [`Report`]. Only the segment times
+/// of actual attempts are considered. Combined segment times of attempts that
+/// skipped the previous split are not.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SegmentReport {
+    /// The name of the segment.
+    pub name: String,
+    /// The best segment time.
+    #[serde(serialize_with = "seconds")]
+    pub best: Option<TimeSpan>,
+    /// The slowest segment time in the segment history.
+    #[serde(serialize_with = "seconds")]
+    pub worst: Option<TimeSpan>,
+    /// The segment time of the Average Segments comparison, which is a
+    /// weighted mean that favors recent attempts.
+    #[serde(serialize_with = "seconds")]
+    pub average: Option<TimeSpan>,
+    /// The segment time of the Median Segments comparison, which is a weighted
+    /// median that favors recent attempts.
+    #[serde(serialize_with = "seconds")]
+    pub median: Option<TimeSpan>,
+    /// The standard deviation of the segment times. The lower it is, the more
+    /// consistent the segment is. At least two segment times are needed to
+    /// calculate it.
+    #[serde(serialize_with = "seconds")]
+    pub consistency: Option<TimeSpan>,
+    /// The number of times the segment was finished faster than in all the
+    /// previous attempts.
+    pub gold_count: u32,
+}
+
+fn seconds<S: Serializer>(time: &Option<TimeSpan>, serializer: S) -> Result<S::Ok, S::Error> {
+    time.map(|t| t.total_seconds()).serialize(serializer)
+}
+
+/// Calculates the [`Report`] of the statistics of the run for the timing
+/// method provided. The averages and medians are calculated the same way the
+/// Average Segments and Median Segments comparisons are, regardless of whether
+/// the run uses those comparisons.
+pub fn full_report(run: &Run, method: TimingMethod) -> Report {
+    let mut generated = run.segments().to_vec();
+    AverageSegments.generate(&mut generated, run.attempt_history());
+    MedianSegments.generate(&mut generated, run.attempt_history());
+
+    let mut segment_times = Vec::new();
+
+    let segments: Vec<SegmentReport> = run
+        .segments()
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let previous = index.checked_sub(1).map(|i| &run.segments()[i]);
+            collect_segment_times(&mut segment_times, previous, segment, method);
+
+            let generated_segment_time = |comparison: &str| {
+                let split_time = generated[index].comparison(comparison)[method]?;
+                let previous_split_time = match index.checked_sub(1) {
+                    Some(i) => generated[i].comparison(comparison)[method]?,
+                    None => TimeSpan::zero(),
+                };
+                Some(split_time - previous_split_time)
+            };
+
+            SegmentReport {
+                name: segment.name().into(),
+                best: segment.best_segment_time()[method],
+                worst: segment_times.iter().copied().max(),
+                average: generated_segment_time(average_segments::NAME),
+                median: generated_segment_time(median_segments::NAME),
+                consistency: standard_deviation(&segment_times),
+                gold_count: gold_count(&segment_times),
+            }
+        })
+        .collect();
+
+    Report {
+        timing_method: method,
+        personal_best: run
+            .segments()
+            .last()
+            .and_then(|s| s.personal_best_split_time()[method]),
+        sum_of_best: sum_of_segments::calculate_best(run.segments(), false, false, method),
+        sum_of_worst: sum_of_segments::calculate_worst(run.segments(), false, method),
+        sum_of_averages: generated
+            .last()
+            .and_then(|s| s.comparison(average_segments::NAME)[method]),
+        gold_count: segments.iter().map(|s| s.gold_count).sum(),
+        segments,
+    }
+}
+
+/// Collects the segment times of the actual attempts in chronological order.
+fn collect_segment_times(
+    buf: &mut Vec<TimeSpan>,
+    previous: Option<&Segment>,
+    segment: &Segment,
+    method: TimingMethod,
+) {
+    buf.clear();
+    for &(id, time) in segment.segment_history().iter_actual_runs() {
+        if let Some(time) = time[method] {
+            // Skip all the combined segments
+            let skip = catch! {
+                previous?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+
+            if !skip {
+                buf.push(time);
+            }
+        }
+    }
+}
+
+fn standard_deviation(times: &[TimeSpan]) -> Option<TimeSpan> {
+    if times.len() < 2 {
+        return None;
+    }
+    let len = times.len() as f64;
+    let mean = times.iter().map(|t| t.total_seconds()).sum::<f64>() / len;
+    let variance = times
+        .iter()
+        .map(|t| {
+            let diff = t.total_seconds() - mean;
+            diff * diff
+        })
+        .sum::<f64>()
+        / len;
+    Some(TimeSpan::from_seconds(sqrt(variance)))
+}
+
+fn gold_count(times: &[TimeSpan]) -> u32 {
+    let mut count = 0;
+    let mut times = times.iter();
+    if let Some(&first) = times.next() {
+        let mut best = first;
+        for &time in times {
+            if time < best {
+                best = time;
+                count += 1;
+            }
+        }
+    }
+    count
+}
@@ -0,0 +1,38 @@
+use super::SkillCurve;
+use crate::{platform::prelude::*, Run, TimeSpan, TimingMethod};
+
+/// Estimates for each segment how much time could realistically be gained by
+/// practicing it, in order to guide practice towards the segments where gains
+/// are most achievable. The realistic target of a segment is the segment time
+/// at the percentile provided on the segment's [`SkillCurve`]. A percentile of
+/// 0 yields the fastest segment times, while a percentile of 1 yields the
+/// slowest. A low percentile, such as 0.1 to 0.25, describes times that are
+/// regularly achieved. The potential of a segment is then how much the sum of
+/// the targets of all the segments would drop if the segment was performed at
+/// its best segment time instead. Segments that are performed at their best
+/// consistently have little potential, while segments where the best segment
+/// time is far ahead of what is regularly achieved have a lot of potential.
+///
+/// The potential is [`None`] for segments without a best segment time or
+/// without segment history. The [`SkillCurve`] discards all segments after the
+/// first segment without segment history, so those don't have a potential
+/// either.
+pub fn sob_improvement_potential(
+    run: &Run,
+    method: TimingMethod,
+    percentile: f64,
+) -> Vec<Option<TimeSpan>> {
+    let mut skill_curve = SkillCurve::new();
+    skill_curve.for_segments(run.segments(), method);
+
+    let mut targets = skill_curve.iter_segment_times_at_percentile(percentile);
+
+    run.segments()
+        .iter()
+        .map(|segment| {
+            let target = targets.next()?;
+            let best = segment.best_segment_time()[method]?;
+            Some((target - best).max(TimeSpan::zero()))
+        })
+        .collect()
+}
@@ -0,0 +1,74 @@
+use super::super::{is_load_removal_consistent, loading_time};
+use crate::{comparison::personal_best, Run, Segment, Time, TimeSpan};
+
+fn run(splits: &[(f64, Option<f64>)]) -> Run {
+    let mut run = Run::new();
+    for &(real_time, game_time) in splits {
+        let mut segment = Segment::new("");
+        segment.set_personal_best_split_time(
+            Time::new()
+                .with_real_time(Some(TimeSpan::from_seconds(real_time)))
+                .with_game_time(game_time.map(TimeSpan::from_seconds)),
+        );
+        run.push_segment(segment);
+    }
+    run
+}
+
+#[test]
+fn loading_time_is_real_time_minus_game_time() {
+    let run = run(&[(10.0, Some(8.0)), (25.0, Some(20.0)), (40.0, Some(33.5))]);
+
+    assert_eq!(
+        loading_time(&run, personal_best::NAME),
+        Some(TimeSpan::from_seconds(6.5)),
+    );
+    assert_eq!(
+        is_load_removal_consistent(&run, personal_best::NAME),
+        Some(true)
+    );
+}
+
+#[test]
+fn game_time_larger_than_real_time_is_inconsistent() {
+    let run = run(&[(10.0, Some(8.0)), (25.0, Some(26.0)), (40.0, Some(35.0))]);
+
+    assert_eq!(
+        is_load_removal_consistent(&run, personal_best::NAME),
+        Some(false)
+    );
+}
+
+#[test]
+fn shrinking_loading_time_is_inconsistent() {
+    // The loading time goes from 5 seconds down to 2 seconds, even though the
+    // game time never exceeds the real time.
+    let run = run(&[(10.0, Some(5.0)), (25.0, Some(23.0)), (40.0, Some(35.0))]);
+
+    assert_eq!(
+        is_load_removal_consistent(&run, personal_best::NAME),
+        Some(false)
+    );
+}
+
+#[test]
+fn missing_game_time() {
+    let run = run(&[(10.0, None), (25.0, None)]);
+
+    assert_eq!(loading_time(&run, personal_best::NAME), None);
+    assert_eq!(is_load_removal_consistent(&run, personal_best::NAME), None);
+}
+
+#[test]
+fn splits_without_game_time_are_skipped() {
+    let run = run(&[(10.0, Some(8.0)), (25.0, None), (40.0, Some(30.0))]);
+
+    assert_eq!(
+        loading_time(&run, personal_best::NAME),
+        Some(TimeSpan::from_seconds(10.0)),
+    );
+    assert_eq!(
+        is_load_removal_consistent(&run, personal_best::NAME),
+        Some(true)
+    );
+}
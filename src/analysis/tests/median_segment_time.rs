@@ -0,0 +1,76 @@
+use super::super::median_segment_time;
+use crate::{Run, Segment, Time, TimeSpan, TimingMethod};
+
+fn run(times: &[Option<f64>]) -> Run {
+    let mut segment = Segment::new("A");
+    for (index, &time) in times.iter().enumerate() {
+        segment.segment_history_mut().insert(
+            index as i32 + 1,
+            Time::new().with_real_time(time.map(TimeSpan::from_seconds)),
+        );
+    }
+    let mut run = Run::new();
+    run.push_segment(segment);
+    run
+}
+
+#[test]
+fn odd_number_of_times() {
+    let run = run(&[Some(30.0), None, Some(10.0), Some(20.0)]);
+
+    assert_eq!(
+        median_segment_time(&run, 0, TimingMethod::RealTime),
+        Some(TimeSpan::from_seconds(20.0)),
+    );
+}
+
+#[test]
+fn even_number_of_times_is_interpolated() {
+    let run = run(&[Some(40.0), Some(10.0), None, None, Some(30.0), Some(20.0)]);
+
+    assert_eq!(
+        median_segment_time(&run, 0, TimingMethod::RealTime),
+        Some(TimeSpan::from_seconds(25.0)),
+    );
+}
+
+#[test]
+fn combined_segment_times_are_skipped() {
+    let mut run = run(&[Some(10.0), None, Some(20.0)]);
+    let mut segment = Segment::new("B");
+    for (index, time) in [1.0, 50.0, 3.0].into_iter().enumerate() {
+        segment.segment_history_mut().insert(
+            index as i32 + 1,
+            Time::new().with_real_time(Some(TimeSpan::from_seconds(time))),
+        );
+    }
+    run.push_segment(segment);
+
+    // The second attempt skipped the first split, so its segment time of the
+    // second segment is combined with the first one.
+    assert_eq!(
+        median_segment_time(&run, 1, TimingMethod::RealTime),
+        Some(TimeSpan::from_seconds(2.0)),
+    );
+}
+
+#[test]
+fn no_times() {
+    let run = run(&[None, None]);
+
+    assert_eq!(median_segment_time(&run, 0, TimingMethod::RealTime), None);
+}
+
+#[test]
+fn only_times_of_the_timing_method_count() {
+    let run = run(&[Some(10.0)]);
+
+    assert_eq!(median_segment_time(&run, 0, TimingMethod::GameTime), None);
+}
+
+#[test]
+fn segment_out_of_range() {
+    let run = run(&[Some(10.0)]);
+
+    assert_eq!(median_segment_time(&run, 1, TimingMethod::RealTime), None);
+}
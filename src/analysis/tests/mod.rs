@@ -1,2 +1,8 @@
 mod empty_run;
+mod loading_time;
+mod median_segment_time;
+mod most_relevant_comparison;
+mod report;
 mod semantic_colors;
+mod sob_improvement_potential;
+mod total_time_save;
@@ -0,0 +1,73 @@
+use super::super::most_relevant_comparison;
+use crate::{
+    comparison::personal_best,
+    util::tests_helper::{create_run, span, start_run},
+    Time, TimeSpan, Timer, TimingMethod,
+};
+
+fn time(seconds: f64) -> Time {
+    Time::new().with_game_time(Some(span(seconds)))
+}
+
+fn create_timer() -> Timer {
+    let mut run = create_run(&["A", "B"]);
+    run.comparison_generators_mut().clear();
+
+    let comparisons = [
+        (personal_best::NAME, 10.0, 20.0),
+        ("Fast", 8.0, 17.0),
+        ("Medium", 9.2, 19.5),
+        ("Close", 9.8, 21.0),
+        ("Slow", 12.0, 24.0),
+    ];
+
+    for (name, a, b) in comparisons {
+        if name != personal_best::NAME {
+            run.add_custom_comparison(name).unwrap();
+        }
+        *run.segment_mut(0).comparison_mut(name) = time(a);
+        *run.segment_mut(1).comparison_mut(name) = time(b);
+    }
+
+    Timer::new(run).unwrap()
+}
+
+fn split_at(timer: &mut Timer, seconds: f64) {
+    start_run(timer);
+    timer
+        .set_game_time(TimeSpan::from_seconds(seconds))
+        .unwrap();
+    timer.split().unwrap();
+}
+
+#[test]
+fn returns_personal_best_without_attempt() {
+    let timer = create_timer();
+    assert_eq!(
+        most_relevant_comparison(&timer.snapshot(), TimingMethod::GameTime),
+        personal_best::NAME,
+    );
+}
+
+#[test]
+fn selects_tightest_comparison_that_is_ahead() {
+    let mut timer = create_timer();
+    // Ahead of the Personal Best by 0.5s, ahead of Close by 0.3s and ahead of
+    // Slow by 2.5s. Behind Fast and Medium.
+    split_at(&mut timer, 9.5);
+    assert_eq!(
+        most_relevant_comparison(&timer.snapshot(), TimingMethod::GameTime),
+        "Close",
+    );
+}
+
+#[test]
+fn selects_closest_comparison_when_behind_all() {
+    let mut timer = create_timer();
+    // Behind every comparison, but Slow is only 1s ahead.
+    split_at(&mut timer, 13.0);
+    assert_eq!(
+        most_relevant_comparison(&timer.snapshot(), TimingMethod::GameTime),
+        "Slow",
+    );
+}
@@ -0,0 +1,87 @@
+use super::super::{full_report, sum_of_segments};
+use crate::{
+    comparison::average_segments,
+    util::tests_helper::{create_timer, run_with_splits},
+    Run, TimeSpan, TimingMethod,
+};
+
+fn run() -> Run {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 32.0]);
+    run_with_splits(&mut timer, &[9.0, 29.5]);
+    timer.into_run(true)
+}
+
+#[test]
+fn aggregates_match_the_individual_analyses() {
+    let run = run();
+    let method = TimingMethod::GameTime;
+    let report = full_report(&run, method);
+
+    assert_eq!(
+        report.personal_best,
+        run.segment(1).personal_best_split_time().game_time,
+    );
+    assert_eq!(report.personal_best, Some(TimeSpan::from_seconds(29.5)));
+    assert_eq!(
+        report.sum_of_best,
+        sum_of_segments::calculate_best(run.segments(), false, false, method),
+    );
+    assert_eq!(report.sum_of_best, Some(TimeSpan::from_seconds(29.0)));
+    assert_eq!(
+        report.sum_of_worst,
+        sum_of_segments::calculate_worst(run.segments(), false, method),
+    );
+    assert_eq!(
+        report.sum_of_averages,
+        run.segment(1).comparison(average_segments::NAME).game_time,
+    );
+    assert!(report.sum_of_averages.is_some());
+    assert_eq!(report.gold_count, 1);
+}
+
+#[test]
+fn segment_statistics() {
+    let report = full_report(&run(), TimingMethod::GameTime);
+
+    let [a, b] = &report.segments[..] else {
+        panic!("There should be two segments.");
+    };
+
+    assert_eq!(a.name, "A");
+    assert_eq!(a.best, Some(TimeSpan::from_seconds(9.0)));
+    assert_eq!(a.worst, Some(TimeSpan::from_seconds(12.0)));
+    assert_eq!(a.gold_count, 1);
+    assert_eq!(b.best, Some(TimeSpan::from_seconds(20.0)));
+    assert_eq!(b.worst, Some(TimeSpan::from_seconds(20.5)));
+    assert_eq!(b.gold_count, 0);
+
+    // The second segment is a lot more consistent than the first one.
+    assert!(b.consistency.unwrap() < a.consistency.unwrap());
+
+    for segment in [a, b] {
+        assert!(segment.best <= segment.median && segment.median <= segment.worst);
+        assert!(segment.best <= segment.average && segment.average <= segment.worst);
+    }
+}
+
+#[test]
+fn empty_run_has_no_statistics() {
+    let report = full_report(&create_timer(&["A"]).into_run(true), TimingMethod::RealTime);
+
+    assert_eq!(report.personal_best, None);
+    assert_eq!(report.sum_of_averages, None);
+    assert_eq!(report.gold_count, 0);
+    assert_eq!(report.segments[0].worst, None);
+    assert_eq!(report.segments[0].consistency, None);
+}
+
+#[test]
+fn serializes_times_as_seconds() {
+    let json = serde_json::to_string(&full_report(&run(), TimingMethod::GameTime)).unwrap();
+
+    assert!(json.contains(r#""personal_best":29.5"#));
+    assert!(json.contains(r#""sum_of_best":29.0"#));
+    assert!(json.contains(r#""timing_method":"GameTime""#));
+}
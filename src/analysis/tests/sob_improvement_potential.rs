@@ -0,0 +1,44 @@
+use super::super::sob_improvement_potential;
+use crate::{
+    util::tests_helper::{create_timer, run_with_splits},
+    TimeSpan, TimingMethod,
+};
+
+#[test]
+fn segment_with_most_headroom_has_largest_potential() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    // The second segment had a single lucky attempt, while the other segments
+    // are consistently close to their best.
+    run_with_splits(&mut timer, &[10.0, 30.0, 40.0]);
+    run_with_splits(&mut timer, &[10.5, 45.5, 56.0]);
+    run_with_splits(&mut timer, &[10.2, 45.2, 55.4]);
+    run_with_splits(&mut timer, &[10.1, 44.1, 54.2]);
+
+    let potential = sob_improvement_potential(timer.run(), TimingMethod::GameTime, 0.5);
+
+    assert_eq!(potential.len(), 3);
+    let potential = potential.into_iter().collect::<Option<Vec<_>>>().unwrap();
+    assert!(potential[1] > potential[0]);
+    assert!(potential[1] > potential[2]);
+    assert!(potential[1] > TimeSpan::from_seconds(10.0));
+}
+
+#[test]
+fn fastest_percentile_has_no_potential() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 35.0]);
+
+    let potential = sob_improvement_potential(timer.run(), TimingMethod::GameTime, 0.0);
+
+    assert_eq!(potential, [Some(TimeSpan::zero()), Some(TimeSpan::zero())]);
+}
+
+#[test]
+fn segments_without_history_have_no_potential() {
+    let timer = create_timer(&["A", "B"]);
+
+    let potential = sob_improvement_potential(timer.run(), TimingMethod::GameTime, 0.5);
+
+    assert_eq!(potential, [None, None]);
+}
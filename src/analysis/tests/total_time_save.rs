@@ -0,0 +1,56 @@
+use super::super::total_time_save;
+use crate::{
+    comparison::personal_best,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run,
+    },
+    TimeSpan, Timer, TimingMethod,
+};
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 30.0, 40.0]);
+    start_run(&mut timer);
+    timer
+}
+
+fn time_save(timer: &Timer) -> Option<TimeSpan> {
+    total_time_save(timer, personal_best::NAME, TimingMethod::GameTime)
+}
+
+#[test]
+fn sums_the_time_saves_of_all_the_splits() {
+    let mut timer = timer();
+    make_progress_run_with_splits_opt(&mut timer, &[Some(8.0)]);
+    assert_eq!(time_save(&timer), Some(span(2.0)));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(31.0)]);
+    assert_eq!(time_save(&timer), Some(span(-1.0)));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(37.5)]);
+    assert_eq!(time_save(&timer), Some(span(2.5)));
+}
+
+#[test]
+fn skipped_splits_are_excluded() {
+    let mut timer = timer();
+    make_progress_run_with_splits_opt(&mut timer, &[None, Some(27.0)]);
+
+    // The second segment spans the skipped first segment.
+    assert_eq!(time_save(&timer), Some(span(3.0)));
+}
+
+#[test]
+fn no_usable_splits() {
+    let mut timer = timer();
+    assert_eq!(time_save(&timer), None);
+
+    make_progress_run_with_splits_opt(&mut timer, &[None]);
+    assert_eq!(time_save(&timer), None);
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(30.0)]);
+    assert_eq!(
+        total_time_save(&timer, "Unknown Comparison", TimingMethod::GameTime),
+        None,
+    );
+}
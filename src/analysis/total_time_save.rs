@@ -0,0 +1,18 @@
+use super::previous_segment_delta;
+use crate::{TimeSpan, Timer, TimingMethod};
+
+/// Calculates how much time the current attempt saved so far in comparison to
+/// the comparison provided. This is the sum of the differences between the
+/// comparison's segment times and the segment times of all the splits of the
+/// current attempt. A positive value means that time was saved, while a
+/// negative value means that time was lost. Skipped splits are excluded, so the
+/// segment of the next split that isn't skipped spans the skipped ones as well.
+///
+/// There is no time save if the current attempt doesn't have any splits that
+/// can be compared against the comparison.
+pub fn total_time_save(timer: &Timer, comparison: &str, method: TimingMethod) -> Option<TimeSpan> {
+    (0..timer.run().len())
+        .filter_map(|index| previous_segment_delta(timer, index, comparison, method))
+        .map(|delta| -delta)
+        .reduce(|total, time_save| total + time_save)
+}
@@ -39,6 +39,13 @@
 //! #[repr(transparent)]
 //! pub struct ProcessId(u64);
 //!
+//! #[repr(C, packed)]
+//! pub struct ReadDescriptor {
+//!     pub address: Address,
+//!     pub buf_offset: u32,
+//!     pub len: u32,
+//! }
+//!
 //! #[repr(transparent)]
 //! pub struct SettingsMap(NonZeroU64);
 //!
@@ -99,6 +106,34 @@
 //! extern "C" {
 //!     /// Gets the state that the timer currently is in.
 //!     pub fn timer_get_state() -> TimerState;
+//!     /// Gets the real time that has elapsed in the current attempt in
+//!     /// nanoseconds. This is `-1` if there is no attempt in progress.
+//!     pub fn timer_get_elapsed_real_time() -> i64;
+//!     /// Gets the total number of attempts of the run. If there is an attempt in
+//!     /// progress, it is included in the count.
+//!     pub fn timer_get_attempt_count() -> u64;
+//!     /// Gets the number of attempts of the run that have been finished.
+//!     pub fn timer_get_finished_runs_count() -> u64;
+//!     /// Gets the name of the comparison that the timer is currently comparing
+//!     /// against by storing it into the buffer provided. Returns `false` if the
+//!     /// buffer is too small. After this call, no matter whether it was
+//!     /// successful or not, the `buf_len_ptr` will be set to the required buffer
+//!     /// size. The name is guaranteed to be valid UTF-8 and is not
+//!     /// nul-terminated.
+//!     pub fn timer_get_comparison(buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
+//!     /// Gets the name of the segment that the timer is currently on by storing
+//!     /// it into the buffer provided. Returns `false` if there is no attempt in
+//!     /// progress, the attempt has ended or the buffer is too small. After this
+//!     /// call, no matter whether it was successful or not, the `buf_len_ptr`
+//!     /// will be set to the required buffer size, which is `0` if there is no
+//!     /// current segment. The name is guaranteed to be valid UTF-8 and is not
+//!     /// nul-terminated.
+//!     pub fn timer_current_split_name(buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
+//!     /// Queries whether the segment at the index provided was split or skipped
+//!     /// in the current attempt. Returns `1` if the segment was split, `0` if it
+//!     /// was skipped and `-1` if the timer didn't get past the segment yet,
+//!     /// which is the case for every index if there is no attempt in progress.
+//!     pub fn timer_segment_splitted(idx: u64) -> i32;
 //!
 //!     /// Starts the timer.
 //!     pub fn timer_start();
@@ -110,6 +145,9 @@
 //!     pub fn timer_undo_split();
 //!     /// Resets the timer.
 //!     pub fn timer_reset();
+//!     /// Removes all the pause times from the current attempt. If the attempt
+//!     /// is paused, it also gets resumed.
+//!     pub fn timer_undo_all_pauses();
 //!     /// Sets a custom key value pair. This may be arbitrary information that the
 //!     /// auto splitter wants to provide for visualization. The pointers need to
 //!     /// point to valid UTF-8 encoded text with the respective given length.
@@ -122,6 +160,10 @@
 //!
 //!     /// Sets the game time.
 //!     pub fn timer_set_game_time(secs: i64, nanos: i32);
+//!     /// Sets the total amount of time the game has been loading in the
+//!     /// current attempt. The game time is then real time minus the loading
+//!     /// times.
+//!     pub fn timer_set_loading_times(secs: i64, nanos: i32);
 //!     /// Pauses the game time. This does not pause the timer, only the
 //!     /// automatic flow of time for the game time.
 //!     pub fn timer_pause_game_time();
@@ -156,9 +198,31 @@
 //!         list_ptr: *mut ProcessId,
 //!         list_len_ptr: *mut usize,
 //!     ) -> bool;
+//!     /// Lists the processes that the auto splitter is currently attached to.
+//!     /// Multiple processes can be attached to at the same time, such as a
+//!     /// launcher and the game itself. Returns `false` if listing the processes
+//!     /// failed. If it was successful, the buffer is now filled with the
+//!     /// handles of the attached processes. They are in no specific order. The
+//!     /// `list_len_ptr` will be updated to the amount of attached processes. If
+//!     /// this is larger than the original value provided, the buffer provided
+//!     /// was too small and not all handles could be stored. This is still
+//!     /// considered successful and can optionally be treated as an error
+//!     /// condition by the caller by checking if the length increased and
+//!     /// potentially reallocating a larger buffer. If the length decreased after
+//!     /// the call, the buffer was larger than needed and the remaining entries
+//!     /// are untouched.
+//!     pub fn process_list_attached(
+//!         list_ptr: *mut AttachedProcess,
+//!         list_len_ptr: *mut usize,
+//!     ) -> bool;
 //!     /// Checks whether a process is still open. You should detach from a
 //!     /// process and stop using it if this returns `false`.
 //!     pub fn process_is_open(process: AttachedProcess) -> bool;
+//!     /// Gets the generation of a process. It starts at `0` and is incremented
+//!     /// whenever the process got relaunched within the grace period and the
+//!     /// runtime reattached to it. Any addresses found in the process before
+//!     /// are no longer valid once its generation changes.
+//!     pub fn process_get_generation(process: AttachedProcess) -> u64;
 //!     /// Reads memory from a process at the address given. This will write
 //!     /// the memory to the buffer given. Returns `false` if this fails.
 //!     pub fn process_read(
@@ -167,6 +231,22 @@
 //!         buf_ptr: *mut u8,
 //!         buf_len: usize,
 //!     ) -> bool;
+//!     /// Reads memory from a process at multiple addresses at once. The memory
+//!     /// at the address of each descriptor is written into the buffer given at
+//!     /// the offset specified by the descriptor. Reads close to each other are
+//!     /// coalesced by the runtime. For each descriptor a status word is written
+//!     /// to the status buffer, which is `1` if the read succeeded and `0`
+//!     /// otherwise. The part of the buffer of a failed read is zeroed out.
+//!     /// Returns `true` even if some or all of the reads failed, as their
+//!     /// status is reported through the status buffer.
+//!     pub fn process_read_multiple(
+//!         process: AttachedProcess,
+//!         descriptors_ptr: *const ReadDescriptor,
+//!         descriptors_len: usize,
+//!         buf_ptr: *mut u8,
+//!         buf_len: usize,
+//!         status_ptr: *mut u32,
+//!     ) -> bool;
 //!     /// Gets the address of a module in a process. The pointer needs to point to
 //!     /// valid UTF-8 encoded text with the given length.
 //!     pub fn process_get_module_address(
@@ -223,10 +303,31 @@
 //!     pub fn process_get_memory_range_size(process: AttachedProcess, idx: u64) -> Option<NonZeroU64>;
 //!     /// Gets the flags of a memory range by its index.
 //!     pub fn process_get_memory_range_flags(process: AttachedProcess, idx: u64) -> Option<MemoryRangeFlags>;
+//!     /// Scans the memory of a process for the first occurrence of a byte
+//!     /// pattern within the given range and returns its address. The mask has
+//!     /// the same length as the pattern. Only the bits that are set in the mask
+//!     /// need to match, so a mask byte of `0` is a wildcard. The range is
+//!     /// clamped to the readable memory ranges of the process and parts of it
+//!     /// that can't be read are skipped. Use the memory ranges of the process to
+//!     /// only scan specific ranges, such as the executable ones. Returns `None`
+//!     /// if the pattern is not found or the pattern is empty.
+//!     pub fn process_scan_signature(
+//!         process: AttachedProcess,
+//!         range_address: Address,
+//!         range_len: u64,
+//!         pattern_ptr: *const u8,
+//!         pattern_len: usize,
+//!         mask_ptr: *const u8,
+//!     ) -> Option<NonZeroAddress>;
 //!
 //!     /// Sets the tick rate of the runtime. This influences the amount of
 //!     /// times the `update` function is called per second.
 //!     pub fn runtime_set_tick_rate(ticks_per_second: f64);
+//!     /// Requests the next `update` to only be called after the given amount of
+//!     /// seconds. This only affects a single tick. Afterwards the runtime goes
+//!     /// back to the regular tick rate. This is useful for doing expensive work,
+//!     /// such as scanning memory, and then idling for a while.
+//!     pub fn runtime_request_slow_tick(seconds: f64);
 //!     /// Prints a log message for debugging purposes. The pointer needs to point
 //!     /// to valid UTF-8 encoded text with the given length.
 //!     pub fn runtime_print_message(text_ptr: *const u8, text_len: usize);
@@ -257,6 +358,22 @@
 //!         description_len: usize,
 //!         default_value: bool,
 //!     ) -> bool;
+//!     /// Adds a new integer setting that the user can modify. This will return
+//!     /// either the specified default value or the value that the user has set.
+//!     /// The value is always clamped to the inclusive range from `min` to `max`.
+//!     /// The minimum must not be larger than the maximum. The key is used to
+//!     /// store the setting in the settings map and needs to be unique across all
+//!     /// types of settings. The pointers need to point to valid UTF-8 encoded
+//!     /// text with the respective given length.
+//!     pub fn user_settings_add_int(
+//!         key_ptr: *const u8,
+//!         key_len: usize,
+//!         description_ptr: *const u8,
+//!         description_len: usize,
+//!         default_value: i64,
+//!         min: i64,
+//!         max: i64,
+//!     ) -> i64;
 //!     /// Adds a new title to the user settings. This is used to group settings
 //!     /// together. The heading level determines the size of the title. The top
 //!     /// level titles use a heading level of 0. The key needs to be unique across
@@ -451,6 +568,12 @@
 //!     /// happens, you still retain ownership of the setting value, which means
 //!     /// you still need to free it.
 //!     pub fn settings_list_insert(list: SettingsList, idx: u64, value: SettingValue) -> bool;
+//!     /// Removes the setting value at the index given from the settings list.
+//!     /// Returns `false` if the index is out of bounds, in which case the
+//!     /// settings list is left unchanged.
+//!     pub fn settings_list_remove(list: SettingsList, idx: u64) -> bool;
+//!     /// Removes all the setting values from the settings list.
+//!     pub fn settings_list_clear(list: SettingsList);
 //!
 //!     /// Creates a new setting value from a settings map. The value is a copy of
 //!     /// the settings map. Any changes to the original settings map afterwards
@@ -551,6 +674,8 @@
 //! - There is no threading.
 //! - Time and random numbers are available.
 
+mod thread_priority;
+
 use crate::{
     event::{self, TimerQuery},
     platform::Arc,
@@ -559,13 +684,14 @@ use crate::{
 use arc_swap::ArcSwapOption;
 pub use livesplit_auto_splitting::{settings, wasi_path};
 use livesplit_auto_splitting::{
-    AutoSplitter, Config, CreationError, LogLevel, Timer as AutoSplitTimer, TimerState,
+    AutoSplitter, CreationError, LogLevel, Timer as AutoSplitTimer, TimerState,
 };
 use snafu::Snafu;
 use std::{
     fmt, fs, io,
     path::PathBuf,
     sync::{
+        atomic::{self, AtomicU64},
         mpsc::{self, Receiver, RecvTimeoutError, Sender},
         Condvar, Mutex,
     },
@@ -573,6 +699,9 @@ use std::{
     time::{Duration, Instant},
 };
 
+#[cfg(test)]
+mod tests;
+
 /// An error that the [`Runtime`] can return.
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -596,6 +725,67 @@ pub enum Error {
     SettingNotFound,
 }
 
+/// The priority of the thread that runs the auto splitter.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ThreadPriority {
+    /// The thread runs at a lower priority than regular threads, so it doesn't
+    /// compete with the game for CPU time. If the platform doesn't support
+    /// lowering the priority, the thread runs at the normal priority instead.
+    #[default]
+    Low,
+    /// The thread runs at the normal priority of the operating system.
+    Normal,
+}
+
+/// The configuration of the [`Runtime`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Config {
+    /// The prefix of the names of the threads that the runtime spawns. The
+    /// thread running the auto splitter is called `"<prefix> Runtime"` and the
+    /// watchdog thread is called `"<prefix> Watchdog"`. Some operating systems
+    /// truncate thread names, so it is best to keep the prefix short. By
+    /// default this is `"Auto Splitting"`.
+    pub thread_name_prefix: String,
+    /// The priority of the thread running the auto splitter. The watchdog
+    /// thread always runs at the normal priority, so it can reliably interrupt
+    /// the auto splitter. By default this is [`ThreadPriority::Low`].
+    pub thread_priority: ThreadPriority,
+    /// The duration for which a process that closed is still reported as open
+    /// to the auto splitter. If the game gets relaunched during this time, the
+    /// auto splitter is reattached to it without losing its state. The
+    /// generation of the process is incremented when this happens. This only
+    /// applies to processes that the auto splitter attached to by name. By
+    /// default this is zero, so closed processes are reported immediately.
+    pub process_grace_period: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            thread_name_prefix: String::from("Auto Splitting"),
+            thread_priority: ThreadPriority::Low,
+            process_grace_period: Duration::ZERO,
+        }
+    }
+}
+
+/// A summary of a single tick of the auto splitter that is passed to the
+/// callback registered via [`Runtime::set_tick_callback`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TickSummary {
+    /// How long the auto splitter took to execute the tick.
+    pub duration: Duration,
+    /// Whether the settings map changed since the previous tick. This is the
+    /// case if either the auto splitter or the user changed the settings. This
+    /// is always [`true`] for the first tick after an auto splitter got loaded.
+    pub settings_map_changed: bool,
+    /// Whether the settings widgets changed since the previous tick. This is
+    /// always [`true`] for the first tick after an auto splitter got loaded.
+    pub settings_widgets_changed: bool,
+}
+
 /// An auto splitter runtime that allows using an auto splitter provided as a
 /// WebAssembly module to control a timer.
 pub struct Runtime<T: event::CommandSink + TimerQuery + Send + 'static> {
@@ -604,12 +794,19 @@ pub struct Runtime<T: event::CommandSink + TimerQuery + Send + 'static> {
     runtime: livesplit_auto_splitting::Runtime,
 }
 
+type TickCallback = Box<dyn FnMut(TickSummary) + Send>;
+
 struct SharedState<T> {
     auto_splitter: ArcSwapOption<AutoSplitter<Timer<T>>>,
     watchdog_state: Mutex<WatchdogState>,
     watchdog_state_update: Condvar,
+    /// The timeout of the watchdog in nanoseconds.
+    watchdog_timeout: AtomicU64,
+    tick_callback: Mutex<Option<TickCallback>>,
 }
 
+const DEFAULT_WATCHDOG_TIMEOUT: Duration = Duration::from_secs(5);
+
 enum WatchdogState {
     Unloaded,
     Shutdown,
@@ -617,6 +814,10 @@ enum WatchdogState {
 }
 
 impl<T> SharedState<T> {
+    fn watchdog_timeout(&self) -> Duration {
+        Duration::from_nanos(self.watchdog_timeout.load(atomic::Ordering::Relaxed))
+    }
+
     fn update_watchdog(&self, watchdog_state: WatchdogState) -> Result<(), ()> {
         *self.watchdog_state.lock().map_err(drop)? = watchdog_state;
         self.watchdog_state_update.notify_one();
@@ -644,25 +845,37 @@ impl<T: event::CommandSink + TimerQuery + Send + 'static> Runtime<T> {
     /// Starts the runtime. Doesn't actually load an auto splitter until
     /// [`load`][Runtime::load] is called.
     pub fn new() -> Self {
+        Self::with_config(Config::default())
+    }
+
+    /// Starts the runtime with the configuration provided. Doesn't actually
+    /// load an auto splitter until [`load`][Runtime::load] is called.
+    pub fn with_config(config: Config) -> Self {
         let (changed_sender, changed_receiver) = mpsc::channel();
         let shared_state = Arc::new(SharedState {
             auto_splitter: ArcSwapOption::from(None),
             watchdog_state: Mutex::new(WatchdogState::Unloaded),
             watchdog_state_update: Condvar::new(),
+            watchdog_timeout: AtomicU64::new(DEFAULT_WATCHDOG_TIMEOUT.as_nanos() as u64),
+            tick_callback: Mutex::new(None),
         });
 
         thread::Builder::new()
-            .name("Auto Splitting Runtime".into())
+            .name(format!("{} Runtime", config.thread_name_prefix))
             .spawn({
                 let shared_state = shared_state.clone();
+                let priority = config.thread_priority;
                 move || {
+                    if !thread_priority::apply_to_current_thread(priority) {
+                        log::warn!(target: "Auto Splitter", "Failed to set the thread priority to {priority:?}");
+                    }
                     run(shared_state, changed_receiver);
                 }
             })
             .unwrap();
 
         thread::Builder::new()
-            .name("Auto Splitting Watchdog".into())
+            .name(format!("{} Watchdog", config.thread_name_prefix))
             .spawn({
                 let shared_state = shared_state.clone();
                 move || {
@@ -671,11 +884,14 @@ impl<T: event::CommandSink + TimerQuery + Send + 'static> Runtime<T> {
             })
             .unwrap();
 
+        let mut runtime_config = livesplit_auto_splitting::Config::default();
+        runtime_config.process_grace_period = config.process_grace_period;
+
         Self {
             shared_state,
             changed_sender,
             // TODO: unwrap?
-            runtime: livesplit_auto_splitting::Runtime::new(Config::default()).unwrap(),
+            runtime: livesplit_auto_splitting::Runtime::new(runtime_config).unwrap(),
         }
     }
 
@@ -699,6 +915,47 @@ impl<T: event::CommandSink + TimerQuery + Send + 'static> Runtime<T> {
             .map_err(|_| Error::ThreadStopped)
     }
 
+    /// Sets how long a single tick of the auto splitter may take before the
+    /// watchdog interrupts it. An auto splitter that gets interrupted is
+    /// unloaded. The change takes effect with the next tick, so it is not
+    /// necessary to reload the auto splitter. The default timeout is 5 seconds.
+    pub fn set_watchdog_timeout(&self, timeout: Duration) {
+        self.shared_state.watchdog_timeout.store(
+            timeout.as_nanos().try_into().unwrap_or(u64::MAX),
+            atomic::Ordering::Relaxed,
+        );
+    }
+
+    /// Accesses how long a single tick of the auto splitter may take before the
+    /// watchdog interrupts it.
+    pub fn watchdog_timeout(&self) -> Duration {
+        self.shared_state.watchdog_timeout()
+    }
+
+    /// Registers a callback that is called after every tick of the auto
+    /// splitter that didn't fail. This replaces any previously registered
+    /// callback. The callback is called on the thread that runs the auto
+    /// splitter, after the auto splitter is done with the tick. The next tick
+    /// is delayed until the callback returns and the time spent in the callback
+    /// counts towards the watchdog timeout, so the callback should return
+    /// quickly, for example by sending the summary to another thread. The
+    /// callback must not register or remove a tick callback itself, as that
+    /// would deadlock.
+    pub fn set_tick_callback(&self, callback: impl FnMut(TickSummary) + Send + 'static) {
+        if let Ok(mut tick_callback) = self.shared_state.tick_callback.lock() {
+            *tick_callback = Some(Box::new(callback));
+        }
+    }
+
+    /// Removes the callback registered via
+    /// [`set_tick_callback`](Self::set_tick_callback). If it is currently
+    /// being called, this waits until it returns.
+    pub fn remove_tick_callback(&self) {
+        if let Ok(mut tick_callback) = self.shared_state.tick_callback.lock() {
+            *tick_callback = None;
+        }
+    }
+
     /// Unloads the current auto splitter. This will _not_ return an error if
     /// there isn't currently an auto splitter loaded, only if the runtime
     /// thread stops unexpectedly.
@@ -807,10 +1064,61 @@ impl<E: event::CommandSink + TimerQuery + Send> AutoSplitTimer for Timer<E> {
         drop(self.0.reset(None));
     }
 
+    fn undo_all_pauses(&mut self) {
+        drop(self.0.undo_all_pauses());
+    }
+
+    fn elapsed_real_time(&self) -> Option<time::Duration> {
+        let timer = self.0.get_timer();
+        if timer.current_phase() == TimerPhase::NotRunning {
+            return None;
+        }
+        timer.snapshot().current_time().real_time.map(Into::into)
+    }
+
+    fn current_comparison(&self) -> String {
+        self.0.get_timer().current_comparison().into()
+    }
+
+    fn current_split_name(&self) -> Option<String> {
+        self.0
+            .get_timer()
+            .current_split()
+            .map(|segment| segment.name().into())
+    }
+
+    fn segment_splitted(&self, index: usize) -> Option<bool> {
+        let timer = self.0.get_timer();
+        if index >= timer.current_split_index()? {
+            return None;
+        }
+        // Splitting always stores a real time, while skipping clears the split
+        // time entirely.
+        Some(timer.run().segment(index).split_time().real_time.is_some())
+    }
+
+    fn attempt_count(&self) -> u64 {
+        self.0.get_timer().run().attempt_count().into()
+    }
+
+    fn finished_runs_count(&self) -> u64 {
+        self.0
+            .get_timer()
+            .run()
+            .attempt_history()
+            .iter()
+            .filter(|a| a.time().real_time.is_some())
+            .count() as u64
+    }
+
     fn set_game_time(&mut self, time: time::Duration) {
         drop(self.0.set_game_time(time.into()));
     }
 
+    fn set_loading_times(&mut self, time: time::Duration) {
+        drop(self.0.set_loading_times(time.into()));
+    }
+
     fn pause_game_time(&mut self) {
         drop(self.0.pause_game_time());
     }
@@ -859,6 +1167,7 @@ fn run<T: event::CommandSink + TimerQuery + Send>(
 
         log::info!(target: "Auto Splitter", "Loaded auto splitter");
         let mut next_tick = Instant::now();
+        let mut previous_settings = None;
 
         if shared_state
             .update_watchdog(WatchdogState::Tick(next_tick))
@@ -881,6 +1190,7 @@ fn run<T: event::CommandSink + TimerQuery + Send>(
                 Ok(()) => {
                     log::info!(target: "Auto Splitter", "Replaced auto splitter");
                     next_tick = Instant::now();
+                    previous_settings = None;
                     if shared_state
                         .update_watchdog(WatchdogState::Tick(next_tick))
                         .is_err()
@@ -894,18 +1204,43 @@ fn run<T: event::CommandSink + TimerQuery + Send>(
                 }
             }
 
+            let tick_start = Instant::now();
+
             // Intentionally not part of the if let to ensure the lock is
             // released early.
             let result = auto_splitter.lock().update();
 
+            let duration = tick_start.elapsed();
+
             if let Err(e) = result {
                 shared_state.auto_splitter.store(None);
                 log::error!(target: "Auto Splitter", "Unloaded, because the script trapped: {:?}", e);
                 continue 'back_to_not_having_an_auto_splitter;
             }
 
+            let settings_map = auto_splitter.settings_map();
+            let settings_widgets = auto_splitter.settings_widgets();
+            let (settings_map_changed, settings_widgets_changed) = match &previous_settings {
+                Some((previous_map, previous_widgets)) => (
+                    !settings_map.is_unchanged(previous_map),
+                    !Arc::ptr_eq(&settings_widgets, previous_widgets),
+                ),
+                None => (true, true),
+            };
+            previous_settings = Some((settings_map, settings_widgets));
+
+            if let Ok(mut tick_callback) = shared_state.tick_callback.lock() {
+                if let Some(tick_callback) = &mut *tick_callback {
+                    tick_callback(TickSummary {
+                        duration,
+                        settings_map_changed,
+                        settings_widgets_changed,
+                    });
+                }
+            }
+
             next_tick = next_tick
-                .checked_add(auto_splitter.tick_rate())
+                .checked_add(auto_splitter.next_tick_interval())
                 .unwrap_or(next_tick);
 
             if shared_state
@@ -919,7 +1254,6 @@ fn run<T: event::CommandSink + TimerQuery + Send>(
 }
 
 fn watchdog<T: event::CommandSink + TimerQuery + Send>(shared_state: Arc<SharedState<T>>) {
-    const TIMEOUT: Duration = Duration::from_secs(5);
     let mut has_timed_out = false;
 
     let Ok(mut state) = shared_state.watchdog_state.lock() else {
@@ -934,7 +1268,8 @@ fn watchdog<T: event::CommandSink + TimerQuery + Send>(shared_state: Arc<SharedS
             },
             WatchdogState::Shutdown => return,
             WatchdogState::Tick(next_tick) => {
-                let timeout_instant = next_tick.checked_add(TIMEOUT).unwrap_or(next_tick);
+                let timeout = shared_state.watchdog_timeout();
+                let timeout_instant = next_tick.checked_add(timeout).unwrap_or(next_tick);
                 let timeout_duration = timeout_instant.saturating_duration_since(Instant::now());
 
                 let Ok((new_state, result)) = shared_state
@@ -946,7 +1281,7 @@ fn watchdog<T: event::CommandSink + TimerQuery + Send>(shared_state: Arc<SharedS
 
                 if result.timed_out() {
                     if !has_timed_out {
-                        log::error!(target: "Auto Splitter", "timeout, no update in {} seconds", TIMEOUT.as_secs_f32());
+                        log::error!(target: "Auto Splitter", "timeout, no update in {} seconds", timeout.as_secs_f32());
                         has_timed_out = true;
                     }
                     if let Some(auto_splitter) = &*shared_state.auto_splitter.load() {
@@ -0,0 +1,30 @@
+use super::Timer;
+use crate::util::tests_helper::create_timer;
+use livesplit_auto_splitting::Timer as _;
+
+#[test]
+fn segment_splitted_distinguishes_splits_from_skipped_splits() {
+    let mut timer = Timer(create_timer(&["A", "B", "C", "D"]).into_shared());
+    assert_eq!(timer.segment_splitted(0), None);
+
+    timer.start();
+    assert_eq!(timer.segment_splitted(0), None);
+
+    timer.split();
+    timer.skip_split();
+    assert_eq!(timer.segment_splitted(0), Some(true));
+    assert_eq!(timer.segment_splitted(1), Some(false));
+    assert_eq!(timer.segment_splitted(2), None);
+
+    timer.split();
+    timer.split();
+    assert_eq!(timer.segment_splitted(2), Some(true));
+    assert_eq!(timer.segment_splitted(3), Some(true));
+    assert_eq!(timer.segment_splitted(4), None);
+
+    timer.undo_split();
+    assert_eq!(timer.segment_splitted(3), None);
+
+    timer.reset();
+    assert_eq!(timer.segment_splitted(0), None);
+}
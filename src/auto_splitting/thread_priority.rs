@@ -0,0 +1,44 @@
+use super::ThreadPriority;
+
+/// Applies the priority to the current thread. Returns `false` if the priority
+/// can't be applied on this platform.
+pub fn apply_to_current_thread(priority: ThreadPriority) -> bool {
+    match priority {
+        // Threads start out with the normal priority.
+        ThreadPriority::Normal => true,
+        ThreadPriority::Low => lower_current_thread(),
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        fn lower_current_thread() -> bool {
+            // On Linux every thread has its own nice value, so this only
+            // affects the current thread. Lowering the priority never requires
+            // any privileges.
+            //
+            // SAFETY: This is always safe to call.
+            unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, 10) == 0 }
+        }
+    } else if #[cfg(any(target_os = "macos", target_os = "ios"))] {
+        fn lower_current_thread() -> bool {
+            // SAFETY: This is always safe to call.
+            unsafe {
+                libc::pthread_set_qos_class_self_np(libc::qos_class_t::QOS_CLASS_UTILITY, 0) == 0
+            }
+        }
+    } else if #[cfg(windows)] {
+        fn lower_current_thread() -> bool {
+            use windows_sys::Win32::System::Threading::{
+                GetCurrentThread, SetThreadPriority, THREAD_PRIORITY_BELOW_NORMAL,
+            };
+
+            // SAFETY: The pseudo handle of the current thread is always valid.
+            unsafe { SetThreadPriority(GetCurrentThread(), THREAD_PRIORITY_BELOW_NORMAL) != 0 }
+        }
+    } else {
+        fn lower_current_thread() -> bool {
+            false
+        }
+    }
+}
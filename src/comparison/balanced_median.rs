@@ -0,0 +1,40 @@
+//! Defines the Comparison Generator for calculating the Balanced Median of a
+//! [`Run`](crate::Run). Unlike the Median Segments, every attempt in the
+//! Segment History is weighted the same, so the split times directly
+//! correspond to the median of each segment. The split times are then balanced
+//! such that they never decrease, even if the history contains negative
+//! segment times.
+
+use super::{percentile, ComparisonGenerator};
+use crate::{platform::prelude::*, Attempt, Segment, TimingMethod};
+
+/// The Comparison Generator for calculating the Balanced Median of a
+/// [`Run`](crate::Run). Unlike the Median Segments, every attempt in the
+/// Segment History is weighted the same, so the split times directly
+/// correspond to the median of each segment. The split times are then balanced
+/// such that they never decrease, even if the history contains negative
+/// segment times.
+///
+/// This comparison is not part of the [default
+/// generators](super::default_generators). It needs to be added to a
+/// [`Run`](crate::Run) explicitly.
+#[derive(Copy, Clone, Debug)]
+pub struct BalancedMedian;
+
+/// The short name of this comparison. Suitable for situations where not a lot
+/// of space for text is available.
+pub const SHORT_NAME: &str = "Bal. Median";
+/// The name of this comparison.
+pub const NAME: &str = "Balanced Median";
+
+impl ComparisonGenerator for BalancedMedian {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        let times = &mut Vec::new();
+        percentile::generate(segments, times, TimingMethod::RealTime, 0.5, NAME);
+        percentile::generate(segments, times, TimingMethod::GameTime, 0.5, NAME);
+    }
+}
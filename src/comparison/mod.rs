@@ -7,6 +7,7 @@
 mod tests;
 
 pub mod average_segments;
+pub mod balanced_median;
 pub mod balanced_pb;
 pub mod best_segments;
 pub mod best_split_times;
@@ -14,12 +15,14 @@ pub mod goal;
 pub mod latest_run;
 pub mod median_segments;
 pub mod none;
+pub mod percentile;
 pub mod worst_segments;
 
 pub use self::{
-    average_segments::AverageSegments, balanced_pb::BalancedPB, best_segments::BestSegments,
-    best_split_times::BestSplitTimes, latest_run::LatestRun, median_segments::MedianSegments,
-    none::None, worst_segments::WorstSegments,
+    average_segments::AverageSegments, balanced_median::BalancedMedian, balanced_pb::BalancedPB,
+    best_segments::BestSegments, best_split_times::BestSplitTimes, latest_run::LatestRun,
+    median_segments::MedianSegments, none::None, percentile::PercentileComparison,
+    worst_segments::WorstSegments,
 };
 
 use crate::{platform::prelude::*, Attempt, Segment, Timer};
@@ -109,6 +112,7 @@ pub fn try_shorten(comparison: &str) -> Option<&'static str> {
         world_record::NAME => world_record::SHORT_NAME,
         average_segments::NAME => average_segments::SHORT_NAME,
         median_segments::NAME => median_segments::SHORT_NAME,
+        balanced_median::NAME => balanced_median::SHORT_NAME,
         balanced_pb::NAME => balanced_pb::SHORT_NAME,
         best_segments::NAME => best_segments::SHORT_NAME,
         best_split_times::NAME => best_split_times::SHORT_NAME,
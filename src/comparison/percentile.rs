@@ -0,0 +1,144 @@
+//! Defines the Comparison Generator for calculating a comparison based on a
+//! percentile of the segment times of a [`Run`](crate::Run). Each split time is
+//! the sum of the segment times at that percentile of the segment's history.
+//! This allows for comparisons like the 75th percentile, which is a pessimistic
+//! target that's still realistic to reach, which makes it useful for practice.
+
+use super::{balanced_median, ComparisonGenerator};
+use crate::{platform::prelude::*, Attempt, Segment, TimeSpan, TimingMethod};
+
+/// The Comparison Generator for calculating a comparison based on a percentile
+/// of the segment times of a [`Run`](crate::Run). Each split time is the sum of
+/// the segment times at that percentile of the segment's history. Every
+/// attempt in the history is weighted the same and the split times are
+/// balanced such that they never decrease, even if the history contains
+/// negative segment times. If the percentile is between two segment times,
+/// the segment time is linearly interpolated.
+///
+/// A percentile of 0 results in the best segment times in the history, while a
+/// percentile of 1 results in the worst segment times in the history. A
+/// percentile of 0.5 is the [`BalancedMedian`](super::BalancedMedian) and is
+/// named accordingly.
+#[derive(Clone, Debug)]
+pub struct PercentileComparison {
+    percentile: f64,
+    name: String,
+}
+
+impl PercentileComparison {
+    /// Creates a new Percentile Comparison Generator for the percentile
+    /// provided. The percentile is clamped to the range from 0 to 1.
+    pub fn new(percentile: f64) -> Self {
+        let percentile = if percentile >= 0.0 {
+            percentile.min(1.0)
+        } else {
+            0.0
+        };
+
+        let name = if percentile == 0.5 {
+            balanced_median::NAME.into()
+        } else {
+            let tenths = (percentile * 1000.0 + 0.5) as u32;
+            let (whole, fraction) = (tenths / 10, tenths % 10);
+            if fraction == 0 {
+                let suffix = match (whole % 10, whole % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{whole}{suffix} Percentile")
+            } else {
+                format!("{whole}.{fraction}th Percentile")
+            }
+        };
+
+        Self { percentile, name }
+    }
+
+    /// Accesses the percentile the comparison is calculated for.
+    pub const fn percentile(&self) -> f64 {
+        self.percentile
+    }
+}
+
+fn percentile_of(times: &mut [TimeSpan], percentile: f64) -> Option<TimeSpan> {
+    times.sort_unstable();
+    let last = times.len().checked_sub(1)?;
+    let rank = percentile * last as f64;
+    let lower_index = rank as usize;
+    let lower = times[lower_index];
+    let fraction = rank - lower_index as f64;
+    if fraction == 0.0 {
+        return Some(lower);
+    }
+    let upper = times[lower_index + 1];
+    Some(lower + TimeSpan::from((upper - lower).to_duration() * fraction))
+}
+
+pub(super) fn generate(
+    segments: &mut [Segment],
+    times: &mut Vec<TimeSpan>,
+    method: TimingMethod,
+    percentile: f64,
+    name: &str,
+) {
+    let mut accumulated = Some(TimeSpan::zero());
+
+    let mut previous_segment: Option<&Segment> = None;
+    for segment in segments {
+        if let Some(accumulated_val) = &mut accumulated {
+            times.clear();
+
+            for &(id, time) in segment.segment_history().iter_actual_runs() {
+                if let Some(time) = time[method] {
+                    // Skip all the combined segments
+                    let skip = catch! {
+                        previous_segment?.segment_history().get(id)?[method].is_none()
+                    }
+                    .unwrap_or(false);
+
+                    if !skip {
+                        times.push(time);
+                    }
+                }
+            }
+
+            match percentile_of(times, percentile) {
+                Some(time) => {
+                    if time > TimeSpan::zero() {
+                        *accumulated_val += time;
+                    }
+                }
+                None => accumulated = None,
+            }
+        }
+        segment.comparison_mut(name)[method] = accumulated;
+        previous_segment = Some(&*segment);
+    }
+}
+
+impl ComparisonGenerator for PercentileComparison {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        let times = &mut Vec::new();
+        generate(
+            segments,
+            times,
+            TimingMethod::RealTime,
+            self.percentile,
+            &self.name,
+        );
+        generate(
+            segments,
+            times,
+            TimingMethod::GameTime,
+            self.percentile,
+            &self.name,
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::comparison::balanced_median::{BalancedMedian, NAME};
+use crate::util::tests_helper::{create_run, run_with_splits, span};
+use crate::{comparison::ComparisonGenerator, GameTime, Segment, Timer};
+
+#[track_caller]
+fn assert_comparison(timer: &Timer, expected: [f64; 3]) {
+    let actual: Vec<_> = timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.comparison(NAME).game_time)
+        .collect();
+    assert_eq!(actual, expected.map(|t| Some(span(t))));
+}
+
+#[test]
+fn odd_and_even_number_of_attempts() {
+    let mut run = create_run(&["A", "B", "C"]);
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut()
+        .push(Box::new(BalancedMedian));
+
+    let mut timer = Timer::new(run).unwrap();
+
+    // Segment times: 10, 20, 30
+    run_with_splits(&mut timer, &[10.0, 30.0, 60.0]);
+    // Segment times: 12, 15, 25
+    run_with_splits(&mut timer, &[12.0, 27.0, 52.0]);
+    // Segment times: 8, 25, 37
+    run_with_splits(&mut timer, &[8.0, 33.0, 70.0]);
+
+    // Medians: 10, 20, 30
+    assert_comparison(&timer, [10.0, 30.0, 60.0]);
+
+    // Segment times: 11, 18, 36
+    run_with_splits(&mut timer, &[11.0, 29.0, 65.0]);
+
+    // Medians: (10 + 11) / 2, (18 + 20) / 2, (30 + 36) / 2
+    assert_comparison(&timer, [10.5, 29.5, 62.5]);
+}
+
+#[test]
+fn never_decreases() {
+    let mut segments = [Segment::new("A"), Segment::new("B")];
+    segments[0]
+        .segment_history_mut()
+        .insert(1, GameTime(Some(span(10.0))).into());
+    segments[1]
+        .segment_history_mut()
+        .insert(1, GameTime(Some(span(-5.0))).into());
+
+    BalancedMedian.generate(&mut segments, &[]);
+
+    assert_eq!(segments[0].comparison(NAME).game_time, Some(span(10.0)));
+    assert_eq!(segments[1].comparison(NAME).game_time, Some(span(10.0)));
+}
@@ -0,0 +1,31 @@
+use crate::comparison::{best_segments, best_split_times::NAME};
+use crate::util::tests_helper::{create_timer, run_with_splits, span};
+
+#[test]
+fn uses_best_cumulative_times() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits(&mut timer, &[10.0, 25.0, 40.0]);
+    run_with_splits(&mut timer, &[8.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 24.0, 42.0]);
+
+    let run = timer.run();
+    let times = run
+        .segments()
+        .iter()
+        .map(|s| s.comparison(NAME).game_time)
+        .collect::<Vec<_>>();
+
+    assert_eq!(times, [Some(span(8.0)), Some(span(24.0)), Some(span(40.0))]);
+
+    // Unlike the Sum of Best Segments, the Best Split Times don't combine
+    // segments from different attempts, so the last split is 40 rather than
+    // 8 + 12 + 15.
+    assert_eq!(
+        run.segment(2).comparison(best_segments::NAME).game_time,
+        Some(span(35.0)),
+    );
+    assert_eq!(times[2], Some(span(40.0)));
+
+    assert!(times.windows(2).all(|w| w[0] <= w[1]));
+}
@@ -9,6 +9,11 @@ fn average_segments() {
     test(comparison::AverageSegments);
 }
 
+#[test]
+fn balanced_median() {
+    test(comparison::BalancedMedian);
+}
+
 #[test]
 fn balanced_pb() {
     test(comparison::BalancedPB);
@@ -0,0 +1,57 @@
+use crate::comparison::{ComparisonGenerator, PercentileComparison};
+use crate::util::tests_helper::span;
+use crate::{GameTime, Segment};
+
+fn segments() -> [Segment; 2] {
+    let mut segments = [Segment::new("A"), Segment::new("B")];
+    for (segment, times) in segments
+        .iter_mut()
+        .zip([[10.0, 12.0, 8.0, 11.0, 9.0], [20.0, 15.0, 25.0, 18.0, 22.0]])
+    {
+        for (index, time) in (1..).zip(times) {
+            segment
+                .segment_history_mut()
+                .insert(index, GameTime(Some(span(time))).into());
+        }
+    }
+    segments
+}
+
+#[track_caller]
+fn assert_percentile(percentile: f64, expected: [f64; 2]) {
+    let mut generator = PercentileComparison::new(percentile);
+    let mut segments = segments();
+    generator.generate(&mut segments, &[]);
+    let actual = segments.map(|s| s.comparison(generator.name()).game_time);
+    assert_eq!(actual, expected.map(|t| Some(span(t))));
+}
+
+#[test]
+fn best() {
+    // Best segments: 8, 15
+    assert_percentile(0.0, [8.0, 23.0]);
+}
+
+#[test]
+fn worst() {
+    // Worst segments: 12, 25
+    assert_percentile(1.0, [12.0, 37.0]);
+}
+
+#[test]
+fn interpolated() {
+    // Sorted segments: 8, 9, 10, 11, 12 and 15, 18, 20, 22, 25
+    assert_percentile(0.75, [11.0, 33.0]);
+    assert_percentile(0.625, [10.5, 31.5]);
+}
+
+#[test]
+fn names() {
+    let name = |percentile| PercentileComparison::new(percentile).name().to_owned();
+    assert_eq!(name(0.75), "75th Percentile");
+    assert_eq!(name(0.01), "1st Percentile");
+    assert_eq!(name(0.22), "22nd Percentile");
+    assert_eq!(name(0.13), "13th Percentile");
+    assert_eq!(name(0.625), "62.5th Percentile");
+    assert_eq!(name(0.5), "Balanced Median");
+}
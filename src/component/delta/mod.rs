@@ -9,7 +9,7 @@ use crate::{
     platform::prelude::*,
     settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
-        formatter::{Accuracy, Delta, TimeFormatter},
+        formatter::{Accuracy, Delta, DeltaSigns, TimeFormatter},
         Snapshot,
     },
     GeneralLayoutSettings,
@@ -48,6 +48,8 @@ pub struct Settings {
     pub drop_decimals: bool,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// Specifies which signs to show in front of the delta.
+    pub delta_signs: DeltaSigns,
 }
 
 impl Default for Settings {
@@ -59,6 +61,7 @@ impl Default for Settings {
             label_color: None,
             drop_decimals: true,
             accuracy: Accuracy::Tenths,
+            delta_signs: DeltaSigns::Both,
         }
     }
 }
@@ -139,7 +142,9 @@ impl Component {
         let _ = write!(
             state.value,
             "{}",
-            Delta::custom(self.settings.drop_decimals, self.settings.accuracy).format(delta),
+            Delta::custom(self.settings.drop_decimals, self.settings.accuracy)
+                .with_signs(self.settings.delta_signs)
+                .format(delta),
         );
 
         state.key_abbreviations.clear();
@@ -197,6 +202,11 @@ impl Component {
                 "The accuracy of the delta shown.".into(),
                 self.settings.accuracy.into()
             ),
+            Field::new(
+                "Delta Signs".into(),
+                "Specifies whether the delta is shown with a plus sign when behind the comparison, a minus sign when ahead of it, or both.".into(),
+                self.settings.delta_signs.into(),
+            ),
         ])
     }
 
@@ -215,6 +225,7 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.drop_decimals = value.into(),
             5 => self.settings.accuracy = value.into(),
+            6 => self.settings.delta_signs = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
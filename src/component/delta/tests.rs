@@ -1,5 +1,11 @@
 use super::Component;
-use crate::{GeneralLayoutSettings, Run, Segment, Timer};
+use crate::{
+    timing::formatter::DeltaSigns,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, Run, Segment, Timer,
+};
 
 #[test]
 fn comparison_text() {
@@ -45,3 +51,40 @@ fn comparison_text() {
         timer.current_comparison()
     );
 }
+
+#[test]
+fn delta_signs_only_affect_the_text() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    let settings = GeneralLayoutSettings::default();
+
+    let both = Component::new();
+    let mut only_minus = Component::new();
+    only_minus.settings_mut().delta_signs = DeltaSigns::OnlyMinus;
+    let mut only_plus = Component::new();
+    only_plus.settings_mut().delta_signs = DeltaSigns::OnlyPlus;
+
+    start_run(&mut timer);
+
+    // Behind the comparison
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.5)]);
+    let expected = both.state(&timer.snapshot(), &settings);
+    assert_eq!(&*expected.value, "+1.5");
+    let state = only_minus.state(&timer.snapshot(), &settings);
+    assert_eq!(&*state.value, "1.5");
+    assert_eq!(state.value_color, expected.value_color);
+    let state = only_plus.state(&timer.snapshot(), &settings);
+    assert_eq!(&*state.value, "+1.5");
+    assert_eq!(state.value_color, expected.value_color);
+
+    // Ahead of the comparison
+    make_progress_run_with_splits_opt(&mut timer, &[Some(19.0)]);
+    let expected = both.state(&timer.snapshot(), &settings);
+    assert_eq!(&*expected.value, "−1.0");
+    let state = only_minus.state(&timer.snapshot(), &settings);
+    assert_eq!(&*state.value, "−1.0");
+    assert_eq!(state.value_color, expected.value_color);
+    let state = only_plus.state(&timer.snapshot(), &settings);
+    assert_eq!(&*state.value, "1.0");
+    assert_eq!(state.value_color, expected.value_color);
+}
@@ -70,7 +70,7 @@ pub struct Settings {
 }
 
 /// The state object describes the information to visualize for this component.
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Default, PartialEq, Serialize, Deserialize)]
 pub struct State {
     /// The background shown behind the component.
     pub background: Gradient,
@@ -101,7 +101,7 @@ pub struct State {
 }
 
 /// The state object describing a comparison to visualize.
-#[derive(Serialize, Deserialize)]
+#[derive(PartialEq, Serialize, Deserialize)]
 pub struct ComparisonState {
     /// The name of the comparison.
     pub name: String,
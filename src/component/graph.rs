@@ -74,7 +74,7 @@ pub struct Settings {
 
 /// The state object describes the information to visualize for this component.
 /// All coordinates are in the range `0..1`.
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Default, PartialEq, Serialize, Deserialize)]
 pub struct State {
     /// All of the graph's points. Connect them to visualize the graph.
     /// If the live delta is active, the last point is to be interpreted as a
@@ -124,7 +124,7 @@ pub struct State {
 }
 
 /// Describes a point on the graph to visualize.
-#[derive(Serialize, Deserialize)]
+#[derive(PartialEq, Serialize, Deserialize)]
 pub struct Point {
     /// The x-coordinate of the point.
     pub x: f32,
@@ -12,7 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The state object describes the information to visualize for a key value
 /// based component.
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Default, PartialEq, Serialize, Deserialize)]
 pub struct State {
     /// The background shown behind the component.
     pub background: Gradient,
@@ -13,10 +13,13 @@ pub mod pb_chance;
 pub mod possible_time_save;
 pub mod previous_segment;
 pub mod segment_time;
+pub mod segments_remaining;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
+pub mod sum_of_best_delta;
 pub mod text;
+pub mod time_to_next_split;
 pub mod timer;
 pub mod title;
 pub mod total_playtime;
@@ -33,10 +36,13 @@ pub use pb_chance::Component as PbChance;
 pub use possible_time_save::Component as PossibleTimeSave;
 pub use previous_segment::Component as PreviousSegment;
 pub use segment_time::Component as SegmentTime;
+pub use segments_remaining::Component as SegmentsRemaining;
 pub use separator::Component as Separator;
 pub use splits::Component as Splits;
 pub use sum_of_best::Component as SumOfBest;
+pub use sum_of_best_delta::Component as SumOfBestDelta;
 pub use text::Component as Text;
+pub use time_to_next_split::Component as TimeToNextSplit;
 pub use timer::Component as Timer;
 pub use title::Component as Title;
 pub use total_playtime::Component as TotalPlaytime;
@@ -14,6 +14,9 @@ use crate::{
 use core::fmt::Write;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// The PB Chance Component is a component that shows how likely it is to beat
 /// the Personal Best. If there is no active attempt it shows the general chance
 /// of beating the Personal Best. During an attempt it actively changes based on
@@ -38,6 +41,8 @@ pub struct Settings {
     /// The color of the value. If `None` is specified, the color is taken from
     /// the layout.
     pub value_color: Option<Color>,
+    /// The number of decimal places shown for the percentage.
+    pub decimal_places: u8,
 }
 
 impl Default for Settings {
@@ -47,6 +52,7 @@ impl Default for Settings {
             display_two_rows: false,
             label_color: None,
             value_color: None,
+            decimal_places: 1,
         }
     }
 }
@@ -90,7 +96,7 @@ impl Component {
         state.key.push_str(self.name());
 
         state.value.clear();
-        let _ = write!(state.value, "{:.1}%", 100.0 * chance);
+        let _ = write_percentage(&mut state.value, chance, self.settings.decimal_places);
 
         state.key_abbreviations.clear();
         state.display_two_rows = self.settings.display_two_rows;
@@ -131,6 +137,11 @@ impl Component {
                     .into(),
                 self.settings.value_color.into(),
             ),
+            Field::new(
+                "Decimal Places".into(),
+                "The number of decimal places shown for the PB chance.".into(),
+                u64::from(self.settings.decimal_places).into(),
+            ),
         ])
     }
 
@@ -147,7 +158,33 @@ impl Component {
             1 => self.settings.display_two_rows = value.into(),
             2 => self.settings.label_color = value.into(),
             3 => self.settings.value_color = value.into(),
+            4 => {
+                self.settings.decimal_places =
+                    value.into_uint().unwrap().try_into().unwrap_or(u8::MAX)
+            }
             _ => panic!("Unsupported Setting Index"),
         }
     }
 }
+
+/// Writes the chance as a percentage with the amount of decimal places
+/// provided. Chances that are neither impossible nor certain, but would be
+/// rounded to 0% or 100%, are shown as being below the smallest or above the
+/// largest percentage that can be shown instead, like `<0.1%` or `>99.9%`.
+fn write_percentage(w: &mut impl Write, chance: f64, decimal_places: u8) -> core::fmt::Result {
+    let decimal_places = decimal_places as usize;
+    let percentage = 100.0 * chance;
+
+    let mut smallest = 1.0;
+    for _ in 0..decimal_places {
+        smallest /= 10.0;
+    }
+
+    if percentage > 0.0 && percentage < 0.5 * smallest {
+        write!(w, "<{smallest:.decimal_places$}%")
+    } else if percentage < 100.0 && percentage >= 100.0 - 0.5 * smallest {
+        write!(w, ">{:.decimal_places$}%", 100.0 - smallest)
+    } else {
+        write!(w, "{percentage:.decimal_places$}%")
+    }
+}
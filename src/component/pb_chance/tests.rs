@@ -0,0 +1,69 @@
+use super::{write_percentage, Component};
+use crate::util::tests_helper::{create_timer, run_with_splits};
+
+#[track_caller]
+fn format(chance: f64, decimal_places: u8) -> String {
+    let mut percentage = String::new();
+    write_percentage(&mut percentage, chance, decimal_places).unwrap();
+    percentage
+}
+
+#[test]
+fn formats_with_the_decimal_places() {
+    assert_eq!(format(0.123456, 0), "12%");
+    assert_eq!(format(0.123456, 1), "12.3%");
+    assert_eq!(format(0.123456, 3), "12.346%");
+}
+
+#[test]
+fn impossible_and_certain_chances_are_shown_as_is() {
+    assert_eq!(format(0.0, 1), "0.0%");
+    assert_eq!(format(1.0, 1), "100.0%");
+    assert_eq!(format(0.0, 0), "0%");
+    assert_eq!(format(1.0, 0), "100%");
+}
+
+#[test]
+fn tiny_chances_are_not_rounded_to_zero() {
+    assert_eq!(format(0.00001, 1), "<0.1%");
+    assert_eq!(format(0.00001, 2), "<0.01%");
+    assert_eq!(format(0.00001, 3), "0.001%");
+    assert_eq!(format(0.004, 0), "<1%");
+    assert_eq!(format(0.0007, 1), "0.1%");
+}
+
+#[test]
+fn near_certain_chances_are_not_rounded_to_a_hundred() {
+    assert_eq!(format(0.99999, 1), ">99.9%");
+    assert_eq!(format(0.99999, 2), ">99.99%");
+    assert_eq!(format(0.99999, 3), "99.999%");
+    assert_eq!(format(0.996, 0), ">99%");
+    assert_eq!(format(0.9993, 1), "99.9%");
+}
+
+#[test]
+fn uses_the_decimal_places_setting() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[11.0, 19.0]);
+
+    let mut component = Component::new();
+    let state = component.state(&timer.snapshot());
+    let (_, decimals) = state.value.trim_end_matches('%').split_once('.').unwrap();
+    assert_eq!(decimals.len(), 1);
+
+    component.settings_mut().decimal_places = 0;
+    let state = component.state(&timer.snapshot());
+    assert!(!state.value.contains('.'));
+    assert!(state.value.ends_with('%'));
+}
+
+#[test]
+fn clamps_the_decimal_places_setting() {
+    let mut component = Component::new();
+    component.set_value(4, 300u64.into());
+    assert_eq!(component.settings().decimal_places, u8::MAX);
+
+    component.set_value(4, 3u64.into());
+    assert_eq!(component.settings().decimal_places, 3);
+}
@@ -12,7 +12,7 @@ use crate::{
     platform::prelude::*,
     settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
-        formatter::{Accuracy, Delta, SegmentTime, TimeFormatter},
+        formatter::{Accuracy, Delta, DeltaSigns, SegmentTime, TimeFormatter},
         Snapshot,
     },
     GeneralLayoutSettings, TimerPhase,
@@ -21,6 +21,9 @@ use alloc::borrow::Cow;
 use core::fmt::Write as FmtWrite;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// The Previous Segment Component is a component that shows how much time was
 /// saved or lost during the previous [`Segment`](crate::run::Segment) based on
 /// the chosen comparison. Additionally, the potential time save for the previous
@@ -55,6 +58,13 @@ pub struct Settings {
     /// Determines if the time save that could've been saved is shown in
     /// addition to the previous segment.
     pub show_possible_time_save: bool,
+    /// Specifies which signs to show in front of the delta.
+    pub delta_signs: DeltaSigns,
+    /// Specifies whether to explicitly show how many segments the delta
+    /// covers if the previous splits were skipped. The delta of such a
+    /// previous segment is the combined delta of all the segments since the
+    /// last split that wasn't skipped.
+    pub show_combined_segment_count: bool,
 }
 
 impl Default for Settings {
@@ -67,6 +77,8 @@ impl Default for Settings {
             drop_decimals: true,
             accuracy: Accuracy::Tenths,
             show_possible_time_save: false,
+            delta_signs: DeltaSigns::Both,
+            show_combined_segment_count: false,
         }
     }
 }
@@ -96,6 +108,7 @@ impl Component {
     pub fn name(&self) -> Cow<'static, str> {
         self.text(
             false,
+            1,
             self.settings
                 .comparison_override
                 .as_ref()
@@ -103,13 +116,19 @@ impl Component {
         )
     }
 
-    fn text(&self, live: bool, comparison: Option<&str>) -> Cow<'static, str> {
-        let text = if live {
-            "Live Segment"
+    fn text(
+        &self,
+        live: bool,
+        combined_segments: usize,
+        comparison: Option<&str>,
+    ) -> Cow<'static, str> {
+        let mut text = if live {
+            Cow::from("Live Segment")
+        } else if combined_segments > 1 {
+            Cow::from(format!("Previous {combined_segments} Segments"))
         } else {
-            "Previous Segment"
+            Cow::from("Previous Segment")
         };
-        let mut text = Cow::from(text);
         if let Some(comparison) = comparison {
             write!(text.to_mut(), " ({})", comparison::shorten(comparison)).unwrap();
         }
@@ -126,6 +145,7 @@ impl Component {
     ) {
         let mut time_change = None;
         let mut previous_possible = None;
+        let mut combined_segments = 1;
         let resolved_comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(resolved_comparison, timer);
         let live_segment =
@@ -149,6 +169,13 @@ impl Component {
             } else if let Some(prev_split_index) = split_index.checked_sub(1) {
                 time_change =
                     analysis::previous_segment_delta(timer, prev_split_index, comparison, method);
+                if self.settings.show_combined_segment_count && time_change.is_some() {
+                    combined_segments += timer.run().segments()[..prev_split_index]
+                        .iter()
+                        .rev()
+                        .take_while(|segment| segment.split_time()[method].is_none())
+                        .count();
+                }
                 if self.settings.show_possible_time_save {
                     previous_possible = analysis::possible_time_save::calculate(
                         timer,
@@ -203,7 +230,11 @@ impl Component {
 
         let value_color = Some(semantic_color.visualize(layout_settings));
 
-        let text = self.text(live_segment.is_some(), resolved_comparison);
+        let text = self.text(
+            live_segment.is_some(),
+            combined_segments,
+            resolved_comparison,
+        );
 
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
@@ -217,7 +248,9 @@ impl Component {
         let _ = write!(
             state.value,
             "{}",
-            Delta::custom(self.settings.drop_decimals, self.settings.accuracy).format(time_change),
+            Delta::custom(self.settings.drop_decimals, self.settings.accuracy)
+                .with_signs(self.settings.delta_signs)
+                .format(time_change),
         );
 
         if self.settings.show_possible_time_save {
@@ -232,6 +265,16 @@ impl Component {
         if live_segment.is_some() {
             state.key_abbreviations.push("Live Segment".into());
             state.key_abbreviations.push("Live Seg.".into());
+        } else if combined_segments > 1 {
+            state
+                .key_abbreviations
+                .push(format!("Previous {combined_segments} Segments").into());
+            state
+                .key_abbreviations
+                .push(format!("Prev. {combined_segments} Segments").into());
+            state
+                .key_abbreviations
+                .push(format!("Prev. {combined_segments} Seg.").into());
         } else {
             state.key_abbreviations.push("Previous Segment".into());
             state.key_abbreviations.push("Prev. Segment".into());
@@ -293,6 +336,16 @@ impl Component {
                 "Specifies whether to show how much time could've been saved for the previous segment in addition to the time saved or lost.".into(),
                 self.settings.show_possible_time_save.into(),
             ),
+            Field::new(
+                "Delta Signs".into(),
+                "Specifies whether the time lost in the previous segment is shown with a plus sign, the time saved with a minus sign, or both.".into(),
+                self.settings.delta_signs.into(),
+            ),
+            Field::new(
+                "Show Combined Segment Count".into(),
+                "Specifies whether to show how many segments the time saved or lost covers when the splits before the previous segment were skipped. In that case the time saved or lost is combined across all of these segments.".into(),
+                self.settings.show_combined_segment_count.into(),
+            ),
         ])
     }
 
@@ -312,6 +365,8 @@ impl Component {
             4 => self.settings.drop_decimals = value.into(),
             5 => self.settings.accuracy = value.into(),
             6 => self.settings.show_possible_time_save = value.into(),
+            7 => self.settings.delta_signs = value.into(),
+            8 => self.settings.show_combined_segment_count = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
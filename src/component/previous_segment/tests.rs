@@ -0,0 +1,55 @@
+use super::Component;
+use crate::{
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, Timer,
+};
+
+fn timer_after_skipped_split() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(35.0)]);
+
+    timer
+}
+
+#[test]
+fn shows_combined_segment_count_after_skipped_splits() {
+    let timer = timer_after_skipped_split();
+    let mut component = Component::new();
+    component.settings_mut().show_combined_segment_count = true;
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(&*state.key, "Previous 2 Segments");
+    assert_eq!(&*state.key_abbreviations[1], "Prev. 2 Segments");
+    assert_eq!(&*state.value, "+5.0");
+}
+
+#[test]
+fn combined_segment_count_is_hidden_by_default() {
+    let timer = timer_after_skipped_split();
+    let component = Component::new();
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(&*state.key, "Previous Segment");
+    assert_eq!(&*state.value, "+5.0");
+}
+
+#[test]
+fn single_segment_is_not_marked_as_combined() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), Some(21.5)]);
+
+    let mut component = Component::new();
+    component.settings_mut().show_combined_segment_count = true;
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(&*state.key, "Previous Segment");
+    assert_eq!(&*state.value, "+1.5");
+}
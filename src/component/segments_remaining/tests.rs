@@ -0,0 +1,33 @@
+use super::Component;
+use crate::util::tests_helper::{create_timer, start_run};
+
+#[test]
+fn shows_the_total_before_the_attempt_starts() {
+    let timer = create_timer(&["A", "B", "C"]);
+    let state = Component::new().state(&timer);
+
+    assert_eq!(&*state.key, "Segments Remaining");
+    assert_eq!(&*state.value, "3");
+    assert!(!state.updates_frequently);
+}
+
+#[test]
+fn counts_down_while_splitting() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    let component = Component::new();
+
+    start_run(&mut timer);
+    assert_eq!(&*component.state(&timer).value, "3 / 3");
+
+    timer.split().unwrap();
+    assert_eq!(&*component.state(&timer).value, "2 / 3");
+
+    timer.skip_split().unwrap();
+    assert_eq!(&*component.state(&timer).value, "1 / 3");
+
+    timer.split().unwrap();
+    assert_eq!(&*component.state(&timer).value, "0 / 3");
+
+    timer.reset(true).unwrap();
+    assert_eq!(&*component.state(&timer).value, "3");
+}
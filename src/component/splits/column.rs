@@ -152,7 +152,7 @@ impl Default for TimeColumn {
 }
 
 /// Describes the state of a single segment's column to visualize.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ColumnState {
     /// The value shown in the column.
     pub value: String,
@@ -242,15 +242,39 @@ fn update_time_column(
     let method = column_settings.timing_method.unwrap_or(method);
     let resolved_comparison = comparison::resolve(&column_settings.comparison_override, timer);
     let comparison = comparison::or_current(resolved_comparison, timer);
-    let update_value = time_column_update_value(
-        column_settings,
-        timer,
-        segment,
-        segment_index,
-        current_split,
-        method,
-        comparison,
-    );
+
+    let live_segment_times = splits_settings.live_segment_times
+        && column_settings.update_with == ColumnUpdateWith::SegmentTime
+        && current_split.is_some_and(|current_split| current_split < timer.run().len());
+
+    if live_segment_times && current_split < Some(segment_index) {
+        // The attempt didn't reach the segment yet, so there is no segment time
+        // to show for it.
+        state.value.clear();
+        state.semantic_color = SemanticColor::Default;
+        state.visual_color = state.semantic_color.visualize(layout_settings);
+        state.updates_frequently = false;
+        return;
+    }
+
+    // Live segment times ignore the update trigger and always show the current
+    // segment's live segment time.
+    let update_value = if current_split == Some(segment_index)
+        && !live_segment_times
+        && is_waiting_for_trigger(column_settings, timer, comparison, method)
+    {
+        None
+    } else {
+        time_column_update_value(
+            column_settings,
+            timer,
+            segment,
+            segment_index,
+            current_split,
+            method,
+            comparison,
+        )
+    };
     let updated = update_value.is_some();
     let ((column_value, semantic_color, formatter), is_live) = update_value.unwrap_or_else(|| {
         (
@@ -302,6 +326,7 @@ fn update_time_column(
                     splits_settings.delta_drop_decimals,
                     splits_settings.delta_time_accuracy,
                 )
+                .with_signs(splits_settings.delta_signs)
                 .format(column_value)
             ),
             ColumnFormatter::SegmentTime => {
@@ -318,6 +343,29 @@ fn update_time_column(
     state.visual_color = semantic_color.visualize(layout_settings);
 }
 
+/// Returns whether the value of the current segment shouldn't be updated yet,
+/// because the update trigger of the column didn't fire yet.
+fn is_waiting_for_trigger(
+    column: &TimeColumn,
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    method: TimingMethod,
+) -> bool {
+    match column.update_trigger {
+        ColumnUpdateTrigger::OnStartingSegment => false,
+        // The trigger wants the value to be updated when splitting, not before.
+        ColumnUpdateTrigger::OnEndingSegment => true,
+        // It's contextual and the live delta shouldn't be shown yet.
+        ColumnUpdateTrigger::Contextual => analysis::check_live_delta(
+            timer,
+            !column.update_with.is_segment_based(),
+            comparison,
+            method,
+        )
+        .is_none(),
+    }
+}
+
 fn time_column_update_value(
     column: &TimeColumn,
     timer: &Snapshot<'_>,
@@ -327,7 +375,7 @@ fn time_column_update_value(
     method: TimingMethod,
     comparison: &str,
 ) -> Option<((Option<TimeSpan>, SemanticColor, ColumnFormatter), bool)> {
-    use self::{ColumnUpdateTrigger::*, ColumnUpdateWith::*};
+    use self::ColumnUpdateWith::*;
 
     if current_split < Some(segment_index) {
         // Didn't reach the segment yet.
@@ -336,26 +384,6 @@ fn time_column_update_value(
 
     let is_current_split = current_split == Some(segment_index);
 
-    if is_current_split {
-        if column.update_trigger == OnEndingSegment {
-            // The trigger wants the value to be updated when splitting, not before.
-            return None;
-        }
-
-        if column.update_trigger == Contextual
-            && analysis::check_live_delta(
-                timer,
-                !column.update_with.is_segment_based(),
-                comparison,
-                method,
-            )
-            .is_none()
-        {
-            // It's contextual and the live delta shouldn't be shown yet.
-            return None;
-        }
-    }
-
     let is_live = is_current_split;
 
     let value = match (column.update_with, is_live) {
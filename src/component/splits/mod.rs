@@ -10,11 +10,17 @@ use crate::{
     settings::{
         self, Color, Field, Gradient, ImageCache, ImageId, ListGradient, SettingsDescription, Value,
     },
-    timing::{formatter::Accuracy, Snapshot},
+    timing::{
+        formatter::{Accuracy, DeltaSigns, Regular, TimeFormatter},
+        Snapshot,
+    },
     util::{Clear, ClearVec},
     GeneralLayoutSettings,
 };
-use core::cmp::{max, min};
+use core::{
+    cmp::{max, min},
+    fmt::Write,
+};
 use serde_derive::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -27,7 +33,7 @@ pub use column::{
     ColumnUpdateWith, TimeColumn, VariableColumn,
 };
 
-const SETTINGS_BEFORE_COLUMNS: usize = 15;
+const SETTINGS_BEFORE_COLUMNS: usize = 19;
 const SETTINGS_PER_TIME_COLUMN: usize = 6;
 const SETTINGS_PER_VARIABLE_COLUMN: usize = 2;
 
@@ -96,6 +102,21 @@ pub struct Settings {
     pub delta_drop_decimals: bool,
     /// Specifies whether to show the names of the columns above the splits.
     pub show_column_labels: bool,
+    /// Specifies whether columns showing the current attempt's segment times
+    /// strictly follow the progress of the attempt while it is in progress.
+    /// If enabled, the current segment always shows its live segment time,
+    /// regardless of the column's update trigger, and the segments that
+    /// haven't been reached yet are left blank, regardless of what the column
+    /// starts out with. This also applies to the final segment. Otherwise the
+    /// columns follow their own settings.
+    pub live_segment_times: bool,
+    /// Specifies which signs to show in front of the deltas.
+    pub delta_signs: DeltaSigns,
+    /// Specifies whether grid lines should be shown between the columns.
+    pub show_column_grid_lines: bool,
+    /// Specifies whether to show the comparison's split time of each segment
+    /// as a secondary line below the segment's times.
+    pub show_comparison_line: bool,
     /// The columns to show on the splits. These can be configured in various
     /// way to show split times, segment times, deltas and so on. The columns
     /// are defined from right to left.
@@ -103,7 +124,7 @@ pub struct Settings {
 }
 
 /// The state object that describes a single segment's information to visualize.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct SplitState {
     /// The icon of the segment. The associated image can be looked up in the
     /// image cache. The image may be the empty image. This indicates that there
@@ -114,6 +135,10 @@ pub struct SplitState {
     /// The state of each column from right to left. The amount of columns is
     /// not guaranteed to be the same across different splits.
     pub columns: ClearVec<ColumnState>,
+    /// The comparison's split time of the segment, meant to be shown as a
+    /// smaller secondary line below the segment's times. This is [`None`] if
+    /// the comparison line is not supposed to be shown.
+    pub comparison_line: Option<ColumnState>,
     /// Describes if this segment is the segment the active attempt is currently
     /// on.
     pub is_current_split: bool,
@@ -133,7 +158,7 @@ impl Clear for SplitState {
 }
 
 /// The state object describes the information to visualize for this component.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct State {
     /// The background shown behind the splits.
     pub background: ListGradient,
@@ -160,6 +185,15 @@ pub struct State {
     /// The gradient to show behind the current segment as an indicator of it
     /// being the current segment.
     pub current_split_gradient: Gradient,
+    /// Specifies whether grid lines should be shown between the columns.
+    pub show_column_grid_lines: bool,
+    /// The boundaries between adjacent columns, specified from right to left.
+    /// There is one boundary for each gap between two columns. Each boundary
+    /// is the combined width of all the columns to the right of it, where the
+    /// width of a column is the number of characters of its longest value,
+    /// including its label if the labels are shown. This allows renderers to
+    /// draw the grid lines consistently across all the segments.
+    pub column_boundaries: Vec<usize>,
 }
 
 impl Default for Settings {
@@ -185,6 +219,10 @@ impl Default for Settings {
             delta_time_accuracy: Accuracy::Tenths,
             delta_drop_decimals: true,
             show_column_labels: false,
+            live_segment_times: false,
+            delta_signs: DeltaSigns::Both,
+            show_column_grid_lines: false,
+            show_comparison_line: false,
             columns: vec![
                 ColumnSettings {
                     name: String::from("Time"),
@@ -351,6 +389,7 @@ impl Component {
                 icon: *ImageId::EMPTY,
                 name: String::new(),
                 columns: ClearVec::new(),
+                comparison_line: None,
                 is_current_split: false,
                 index: 0,
             });
@@ -379,6 +418,27 @@ impl Component {
                 );
             }
 
+            if self.settings.show_comparison_line {
+                let comparison_line = state.comparison_line.get_or_insert_with(|| ColumnState {
+                    value: String::new(),
+                    semantic_color: Default::default(),
+                    visual_color: Color::transparent(),
+                    updates_frequently: false,
+                });
+                comparison_line.value.clear();
+                let _ = write!(
+                    comparison_line.value,
+                    "{}",
+                    Regular::with_accuracy(self.settings.split_time_accuracy).format(
+                        segment.comparison_timing_method(timer.current_comparison(), method)
+                    ),
+                );
+                comparison_line.semantic_color = Default::default();
+                comparison_line.visual_color = layout_settings.text_color;
+            } else {
+                state.comparison_line = None;
+            }
+
             state.is_current_split = Some(i) == current_split;
             state.index = i;
         }
@@ -390,9 +450,11 @@ impl Component {
                     icon: *ImageId::EMPTY,
                     name: String::new(),
                     columns: ClearVec::new(),
+                    comparison_line: None,
                     is_current_split: false,
                     index: 0,
                 });
+                state.comparison_line = None;
                 state.is_current_split = false;
                 state.index = (usize::MAX ^ 1) - 2 * i;
             }
@@ -403,6 +465,27 @@ impl Component {
         state.show_final_separator = show_final_separator;
         state.display_two_rows = display_two_rows;
         state.current_split_gradient = self.settings.current_split_gradient;
+
+        state.show_column_grid_lines = self.settings.show_column_grid_lines;
+        state.column_boundaries.clear();
+        let mut boundary = 0;
+        for column_index in 0..columns.len().saturating_sub(1) {
+            let values = state
+                .splits
+                .iter()
+                .filter_map(|split| Some(&split.columns.get(column_index)?.value));
+            let label = state
+                .column_labels
+                .iter()
+                .filter_map(|labels| labels.get(column_index));
+
+            boundary += values
+                .chain(label)
+                .map(|value| value.chars().count())
+                .max()
+                .unwrap_or_default();
+            state.column_boundaries.push(boundary);
+        }
     }
 
     /// Calculates the component's state based on the timer and layout settings
@@ -495,6 +578,26 @@ impl Component {
                 "Specifies whether to show the names of the columns at the top of the list.".into(),
                 self.settings.show_column_labels.into(),
             ),
+            Field::new(
+                "Live Segment Times".into(),
+                "Specifies whether columns that show the segment times of the current attempt strictly follow the progress of the attempt. If enabled, the current segment always shows its live segment time and the segments that haven't been reached yet are left blank while the attempt is in progress.".into(),
+                self.settings.live_segment_times.into(),
+            ),
+            Field::new(
+                "Delta Signs".into(),
+                "Specifies which signs the columns showing how far ahead or behind you are use. Only showing the plus sign leaves the segments you were ahead on without a sign, while only showing the minus sign does the same for the ones you were behind on.".into(),
+                self.settings.delta_signs.into(),
+            ),
+            Field::new(
+                "Show Column Grid Lines".into(),
+                "Specifies whether to show grid lines between the columns.".into(),
+                self.settings.show_column_grid_lines.into(),
+            ),
+            Field::new(
+                "Show Comparison Line".into(),
+                "Specifies whether to show the split time of the comparison as a smaller line below the times of each segment.".into(),
+                self.settings.show_comparison_line.into(),
+            ),
             Field::new(
                 "Columns".into(),
                 "The number of columns to show in each row. Each column can be configured to show different information. The columns are defined from right to left.".into(),
@@ -600,7 +703,11 @@ impl Component {
             11 => self.settings.delta_time_accuracy = value.into(),
             12 => self.settings.delta_drop_decimals = value.into(),
             13 => self.settings.show_column_labels = value.into(),
-            14 => {
+            14 => self.settings.live_segment_times = value.into(),
+            15 => self.settings.delta_signs = value.into(),
+            16 => self.settings.show_column_grid_lines = value.into(),
+            17 => self.settings.show_comparison_line = value.into(),
+            18 => {
                 let new_len = value.into_uint().unwrap() as usize;
                 self.settings.columns.resize(new_len, Default::default());
             }
@@ -1354,3 +1354,93 @@ fn check_column_color(state: &State, split_index: usize, expected_color: Semanti
         expected_color
     );
 }
+
+#[test]
+fn columns_resolve_their_own_comparison() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.add_custom_comparison("Fast").unwrap();
+    run.add_custom_comparison("Slow").unwrap();
+    for (segment, (fast, slow)) in run
+        .segments_mut()
+        .iter_mut()
+        .zip([(5.0, 7.0), (10.0, 14.0)])
+    {
+        segment.comparison_mut("Fast").game_time = Some(TimeSpan::from_seconds(fast));
+        segment.comparison_mut("Slow").game_time = Some(TimeSpan::from_seconds(slow));
+    }
+    let mut timer = Timer::new(run).unwrap();
+
+    let column = |start_with, update_with, comparison_override: Option<&str>| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with,
+            update_with,
+            comparison_override: comparison_override.map(Into::into),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            column(
+                ColumnStartWith::ComparisonTime,
+                ColumnUpdateWith::DontUpdate,
+                Some("Fast"),
+            ),
+            column(
+                ColumnStartWith::ComparisonTime,
+                ColumnUpdateWith::DontUpdate,
+                Some("Slow"),
+            ),
+            column(
+                ColumnStartWith::Empty,
+                ColumnUpdateWith::Delta,
+                Some("Fast"),
+            ),
+            column(
+                ColumnStartWith::Empty,
+                ColumnUpdateWith::Delta,
+                Some("Slow"),
+            ),
+            // Without an override the column falls back to the current
+            // comparison, which is the empty Personal Best.
+            column(
+                ColumnStartWith::ComparisonTime,
+                ColumnUpdateWith::DontUpdate,
+                None,
+            ),
+        ],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    let mut image_cache = ImageCache::new();
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(8.0)).unwrap();
+    timer.split().unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let values = state
+        .splits
+        .iter()
+        .map(|split| {
+            split
+                .columns
+                .iter()
+                .map(|column| column.value.as_str())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        values,
+        [
+            ["0:05", "0:07", "+3.0", "+1.0", "—"],
+            ["0:10", "0:14", "", "", "—"],
+        ],
+    );
+}
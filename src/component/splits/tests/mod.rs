@@ -5,11 +5,21 @@ use super::{
 use crate::{
     component::splits::{ColumnKind, TimeColumn},
     settings::ImageCache,
+    util::tests_helper::{create_timer, run_with_splits, start_run},
     Run, Segment, TimeSpan, Timer, TimingMethod,
 };
 
 pub mod column;
 
+fn setting_index(component: &Component, name: &str) -> usize {
+    component
+        .settings_description()
+        .fields
+        .iter()
+        .position(|field| field.text == name)
+        .unwrap()
+}
+
 #[test]
 fn zero_visual_split_count_always_shows_all_splits() {
     let mut run = Run::new();
@@ -146,3 +156,186 @@ fn unique_split_indices() {
 
     assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
 }
+
+fn segment_time_component(live_segment_times: bool) -> Component {
+    Component::with_settings(Settings {
+        live_segment_times,
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(TimeColumn {
+                start_with: ColumnStartWith::ComparisonSegmentTime,
+                update_with: ColumnUpdateWith::SegmentTime,
+                update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    })
+}
+
+fn column_values(component: &mut Component, timer: &Timer) -> Vec<String> {
+    let mut image_cache = ImageCache::new();
+    component
+        .state(&mut image_cache, &timer.snapshot(), &Default::default())
+        .splits
+        .iter()
+        .filter(|split| !split.name.is_empty())
+        .map(|split| split.columns[0].value.clone())
+        .collect()
+}
+
+#[test]
+fn live_segment_times_follow_the_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    let mut component = segment_time_component(true);
+
+    // Before the attempt the comparison's segment times are shown.
+    assert_eq!(
+        column_values(&mut component, &timer),
+        ["1.00", "2.00", "3.00"]
+    );
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(0.5)).unwrap();
+    assert_eq!(column_values(&mut component, &timer), ["0.50", "", ""]);
+
+    timer.set_game_time(TimeSpan::from_seconds(1.5)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(2.5)).unwrap();
+    assert_eq!(column_values(&mut component, &timer), ["1.50", "1.00", ""]);
+
+    // The final segment is treated just like every other segment.
+    timer.set_game_time(TimeSpan::from_seconds(4.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    assert_eq!(
+        column_values(&mut component, &timer),
+        ["1.50", "2.50", "1.00"]
+    );
+
+    let mut image_cache = ImageCache::new();
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert!(state.splits[2].is_current_split);
+    assert!(state.splits[2].columns[0].updates_frequently);
+
+    timer.set_game_time(TimeSpan::from_seconds(6.5)).unwrap();
+    timer.split().unwrap();
+    assert_eq!(
+        column_values(&mut component, &timer),
+        ["1.50", "2.50", "2.50"]
+    );
+}
+
+#[test]
+fn segment_times_follow_column_settings_by_default() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    let mut component = segment_time_component(false);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(1.5)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(2.5)).unwrap();
+    assert_eq!(
+        column_values(&mut component, &timer),
+        ["1.50", "2.00", "3.00"]
+    );
+}
+
+#[test]
+fn column_boundaries_and_grid_lines() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[5.0, 123.0, 4567.0]);
+    start_run(&mut timer);
+
+    let mut settings = Settings::default();
+    settings.columns.push(ColumnSettings {
+        name: String::from("Segment Time"),
+        kind: ColumnKind::Time(TimeColumn {
+            update_with: ColumnUpdateWith::SegmentTime,
+            ..Default::default()
+        }),
+    });
+    let mut component = Component::with_settings(settings);
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(!state.show_column_grid_lines);
+    assert_eq!(state.column_boundaries.len(), 2);
+
+    let longest_value = |state: &State, column: usize| {
+        state
+            .splits
+            .iter()
+            .filter_map(|split| split.columns.get(column))
+            .map(|column| column.value.chars().count())
+            .max()
+            .unwrap()
+    };
+    assert_eq!(state.column_boundaries[0], longest_value(&state, 0));
+    assert_eq!(
+        state.column_boundaries[1],
+        longest_value(&state, 0) + longest_value(&state, 1),
+    );
+
+    component.set_value(
+        setting_index(&component, "Show Column Grid Lines"),
+        true.into(),
+    );
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.show_column_grid_lines);
+
+    component.settings_mut().columns.truncate(1);
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.column_boundaries.is_empty());
+}
+
+#[test]
+fn comparison_line() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[5.0, 123.0, 4567.0]);
+    start_run(&mut timer);
+
+    let mut component = Component::new();
+    let layout_settings = crate::GeneralLayoutSettings::default();
+    let mut image_cache = ImageCache::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state
+        .splits
+        .iter()
+        .all(|split| split.comparison_line.is_none()));
+
+    component.set_value(
+        setting_index(&component, "Show Comparison Line"),
+        true.into(),
+    );
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let comparison_lines: Vec<_> = state
+        .splits
+        .iter()
+        .take(3)
+        .map(|split| split.comparison_line.as_ref().unwrap().value.as_str())
+        .collect();
+    assert_eq!(comparison_lines, ["0:05", "2:03", "1:16:07"]);
+    assert_eq!(
+        state.splits[0]
+            .comparison_line
+            .as_ref()
+            .unwrap()
+            .visual_color,
+        layout_settings.text_color,
+    );
+
+    component.set_value(
+        setting_index(&component, "Show Comparison Line"),
+        false.into(),
+    );
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state
+        .splits
+        .iter()
+        .all(|split| split.comparison_line.is_none()));
+}
@@ -0,0 +1,225 @@
+//! Provides the Sum of Best Delta Component and relevant types for using it.
+//! The Sum of Best Delta Component shows how far the predicted final time of
+//! the current attempt is away from the Sum of Best Segments, which is the
+//! fastest time that is theoretically possible based on all the previous
+//! attempts.
+
+use super::key_value;
+use crate::{
+    analysis::{current_pace, sum_of_segments::calculate_best},
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Delta, TimeFormatter},
+        Snapshot,
+    },
+    TimeSpan, TimerPhase,
+};
+use alloc::borrow::Cow;
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Sum of Best Delta Component shows how far the predicted final time of
+/// the current attempt is away from the Sum of Best Segments. The final time is
+/// predicted the same way the Current Pace Component does it, by assuming that
+/// the remainder of the attempt matches the chosen comparison.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison used for predicting the final time. Uses the Timer's
+    /// current comparison if set to `None`.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// Specifies if the decimals should not be shown anymore when the
+    /// visualized delta is above one minute.
+    pub drop_decimals: bool,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            drop_decimals: true,
+            accuracy: Accuracy::Tenths,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Sum of Best Delta Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Sum of Best Delta Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if let Some(comparison) = &self.settings.comparison_override {
+            format!("Sum of Best Delta ({})", comparison::shorten(comparison)).into()
+        } else {
+            "Sum of Best Delta".into()
+        }
+    }
+
+    /// Calculates the difference between the predicted final time of the
+    /// current attempt and the Sum of Best Segments. This is `None` if there is
+    /// no attempt in progress. The boolean returned specifies whether the value
+    /// changes on its own as time passes.
+    fn calculate(&self, timer: &Snapshot<'_>) -> (Option<TimeSpan>, bool) {
+        if timer.current_phase() == TimerPhase::NotRunning {
+            return (None, false);
+        }
+
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+
+        let (current_pace, updates_frequently) = current_pace::calculate(timer, comparison);
+        let sum_of_best = calculate_best(
+            timer.run().segments(),
+            false,
+            true,
+            timer.current_timing_method(),
+        );
+
+        (catch! { current_pace? - sum_of_best? }, updates_frequently)
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
+        let (delta, updates_frequently) = self.calculate(timer);
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str("Sum of Best Delta");
+        if let Some(comparison) = comparison::resolve(&self.settings.comparison_override, timer) {
+            let _ = write!(state.key, " ({})", comparison::shorten(comparison));
+        }
+
+        state.value.clear();
+        let _ = write!(
+            state.value,
+            "{}",
+            Delta::custom(self.settings.drop_decimals, self.settings.accuracy).format(delta),
+        );
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push("SoB Delta".into());
+        state.key_abbreviations.push("vs. SoB".into());
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = updates_frequently;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Snapshot<'_>) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Comparison".into(),
+                "The comparison to predict the final time from. If not specified, the current comparison is used.".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the delta in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the delta. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Drop Decimals".into(),
+                "Specifies if the decimals should not be shown anymore when the visualized delta is over a minute.".into(),
+                self.settings.drop_decimals.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the delta shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.drop_decimals = value.into(),
+            6 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::Component;
+use crate::{
+    analysis::{current_pace, sum_of_segments::calculate_best},
+    timing::formatter::{Delta, TimeFormatter, DASH},
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    Timer,
+};
+
+fn timer_with_history() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    // The Personal Best is 30 seconds, while the best segments are 10, 8 and 10
+    // seconds, so the Sum of Best Segments is 28 seconds.
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0, 31.0]);
+    timer
+}
+
+#[test]
+fn is_empty_before_the_attempt_starts() {
+    let timer = timer_with_history();
+    let state = Component::new().state(&timer.snapshot());
+
+    assert_eq!(&*state.key, "Sum of Best Delta");
+    assert_eq!(&*state.value, DASH);
+    assert!(!state.updates_frequently);
+}
+
+#[test]
+fn is_the_current_pace_minus_the_sum_of_best() {
+    let mut timer = timer_with_history();
+    let component = Component::new();
+
+    start_run(&mut timer);
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "+3.0");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(19.5)]);
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "+1.5");
+
+    let snapshot = timer.snapshot();
+    let method = snapshot.current_timing_method();
+    let (pace, _) = current_pace::calculate(&snapshot, snapshot.current_comparison());
+    let sum_of_best = calculate_best(snapshot.run().segments(), false, true, method);
+    let expected = Delta::with_decimal_dropping()
+        .format(Some(pace.unwrap() - sum_of_best.unwrap()))
+        .to_string();
+    assert_eq!(*state.value, *expected);
+}
+
+#[test]
+fn uses_the_comparison_override_for_the_prediction() {
+    let mut timer = timer_with_history();
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some("Best Segments".into());
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+
+    // Finishing the attempt with the best segments from here on results in 29
+    // seconds, which is 1 second behind the Sum of Best Segments.
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.key, "Sum of Best Delta (Best)");
+    assert_eq!(&*state.value, "+1.0");
+}
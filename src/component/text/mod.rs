@@ -48,7 +48,7 @@ pub struct Settings {
 }
 
 /// The text that is supposed to be shown.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub enum Text {
     /// A single centered text.
     Center(String),
@@ -119,7 +119,7 @@ impl Text {
 }
 
 /// The state object describes the information to visualize for this component.
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Default, PartialEq, Serialize, Deserialize)]
 pub struct State {
     /// The background shown behind the component.
     pub background: Gradient,
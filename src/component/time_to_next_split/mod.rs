@@ -0,0 +1,221 @@
+//! Provides the Time to Next Split Component and relevant types for using it.
+//! The Time to Next Split Component shows a live countdown to the time at which
+//! the current split is expected to happen, based on the chosen comparison.
+
+use super::key_value;
+use crate::{
+    analysis::state_helper::live_segment_delta,
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter},
+        Snapshot,
+    },
+    TimeSpan, TimerPhase,
+};
+use alloc::borrow::Cow;
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Time to Next Split Component shows how much time is left until the
+/// current split is expected to happen. This is the comparison's segment time
+/// of the current segment minus the time that has passed in the current
+/// segment so far. Once the segment takes longer than the comparison's segment
+/// time, the countdown turns negative.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison that the expected segment time is taken from. Uses the
+    /// Timer's current comparison if set to `None`.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Tenths,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Time to Next Split Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Time to Next Split Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if let Some(comparison) = &self.settings.comparison_override {
+            format!("Time to Next Split ({})", comparison::shorten(comparison)).into()
+        } else {
+            "Time to Next Split".into()
+        }
+    }
+
+    /// Calculates the time left until the current split is expected to happen.
+    /// This is `None` if there is no attempt in progress, the attempt has
+    /// ended or the comparison has no time for the current split. The boolean
+    /// returned specifies whether the value changes on its own as time passes.
+    fn calculate(&self, timer: &Snapshot<'_>) -> (Option<TimeSpan>, bool) {
+        let phase = timer.current_phase();
+        let Some(split_index) = timer
+            .current_split_index()
+            .filter(|_| matches!(phase, TimerPhase::Running | TimerPhase::Paused))
+        else {
+            return (None, false);
+        };
+
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+
+        // The live segment delta is the time passed in the current segment
+        // minus the comparison's segment time, both measured from the last
+        // split that has a split time, so skipped splits are accounted for.
+        let time_left = live_segment_delta(
+            timer,
+            split_index,
+            comparison,
+            timer.current_timing_method(),
+        )
+        .map(|delta| -delta);
+
+        (time_left, phase == TimerPhase::Running)
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
+        let (time_left, updates_frequently) = self.calculate(timer);
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str("Time to Next Split");
+        if let Some(comparison) = comparison::resolve(&self.settings.comparison_override, timer) {
+            let _ = write!(state.key, " ({})", comparison::shorten(comparison));
+        }
+
+        state.value.clear();
+        let _ = write!(
+            state.value,
+            "{}",
+            Regular::with_accuracy(self.settings.accuracy).format(time_left),
+        );
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push("To Next Split".into());
+        state.key_abbreviations.push("Next Split".into());
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = updates_frequently;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Snapshot<'_>) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Comparison".into(),
+                "The comparison to take the expected segment time from. If not specified, the current comparison is used.".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the time left in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the time left. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the time left shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
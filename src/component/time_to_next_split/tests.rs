@@ -0,0 +1,127 @@
+use super::Component;
+use crate::{
+    analysis::{comparison_combined_segment_time, live_segment_time},
+    timing::formatter::{Accuracy, Regular, TimeFormatter, DASH},
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run,
+    },
+    Timer,
+};
+
+fn timer_with_pb() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    // The Personal Best's segments are 10, 15 and 15 seconds.
+    run_with_splits(&mut timer, &[10.0, 25.0, 40.0]);
+    timer
+}
+
+#[track_caller]
+fn value(component: &Component, timer: &Timer) -> String {
+    component.state(&timer.snapshot()).value.to_string()
+}
+
+#[test]
+fn is_empty_without_an_attempt_in_progress() {
+    let timer = timer_with_pb();
+    let state = Component::new().state(&timer.snapshot());
+
+    assert_eq!(&*state.key, "Time to Next Split");
+    assert_eq!(&*state.value, DASH);
+    assert!(!state.updates_frequently);
+}
+
+#[test]
+fn counts_down_during_the_segment() {
+    let mut timer = timer_with_pb();
+    let component = Component::new();
+    start_run(&mut timer);
+
+    timer.set_game_time(span(4.0)).unwrap();
+    assert_eq!(value(&component, &timer), "0:06.0");
+
+    timer.set_game_time(span(7.5)).unwrap();
+    assert_eq!(value(&component, &timer), "0:02.5");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+    timer.set_game_time(span(12.0)).unwrap();
+    assert_eq!(value(&component, &timer), "0:12.0");
+
+    // It matches the comparison's segment time minus the time that has passed
+    // in the current segment.
+    let snapshot = timer.snapshot();
+    let method = snapshot.current_timing_method();
+    let expected =
+        comparison_combined_segment_time(snapshot.run(), 1, snapshot.current_comparison(), method)
+            .unwrap()
+            - live_segment_time(&snapshot, 1, method).unwrap();
+    assert_eq!(
+        value(&component, &timer),
+        Regular::with_accuracy(Accuracy::Tenths)
+            .format(expected)
+            .to_string(),
+    );
+}
+
+#[test]
+fn turns_negative_once_the_segment_takes_longer() {
+    let mut timer = timer_with_pb();
+    start_run(&mut timer);
+    timer.set_game_time(span(12.0)).unwrap();
+
+    assert_eq!(value(&Component::new(), &timer), "−0:02.0");
+}
+
+#[test]
+fn handles_the_final_segment_and_the_end_of_the_attempt() {
+    let mut timer = timer_with_pb();
+    let component = Component::new();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0), Some(24.0)]);
+
+    timer.set_game_time(span(30.0)).unwrap();
+    assert_eq!(value(&component, &timer), "0:09.0");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(38.0)]);
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, DASH);
+    assert!(!state.updates_frequently);
+}
+
+#[test]
+fn combines_skipped_segments() {
+    let mut timer = timer_with_pb();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0), None]);
+
+    // The segments B and C take 30 seconds in the Personal Best, and 21
+    // seconds have passed since splitting A.
+    timer.set_game_time(span(30.0)).unwrap();
+    assert_eq!(value(&Component::new(), &timer), "0:09.0");
+}
+
+#[test]
+fn uses_the_comparison_override() {
+    let mut timer = timer_with_pb();
+    run_with_splits(&mut timer, &[8.0, 26.0, 41.0]);
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some("Best Segments".into());
+
+    start_run(&mut timer);
+    timer.set_game_time(span(4.0)).unwrap();
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.key, "Time to Next Split (Best)");
+    assert_eq!(&*state.value, "0:04.0");
+}
+
+#[test]
+fn ignores_an_unknown_comparison_override() {
+    let mut timer = timer_with_pb();
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some("Unknown".into());
+
+    start_run(&mut timer);
+    timer.set_game_time(span(4.0)).unwrap();
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.key, "Time to Next Split");
+    assert_eq!(&*state.value, "0:06.0");
+}
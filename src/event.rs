@@ -59,6 +59,13 @@ pub enum Event {
     LoadingTimesSet = 16,
     /// A custom variable has been set.
     CustomVariableSet = 17,
+    /// A bookmark has been added to the current attempt.
+    BookmarkAdded = 18,
+    /// The most recent reset has been undone.
+    ResetUndone = 19,
+    /// The start of the attempt has been undone, because the first split was
+    /// undone.
+    StartUndone = 20,
     /// An unknown event occurred.
     #[serde(other)]
     Unknown,
@@ -85,6 +92,9 @@ impl From<u32> for Event {
             15 => Event::GameTimeResumed,
             16 => Event::LoadingTimesSet,
             17 => Event::CustomVariableSet,
+            18 => Event::BookmarkAdded,
+            19 => Event::ResetUndone,
+            20 => Event::StartUndone,
             _ => Event::Unknown,
         }
     }
@@ -139,6 +149,8 @@ pub enum Error {
     TimerPaused = 15,
     /// The runner decided to not reset the run.
     RunnerDecidedAgainstReset = 16,
+    /// There is no reset that can be undone.
+    NoResetToUndo = 17,
     /// An unknown error occurred.
     #[serde(other)]
     Unknown,
@@ -164,6 +176,7 @@ impl From<u32> for Error {
             14 => Error::CouldNotParseTime,
             15 => Error::TimerPaused,
             16 => Error::RunnerDecidedAgainstReset,
+            17 => Error::NoResetToUndo,
             _ => Error::Unknown,
         }
     }
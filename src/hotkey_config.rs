@@ -7,6 +7,9 @@ use crate::{
 };
 use serde_derive::{Deserialize, Serialize};
 
+/// The amount of comparisons that can directly be switched to by a hotkey.
+pub(crate) const COMPARISON_HOTKEY_COUNT: usize = 10;
+
 /// The configuration to use for a [`HotkeySystem`](crate::HotkeySystem). It describes which [`Hotkey`](livesplit_hotkey::Hotkey) to use as hotkeys for the different actions.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(default)]
@@ -31,6 +34,11 @@ pub struct HotkeyConfig {
     /// The key to use for toggling between the `Real Time` and `Game Time`
     /// timing methods.
     pub toggle_timing_method: Option<Hotkey>,
+    /// The keys to use for directly switching to a specific comparison. The
+    /// position of a key in this list is the index of the comparison in the
+    /// list of all the comparisons of the run. If the run doesn't have a
+    /// comparison at that index, pressing the key doesn't do anything.
+    pub select_comparison: [Option<Hotkey>; COMPARISON_HOTKEY_COUNT],
 }
 
 impl Default for HotkeyConfig {
@@ -46,6 +54,7 @@ impl Default for HotkeyConfig {
             previous_comparison: Some(Numpad4.into()),
             next_comparison: Some(Numpad6.into()),
             toggle_timing_method: None,
+            select_comparison: [None; COMPARISON_HOTKEY_COUNT],
         }
     }
 }
@@ -54,7 +63,7 @@ impl HotkeyConfig {
     /// Accesses a generic description of the settings available for the hotkey
     /// configuration and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut settings = SettingsDescription::with_fields(vec![
             Field::new(
                 "Start / Split".into(),
                 "The hotkey to use for splitting and starting a new attempt.".into(),
@@ -100,7 +109,23 @@ impl HotkeyConfig {
                 r#"The hotkey to use for toggling between the "Real Time" and "Game Time" timing methods."#.into(),
                 self.toggle_timing_method.into(),
             ),
-        ])
+        ]);
+
+        settings
+            .fields
+            .extend(self.select_comparison.iter().enumerate().map(|(i, &hotkey)| {
+                Field::new(
+                    format!("Select Comparison {}", i + 1).into(),
+                    format!(
+                        "The hotkey to use for switching to comparison number {} in the list of all the comparisons.",
+                        i + 1,
+                    )
+                    .into(),
+                    hotkey.into(),
+                )
+            }));
+
+        settings
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -131,6 +156,7 @@ impl HotkeyConfig {
                 self.toggle_timing_method,
             ]
             .into_iter()
+            .chain(self.select_comparison)
             .enumerate()
             .filter(|&(i, _)| i != index)
             .any(|(_, v)| v == value);
@@ -150,7 +176,12 @@ impl HotkeyConfig {
             6 => self.previous_comparison = value,
             7 => self.next_comparison = value,
             8 => self.toggle_timing_method = value,
-            _ => panic!("Unsupported Setting Index"),
+            _ => {
+                *self
+                    .select_comparison
+                    .get_mut(index - 9)
+                    .expect("Unsupported Setting Index") = value
+            }
         }
 
         Ok(())
@@ -1,8 +1,10 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, sync::Arc};
+use core::sync::atomic::{self, AtomicBool};
 
 use crate::{
     event,
     hotkey::{ConsumePreference, Hook, Hotkey, KeyCode},
+    hotkey_config::COMPARISON_HOTKEY_COUNT,
     HotkeyConfig,
 };
 
@@ -30,6 +32,8 @@ enum Action {
     /// The key to use for toggling between the `Real Time` and `Game Time`
     /// timing methods.
     ToggleTimingMethod,
+    /// The key to use for switching to the comparison at the given index.
+    SelectComparison(usize),
 }
 
 impl Action {
@@ -44,6 +48,11 @@ impl Action {
             Action::PreviousComparison => config.previous_comparison = hotkey,
             Action::NextComparison => config.next_comparison = hotkey,
             Action::ToggleTimingMethod => config.toggle_timing_method = hotkey,
+            Action::SelectComparison(index) => {
+                if let Some(slot) = config.select_comparison.get_mut(index) {
+                    *slot = hotkey;
+                }
+            }
         }
     }
 
@@ -58,12 +67,20 @@ impl Action {
             Action::PreviousComparison => config.previous_comparison,
             Action::NextComparison => config.next_comparison,
             Action::ToggleTimingMethod => config.toggle_timing_method,
+            Action::SelectComparison(index) => {
+                if index < COMPARISON_HOTKEY_COUNT {
+                    config.select_comparison[index]
+                } else {
+                    None
+                }
+            }
         }
     }
 
     fn callback<S: event::CommandSink + Send + 'static>(
         self,
         command_sink: S,
+        select_comparison: Option<fn(&S, usize)>,
     ) -> Box<dyn FnMut() + Send + 'static> {
         match self {
             Action::Split => Box::new(move || {
@@ -93,10 +110,56 @@ impl Action {
             Action::ToggleTimingMethod => Box::new(move || {
                 drop(command_sink.toggle_timing_method());
             }),
+            Action::SelectComparison(index) => Box::new(move || {
+                if let Some(select_comparison) = select_comparison {
+                    select_comparison(&command_sink, index);
+                }
+            }),
         }
     }
 }
 
+/// Tracks whether the Hotkey System is capturing the next hotkey, so that the
+/// actions don't trigger until the hotkey is captured.
+#[derive(Clone, Default)]
+struct Capture(Arc<AtomicBool>);
+
+impl Capture {
+    fn guard(
+        &self,
+        mut callback: Box<dyn FnMut() + Send + 'static>,
+    ) -> impl FnMut() + Send + 'static {
+        let capture = self.clone();
+        move || {
+            if !capture.is_capturing() {
+                callback();
+            }
+        }
+    }
+
+    fn start<F>(&self, callback: F) -> impl FnOnce(Hotkey) + Send + 'static
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        self.0.store(true, atomic::Ordering::Relaxed);
+        let capture = self.clone();
+        move |hotkey| {
+            // The capture ends before the callback is called, so the callback
+            // can already trigger the actions again.
+            capture.stop();
+            callback(hotkey);
+        }
+    }
+
+    fn stop(&self) {
+        self.0.store(false, atomic::Ordering::Relaxed);
+    }
+
+    fn is_capturing(&self) -> bool {
+        self.0.load(atomic::Ordering::Relaxed)
+    }
+}
+
 /// With a `HotkeySystem` the runner can use hotkeys on their keyboard to control
 /// the Timer. The hotkeys are global, so the application doesn't need to be in
 /// focus. The behavior of the hotkeys depends on the platform and is stubbed
@@ -106,6 +169,8 @@ pub struct HotkeySystem<S> {
     config: HotkeyConfig,
     hook: Hook,
     command_sink: S,
+    select_comparison: Option<fn(&S, usize)>,
+    capture: Capture,
     is_active: bool,
 }
 
@@ -116,12 +181,16 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
     }
 
     /// Creates a new Hotkey System for a Timer with a custom configuration for
-    /// the hotkeys.
+    /// the hotkeys. The hotkeys for switching to a comparison by its index
+    /// only get registered once
+    /// [`enable_select_comparison`](Self::enable_select_comparison) is called.
     pub fn with_config(command_sink: S, config: HotkeyConfig) -> Result<Self> {
         let mut hotkey_system = Self {
             config,
             hook: Hook::with_consume_preference(ConsumePreference::PreferNoConsume)?,
             command_sink,
+            select_comparison: None,
+            capture: Capture::default(),
             is_active: false,
         };
         hotkey_system.activate()?;
@@ -132,12 +201,22 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
     // state and we might leak a registered hotkey
     fn register_inner(&self, action: Action) -> Result<()> {
         let inner = self.command_sink.clone();
-        if let Some(hotkey) = action.get_hotkey(&self.config) {
-            self.hook.register(hotkey, action.callback(inner))?;
+        if let Some(hotkey) = self.registered_hotkey(action) {
+            let callback = action.callback(inner, self.select_comparison);
+            self.hook.register(hotkey, self.capture.guard(callback))?;
         }
         Ok(())
     }
 
+    // The hotkeys for selecting a comparison by its index only get registered
+    // once the command sink is known to be able to query the comparisons.
+    fn registered_hotkey(&self, action: Action) -> Option<Hotkey> {
+        if matches!(action, Action::SelectComparison(_)) && self.select_comparison.is_none() {
+            return None;
+        }
+        action.get_hotkey(&self.config)
+    }
+
     fn register(&mut self, action: Action, hotkey: Option<Hotkey>) -> Result<()> {
         action.set_hotkey(&mut self.config, hotkey);
         self.register_inner(action)
@@ -146,7 +225,7 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
     // This method should never be public, because it might mess up the internal
     // state and we might leak a registered hotkey
     fn unregister_inner(&self, action: Action) -> Result<()> {
-        if let Some(hotkey) = action.get_hotkey(&self.config) {
+        if let Some(hotkey) = self.registered_hotkey(action) {
             self.hook.unregister(hotkey)?;
         }
         Ok(())
@@ -233,6 +312,9 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
             self.unregister_inner(Action::PreviousComparison)?;
             self.unregister_inner(Action::NextComparison)?;
             self.unregister_inner(Action::ToggleTimingMethod)?;
+            for index in 0..COMPARISON_HOTKEY_COUNT {
+                self.unregister_inner(Action::SelectComparison(index))?;
+            }
         }
         self.is_active = false;
         Ok(())
@@ -251,6 +333,9 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
             self.register_inner(Action::PreviousComparison)?;
             self.register_inner(Action::NextComparison)?;
             self.register_inner(Action::ToggleTimingMethod)?;
+            for index in 0..COMPARISON_HOTKEY_COUNT {
+                self.register_inner(Action::SelectComparison(index))?;
+            }
         }
         self.is_active = true;
         Ok(())
@@ -280,12 +365,205 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
         self.set_next_comparison(config.next_comparison)?;
         self.set_undo_all_pauses(config.undo_all_pauses)?;
         self.set_toggle_timing_method(config.toggle_timing_method)?;
+        for (index, hotkey) in config.select_comparison.into_iter().enumerate() {
+            self.set_hotkey(Action::SelectComparison(index), hotkey)?;
+        }
 
         Ok(())
     }
 
+    /// Captures the next hotkey that gets pressed and passes it to the
+    /// callback. This allows the user to choose a new hotkey by pressing it.
+    /// None of the hotkeys of the Hotkey System trigger while capturing. They
+    /// work as usual again once the callback is called. Modifier keys on their
+    /// own don't end the capture, so hotkeys with modifiers can be captured.
+    pub fn capture_next_key<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnOnce(Hotkey) + Send + 'static,
+    {
+        let result = self.hook.capture_next(self.capture.start(callback));
+        if result.is_err() {
+            self.capture.stop();
+        }
+        result
+    }
+
+    /// Stops capturing the next hotkey without calling the callback passed to
+    /// [`capture_next_key`](Self::capture_next_key).
+    pub fn cancel_capture(&self) -> Result<()> {
+        self.hook.cancel_capture()?;
+        self.capture.stop();
+        Ok(())
+    }
+
     /// Resolves the key according to the current keyboard layout.
     pub fn resolve(&self, key_code: KeyCode) -> Cow<'static, str> {
         key_code.resolve(&self.hook)
     }
 }
+
+impl<S: event::CommandSink + event::TimerQuery + Clone + Send + 'static> HotkeySystem<S> {
+    /// Enables the hotkeys for switching to a comparison by its index. These
+    /// need to query the comparisons of the run, so they only work for command
+    /// sinks that allow querying the timer. If they are already enabled,
+    /// nothing happens.
+    pub fn enable_select_comparison(&mut self) -> Result<()> {
+        if self.select_comparison.is_some() {
+            return Ok(());
+        }
+        self.select_comparison = Some(Self::select_comparison);
+        if self.is_active {
+            for index in 0..COMPARISON_HOTKEY_COUNT {
+                self.register_inner(Action::SelectComparison(index))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the key to use for switching to the comparison at the given index
+    /// in the list of all the comparisons. If the index is out of range of the
+    /// hotkeys that can be configured, nothing happens. This also enables the
+    /// hotkeys for switching to a comparison by its index.
+    pub fn set_select_comparison(&mut self, index: usize, hotkey: Option<Hotkey>) -> Result<()> {
+        if index >= COMPARISON_HOTKEY_COUNT {
+            return Ok(());
+        }
+        self.enable_select_comparison()?;
+        self.set_hotkey(Action::SelectComparison(index), hotkey)
+    }
+
+    fn select_comparison(command_sink: &S, index: usize) {
+        // The timer needs to be released again before the comparison can be
+        // changed.
+        let comparison = command_sink
+            .get_timer()
+            .run()
+            .comparisons()
+            .nth(index)
+            .map(String::from);
+        if let Some(comparison) = comparison {
+            drop(command_sink.set_current_comparison(comparison.into()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hotkey::KeyCode, util::tests_helper::create_run, Timer, TimerPhase};
+    use std::sync::Mutex;
+
+    fn timer_with_comparisons() -> crate::SharedTimer {
+        let mut run = create_run(&["A", "B"]);
+        run.add_custom_comparison("Foo").unwrap();
+        Timer::new(run).unwrap().into_shared()
+    }
+
+    #[test]
+    fn select_comparison() {
+        let timer = timer_with_comparisons();
+        let expected = timer
+            .read()
+            .unwrap()
+            .run()
+            .comparisons()
+            .nth(1)
+            .unwrap()
+            .to_owned();
+
+        let mut config = HotkeyConfig::default();
+        Action::SelectComparison(1).set_hotkey(&mut config, Some(KeyCode::Digit2.into()));
+        assert_eq!(
+            Action::SelectComparison(1).get_hotkey(&config),
+            Some(KeyCode::Digit2.into()),
+        );
+
+        Action::SelectComparison(1).callback(timer.clone(), Some(HotkeySystem::select_comparison))(
+        );
+        assert_eq!(timer.read().unwrap().current_comparison(), expected);
+    }
+
+    #[test]
+    fn select_comparison_out_of_range() {
+        let timer = timer_with_comparisons();
+        let before = timer.read().unwrap().current_comparison().to_owned();
+
+        Action::SelectComparison(100)
+            .callback(timer.clone(), Some(HotkeySystem::select_comparison))();
+        assert_eq!(timer.read().unwrap().current_comparison(), before);
+
+        let mut config = HotkeyConfig::default();
+        Action::SelectComparison(100).set_hotkey(&mut config, Some(KeyCode::Digit2.into()));
+        assert_eq!(config, HotkeyConfig::default());
+    }
+
+    #[test]
+    fn select_comparison_config_round_trips() {
+        let mut config = HotkeyConfig::default();
+        config.select_comparison[1] = Some(KeyCode::Digit2.into());
+
+        let mut json = Vec::new();
+        config.write_json(&mut json).unwrap();
+        let parsed = HotkeyConfig::from_json(&*json).unwrap();
+
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn capture_next_key() {
+        let timer = Timer::new(create_run(&["A", "B"])).unwrap().into_shared();
+        let capture = Capture::default();
+        let mut split = capture.guard(Action::Split.callback(timer.clone(), None));
+
+        let captured = Arc::new(Mutex::new(None));
+        let on_capture = capture.start({
+            let captured = captured.clone();
+            move |hotkey| *captured.lock().unwrap() = Some(hotkey)
+        });
+
+        // The actions don't trigger while capturing.
+        split();
+        assert_eq!(
+            timer.read().unwrap().current_phase(),
+            TimerPhase::NotRunning
+        );
+
+        let hotkey = KeyCode::KeyS.with_modifiers(crate::hotkey::Modifiers::SHIFT);
+        on_capture(hotkey);
+        assert_eq!(*captured.lock().unwrap(), Some(hotkey));
+
+        split();
+        assert_eq!(timer.read().unwrap().current_phase(), TimerPhase::Running);
+    }
+
+    #[test]
+    #[ignore = "needs a hotkey hook, which headless environments don't provide"]
+    fn capture_next_key_through_the_hook() {
+        let timer = Timer::new(create_run(&["A", "B"])).unwrap().into_shared();
+        let hotkey_system = HotkeySystem::new(timer).unwrap();
+
+        hotkey_system.capture_next_key(|_| {}).unwrap();
+        assert!(hotkey_system.capture.is_capturing());
+
+        hotkey_system.cancel_capture().unwrap();
+        assert!(!hotkey_system.capture.is_capturing());
+    }
+
+    #[test]
+    fn cancelled_capture_triggers_the_actions_again() {
+        let timer = Timer::new(create_run(&["A", "B"])).unwrap().into_shared();
+        let capture = Capture::default();
+        let mut split = capture.guard(Action::Split.callback(timer.clone(), None));
+
+        let _on_capture = capture.start(|_| panic!("The capture was cancelled"));
+        split();
+        assert_eq!(
+            timer.read().unwrap().current_phase(),
+            TimerPhase::NotRunning
+        );
+
+        capture.stop();
+        split();
+        assert_eq!(timer.read().unwrap().current_phase(), TimerPhase::Running);
+    }
+}
@@ -2,8 +2,8 @@ use super::{ComponentSettings, ComponentState, GeneralSettings};
 use crate::{
     component::{
         blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        possible_time_save, previous_segment, segment_time, segments_remaining, separator, splits,
+        sum_of_best, sum_of_best_delta, text, time_to_next_split, timer, title, total_playtime,
     },
     platform::prelude::*,
     settings::{ImageCache, SettingsDescription, Value},
@@ -35,14 +35,20 @@ pub enum Component {
     PreviousSegment(previous_segment::Component),
     /// The Segment Time Component.
     SegmentTime(segment_time::Component),
+    /// The Segments Remaining Component.
+    SegmentsRemaining(segments_remaining::Component),
     /// The Separator Component.
     Separator(separator::Component),
     /// The Splits Component.
     Splits(splits::Component),
     /// The Sum of Best Component.
     SumOfBest(sum_of_best::Component),
+    /// The Sum of Best Delta Component.
+    SumOfBestDelta(sum_of_best_delta::Component),
     /// The Text Component.
     Text(text::Component),
+    /// The Time to Next Split Component.
+    TimeToNextSplit(time_to_next_split::Component),
     /// The Timer Component.
     Timer(timer::Component),
     /// The Title Component.
@@ -111,6 +117,12 @@ impl From<segment_time::Component> for Component {
     }
 }
 
+impl From<segments_remaining::Component> for Component {
+    fn from(component: segments_remaining::Component) -> Self {
+        Self::SegmentsRemaining(component)
+    }
+}
+
 impl From<separator::Component> for Component {
     fn from(component: separator::Component) -> Self {
         Self::Separator(component)
@@ -129,12 +141,24 @@ impl From<sum_of_best::Component> for Component {
     }
 }
 
+impl From<sum_of_best_delta::Component> for Component {
+    fn from(component: sum_of_best_delta::Component) -> Self {
+        Self::SumOfBestDelta(component)
+    }
+}
+
 impl From<text::Component> for Component {
     fn from(component: text::Component) -> Self {
         Self::Text(component)
     }
 }
 
+impl From<time_to_next_split::Component> for Component {
+    fn from(component: time_to_next_split::Component) -> Self {
+        Self::TimeToNextSplit(component)
+    }
+}
+
 impl From<timer::Component> for Component {
     fn from(component: timer::Component) -> Self {
         Self::Timer(component)
@@ -199,6 +223,9 @@ impl Component {
             (ComponentState::KeyValue(state), Component::SegmentTime(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::SegmentsRemaining(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::Separator(state), Component::Separator(component)) => {
                 component.update_state(state)
             }
@@ -208,9 +235,15 @@ impl Component {
             (ComponentState::KeyValue(state), Component::SumOfBest(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::SumOfBestDelta(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::Text(state), Component::Text(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::TimeToNextSplit(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::Timer(state), Component::Timer(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
@@ -261,12 +294,21 @@ impl Component {
                 ComponentState::KeyValue(component.state(timer, layout_settings))
             }
             Component::SegmentTime(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::SegmentsRemaining(component) => {
+                ComponentState::KeyValue(component.state(timer))
+            }
             Component::Separator(component) => ComponentState::Separator(component.state()),
             Component::Splits(component) => {
                 ComponentState::Splits(component.state(image_cache, timer, layout_settings))
             }
             Component::SumOfBest(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::SumOfBestDelta(component) => {
+                ComponentState::KeyValue(component.state(timer))
+            }
             Component::Text(component) => ComponentState::Text(component.state(timer)),
+            Component::TimeToNextSplit(component) => {
+                ComponentState::KeyValue(component.state(timer))
+            }
             Component::Timer(component) => {
                 ComponentState::Timer(component.state(timer, layout_settings))
             }
@@ -309,12 +351,21 @@ impl Component {
             Component::SegmentTime(component) => {
                 ComponentSettings::SegmentTime(component.settings().clone())
             }
+            Component::SegmentsRemaining(component) => {
+                ComponentSettings::SegmentsRemaining(component.settings().clone())
+            }
             Component::Separator(_) => ComponentSettings::Separator,
             Component::Splits(component) => ComponentSettings::Splits(component.settings().clone()),
             Component::SumOfBest(component) => {
                 ComponentSettings::SumOfBest(component.settings().clone())
             }
+            Component::SumOfBestDelta(component) => {
+                ComponentSettings::SumOfBestDelta(component.settings().clone())
+            }
             Component::Text(component) => ComponentSettings::Text(component.settings().clone()),
+            Component::TimeToNextSplit(component) => {
+                ComponentSettings::TimeToNextSplit(component.settings().clone())
+            }
             Component::Timer(component) => ComponentSettings::Timer(component.settings().clone()),
             Component::Title(component) => ComponentSettings::Title(component.settings().clone()),
             Component::TotalPlaytime(component) => {
@@ -336,10 +387,13 @@ impl Component {
             Component::PossibleTimeSave(component) => component.name(),
             Component::PreviousSegment(component) => component.name(),
             Component::SegmentTime(component) => component.name(),
+            Component::SegmentsRemaining(component) => component.name().into(),
             Component::Separator(component) => component.name().into(),
             Component::Splits(component) => component.name().into(),
             Component::SumOfBest(component) => component.name().into(),
+            Component::SumOfBestDelta(component) => component.name(),
             Component::Text(component) => component.name(),
+            Component::TimeToNextSplit(component) => component.name(),
             Component::Timer(component) => component.name().into(),
             Component::Title(component) => component.name().into(),
             Component::TotalPlaytime(component) => component.name().into(),
@@ -378,10 +432,13 @@ impl Component {
             Component::PossibleTimeSave(component) => component.settings_description(),
             Component::PreviousSegment(component) => component.settings_description(),
             Component::SegmentTime(component) => component.settings_description(),
+            Component::SegmentsRemaining(component) => component.settings_description(),
             Component::Separator(component) => component.settings_description(),
             Component::Splits(component) => component.settings_description(),
             Component::SumOfBest(component) => component.settings_description(),
+            Component::SumOfBestDelta(component) => component.settings_description(),
             Component::Text(component) => component.settings_description(),
+            Component::TimeToNextSplit(component) => component.settings_description(),
             Component::Timer(component) => component.settings_description(),
             Component::Title(component) => component.settings_description(),
             Component::TotalPlaytime(component) => component.settings_description(),
@@ -408,10 +465,13 @@ impl Component {
             Component::PossibleTimeSave(component) => component.set_value(index, value),
             Component::PreviousSegment(component) => component.set_value(index, value),
             Component::SegmentTime(component) => component.set_value(index, value),
+            Component::SegmentsRemaining(component) => component.set_value(index, value),
             Component::Separator(component) => component.set_value(index, value),
             Component::Splits(component) => component.set_value(index, value),
             Component::SumOfBest(component) => component.set_value(index, value),
+            Component::SumOfBestDelta(component) => component.set_value(index, value),
             Component::Text(component) => component.set_value(index, value),
+            Component::TimeToNextSplit(component) => component.set_value(index, value),
             Component::Timer(component) => component.set_value(index, value),
             Component::Title(component) => component.set_value(index, value),
             Component::TotalPlaytime(component) => component.set_value(index, value),
@@ -2,8 +2,8 @@ use super::Component;
 use crate::{
     component::{
         blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        possible_time_save, previous_segment, segment_time, segments_remaining, separator, splits,
+        sum_of_best, sum_of_best_delta, text, time_to_next_split, timer, title, total_playtime,
     },
     platform::prelude::*,
 };
@@ -32,14 +32,20 @@ pub enum ComponentSettings {
     PreviousSegment(previous_segment::Settings),
     /// The Settings for the Segment Time Component.
     SegmentTime(segment_time::Settings),
+    /// The Settings for the Segments Remaining Component.
+    SegmentsRemaining(segments_remaining::Settings),
     /// The Settings for the Separator Component.
     Separator,
     /// The Settings for the Splits Component.
     Splits(splits::Settings),
     /// The Settings for the Sum Of Best Component.
     SumOfBest(sum_of_best::Settings),
+    /// The Settings for the Sum of Best Delta Component.
+    SumOfBestDelta(sum_of_best_delta::Settings),
     /// The Settings for the Text Component.
     Text(text::Settings),
+    /// The Settings for the Time to Next Split Component.
+    TimeToNextSplit(time_to_next_split::Settings),
     /// The Settings for the Timer Component.
     Timer(timer::Settings),
     /// The Settings for the Title Component.
@@ -81,6 +87,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::SegmentTime(settings) => {
                 Component::SegmentTime(segment_time::Component::with_settings(settings))
             }
+            ComponentSettings::SegmentsRemaining(settings) => {
+                Component::SegmentsRemaining(segments_remaining::Component::with_settings(settings))
+            }
             ComponentSettings::Separator => Component::Separator(separator::Component::new()),
             ComponentSettings::Splits(settings) => {
                 Component::Splits(splits::Component::with_settings(settings))
@@ -88,9 +97,15 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::SumOfBest(settings) => {
                 Component::SumOfBest(sum_of_best::Component::with_settings(settings))
             }
+            ComponentSettings::SumOfBestDelta(settings) => {
+                Component::SumOfBestDelta(sum_of_best_delta::Component::with_settings(settings))
+            }
             ComponentSettings::Text(settings) => {
                 Component::Text(text::Component::with_settings(settings))
             }
+            ComponentSettings::TimeToNextSplit(settings) => {
+                Component::TimeToNextSplit(time_to_next_split::Component::with_settings(settings))
+            }
             ComponentSettings::Timer(settings) => {
                 Component::Timer(timer::Component::with_settings(settings))
             }
@@ -50,6 +50,12 @@ pub struct GeneralSettings {
     pub separators_color: Color,
     /// The text color to use for text that doesn't specify its own color.
     pub text_color: Color,
+    /// The color of the outline drawn around all the text to make it easier
+    /// to read on busy backgrounds. `None` means no outline is drawn.
+    pub text_outline_color: Option<Color>,
+    /// The width of the outline drawn around the text in percent of the size
+    /// of the text.
+    pub text_outline_width: u32,
 }
 
 impl Default for GeneralSettings {
@@ -73,6 +79,8 @@ impl Default for GeneralSettings {
             thin_separators_color: Color::hsla(0.0, 0.0, 1.0, 0.09),
             separators_color: Color::hsla(0.0, 0.0, 1.0, 0.35),
             text_color: Color::hsla(0.0, 0.0, 1.0, 1.0),
+            text_outline_color: None,
+            text_outline_width: 5,
         }
     }
 }
@@ -173,6 +181,18 @@ impl GeneralSettings {
                 "The color to use for text that doesn't specify its own color.".into(),
                 self.text_color.into(),
             ),
+            Field::new(
+                "Text Outline".into(),
+                "The color of the outline drawn around all the text. If this is not set, no outline is drawn."
+                    .into(),
+                self.text_outline_color.into(),
+            ),
+            Field::new(
+                "Text Outline Width".into(),
+                "The width of the outline drawn around the text in percent of the size of the text."
+                    .into(),
+                u64::from(self.text_outline_width).into(),
+            ),
         ])
     }
 
@@ -201,6 +221,10 @@ impl GeneralSettings {
             13 => self.thin_separators_color = value.into(),
             14 => self.separators_color = value.into(),
             15 => self.text_color = value.into(),
+            16 => self.text_outline_color = value.into(),
+            17 => {
+                self.text_outline_width = value.into_uint().unwrap().try_into().unwrap_or(u32::MAX)
+            }
             _ => panic!("Unsupported Setting Index"),
         }
     }
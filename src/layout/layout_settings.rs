@@ -1,17 +1,159 @@
 use super::{ComponentSettings, GeneralSettings};
 use crate::platform::prelude::*;
+use core::fmt;
+use serde::{
+    de::{
+        self, value::MapAccessDeserializer, DeserializeSeed, IgnoredAny, IntoDeserializer,
+        MapAccess, Visitor,
+    },
+    Deserialize as _, Deserializer,
+};
 use serde_derive::{Deserialize, Serialize};
 
 /// Describes a whole layout by its settings in a way that can easily be
 /// serialized and deserialized.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct LayoutSettings {
-    /// The settings for all the components.
+    /// The settings for all the components. When deserializing, components
+    /// that are unknown to this version of livesplit-core are skipped, so
+    /// layouts created by newer versions can still be loaded. Settings of known
+    /// components that can't be understood are still an error.
+    #[serde(deserialize_with = "deserialize_components")]
     pub components: Vec<ComponentSettings>,
     /// The general settings of the layout that apply to all components.
     pub general: GeneralSettings,
 }
 
+fn deserialize_components<'de, D>(deserializer: D) -> Result<Vec<ComponentSettings>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct MaybeKnown(Option<ComponentSettings>);
+
+    impl<'de> serde::Deserialize<'de> for MaybeKnown {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(MaybeKnownVisitor)
+        }
+    }
+
+    struct MaybeKnownVisitor;
+
+    impl<'de> Visitor<'de> for MaybeKnownVisitor {
+        type Value = MaybeKnown;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("the settings of a component")
+        }
+
+        fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let Some(name) = known_variant(name) else {
+                return Ok(MaybeKnown(None));
+            };
+            ComponentSettings::deserialize(name.into_deserializer()).map(|c| MaybeKnown(Some(c)))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let Some(name) = map.next_key::<String>()? else {
+                return Err(de::Error::invalid_length(0, &self));
+            };
+            let Some(name) = known_variant(&name) else {
+                map.next_value::<IgnoredAny>()?;
+                while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                return Ok(MaybeKnown(None));
+            };
+            ComponentSettings::deserialize(MapAccessDeserializer::new(ReplayKey {
+                key: Some(name),
+                map,
+            }))
+            .map(|c| MaybeKnown(Some(c)))
+        }
+    }
+
+    /// Gives the map back to the deserializer of the component settings with
+    /// the name of the component that was already read from it.
+    struct ReplayKey<A> {
+        key: Option<&'static str>,
+        map: A,
+    }
+
+    impl<'de, A: MapAccess<'de>> MapAccess<'de> for ReplayKey<A> {
+        type Error = A::Error;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            match self.key.take() {
+                Some(key) => seed.deserialize(key.into_deserializer()).map(Some),
+                None => self.map.next_key_seed(seed),
+            }
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            self.map.next_value_seed(seed)
+        }
+    }
+
+    let components = Vec::<MaybeKnown>::deserialize(deserializer)?;
+    Ok(components
+        .into_iter()
+        .filter_map(|component| component.0)
+        .collect())
+}
+
+/// Looks up the name of the component among the variants of
+/// [`ComponentSettings`]. The variants are queried from the deserialization
+/// of the settings themselves, so they can't get out of sync.
+fn known_variant(name: &str) -> Option<&'static str> {
+    struct Variants<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for Variants<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V>(self, _: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            Err(de::Error::custom("expected an enum"))
+        }
+
+        fn deserialize_enum<V>(
+            self,
+            _: &'static str,
+            variants: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            *self.0 = variants;
+            Err(de::Error::custom("only the variants are queried"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct identifier ignored_any
+        }
+    }
+
+    let mut variants: &'static [&'static str] = &[];
+    let _ = ComponentSettings::deserialize(Variants(&mut variants));
+    variants.iter().copied().find(|&variant| variant == name)
+}
+
 #[cfg(feature = "std")]
 impl LayoutSettings {
     /// Decodes the layout's settings from JSON.
@@ -1,3 +1,5 @@
+use core::mem;
+
 use serde_derive::{Deserialize, Serialize};
 
 use super::{ComponentState, LayoutDirection};
@@ -30,6 +32,44 @@ pub struct LayoutState {
     pub separators_color: Color,
     /// The text color to use for text that doesn't specify its own color.
     pub text_color: Color,
+    /// The color of the outline drawn around all the text. `None` means no
+    /// outline is drawn.
+    pub text_outline_color: Option<Color>,
+    /// The width of the outline drawn around the text in percent of the size
+    /// of the text.
+    pub text_outline_width: u32,
+    #[serde(skip)]
+    generation: u64,
+    #[serde(skip)]
+    previous_components: Vec<ComponentState>,
+}
+
+impl LayoutState {
+    /// Returns a counter that advances whenever updating the state changed
+    /// any of the information to visualize. This includes splits, changes to
+    /// the settings or the comparison, but also the time shown by the
+    /// components, at the accuracy they display it with. Frontends can
+    /// remember the generation they last rendered and skip rendering entirely
+    /// for as long as it stays the same.
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Keeps the component states around before they get updated, so they can
+    /// be compared with the updated states afterwards. The states from the
+    /// update before that are reused for the update, so no new states need to
+    /// be allocated.
+    pub(super) fn begin_update(&mut self) {
+        mem::swap(&mut self.components, &mut self.previous_components);
+    }
+
+    /// Advances the generation if any of the component states or the general
+    /// settings changed since the previous update.
+    pub(super) fn end_update(&mut self, settings_changed: bool) {
+        if settings_changed || self.components != self.previous_components || self.generation == 0 {
+            self.generation += 1;
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -11,6 +11,8 @@ mod layout_direction;
 mod layout_settings;
 mod layout_state;
 pub mod parser;
+#[cfg(test)]
+mod tests;
 
 pub use self::{
     component::Component, component_settings::ComponentSettings, component_state::ComponentState,
@@ -99,6 +101,7 @@ impl Layout {
     ) {
         let settings = &self.settings;
 
+        state.begin_update();
         state.components.truncate(self.components.len());
         let mut components = self.components.iter_mut();
         // First update all the states that we have.
@@ -110,15 +113,31 @@ impl Layout {
             .components
             .extend(components.map(|c| c.state(image_cache, timer, settings)));
 
+        let background = settings.background.cache(image_cache);
+        let settings_changed = state.timer_font != settings.timer_font
+            || state.times_font != settings.times_font
+            || state.text_font != settings.text_font
+            || state.background != background
+            || state.thin_separators_color != settings.thin_separators_color
+            || state.separators_color != settings.separators_color
+            || state.text_color != settings.text_color
+            || state.text_outline_color != settings.text_outline_color
+            || state.text_outline_width != settings.text_outline_width
+            || state.direction != settings.direction;
+
         state.timer_font.clone_from(&settings.timer_font);
         state.times_font.clone_from(&settings.times_font);
         state.text_font.clone_from(&settings.text_font);
 
-        state.background = settings.background.cache(image_cache);
+        state.background = background;
         state.thin_separators_color = settings.thin_separators_color;
         state.separators_color = settings.separators_color;
         state.text_color = settings.text_color;
+        state.text_outline_color = settings.text_outline_color;
+        state.text_outline_width = settings.text_outline_width;
         state.direction = settings.direction;
+
+        state.end_update(settings_changed);
     }
 
     /// Calculates the layout's state based on the timer provided. You can use
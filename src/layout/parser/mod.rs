@@ -676,10 +676,13 @@ where
                         Component::PossibleTimeSave(c) => possible_time_save::settings(reader, c),
                         Component::PreviousSegment(c) => previous_segment::settings(reader, c),
                         Component::SegmentTime(_) => end_tag(reader),
+                        Component::SegmentsRemaining(_) => end_tag(reader),
                         Component::Separator(_) => end_tag(reader),
                         Component::Splits(c) => splits::settings(reader, c),
                         Component::SumOfBest(c) => sum_of_best::settings(reader, c),
+                        Component::SumOfBestDelta(_) => end_tag(reader),
                         Component::Text(c) => text::settings(reader, c),
+                        Component::TimeToNextSplit(_) => end_tag(reader),
                         Component::Timer(c) => timer::settings(reader, c),
                         Component::Title(c) => title::settings(reader, c),
                         Component::TotalPlaytime(c) => total_playtime::settings(reader, c),
@@ -0,0 +1,168 @@
+use super::{Component, GeneralSettings, Layout, LayoutDirection, LayoutSettings};
+use crate::{
+    component::timer,
+    settings::{Color, ImageCache},
+    timing::formatter::Accuracy,
+    util::tests_helper::{create_timer, start_run},
+    TimeSpan,
+};
+
+fn layout_with_timer() -> Layout {
+    let mut layout = Layout::new();
+    layout.push(timer::Component::with_settings(timer::Settings {
+        accuracy: Accuracy::Tenths,
+        ..Default::default()
+    }));
+    layout
+}
+
+#[test]
+fn generation_advances_on_split() {
+    let mut layout = layout_with_timer();
+    let mut image_cache = ImageCache::new();
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+
+    let mut state = layout.state(&mut image_cache, &timer.snapshot());
+    let before = state.generation();
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    timer.split().unwrap();
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+
+    assert!(state.generation() > before);
+}
+
+#[test]
+fn generation_advances_on_setting_change() {
+    let mut layout = layout_with_timer();
+    let mut image_cache = ImageCache::new();
+    let mut timer = create_timer(&["A"]);
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(1.23)).unwrap();
+
+    let mut state = layout.state(&mut image_cache, &timer.snapshot());
+    let before = state.generation();
+
+    let Component::Timer(component) = &mut layout.components[0] else {
+        unreachable!()
+    };
+    component.settings_mut().accuracy = Accuracy::Hundredths;
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+
+    assert!(state.generation() > before);
+}
+
+#[test]
+fn generation_stays_for_identical_ticks() {
+    let mut layout = layout_with_timer();
+    let mut image_cache = ImageCache::new();
+    let mut timer = create_timer(&["A"]);
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(1.21)).unwrap();
+    let mut state = layout.state(&mut image_cache, &timer.snapshot());
+    let before = state.generation();
+
+    // Both of these are shown as 1.2 with an accuracy of a single digit.
+    timer.set_game_time(TimeSpan::from_seconds(1.24)).unwrap();
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    assert_eq!(state.generation(), before);
+
+    timer.set_game_time(TimeSpan::from_seconds(1.31)).unwrap();
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    assert!(state.generation() > before);
+}
+
+#[test]
+fn settings_round_trip_through_json() {
+    let mut layout = Layout::default_layout();
+    layout.general_settings_mut().direction = LayoutDirection::Horizontal;
+
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    let parsed = Layout::from_settings(LayoutSettings::from_json(&*buf).unwrap());
+
+    assert_eq!(parsed.components.len(), layout.components.len());
+    for (parsed, original) in parsed.components.iter().zip(&layout.components) {
+        assert_eq!(parsed.name(), original.name());
+    }
+    assert!(parsed.general_settings().direction == LayoutDirection::Horizontal);
+}
+
+#[test]
+fn unknown_components_and_settings_are_skipped() {
+    let json = r#"{
+        "components": [
+            { "Title": { "show_game_name": false, "some_future_setting": 5 } },
+            { "SomeFutureComponent": { "foo": [1, 2, 3] } },
+            "SomeFutureUnitComponent",
+            "Separator",
+            { "Timer": {} }
+        ],
+        "general": { "some_future_general_setting": true }
+    }"#;
+
+    let layout = Layout::from_settings(LayoutSettings::from_json(json.as_bytes()).unwrap());
+
+    let names: Vec<_> = layout.components.iter().map(|c| c.name()).collect();
+    assert_eq!(names, ["Title", "Separator", "Timer"]);
+    let Component::Title(title) = &layout.components[0] else {
+        unreachable!()
+    };
+    assert!(!title.settings().show_game_name);
+}
+
+#[test]
+fn invalid_settings_of_known_components_are_an_error() {
+    let json = r#"{
+        "components": [
+            { "SomeFutureComponent": {} },
+            { "Title": { "show_game_name": "not a bool" } }
+        ],
+        "general": {}
+    }"#;
+
+    assert!(LayoutSettings::from_json(json.as_bytes()).is_err());
+}
+
+#[test]
+fn text_outline_round_trips_through_json() {
+    let mut layout = Layout::default_layout();
+    let general = layout.general_settings_mut();
+    general.text_outline_color = Some(Color::rgba(0.0, 0.0, 0.0, 0.5));
+    general.text_outline_width = 8;
+
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    let parsed = Layout::from_settings(LayoutSettings::from_json(&*buf).unwrap());
+
+    assert_eq!(
+        parsed.general_settings().text_outline_color,
+        Some(Color::rgba(0.0, 0.0, 0.0, 0.5)),
+    );
+    assert_eq!(parsed.general_settings().text_outline_width, 8);
+}
+
+#[test]
+fn text_outline_width_is_clamped() {
+    let mut general = GeneralSettings::default();
+    let image_cache = ImageCache::new();
+    general.set_value(17, u64::MAX.into(), &image_cache);
+    assert_eq!(general.text_outline_width, u32::MAX);
+
+    general.set_value(17, 8u64.into(), &image_cache);
+    assert_eq!(general.text_outline_width, 8);
+}
+
+#[test]
+fn old_layouts_have_no_text_outline() {
+    let json = r#"{
+        "components": [{ "Timer": {} }],
+        "general": { "direction": "Vertical", "text_color": [1.0, 1.0, 1.0, 1.0] }
+    }"#;
+
+    let layout = Layout::from_settings(LayoutSettings::from_json(json.as_bytes()).unwrap());
+
+    assert_eq!(layout.general_settings().text_outline_color, None);
+}
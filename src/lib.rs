@@ -72,6 +72,7 @@ mod hotkey_config;
 #[cfg(feature = "std")]
 mod hotkey_system;
 pub mod layout;
+pub mod localization;
 pub mod networking;
 #[cfg(feature = "rendering")]
 pub mod rendering;
@@ -0,0 +1,263 @@
+//! The localization module provides the building blocks for presenting text
+//! in different languages.
+
+use core::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// A language that text can be localized to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Lang {
+    /// English
+    #[default]
+    English,
+    /// Dutch
+    Dutch,
+    /// French
+    French,
+    /// German
+    German,
+    /// Italian
+    Italian,
+    /// Polish
+    Polish,
+    /// Portuguese
+    Portuguese,
+    /// Russian
+    Russian,
+    /// Spanish
+    Spanish,
+    /// Chinese
+    Chinese,
+    /// Japanese
+    Japanese,
+    /// Korean
+    Korean,
+    /// Turkish
+    Turkish,
+}
+
+const LANGUAGE_CODES: [(&str, Lang); 13] = [
+    ("en", Lang::English),
+    ("nl", Lang::Dutch),
+    ("fr", Lang::French),
+    ("de", Lang::German),
+    ("it", Lang::Italian),
+    ("pl", Lang::Polish),
+    ("pt", Lang::Portuguese),
+    ("ru", Lang::Russian),
+    ("es", Lang::Spanish),
+    ("zh", Lang::Chinese),
+    ("ja", Lang::Japanese),
+    ("ko", Lang::Korean),
+    ("tr", Lang::Turkish),
+];
+
+/// The plural category a count falls into for a specific [`Lang`]. Each
+/// language uses a subset of these categories and chooses the grammatical form
+/// of a count-bearing text based on them. The categories follow the [Unicode
+/// CLDR plural
+/// rules](https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html)
+/// for integer counts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    /// The singular form, like "1 attempt".
+    One,
+    /// The form used for small counts in languages such as Polish or Russian,
+    /// like "2 próby".
+    Few,
+    /// The form used for larger counts in languages such as Polish or Russian,
+    /// like "5 prób".
+    Many,
+    /// The general form, like "2 attempts". Languages without any plural forms
+    /// only use this category.
+    Other,
+}
+
+impl Lang {
+    /// Parses a locale such as `en-US` or `tr_TR` and returns the language it
+    /// refers to. Only the primary language subtag is considered, so regional
+    /// variants resolve to the same language. Returns [`None`] if the language
+    /// is not supported.
+    pub fn parse_locale(locale: &str) -> Option<Self> {
+        let language = locale.split(['-', '_']).next()?;
+        LANGUAGE_CODES
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(language))
+            .map(|&(_, lang)| lang)
+    }
+
+    /// Returns the character used to separate groups of digits, like the
+    /// thousands, in large numbers.
+    pub const fn grouping_separator(self) -> char {
+        match self {
+            Lang::English | Lang::Chinese | Lang::Japanese | Lang::Korean => ',',
+            Lang::Dutch
+            | Lang::German
+            | Lang::Italian
+            | Lang::Portuguese
+            | Lang::Spanish
+            | Lang::Turkish => '.',
+            Lang::French => '\u{202F}',
+            Lang::Polish | Lang::Russian => '\u{A0}',
+        }
+    }
+
+    /// Returns how many digits there need to be in front of the first
+    /// grouping separator for the digits to be grouped at all. Some languages
+    /// don't group the digits of four digit numbers.
+    const fn minimum_grouping_digits(self) -> usize {
+        match self {
+            Lang::Polish | Lang::Spanish => 2,
+            _ => 1,
+        }
+    }
+
+    /// Determines the plural category of the count in this language.
+    pub const fn plural_category(self, count: u64) -> PluralCategory {
+        match self {
+            Lang::English
+            | Lang::Dutch
+            | Lang::German
+            | Lang::Italian
+            | Lang::Spanish
+            | Lang::Turkish => {
+                if count == 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            Lang::French | Lang::Portuguese => {
+                if count <= 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            Lang::Polish => {
+                if count == 1 {
+                    PluralCategory::One
+                } else if is_slavic_few(count) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            Lang::Russian => {
+                if count % 10 == 1 && count % 100 != 11 {
+                    PluralCategory::One
+                } else if is_slavic_few(count) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            Lang::Chinese | Lang::Japanese | Lang::Korean => PluralCategory::Other,
+        }
+    }
+}
+
+/// The plural forms of a count-bearing text. The form to use is selected based
+/// on the [`PluralCategory`] of the count. Forms for categories that the
+/// language doesn't use can be left empty. If the form for the category is
+/// empty, the `other` form is used instead.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PluralForms<'a> {
+    /// The form used for the [`One`](PluralCategory::One) category.
+    pub one: &'a str,
+    /// The form used for the [`Few`](PluralCategory::Few) category.
+    pub few: &'a str,
+    /// The form used for the [`Many`](PluralCategory::Many) category.
+    pub many: &'a str,
+    /// The form used for the [`Other`](PluralCategory::Other) category and as
+    /// the fallback for any missing form.
+    pub other: &'a str,
+}
+
+impl<'a> PluralForms<'a> {
+    /// Selects the form to use for the count in the language provided.
+    pub const fn select(&self, lang: Lang, count: u64) -> &'a str {
+        let form = match lang.plural_category(count) {
+            PluralCategory::One => self.one,
+            PluralCategory::Few => self.few,
+            PluralCategory::Many => self.many,
+            PluralCategory::Other => self.other,
+        };
+        if form.is_empty() {
+            self.other
+        } else {
+            form
+        }
+    }
+}
+
+const fn is_slavic_few(count: u64) -> bool {
+    matches!(count % 10, 2..=4) && !matches!(count % 100, 12..=14)
+}
+
+/// Localizes the integer for the language provided by grouping its digits
+/// with the language's [grouping separator](Lang::grouping_separator). The
+/// returned value implements [`Display`](fmt::Display) and respects the width,
+/// fill, alignment and `+` flag of the format string, so `{:>8}` right aligns
+/// the localized integer to a width of 8 characters.
+pub const fn localize_integer(lang: Lang, value: i64) -> IntegerDisplay {
+    IntegerDisplay { lang, value }
+}
+
+/// An integer localized for a specific [`Lang`]. You can create one with
+/// [`localize_integer`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntegerDisplay {
+    lang: Lang,
+    value: i64,
+}
+
+impl fmt::Display for IntegerDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = [0; 4];
+        let separator = self
+            .lang
+            .grouping_separator()
+            .encode_utf8(&mut separator)
+            .as_bytes();
+
+        // The integer is written from the back of the buffer. There's enough
+        // room for the sign, the 19 digits of the largest value and the 6
+        // separators in between them.
+        let mut buf = [0; 48];
+        let mut start = buf.len();
+
+        let mut value = self.value.unsigned_abs();
+        let is_grouped = value >= 10u64.pow(2 + self.lang.minimum_grouping_digits() as u32);
+        let mut digits = 0;
+        loop {
+            if is_grouped && digits != 0 && digits % 3 == 0 {
+                start -= separator.len();
+                buf[start..][..separator.len()].copy_from_slice(separator);
+            }
+            start -= 1;
+            buf[start] = b'0' + (value % 10) as u8;
+            digits += 1;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+
+        if self.value < 0 {
+            start -= 1;
+            buf[start] = b'-';
+        } else if f.sign_plus() {
+            start -= 1;
+            buf[start] = b'+';
+        }
+
+        // The buffer only consists of ASCII characters and the separator, so
+        // this always succeeds. The padding is based on the amount of
+        // characters, so separators that take up multiple bytes don't affect
+        // the width.
+        f.pad(core::str::from_utf8(&buf[start..]).map_err(|_| fmt::Error)?)
+    }
+}
@@ -0,0 +1,155 @@
+use super::{localize_integer, Lang, PluralCategory, PluralForms};
+
+const ATTEMPTS_EN: PluralForms<'static> = PluralForms {
+    one: "attempt",
+    few: "",
+    many: "",
+    other: "attempts",
+};
+
+const ATTEMPTS_PL: PluralForms<'static> = PluralForms {
+    one: "próba",
+    few: "próby",
+    many: "prób",
+    other: "próby",
+};
+
+const ATTEMPTS_RU: PluralForms<'static> = PluralForms {
+    one: "попытка",
+    few: "попытки",
+    many: "попыток",
+    other: "попытки",
+};
+
+#[test]
+fn english() {
+    assert_eq!(Lang::English.plural_category(0), PluralCategory::Other);
+    assert_eq!(Lang::English.plural_category(1), PluralCategory::One);
+    assert_eq!(Lang::English.plural_category(2), PluralCategory::Other);
+    assert_eq!(Lang::English.plural_category(5), PluralCategory::Other);
+    assert_eq!(Lang::English.plural_category(21), PluralCategory::Other);
+
+    assert_eq!(ATTEMPTS_EN.select(Lang::English, 1), "attempt");
+    assert_eq!(ATTEMPTS_EN.select(Lang::English, 2), "attempts");
+    assert_eq!(ATTEMPTS_EN.select(Lang::English, 5), "attempts");
+    assert_eq!(ATTEMPTS_EN.select(Lang::English, 21), "attempts");
+}
+
+#[test]
+fn turkish() {
+    let turkish = Lang::parse_locale("tr-TR").unwrap();
+    assert_eq!(turkish, Lang::Turkish);
+    assert_eq!(Lang::parse_locale("tr"), Some(Lang::Turkish));
+
+    assert_eq!(turkish.plural_category(0), PluralCategory::Other);
+    assert_eq!(turkish.plural_category(1), PluralCategory::One);
+    assert_eq!(turkish.plural_category(2), PluralCategory::Other);
+    assert_eq!(ATTEMPTS_EN.select(turkish, 1), "attempt");
+    assert_eq!(ATTEMPTS_EN.select(turkish, 5), "attempts");
+
+    let count = localize_integer(turkish, 1234567).to_string();
+    assert_eq!(count, "1.234.567");
+    assert_ne!(count, localize_integer(Lang::English, 1234567).to_string());
+}
+
+#[test]
+fn parse_locale() {
+    assert_eq!(Lang::parse_locale("en-US"), Some(Lang::English));
+    assert_eq!(Lang::parse_locale("pt_BR"), Some(Lang::Portuguese));
+    assert_eq!(Lang::parse_locale("DE"), Some(Lang::German));
+    assert_eq!(Lang::parse_locale("zh-Hans-CN"), Some(Lang::Chinese));
+    assert_eq!(Lang::parse_locale("xx"), None);
+    assert_eq!(Lang::parse_locale(""), None);
+}
+
+#[test]
+fn polish() {
+    assert_eq!(ATTEMPTS_PL.select(Lang::Polish, 1), "próba");
+    assert_eq!(ATTEMPTS_PL.select(Lang::Polish, 2), "próby");
+    assert_eq!(ATTEMPTS_PL.select(Lang::Polish, 5), "prób");
+    assert_eq!(ATTEMPTS_PL.select(Lang::Polish, 12), "prób");
+    assert_eq!(ATTEMPTS_PL.select(Lang::Polish, 21), "prób");
+    assert_eq!(ATTEMPTS_PL.select(Lang::Polish, 22), "próby");
+}
+
+#[test]
+fn russian() {
+    assert_eq!(ATTEMPTS_RU.select(Lang::Russian, 1), "попытка");
+    assert_eq!(ATTEMPTS_RU.select(Lang::Russian, 2), "попытки");
+    assert_eq!(ATTEMPTS_RU.select(Lang::Russian, 5), "попыток");
+    assert_eq!(ATTEMPTS_RU.select(Lang::Russian, 11), "попыток");
+    assert_eq!(ATTEMPTS_RU.select(Lang::Russian, 21), "попытка");
+    assert_eq!(ATTEMPTS_RU.select(Lang::Russian, 111), "попыток");
+}
+
+#[test]
+fn missing_forms_fall_back_to_other() {
+    assert_eq!(ATTEMPTS_EN.select(Lang::Russian, 5), "attempts");
+    assert_eq!(ATTEMPTS_EN.select(Lang::Japanese, 1), "attempts");
+}
+
+#[test]
+fn integers_are_grouped() {
+    let localize = |lang, value| localize_integer(lang, value).to_string();
+
+    assert_eq!(localize(Lang::English, 0), "0");
+    assert_eq!(localize(Lang::English, 999), "999");
+    assert_eq!(localize(Lang::English, 1000), "1,000");
+    assert_eq!(localize(Lang::English, 1234567), "1,234,567");
+    assert_eq!(localize(Lang::English, -1234567), "-1,234,567");
+
+    assert_eq!(localize(Lang::German, 1000), "1.000");
+    assert_eq!(localize(Lang::German, 1234567), "1.234.567");
+    assert_eq!(localize(Lang::German, -1234567), "-1.234.567");
+
+    assert_eq!(localize(Lang::French, 1000), "1\u{202F}000");
+    assert_eq!(localize(Lang::French, 1234567), "1\u{202F}234\u{202F}567");
+    assert_eq!(localize(Lang::French, -1234567), "-1\u{202F}234\u{202F}567");
+
+    assert_eq!(localize(Lang::Russian, 1234567), "1\u{A0}234\u{A0}567");
+
+    assert_eq!(
+        localize(Lang::English, i64::MIN),
+        "-9,223,372,036,854,775,808"
+    );
+}
+
+#[test]
+fn four_digit_integers_are_not_grouped_in_some_languages() {
+    assert_eq!(localize_integer(Lang::Spanish, 1000).to_string(), "1000");
+    assert_eq!(localize_integer(Lang::Spanish, 10000).to_string(), "10.000");
+    assert_eq!(localize_integer(Lang::Polish, -1000).to_string(), "-1000");
+    assert_eq!(
+        localize_integer(Lang::Polish, 10000).to_string(),
+        "10\u{A0}000",
+    );
+}
+
+#[test]
+fn integers_respect_the_formatting_options() {
+    assert_eq!(
+        format!("{:>8}", localize_integer(Lang::English, 1000)),
+        "   1,000"
+    );
+    assert_eq!(
+        format!("{:<8}|", localize_integer(Lang::German, -1000)),
+        "-1.000  |"
+    );
+    assert_eq!(
+        format!("{:*^9}", localize_integer(Lang::English, 1000)),
+        "**1,000**"
+    );
+    assert_eq!(
+        format!("{:+}", localize_integer(Lang::English, 1234567)),
+        "+1,234,567"
+    );
+    assert_eq!(format!("{:+}", localize_integer(Lang::English, -5)), "-5");
+    assert_eq!(
+        format!("{:>10}", localize_integer(Lang::French, 1234567)),
+        " 1\u{202F}234\u{202F}567",
+    );
+    assert_eq!(
+        format!("{:2}", localize_integer(Lang::English, 1000)),
+        "1,000"
+    );
+}
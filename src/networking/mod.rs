@@ -3,6 +3,8 @@
 //! and Speedrun.com to query and submit to the leaderboards of most games. The
 //! module is optional and is not compiled in by default.
 
+#[cfg(feature = "std")]
+pub mod server;
 #[cfg(feature = "std")]
 pub mod server_protocol;
 #[cfg(feature = "networking")]
@@ -0,0 +1,343 @@
+//! The server module implements the line based TCP protocol of the original
+//! LiveSplit Server component. Clients connect to the server and send one
+//! command per line, such as `starttimer` or `split`. Commands that carry an
+//! argument separate it from the command by a single space, like
+//! `setgametime 1:23:45`.
+//!
+//! The following commands control the timer:
+//!
+//! `starttimer`, `startorsplit`, `split`, `unsplit`, `skipsplit`, `pause`,
+//! `resume`, `reset`, `initgametime`, `setgametime <time>`,
+//! `setloadingtimes <time>`, `pausegametime`, `unpausegametime`,
+//! `setcomparison <name>` and `switchto <realtime|gametime>`.
+//!
+//! The following commands query information from the timer and are answered
+//! with a single line:
+//!
+//! `getcurrenttime`, `getsplitindex`, `getcurrentsplitname`,
+//! `getprevioussplitname`, `getlastsplittime`, `getcomparisonsplittime`,
+//! `getcurrenttimerphase` and `ping`.
+//!
+//! Times that are not available are answered with `-`. Unknown commands are
+//! ignored.
+
+use alloc::borrow::Cow;
+use core::{
+    future::Future,
+    pin::pin,
+    str,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{
+        IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs,
+    },
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::Wake,
+    thread,
+};
+
+use super::server_protocol::format_time;
+use crate::{
+    event::{self, CommandSink, TimerQuery},
+    TimeSpan, TimerPhase, TimingMethod,
+};
+
+/// The port the original LiveSplit Server component listens on by default.
+pub const DEFAULT_PORT: u16 = 16834;
+
+/// The maximum length of a line in bytes. Connections sending longer lines are
+/// closed.
+const MAX_LINE_LENGTH: u64 = 4096;
+
+/// The maximum number of connections that are handled at the same time.
+/// Connections beyond that are closed right away.
+pub const MAX_CONNECTIONS: usize = 16;
+
+/// A server that accepts connections speaking the LiveSplit Server protocol
+/// and forwards their commands to a command sink.
+pub struct Server<S> {
+    listener: TcpListener,
+    command_sink: S,
+    shared: Arc<Shared>,
+}
+
+/// A handle that stops a running [`Server`]. It can be sent to and used from
+/// other threads.
+#[derive(Clone)]
+pub struct StopHandle {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    stopped: AtomicBool,
+    connections: Mutex<Connections>,
+}
+
+#[derive(Default)]
+struct Connections {
+    next_id: usize,
+    streams: Vec<(usize, TcpStream)>,
+}
+
+impl<S: CommandSink + TimerQuery + Clone + Send + 'static> Server<S> {
+    /// Binds the server to the address provided. Use [`DEFAULT_PORT`] to be
+    /// compatible with clients that expect the original LiveSplit Server.
+    pub fn bind(addr: impl ToSocketAddrs, command_sink: S) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            command_sink,
+            shared: Arc::new(Shared {
+                stopped: AtomicBool::new(false),
+                connections: Mutex::new(Connections::default()),
+            }),
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns a handle that stops the server once it is running.
+    pub fn stop_handle(&self) -> io::Result<StopHandle> {
+        Ok(StopHandle {
+            addr: self.local_addr()?,
+            shared: self.shared.clone(),
+        })
+    }
+
+    /// Accepts connections until the server is stopped through its
+    /// [`StopHandle`]. Each connection is handled on its own thread. At most
+    /// [`MAX_CONNECTIONS`] connections are handled at the same time, any
+    /// further connections are closed right away. Connections that fail to be
+    /// accepted are skipped.
+    pub fn run(self) {
+        for stream in self.listener.incoming() {
+            if self.shared.stopped.load(Ordering::Acquire) {
+                break;
+            }
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(_e) => {
+                    #[cfg(feature = "log")]
+                    log::warn!("Failed to accept a connection: {_e}");
+                    continue;
+                }
+            };
+            let Some(id) = self.shared.add_connection(&stream) else {
+                #[cfg(feature = "log")]
+                log::warn!("Closing a connection, as there are too many connections.");
+                continue;
+            };
+            let command_sink = self.command_sink.clone();
+            let shared = self.shared.clone();
+            thread::spawn(move || {
+                let _ = handle_connection(stream, &command_sink);
+                shared.remove_connection(id);
+            });
+        }
+    }
+}
+
+impl StopHandle {
+    /// Stops the server. It doesn't accept any new connections and all the
+    /// connections it is currently handling get closed.
+    pub fn stop(&self) {
+        self.shared.stopped.store(true, Ordering::Release);
+
+        for (_, stream) in self.shared.connections.lock().unwrap().streams.drain(..) {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+
+        // The server is blocked waiting for the next connection, so it needs a
+        // connection of its own to notice that it got stopped.
+        let mut addr = self.addr;
+        if addr.ip().is_unspecified() {
+            addr.set_ip(match addr.ip() {
+                IpAddr::V4(_) => Ipv4Addr::LOCALHOST.into(),
+                IpAddr::V6(_) => Ipv6Addr::LOCALHOST.into(),
+            });
+        }
+        let _ = TcpStream::connect(addr);
+    }
+}
+
+impl Shared {
+    /// Registers the connection, so it can be closed when the server is
+    /// stopped. Returns `None` if the connection should not be handled, either
+    /// because the server is stopped or because there are too many
+    /// connections.
+    fn add_connection(&self, stream: &TcpStream) -> Option<usize> {
+        let mut connections = self.connections.lock().unwrap();
+        if self.stopped.load(Ordering::Acquire) || connections.streams.len() >= MAX_CONNECTIONS {
+            return None;
+        }
+        let stream = stream.try_clone().ok()?;
+        let id = connections.next_id;
+        connections.next_id = id.wrapping_add(1);
+        connections.streams.push((id, stream));
+        Some(id)
+    }
+
+    fn remove_connection(&self, id: usize) {
+        self.connections
+            .lock()
+            .unwrap()
+            .streams
+            .retain(|&(other, _)| other != id);
+    }
+}
+
+fn handle_connection<S: CommandSink + TimerQuery>(
+    stream: TcpStream,
+    command_sink: &S,
+) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        (&mut reader)
+            .take(MAX_LINE_LENGTH)
+            .read_until(b'\n', &mut line)?;
+        if line.is_empty() {
+            return Ok(());
+        }
+        if line.len() as u64 == MAX_LINE_LENGTH && !line.ends_with(b"\n") {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The line is too long.",
+            ));
+        }
+        let line =
+            str::from_utf8(&line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if let Some(response) = handle_line(line, command_sink) {
+            writer.write_all(response.as_bytes())?;
+            writer.write_all(b"\r\n")?;
+        }
+    }
+}
+
+/// Handles a single line of the protocol. If the command is a query, the
+/// response to be sent back is returned.
+pub fn handle_line<S: CommandSink + TimerQuery>(line: &str, command_sink: &S) -> Option<String> {
+    let line = line.trim();
+    let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+
+    match command {
+        "starttimer" => execute(command_sink.start()),
+        "startorsplit" => execute(command_sink.split_or_start()),
+        "split" => execute(command_sink.split()),
+        "unsplit" => execute(command_sink.undo_split()),
+        "skipsplit" => execute(command_sink.skip_split()),
+        "pause" => execute(command_sink.pause()),
+        "resume" => execute(command_sink.resume()),
+        "reset" => execute(command_sink.reset(None)),
+        "initgametime" => execute(command_sink.initialize_game_time()),
+        "setgametime" => {
+            if let Ok(time) = argument.parse::<TimeSpan>() {
+                execute(command_sink.set_game_time(time));
+            }
+        }
+        "setloadingtimes" => {
+            if let Ok(time) = argument.parse::<TimeSpan>() {
+                execute(command_sink.set_loading_times(time));
+            }
+        }
+        "pausegametime" => execute(command_sink.pause_game_time()),
+        "unpausegametime" => execute(command_sink.resume_game_time()),
+        "setcomparison" => execute(command_sink.set_current_comparison(Cow::Borrowed(argument))),
+        "switchto" => match argument {
+            "realtime" => execute(command_sink.set_current_timing_method(TimingMethod::RealTime)),
+            "gametime" => execute(command_sink.set_current_timing_method(TimingMethod::GameTime)),
+            _ => {}
+        },
+
+        "getcurrenttime" => {
+            let timer = command_sink.get_timer();
+            let time = timer.snapshot().current_time()[timer.current_timing_method()];
+            return Some(format_time(time));
+        }
+        "getsplitindex" => {
+            let timer = command_sink.get_timer();
+            let index = timer
+                .current_split_index()
+                .map_or(-1, |index| index as isize);
+            return Some(index.to_string());
+        }
+        "getcurrentsplitname" => {
+            let timer = command_sink.get_timer();
+            let name = timer.current_split().map_or("-", |segment| segment.name());
+            return Some(name.into());
+        }
+        "getprevioussplitname" => {
+            let timer = command_sink.get_timer();
+            let name = previous_split_index(timer.current_split_index())
+                .map_or("-", |index| timer.run().segment(index).name());
+            return Some(name.into());
+        }
+        "getlastsplittime" => {
+            let timer = command_sink.get_timer();
+            let time = previous_split_index(timer.current_split_index()).and_then(|index| {
+                timer.run().segment(index).split_time()[timer.current_timing_method()]
+            });
+            return Some(format_time(time));
+        }
+        "getcomparisonsplittime" => {
+            let timer = command_sink.get_timer();
+            let time = timer.current_split().and_then(|segment| {
+                segment.comparison(timer.current_comparison())[timer.current_timing_method()]
+            });
+            return Some(format_time(time));
+        }
+        "getcurrenttimerphase" => {
+            let phase = match command_sink.get_timer().current_phase() {
+                TimerPhase::NotRunning => "NotRunning",
+                TimerPhase::Running => "Running",
+                TimerPhase::Paused => "Paused",
+                TimerPhase::Ended => "Ended",
+            };
+            return Some(phase.into());
+        }
+        "ping" => return Some("pong".into()),
+        _ => {}
+    }
+
+    None
+}
+
+/// Waits for the command to be processed by the command sink. The protocol
+/// doesn't report whether a command succeeded, so the result is ignored.
+fn execute(command: impl Future<Output = event::Result>) {
+    let _ = block_on(command);
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn previous_split_index(current_split_index: Option<usize>) -> Option<usize> {
+    current_split_index?.checked_sub(1)
+}
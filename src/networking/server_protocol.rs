@@ -547,7 +547,7 @@ fn resolve_index(timer: &Timer, index: Option<isize>, relative: bool) -> Result<
     }
 }
 
-fn format_time(time: TimeSpan) -> String {
+pub(super) fn format_time(time: impl Into<Option<TimeSpan>>) -> String {
     // FIXME: I don't think we can parse it again if days are included. Let's not
     // use this formatter.
     formatter::none_wrapper::NoneWrapper::new(formatter::Complete::new(), ASCII_MINUS)
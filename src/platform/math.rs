@@ -10,10 +10,33 @@ cfg_if::cfg_if! {
             pub fn powf(x: f32, y: f32) -> f32 {
                 x.powf(y)
             }
+
+            #[cfg(feature = "rendering")]
+            #[inline(always)]
+            pub fn sin_cos(x: f32) -> (f32, f32) {
+                x.sin_cos()
+            }
+        }
+
+        pub mod f64 {
+            #[inline(always)]
+            pub fn sqrt(x: f64) -> f64 {
+                x.sqrt()
+            }
         }
     } else {
         pub mod f32 {
             pub use libm::{fabsf as abs, powf};
+
+            #[cfg(feature = "rendering")]
+            #[inline(always)]
+            pub fn sin_cos(x: f32) -> (f32, f32) {
+                libm::sincosf(x)
+            }
+        }
+
+        pub mod f64 {
+            pub use libm::sqrt;
         }
     }
 }
@@ -1,7 +1,10 @@
 use crate::layout::{ComponentState, LayoutState};
 
 use super::{
-    consts::{DEFAULT_COMPONENT_HEIGHT, PSEUDO_PIXELS, SEPARATOR_THICKNESS, TWO_ROW_HEIGHT},
+    consts::{
+        COMPARISON_LINE_HEIGHT, DEFAULT_COMPONENT_HEIGHT, PSEUDO_PIXELS, SEPARATOR_THICKNESS,
+        TWO_ROW_HEIGHT,
+    },
     resource::ResourceAllocator,
     RenderContext,
 };
@@ -55,6 +58,13 @@ impl<L> Cache<L> {
         }
     }
 
+    pub const fn is_animating(&self) -> bool {
+        match self {
+            Self::Splits(cache) => cache.is_animating(),
+            _ => false,
+        }
+    }
+
     fn make_empty(&mut self) {
         *self = Self::Empty;
     }
@@ -113,11 +123,15 @@ pub fn height(component: &ComponentState) -> f32 {
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
             state.splits.len() as f32
-                * if state.display_two_rows {
+                * (if state.display_two_rows {
                     TWO_ROW_HEIGHT
                 } else {
                     DEFAULT_COMPONENT_HEIGHT
-                }
+                } + if splits::has_comparison_lines(state) {
+                    COMPARISON_LINE_HEIGHT
+                } else {
+                    0.0
+                })
                 + if state.column_labels.is_some() {
                     DEFAULT_COMPONENT_HEIGHT
                 } else {
@@ -1,18 +1,21 @@
+use core::mem;
+
 use crate::{
-    component::splits::State,
+    component::splits::{SplitState, State},
     layout::{LayoutDirection, LayoutState},
     platform::prelude::*,
     rendering::{
         consts::{
-            vertical_padding, BOTH_PADDINGS, DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING,
+            vertical_padding, BOTH_PADDINGS, COMPARISON_LINE_HEIGHT, DEFAULT_COMPONENT_HEIGHT,
+            DEFAULT_TEXT_SIZE, PADDING, SMALL_TEXT_ALIGN_BOTTOM, SMALL_TEXT_SIZE,
             TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP, THIN_SEPARATOR_THICKNESS, TWO_ROW_HEIGHT,
         },
         font::CachedLabel,
         resource::ResourceAllocator,
         scene::Layer,
-        solid, RenderContext,
+        solid, RenderContext, SplitAnimation, SplitAnimationKind,
     },
-    settings::{Gradient, ListGradient},
+    settings::{Color, Gradient, ListGradient},
 };
 
 pub struct Cache<L> {
@@ -20,6 +23,44 @@ pub struct Cache<L> {
     column_labels: Vec<CachedLabel<L>>,
     column_width_labels: Vec<(f32, CachedLabel<L>)>,
     longest_column_values: Vec<ShortLivedStr>,
+    current_split: Option<usize>,
+    /// The indices of the splits shown by the previous render.
+    shown_splits: Vec<usize>,
+    transition: Option<Transition>,
+}
+
+/// The transition from the previous current split to the new one. The
+/// highlight moves from the previous current split to the new one, while the
+/// splits themselves move from where they were shown before to their new
+/// positions, in case the splits scrolled.
+struct Transition {
+    kind: SplitAnimationKind,
+    /// The position of the previous current split among the splits shown
+    /// before the transition.
+    from: f32,
+    /// The position of the new current split among the splits shown.
+    to: f32,
+    /// For each of the splits shown, the number of rows between the position
+    /// it started at and its target position.
+    offsets: Vec<f32>,
+    /// How far the transition progressed, from 0 to 1.
+    progress: f32,
+}
+
+impl Transition {
+    /// The eased progress of the transition.
+    fn t(&self) -> f32 {
+        let t = self.progress;
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    /// The number of rows the split at the position provided is currently
+    /// away from its target position.
+    fn offset(&self, position: usize) -> f32 {
+        self.offsets
+            .get(position)
+            .map_or(0.0, |offset| offset * (1.0 - self.t()))
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -60,6 +101,7 @@ unsafe impl Sync for ShortLivedStr {}
 struct SplitCache<L> {
     name: CachedLabel<L>,
     columns: Vec<CachedLabel<L>>,
+    comparison_line: CachedLabel<L>,
 }
 
 impl<L> SplitCache<L> {
@@ -67,6 +109,7 @@ impl<L> SplitCache<L> {
         Self {
             name: CachedLabel::new(),
             columns: Vec::new(),
+            comparison_line: CachedLabel::new(),
         }
     }
 }
@@ -78,8 +121,105 @@ impl<L> Cache<L> {
             column_labels: Vec::new(),
             column_width_labels: Vec::new(),
             longest_column_values: Vec::new(),
+            current_split: None,
+            shown_splits: Vec::new(),
+            transition: None,
         }
     }
+
+    pub const fn is_animating(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Starts a new transition if the current split changed and otherwise
+    /// advances the transition that is in progress.
+    fn update_transition(
+        &mut self,
+        animation: Option<SplitAnimation>,
+        elapsed: f32,
+        splits: &[SplitState],
+    ) {
+        let current_position = splits.iter().position(|split| split.is_current_split);
+        let current_split = current_position.map(|position| splits[position].index);
+        let previous_split = mem::replace(&mut self.current_split, current_split);
+
+        match animation.filter(|animation| animation.duration > 0.0) {
+            None => self.transition = None,
+            Some(animation) if previous_split != current_split => {
+                let shown_before =
+                    |index| self.shown_splits.iter().position(|&shown| shown == index);
+
+                self.transition = catch! {
+                    let previous_split = previous_split?;
+                    Transition {
+                        kind: animation.kind,
+                        from: shown_before(previous_split)
+                            .map_or_else(|| position_of(splits, previous_split), |p| p as f32),
+                        to: current_position? as f32,
+                        offsets: if animation.kind == SplitAnimationKind::Slide {
+                            offsets(splits, shown_before)
+                        } else {
+                            Vec::new()
+                        },
+                        progress: 0.0,
+                    }
+                };
+            }
+            Some(animation) => {
+                if let Some(transition) = &mut self.transition {
+                    transition.progress += elapsed / animation.duration;
+                    if transition.progress >= 1.0 {
+                        self.transition = None;
+                    }
+                }
+            }
+        }
+
+        self.shown_splits.clear();
+        self.shown_splits
+            .extend(splits.iter().map(|split| split.index));
+    }
+}
+
+/// Returns whether the splits are shown with a comparison line below their
+/// times. This makes each of the splits taller.
+pub(in crate::rendering) fn has_comparison_lines(component: &State) -> bool {
+    component
+        .splits
+        .iter()
+        .any(|split| split.comparison_line.is_some())
+}
+
+/// Calculates for each of the splits shown how many rows it moved since the
+/// previous render. The splits that weren't shown before move along with the
+/// closest split that was, so they slide in from outside the splits shown
+/// before.
+fn offsets(splits: &[SplitState], shown_before: impl Fn(usize) -> Option<usize>) -> Vec<f32> {
+    let known: Vec<Option<f32>> = splits
+        .iter()
+        .enumerate()
+        .map(|(position, split)| Some(shown_before(split.index)? as f32 - position as f32))
+        .collect();
+
+    (0..known.len())
+        .map(|position| {
+            (0..known.len())
+                .flat_map(|distance| [position.checked_sub(distance), Some(position + distance)])
+                .find_map(|position| *known.get(position?)?)
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// The position of the split among the splits shown. If the split is not shown
+/// anymore, the position of the closest split shown is used instead.
+fn position_of(splits: &[SplitState], index: usize) -> f32 {
+    let position = match splits.iter().position(|split| split.index >= index) {
+        Some(position) if splits[position].index == index => position,
+        Some(position) => position.saturating_sub(1),
+        None => splits.len().saturating_sub(1),
+    };
+    position as f32
 }
 
 pub(in crate::rendering) fn render<A: ResourceAllocator>(
@@ -152,13 +292,21 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     let display_two_rows =
         component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal;
 
-    let split_height = if display_two_rows {
+    let row_height = if display_two_rows {
         TWO_ROW_HEIGHT
     } else {
         DEFAULT_COMPONENT_HEIGHT
     };
 
-    let vertical_padding = vertical_padding(split_height);
+    // The comparison lines are shown below the rows, so the icons and the rest
+    // of the texts stay where they would be without them.
+    let split_height = if has_comparison_lines(component) {
+        row_height + COMPARISON_LINE_HEIGHT
+    } else {
+        row_height
+    };
+
+    let vertical_padding = vertical_padding(row_height);
 
     let (split_width, (delta_x, delta_y), separator_pos, split_background_bottom_right, icon_y) =
         if layout_state.direction == LayoutDirection::Horizontal {
@@ -219,7 +367,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         }
     }
 
-    let icon_size = split_height - 2.0 * vertical_padding;
+    let icon_size = row_height - 2.0 * vertical_padding;
     let icon_right = if component.has_icons {
         BOTH_PADDINGS + icon_size
     } else {
@@ -230,7 +378,16 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         .splits
         .resize_with(component.splits.len(), SplitCache::new);
 
+    cache.update_transition(context.split_animation, context.elapsed, &component.splits);
+    let is_horizontal = layout_state.direction == LayoutDirection::Horizontal;
+
     for (i, (split, split_cache)) in component.splits.iter().zip(&mut cache.splits).enumerate() {
+        let offset = cache
+            .transition
+            .as_ref()
+            .map_or(0.0, |transition| transition.offset(i));
+        context.translate(offset * delta_x, offset * delta_y);
+
         if component.show_thin_separators && i + 1 != component.splits.len() {
             context.render_rectangle(
                 separator_pos,
@@ -239,7 +396,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             );
         }
 
-        if split.is_current_split {
+        if split.is_current_split && cache.transition.is_none() {
             context.render_background(
                 [split_width, split_height],
                 &component.current_split_gradient,
@@ -249,6 +406,17 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             context.render_background(split_background_bottom_right, color);
         }
 
+        if let Some(transition) = &cache.transition {
+            render_transition(
+                context,
+                transition,
+                i as f32 + offset,
+                &component.current_split_gradient,
+                [split_width, split_height],
+                is_horizontal,
+            );
+        }
+
         {
             if let Some(icon) = context.create_image(&split.icon) {
                 context.render_image([PADDING, icon_y], [icon_size, icon_size], icon);
@@ -261,23 +429,49 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 .columns
                 .resize_with(split.columns.len(), CachedLabel::new);
 
-            for ((column, column_cache), (max_width, _)) in split
+            let column_count = split.columns.len();
+
+            for (column_index, ((column, column_cache), (max_width, _))) in split
                 .columns
                 .iter()
                 .zip(&mut split_cache.columns)
                 .zip(&cache.column_width_labels)
+                .enumerate()
             {
                 if !column.value.is_empty() {
                     left_x = context.render_numbers(
                         &column.value,
                         column_cache,
                         Layer::from_updates_frequently(column.updates_frequently),
-                        [right_x, split_height + TEXT_ALIGN_BOTTOM],
+                        [right_x, row_height + TEXT_ALIGN_BOTTOM],
                         DEFAULT_TEXT_SIZE,
                         solid(&column.visual_color),
                     );
                 }
                 right_x -= max_width + PADDING;
+
+                if component.show_column_grid_lines && column_index + 1 < column_count {
+                    let x = right_x + 0.5 * PADDING;
+                    context.render_rectangle(
+                        [x - 0.5 * THIN_SEPARATOR_THICKNESS, 0.0],
+                        [x + 0.5 * THIN_SEPARATOR_THICKNESS, split_height],
+                        &Gradient::Plain(layout_state.thin_separators_color),
+                    );
+                }
+            }
+
+            if let Some(comparison_line) = &split.comparison_line {
+                context.render_numbers(
+                    &comparison_line.value,
+                    &mut split_cache.comparison_line,
+                    Layer::from_updates_frequently(comparison_line.updates_frequently),
+                    [
+                        split_width - PADDING,
+                        split_height + SMALL_TEXT_ALIGN_BOTTOM,
+                    ],
+                    SMALL_TEXT_SIZE,
+                    solid(&comparison_line.visual_color),
+                );
             }
 
             if display_two_rows {
@@ -293,7 +487,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 left_x - PADDING,
             );
         }
-        context.translate(delta_x, delta_y);
+        context.translate((1.0 - offset) * delta_x, (1.0 - offset) * delta_y);
     }
 
     if component.show_final_separator {
@@ -313,3 +507,83 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
 
     context.transform = transform;
 }
+
+/// Renders the part of the highlight of the transition that overlaps the split
+/// at the position provided.
+fn render_transition<A: ResourceAllocator>(
+    context: &mut RenderContext<'_, A>,
+    transition: &Transition,
+    position: f32,
+    gradient: &Gradient,
+    [split_width, split_height]: [f32; 2],
+    is_horizontal: bool,
+) {
+    let t = transition.t();
+    match transition.kind {
+        SplitAnimationKind::Slide => {
+            let highlight_position = transition.from + (transition.to - transition.from) * t;
+            let start = (highlight_position - position).max(0.0);
+            let end = (highlight_position + 1.0 - position).min(1.0);
+            if start >= end {
+                return;
+            }
+
+            let (top_left, bottom_right, gradient) = if is_horizontal {
+                (
+                    [start * split_width, 0.0],
+                    [end * split_width, split_height],
+                    match *gradient {
+                        Gradient::Horizontal(left, right) => {
+                            Gradient::Horizontal(mix(left, right, start), mix(left, right, end))
+                        }
+                        gradient => gradient,
+                    },
+                )
+            } else {
+                (
+                    [0.0, start * split_height],
+                    [split_width, end * split_height],
+                    match *gradient {
+                        Gradient::Vertical(top, bottom) => {
+                            Gradient::Vertical(mix(top, bottom, start), mix(top, bottom, end))
+                        }
+                        gradient => gradient,
+                    },
+                )
+            };
+
+            context.render_rectangle(top_left, bottom_right, &gradient);
+        }
+        SplitAnimationKind::Fade => {
+            for (highlight_position, opacity) in [(transition.from, 1.0 - t), (transition.to, t)] {
+                if highlight_position == position {
+                    context
+                        .render_background([split_width, split_height], &faded(gradient, opacity));
+                }
+            }
+        }
+    }
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color::rgba(
+        a.red + (b.red - a.red) * t,
+        a.green + (b.green - a.green) * t,
+        a.blue + (b.blue - a.blue) * t,
+        a.alpha + (b.alpha - a.alpha) * t,
+    )
+}
+
+fn faded(gradient: &Gradient, opacity: f32) -> Gradient {
+    let fade = |color: Color| Color {
+        alpha: color.alpha * opacity,
+        ..color
+    };
+    match *gradient {
+        Gradient::Transparent => Gradient::Transparent,
+        Gradient::Plain(color) => Gradient::Plain(fade(color)),
+        Gradient::Vertical(top, bottom) => Gradient::Vertical(fade(top), fade(bottom)),
+        Gradient::Horizontal(left, right) => Gradient::Horizontal(fade(left), fade(right)),
+        Gradient::Angular(top, bottom, angle) => Gradient::Angular(fade(top), fade(bottom), angle),
+    }
+}
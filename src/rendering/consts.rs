@@ -12,6 +12,10 @@ const DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE: f32 = DEFAULT_TEXT_ASCENT + DEFAULT_
 pub const TEXT_ALIGN_TOP: f32 = VERTICAL_PADDING + DEFAULT_TEXT_ASCENT;
 pub const TEXT_ALIGN_BOTTOM: f32 = -(VERTICAL_PADDING + DEFAULT_TEXT_DESCENT);
 pub const TEXT_ALIGN_CENTER: f32 = DEFAULT_TEXT_ASCENT - DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE / 2.0;
+const SMALL_TEXT_SCALE: f32 = 0.8;
+pub const SMALL_TEXT_SIZE: f32 = SMALL_TEXT_SCALE * DEFAULT_TEXT_SIZE;
+pub const SMALL_TEXT_ALIGN_BOTTOM: f32 = SMALL_TEXT_SCALE * TEXT_ALIGN_BOTTOM;
+pub const COMPARISON_LINE_HEIGHT: f32 = SMALL_TEXT_SCALE * DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE;
 pub const SEPARATOR_THICKNESS: f32 = 0.1;
 pub const THIN_SEPARATOR_THICKNESS: f32 = SEPARATOR_THICKNESS / 2.0;
 pub const PSEUDO_PIXELS: f32 = 1.0 / 24.0;
@@ -23,6 +23,8 @@ use self::color_font::ColorTables;
 use super::{FontKind, PathBuilder, Rgba, SharedOwnership, TEXT_FONT, TIMER_FONT};
 
 mod color_font;
+#[cfg(test)]
+mod tests;
 
 struct CachedGlyph<P> {
     scale: f32,
@@ -55,6 +57,10 @@ impl<P: SharedOwnership> TextEngine<P> {
         db.load_font_source(Source::Binary(Arc::<&[u8]>::from(TIMER_FONT)));
         db.load_font_source(Source::Binary(Arc::<&[u8]>::from(TEXT_FONT)));
 
+        Self::with_database(db)
+    }
+
+    fn with_database(db: Database) -> Self {
         Self {
             // FIXME: Whenever we introduce localization, we need to make sure
             // to use the correct locale here.
@@ -112,12 +118,38 @@ impl<P: SharedOwnership> TextEngine<P> {
         // exactly. So we do a more relaxed query by ourselves and then reassign
         // the properties to the exact properties that we find in the database.
         // https://github.com/pop-os/cosmic-text/issues/58
-        if let Some(found_id) = self.font_system.db().query(&Query {
-            families,
-            weight,
-            stretch,
-            style,
-        }) {
+        let found_id = self
+            .font_system
+            .db()
+            .query(&Query {
+                families,
+                weight,
+                stretch,
+                style,
+            })
+            .or_else(|| {
+                // Not even our own fonts are available, so we fall back to any
+                // font that we can find, so the text is at least still legible.
+                #[cfg(feature = "log")]
+                log::warn!(
+                    "Neither the font family {family:?} nor {fallback_family:?} is available. Falling back to another font."
+                );
+                let db = self.font_system.db();
+                db.query(&Query {
+                    families: &[
+                        Family::Name("Fira Sans"),
+                        Family::Name("LiveSplit Timer"),
+                        Family::SansSerif,
+                        Family::Monospace,
+                    ],
+                    weight,
+                    stretch,
+                    style,
+                })
+                .or_else(|| Some(db.faces().next()?.id))
+            });
+
+        if let Some(found_id) = found_id {
             if let Some(info) = self.font_system.db().face(found_id) {
                 stretch = info.stretch;
                 style = info.style;
@@ -172,6 +204,10 @@ impl<P: SharedOwnership> TextEngine<P> {
     }
 
     fn glyph_width(&mut self, glyph_text: &str, attrs_list: &AttrsList) -> Option<(ID, u16, f32)> {
+        // cosmic-text panics when shaping without any fonts available.
+        if self.font_system.db().is_empty() {
+            return None;
+        }
         let shape_line = ShapeLine::new_in_buffer(
             &mut self.shape_buffer,
             &mut self.font_system,
@@ -224,6 +260,14 @@ impl<P: SharedOwnership> TextEngine<P> {
 
         label.glyphs.clear();
 
+        if self.font_system.db().is_empty() {
+            // Without any fonts there is nothing to shape, so the label stays
+            // empty.
+            label.width_without_max_width = 0.0;
+            label.width = 0.0;
+            return;
+        }
+
         // FIXME: Look into shape plans in 0.11
         let shape_line = ShapeLine::new_in_buffer(
             &mut self.shape_buffer,
@@ -385,7 +429,14 @@ fn cache_glyph<'gc, P, PB: PathBuilder<Path = P>>(
     path_builder: &mut impl FnMut() -> PB,
 ) -> &'gc mut CachedGlyph<P> {
     glyph_cache.entry((font_id, glyph_id)).or_insert_with(|| {
-        let font = font_system.get_font(font_id).unwrap();
+        let Some(font) = font_system.get_font(font_id) else {
+            // The font isn't available, so there is nothing we can render.
+            return CachedGlyph {
+                scale: 1.0,
+                paths: Vec::new(),
+                unkerned_x_advance: 0.0,
+            };
+        };
         let font = font.rustybuzz();
         let mut paths = Vec::new();
         let color_tables = ColorTables::new(font);
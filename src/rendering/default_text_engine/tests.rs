@@ -0,0 +1,42 @@
+use super::{Database, Source, TextEngine, TEXT_FONT};
+use crate::{
+    platform::Arc,
+    rendering::{FontKind, Label, PathBuilder},
+};
+
+struct NoopPathBuilder;
+
+impl PathBuilder for NoopPathBuilder {
+    type Path = ();
+    fn move_to(&mut self, _: f32, _: f32) {}
+    fn line_to(&mut self, _: f32, _: f32) {}
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+    fn close(&mut self) {}
+    fn finish(self) -> Self::Path {}
+}
+
+#[test]
+fn falls_back_when_timer_font_is_missing() {
+    let mut db = Database::new();
+    db.load_font_source(Source::Binary(Arc::<&[u8]>::from(TEXT_FONT)));
+    let mut engine = TextEngine::<()>::with_database(db);
+
+    let font = engine.create_font(None, FontKind::Timer);
+    assert!(font.monotonic.as_ref().unwrap().digit_width > 0.0);
+
+    let label = engine.create_label(|| NoopPathBuilder, "1:23.45", &font, None);
+    assert!(!label.read().unwrap().glyphs().is_empty());
+    assert!(label.width(1.0) > 0.0);
+}
+
+#[test]
+fn does_not_crash_without_any_fonts() {
+    let mut engine = TextEngine::<()>::with_database(Database::new());
+
+    for kind in [FontKind::Timer, FontKind::Times, FontKind::Text] {
+        let font = engine.create_font(None, kind);
+        let label = engine.create_label(|| NoopPathBuilder, "1:23.45", &font, None);
+        assert!(label.read().unwrap().glyphs().is_empty());
+    }
+}
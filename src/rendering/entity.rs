@@ -82,6 +82,12 @@ pub enum Entity<P, I, L> {
     Image(Handle<I>, Transform),
     /// A text label.
     Label(LabelHandle<L>, FillShader, Transform),
+    /// The outline of a text label, where only the outlines of the glyphs are
+    /// supposed to be drawn. The floating point number determines the
+    /// thickness of the outline in the label's coordinate space, where 1.0 is
+    /// the size of the font. The outline is placed right before the label
+    /// itself, so it ends up underneath the label's fill.
+    StrokeLabel(LabelHandle<L>, f32, Rgba, Transform),
 }
 
 pub fn calculate_hash<P, I, L>(
@@ -131,6 +137,15 @@ fn hash_shader(shader: &FillShader, state: &mut impl Hasher) {
             hash_floats(l, state);
             hash_floats(r, state);
         }
+        FillShader::AngularGradient {
+            top,
+            bottom,
+            angle_degrees,
+        } => {
+            hash_floats(top, state);
+            hash_floats(bottom, state);
+            angle_degrees.to_bits().hash(state);
+        }
     }
 }
 
@@ -158,6 +173,12 @@ impl<P, I, L> Hash for Entity<P, I, L> {
                 hash_shader(shader, state);
                 hash_transform(transform, state);
             }
+            Entity::StrokeLabel(label, stroke_width, color, transform) => {
+                label.hash(state);
+                hash_float(*stroke_width, state);
+                hash_floats(color, state);
+                hash_transform(transform, state);
+            }
         }
     }
 }
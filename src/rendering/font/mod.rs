@@ -164,13 +164,17 @@ impl<L> CachedLabel<L> {
     }
 }
 
-pub fn left_aligned(transform: &Transform, [x, y]: Pos, scale: f32) -> Transform {
-    transform.pre_translate(x, y).pre_scale(scale, scale)
+pub fn left_aligned(transform: &Transform, [x, y]: Pos, scale: f32, width: f32) -> Transform {
+    transform
+        .pre_translate(x, y)
+        .unmirrored(width)
+        .pre_scale(scale, scale)
 }
 
 pub fn right_aligned(transform: &Transform, [x, y]: Pos, scale: f32, width: f32) -> Transform {
     transform
         .pre_translate(x - width, y)
+        .unmirrored(width)
         .pre_scale(scale, scale)
 }
 
@@ -188,5 +192,8 @@ pub fn centered(
     } else if x + width > max_x {
         x = max_x - width;
     }
-    transform.pre_translate(x, y).pre_scale(scale, scale)
+    transform
+        .pre_translate(x, y)
+        .unmirrored(width)
+        .pre_scale(scale, scale)
 }
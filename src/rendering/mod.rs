@@ -77,6 +77,8 @@ mod font;
 mod icon;
 mod resource;
 mod scene;
+#[cfg(test)]
+mod tests;
 
 #[cfg(feature = "default-text-engine")]
 pub mod default_text_engine;
@@ -90,8 +92,8 @@ pub mod web;
 
 use self::{
     consts::{
-        DEFAULT_TEXT_SIZE, DEFAULT_VERTICAL_WIDTH, PADDING, TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP,
-        TWO_ROW_HEIGHT,
+        DEFAULT_TEXT_SIZE, DEFAULT_VERTICAL_WIDTH, PADDING, PSEUDO_PIXELS, TEXT_ALIGN_BOTTOM,
+        TEXT_ALIGN_TOP, TWO_ROW_HEIGHT,
     },
     font::{AbbreviatedLabel, CachedLabel, FontCache},
     icon::{CachedImage, ImageHandle},
@@ -99,12 +101,12 @@ use self::{
 };
 use crate::{
     layout::{LayoutDirection, LayoutState},
-    platform::prelude::*,
+    platform::{math, prelude::*},
     settings::{self, BackgroundImage, Color, Gradient, ImageCache, ImageId, LayoutBackground},
 };
 use alloc::borrow::Cow;
 use bytemuck_derive::{Pod, Zeroable};
-use core::iter;
+use core::{iter, mem};
 
 pub use self::{
     entity::Entity,
@@ -146,6 +148,38 @@ pub enum FillShader {
     VerticalGradient(Rgba, Rgba),
     /// Use a horizontal gradient (left, right) to fill the path.
     HorizontalGradient(Rgba, Rgba),
+    /// Use a gradient that is rotated by an angle in degrees to fill the path.
+    /// An angle of 0° results in a vertical gradient and an angle of 90° in a
+    /// horizontal gradient. Use [`angular_gradient_endpoints`] to calculate
+    /// where the gradient starts and ends for the bounds of the path.
+    AngularGradient {
+        /// The color at the start of the gradient.
+        top: Rgba,
+        /// The color at the end of the gradient.
+        bottom: Rgba,
+        /// The clockwise rotation of the gradient in degrees.
+        angle_degrees: f32,
+    },
+}
+
+/// Calculates the start and end point of an angular gradient for the bounding
+/// box given by its minimum and maximum coordinates. The gradient line goes
+/// through the center of the bounding box and is long enough for the corners
+/// of the bounding box to be exactly at the start and end colors.
+pub fn angular_gradient_endpoints(
+    angle_degrees: f32,
+    [min_x, min_y]: [f32; 2],
+    [max_x, max_y]: [f32; 2],
+) -> [[f32; 2]; 2] {
+    let (sin, cos) = math::f32::sin_cos(angle_degrees.to_radians());
+    let (width, height) = (max_x - min_x, max_y - min_y);
+    let half_length = 0.5 * (math::f32::abs(width * sin) + math::f32::abs(height * cos));
+    let [dx, dy] = [sin * half_length, cos * half_length];
+    let [center_x, center_y] = [0.5 * (min_x + max_x), 0.5 * (min_y + max_y)];
+    [
+        [center_x - dx, center_y - dy],
+        [center_x + dx, center_y + dy],
+    ]
 }
 
 /// The background of the bottom layer of the scene.
@@ -156,6 +190,29 @@ pub enum Background<I> {
     Image(BackgroundImage<Handle<I>>, Transform),
 }
 
+/// Describes how the splits component animates a change of the current split.
+/// The animation is based on the time that passed, so it takes the same amount
+/// of time regardless of how often the scene is updated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SplitAnimation {
+    /// The kind of animation to use.
+    pub kind: SplitAnimationKind,
+    /// The duration of the animation in seconds.
+    pub duration: f32,
+}
+
+/// The kind of a [`SplitAnimation`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SplitAnimationKind {
+    /// The highlight of the current split slides from the previous current
+    /// split to the new one. If the splits scroll, each of them slides from
+    /// its previous position to its new one.
+    Slide,
+    /// The highlight of the previous current split fades out, while the
+    /// highlight of the new one fades in.
+    Fade,
+}
+
 enum CachedSize {
     Vertical(f32),
     Horizontal(f32),
@@ -178,6 +235,9 @@ pub struct SceneManager<P, I, F, L> {
     cached_size: Option<CachedSize>,
     fonts: FontCache<F>,
     images: ImageCache<CachedImage<I>>,
+    mirrored: bool,
+    split_animation: Option<SplitAnimation>,
+    elapsed: f32,
 }
 
 impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager<P, I, F, L> {
@@ -196,9 +256,52 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             cached_size: None,
             fonts,
             images: ImageCache::new(),
+            mirrored: false,
+            split_animation: None,
+            elapsed: 0.0,
         }
     }
 
+    /// Returns whether the layout is rendered horizontally mirrored.
+    pub const fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Sets whether the layout should be rendered horizontally mirrored. This
+    /// flips the placement of all the components and their contents, which
+    /// may be useful for right-to-left languages or overlays that are placed
+    /// on the right side of the screen. Texts and images are still rendered
+    /// the right way around, only their position and alignment is mirrored.
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.mirrored = mirrored;
+    }
+
+    /// Returns the animation that is used when the current split changes. By
+    /// default there is no animation.
+    pub const fn split_animation(&self) -> Option<SplitAnimation> {
+        self.split_animation
+    }
+
+    /// Sets the animation to use when the current split changes. Use [`None`]
+    /// to turn off the animation.
+    pub fn set_split_animation(&mut self, split_animation: Option<SplitAnimation>) {
+        self.split_animation = split_animation;
+    }
+
+    /// Advances the animations by the time in seconds that passed since the
+    /// previous update. The time is applied by the next call to
+    /// [`update_scene`](Self::update_scene).
+    pub fn advance_animations(&mut self, elapsed: f32) {
+        self.elapsed += elapsed;
+    }
+
+    /// Returns whether any animation is in progress. While that is the case,
+    /// the [`Scene`] needs to keep being updated, even if the
+    /// [`LayoutState`] doesn't change.
+    pub fn is_animating(&self) -> bool {
+        self.components.iter().any(component::Cache::is_animating)
+    }
+
     /// Accesses the [`Scene`] in order to render the [`Entities`](Entity).
     pub const fn scene(&self) -> &Scene<P, I, L> {
         &self.scene
@@ -279,15 +382,20 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
 
         let mut context = RenderContext {
             handles: Handles::new(self.next_id, allocator),
-            transform: Transform::scale(width, height),
+            transform: Transform::backend(width, height, self.mirrored),
             scene: &mut self.scene,
             fonts: &mut self.fonts,
             images: &mut self.images,
             image_cache,
+            split_animation: self.split_animation,
+            elapsed: mem::take(&mut self.elapsed),
+            text_outline: decode_text_outline(state),
         };
 
         let background = context.decode_layout_background(&state.background, resolution);
-        context.scene.set_background(background);
+        context
+            .scene
+            .set_background(background.map(|b| b.mirrored(self.mirrored)));
 
         context.fonts.maybe_reload(&mut context.handles, state);
 
@@ -352,15 +460,20 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
 
         let mut context = RenderContext {
             handles: Handles::new(self.next_id, allocator),
-            transform: Transform::scale(width, height),
+            transform: Transform::backend(width, height, self.mirrored),
             scene: &mut self.scene,
             fonts: &mut self.fonts,
             images: &mut self.images,
             image_cache,
+            split_animation: self.split_animation,
+            elapsed: mem::take(&mut self.elapsed),
+            text_outline: decode_text_outline(state),
         };
 
         let background = context.decode_layout_background(&state.background, resolution);
-        context.scene.set_background(background);
+        context
+            .scene
+            .set_background(background.map(|b| b.mirrored(self.mirrored)));
 
         context.fonts.maybe_reload(&mut context.handles, state);
 
@@ -397,6 +510,21 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
     }
 }
 
+/// Calculates the preferred size of the layout in the default pixel space for
+/// the [`LayoutState`] provided. This is the size at which none of the
+/// components get clipped, which makes it suitable for automatically sizing a
+/// window or an image to the layout. In vertical mode the height depends on
+/// the components, such as the amount of splits shown, while the width is the
+/// default width of a vertical layout. In horizontal mode the width depends on
+/// the components, while the height is the two row height.
+pub fn preferred_size(state: &LayoutState) -> [f32; 2] {
+    let [width, height] = match state.direction {
+        LayoutDirection::Vertical => [DEFAULT_VERTICAL_WIDTH, component::layout_height(state)],
+        LayoutDirection::Horizontal => [component::layout_width(state), TWO_ROW_HEIGHT],
+    };
+    [width / PSEUDO_PIXELS, height / PSEUDO_PIXELS]
+}
+
 struct RenderContext<'b, A: ResourceAllocator> {
     transform: Transform,
     handles: Handles<A>,
@@ -404,6 +532,9 @@ struct RenderContext<'b, A: ResourceAllocator> {
     fonts: &'b mut FontCache<A::Font>,
     images: &'b mut ImageCache<CachedImage<A::Image>>,
     image_cache: &'b ImageCache,
+    split_animation: Option<SplitAnimation>,
+    elapsed: f32,
+    text_outline: Option<(f32, Rgba)>,
 }
 
 impl<A: ResourceAllocator> RenderContext<'_, A> {
@@ -411,6 +542,25 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         self.scene.rectangle()
     }
 
+    fn push_label(
+        &mut self,
+        layer: Layer,
+        label: &LabelHandle<A::Label>,
+        shader: FillShader,
+        transform: Transform,
+    ) {
+        let layer = self.scene.layer_mut(layer);
+        if let Some((stroke_width, color)) = self.text_outline {
+            layer.push(Entity::StrokeLabel(
+                label.share(),
+                stroke_width,
+                color,
+                transform,
+            ));
+        }
+        layer.push(Entity::Label(label.share(), shader, transform));
+    }
+
     fn render_background(&mut self, [w, h]: Pos, gradient: &Gradient) {
         if let Some(shader) = decode_gradient(gradient) {
             let rectangle = self.rectangle();
@@ -543,7 +693,12 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             height = new_height;
         }
 
-        let transform = self.transform.pre_translate(x, y).pre_scale(width, height);
+        // Images are never supposed to be mirrored, only their position is.
+        let transform = self
+            .transform
+            .pre_translate(x, y)
+            .unmirrored(width)
+            .pre_scale(width, height);
 
         self.scene
             .bottom_layer_mut()
@@ -603,13 +758,16 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             (max_x - x) / scale,
         );
 
-        self.scene.bottom_layer_mut().push(Entity::Label(
-            label.share(),
+        let width = label.width(scale);
+
+        self.push_label(
+            Layer::Bottom,
+            label,
             shader,
-            font::left_aligned(&self.transform, pos, scale),
-        ));
+            font::left_aligned(&self.transform, pos, scale, width),
+        );
 
-        x + label.width(scale)
+        x + width
     }
 
     fn render_text_ellipsis(
@@ -628,13 +786,16 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             Some((max_x - x) / scale),
         );
 
-        self.scene.bottom_layer_mut().push(Entity::Label(
-            label.share(),
+        let width = label.width(scale);
+
+        self.push_label(
+            Layer::Bottom,
+            label,
             shader,
-            font::left_aligned(&self.transform, pos, scale),
-        ));
+            font::left_aligned(&self.transform, pos, scale, width),
+        );
 
-        x + label.width(scale)
+        x + width
     }
 
     fn render_text_centered(
@@ -654,8 +815,9 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             Some((max_x - min_x) / scale),
         );
 
-        self.scene.bottom_layer_mut().push(Entity::Label(
-            label.share(),
+        self.push_label(
+            Layer::Bottom,
+            label,
             shader,
             font::centered(
                 &self.transform,
@@ -665,7 +827,7 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
                 min_x,
                 max_x,
             ),
-        ));
+        );
     }
 
     fn render_abbreviated_text_centered<'a>(
@@ -685,8 +847,9 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             (max_x - min_x) / scale,
         );
 
-        self.scene.bottom_layer_mut().push(Entity::Label(
-            label.share(),
+        self.push_label(
+            Layer::Bottom,
+            label,
             shader,
             font::centered(
                 &self.transform,
@@ -696,7 +859,7 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
                 min_x,
                 max_x,
             ),
-        ));
+        );
     }
 
     fn render_text_right_align(
@@ -711,11 +874,12 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         let label = label.update(text, &mut self.handles, &mut self.fonts.text.font, None);
         let width = label.width(scale);
 
-        self.scene.layer_mut(layer).push(Entity::Label(
-            label.share(),
+        self.push_label(
+            layer,
+            label,
             shader,
             font::right_aligned(&self.transform, pos, scale, width),
-        ));
+        );
 
         x - width
     }
@@ -758,11 +922,12 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         let label = label.update(text, &mut self.handles, &mut self.fonts.times.font, None);
         let width = label.width(scale);
 
-        self.scene.layer_mut(layer).push(Entity::Label(
-            label.share(),
+        self.push_label(
+            layer,
+            label,
             shader,
             font::right_aligned(&self.transform, pos, scale, width),
-        ));
+        );
 
         x - width
     }
@@ -779,11 +944,12 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         let label = label.update(text, &mut self.handles, &mut self.fonts.timer.font, None);
         let width = label.width(scale);
 
-        self.scene.layer_mut(layer).push(Entity::Label(
-            label.share(),
+        self.push_label(
+            layer,
+            label,
             shader,
             font::right_aligned(&self.transform, pos, scale, width),
-        ));
+        );
 
         x - width
     }
@@ -848,13 +1014,47 @@ const fn decode_gradient(gradient: &Gradient) -> Option<FillShader> {
             FillShader::VerticalGradient(top.to_array(), bottom.to_array())
         }
         Gradient::Plain(plain) => FillShader::SolidColor(plain.to_array()),
+        Gradient::Angular(top, bottom, angle_degrees) => FillShader::AngularGradient {
+            top: top.to_array(),
+            bottom: bottom.to_array(),
+            angle_degrees: *angle_degrees,
+        },
     })
 }
 
+fn decode_text_outline(state: &LayoutState) -> Option<(f32, Rgba)> {
+    // The outline is stroked centered on the edges of the glyphs, with the
+    // inner half being covered by the fill, so the stroke needs to be twice as
+    // wide as the visible outline.
+    let color = state.text_outline_color?;
+    let stroke_width = 2.0 * state.text_outline_width as f32 / 100.0;
+    Some((stroke_width, color.to_array()))
+}
+
 const fn solid(color: &Color) -> FillShader {
     FillShader::SolidColor(color.to_array())
 }
 
+impl<I> Background<I> {
+    fn mirrored(self, mirrored: bool) -> Self {
+        match self {
+            Background::Shader(FillShader::HorizontalGradient(left, right)) if mirrored => {
+                Background::Shader(FillShader::HorizontalGradient(right, left))
+            }
+            Background::Shader(FillShader::AngularGradient {
+                top,
+                bottom,
+                angle_degrees,
+            }) if mirrored => Background::Shader(FillShader::AngularGradient {
+                top,
+                bottom,
+                angle_degrees: -angle_degrees,
+            }),
+            background => background,
+        }
+    }
+}
+
 impl Transform {
     const fn scale(scale_x: f32, scale_y: f32) -> Transform {
         Self {
@@ -865,6 +1065,39 @@ impl Transform {
         }
     }
 
+    /// The transform from the unit square to the Backend Coordinate Space. If
+    /// the layout is mirrored, the x axis is flipped, such that x = 0 is on the
+    /// right side.
+    const fn backend(width: f32, height: f32, mirrored: bool) -> Transform {
+        if mirrored {
+            Self {
+                x: width,
+                y: 0.0,
+                scale_x: -width,
+                scale_y: height,
+            }
+        } else {
+            Self::scale(width, height)
+        }
+    }
+
+    /// Returns a transform that places the area from x = 0 to x = `width` at
+    /// the same position, but without flipping its contents if the transform
+    /// is mirrored. This is meant for contents like texts and images that
+    /// would become unreadable when mirrored.
+    fn unmirrored(&self, width: f32) -> Transform {
+        if self.scale_x < 0.0 {
+            Self {
+                scale_x: -self.scale_x,
+                scale_y: self.scale_y,
+                x: self.x + self.scale_x * width,
+                y: self.y,
+            }
+        } else {
+            *self
+        }
+    }
+
     /// Returns a new transform that first scales the coordinates.
     pub fn pre_scale(&self, scale_x: f32, scale_y: f32) -> Transform {
         Self {
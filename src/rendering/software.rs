@@ -2,8 +2,9 @@
 //! surprisingly fast and can be considered the default rendering backend.
 
 use super::{
+    angular_gradient_endpoints,
     default_text_engine::{Font, Label, TextEngine},
-    entity::Entity,
+    entity::{calculate_hash, Entity},
     resource::{self, ResourceAllocator},
     FillShader, FontKind, Scene, SceneManager, SharedOwnership, Transform,
 };
@@ -14,7 +15,7 @@ use crate::{
 use alloc::rc::Rc;
 use core::{mem, ops::Deref};
 use tiny_skia::{
-    BlendMode, Color, FillRule, FilterQuality, GradientStop, LinearGradient, Paint, Path,
+    BlendMode, Color, FillRule, FilterQuality, GradientStop, LineJoin, LinearGradient, Paint, Path,
     PathBuilder, Pattern, Pixmap, PixmapMut, Point, Rect, Shader, SpreadMode, Stroke,
 };
 use tiny_skia_path::NormalizedF32;
@@ -252,6 +253,11 @@ impl BorrowedRenderer {
         }
     }
 
+    /// Sets whether the layout should be rendered horizontally mirrored.
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.scene_manager.set_mirrored(mirrored);
+    }
+
     /// Renders the layout state provided into the image buffer provided. The
     /// image has to be an array of `RGBA8` encoded pixels (red, green, blue,
     /// alpha with each channel being an u8). Some frameworks may over allocate
@@ -273,18 +279,38 @@ impl BorrowedRenderer {
         stride: u32,
         force_redraw: bool,
     ) -> Option<[f32; 2]> {
-        let mut frame_buffer = PixmapMut::from_bytes(image, stride, height).unwrap();
+        let new_resolution = self.update_scene(state, image_cache, [width, height], stride);
+        self.draw(image, [width, height], stride, force_redraw);
+        new_resolution
+    }
 
+    fn update_scene(
+        &mut self,
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        [width, height]: [u32; 2],
+        stride: u32,
+    ) -> Option<[f32; 2]> {
         if stride != self.background.width() || height != self.background.height() {
             self.background = Pixmap::new(stride, height).unwrap();
         }
 
-        let new_resolution = self.scene_manager.update_scene(
+        self.scene_manager.update_scene(
             &mut self.allocator,
             [width as _, height as _],
             state,
             image_cache,
-        );
+        )
+    }
+
+    fn draw(
+        &mut self,
+        image: &mut [u8],
+        [width, height]: [u32; 2],
+        stride: u32,
+        force_redraw: bool,
+    ) {
+        let mut frame_buffer = PixmapMut::from_bytes(image, stride, height).unwrap();
 
         let scene = self.scene_manager.scene();
         let rectangle = scene.rectangle();
@@ -327,8 +353,6 @@ impl BorrowedRenderer {
         }
 
         render_layer(&mut frame_buffer, top_layer, rectangle);
-
-        new_resolution
     }
 }
 
@@ -339,6 +363,7 @@ impl BorrowedRenderer {
 pub struct Renderer {
     renderer: BorrowedRenderer,
     frame_buffer: Pixmap,
+    top_layer_hash: Option<u64>,
 }
 
 impl Default for Renderer {
@@ -353,9 +378,15 @@ impl Renderer {
         Self {
             renderer: BorrowedRenderer::new(),
             frame_buffer: Pixmap::new(1, 1).unwrap(),
+            top_layer_hash: None,
         }
     }
 
+    /// Sets whether the layout should be rendered horizontally mirrored.
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.renderer.set_mirrored(mirrored);
+    }
+
     /// Renders the layout state provided with the chosen resolution. It may
     /// detect that the layout got resized. In that case it returns the new
     /// ideal size. This is just a hint and can be ignored entirely. The image
@@ -370,6 +401,8 @@ impl Renderer {
             self.frame_buffer = Pixmap::new(width, height).unwrap();
         }
 
+        self.top_layer_hash = None;
+
         self.renderer.render(
             state,
             image_cache,
@@ -380,6 +413,76 @@ impl Renderer {
         )
     }
 
+    /// Renders the layout state provided with the chosen resolution, but only
+    /// if the resulting image would be different from the one rendered by the
+    /// previous call. If nothing changed, no rendering happens at all and
+    /// `None` is returned. Otherwise the image data of the newly rendered
+    /// image is returned. This allows skipping the presentation of unchanged
+    /// frames entirely.
+    pub fn render_if_changed(
+        &mut self,
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        [width, height]: [u32; 2],
+    ) -> Option<&[u8]> {
+        let resized = width != self.frame_buffer.width() || height != self.frame_buffer.height();
+        if resized {
+            self.frame_buffer = Pixmap::new(width, height).unwrap();
+        }
+
+        self.renderer
+            .update_scene(state, image_cache, [width, height], width);
+
+        let scene = self.renderer.scene_manager.scene();
+        let top_layer_hash = calculate_hash(&None, scene.top_layer());
+        let top_layer_changed = self.top_layer_hash.replace(top_layer_hash) != Some(top_layer_hash);
+
+        if !resized && !scene.bottom_layer_changed() && !top_layer_changed {
+            return None;
+        }
+
+        self.renderer.draw(
+            self.frame_buffer.data_mut(),
+            [width, height],
+            width,
+            resized,
+        );
+
+        Some(self.frame_buffer.data())
+    }
+
+    /// Renders the layout state provided with the chosen resolution and
+    /// encodes the image as PNG. This is useful for creating thumbnails or for
+    /// sharing the layout as an image. Unlike the image data of the renderer,
+    /// the PNG doesn't use premultiplied alpha.
+    #[cfg(feature = "std")]
+    pub fn render_to_png(
+        &mut self,
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        [width, height]: [u32; 2],
+    ) -> Vec<u8> {
+        use image::{ExtendedColorType, ImageEncoder};
+
+        self.render(state, image_cache, [width, height]);
+
+        let pixels = self
+            .frame_buffer
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let pixel = pixel.demultiply();
+                [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+            })
+            .collect::<Vec<_>>();
+
+        let mut png = Vec::new();
+        crate::util::image::create_reencoder(&mut png)
+            .write_image(&pixels, width, height, ExtendedColorType::Rgba8)
+            .expect("Encoding a PNG into memory can't fail");
+        png
+    }
+
     /// Accesses the image as a byte slice of RGBA8 encoded pixels (red, green,
     /// blue, alpha with each channel being an u8).
     pub fn image_data(&self) -> &[u8] {
@@ -550,6 +653,33 @@ fn render_layer(
                     }
                 }
             }
+            Entity::StrokeLabel(label, stroke_width, color, transform) => {
+                let paint = Paint {
+                    shader: Shader::SolidColor(convert_color(color)),
+                    anti_alias: true,
+                    ..Default::default()
+                };
+
+                for glyph in label.read().unwrap().glyphs() {
+                    if let Some(path) = &glyph.path {
+                        let transform = transform
+                            .pre_translate(glyph.x, glyph.y)
+                            .pre_scale(glyph.scale, glyph.scale);
+
+                        canvas.stroke_path(
+                            path,
+                            &paint,
+                            &Stroke {
+                                width: stroke_width / glyph.scale,
+                                line_join: LineJoin::Round,
+                                ..Default::default()
+                            },
+                            convert_transform(&transform),
+                            None,
+                        );
+                    }
+                }
+            }
         }
     }
 }
@@ -590,6 +720,30 @@ fn convert_shader<T>(
             )
             .unwrap()
         }
+        FillShader::AngularGradient {
+            top,
+            bottom,
+            angle_degrees,
+        } => {
+            let [bound_top, bound_bottom] = calculate_top_bottom(has_bounds);
+            let [bound_left, bound_right] = calculate_left_right(has_bounds);
+            let [[start_x, start_y], [end_x, end_y]] = angular_gradient_endpoints(
+                *angle_degrees,
+                [bound_left, bound_top],
+                [bound_right, bound_bottom],
+            );
+            LinearGradient::new(
+                Point::from_xy(start_x, start_y),
+                Point::from_xy(end_x, end_y),
+                vec![
+                    GradientStop::new(0.0, convert_color(top)),
+                    GradientStop::new(1.0, convert_color(bottom)),
+                ],
+                SpreadMode::Pad,
+                tiny_skia::Transform::identity(),
+            )
+            .unwrap()
+        }
     };
 
     Paint {
@@ -665,6 +819,37 @@ fn fill_background(
                         None,
                     );
                 }
+                FillShader::AngularGradient {
+                    top,
+                    bottom,
+                    angle_degrees,
+                } => {
+                    let [[start_x, start_y], [end_x, end_y]] = angular_gradient_endpoints(
+                        *angle_degrees,
+                        [0.0, 0.0],
+                        [width as _, height as _],
+                    );
+                    background_layer.fill_rect(
+                        Rect::from_xywh(0.0, 0.0, width as _, height as _).unwrap(),
+                        &Paint {
+                            shader: LinearGradient::new(
+                                Point::from_xy(start_x, start_y),
+                                Point::from_xy(end_x, end_y),
+                                vec![
+                                    GradientStop::new(0.0, convert_color(top)),
+                                    GradientStop::new(1.0, convert_color(bottom)),
+                                ],
+                                SpreadMode::Pad,
+                                tiny_skia::Transform::identity(),
+                            )
+                            .unwrap(),
+                            blend_mode: BlendMode::Source,
+                            ..Default::default()
+                        },
+                        tiny_skia::Transform::identity(),
+                        None,
+                    );
+                }
             },
             Background::Image(image, transform) => {
                 #[cfg(feature = "image")]
@@ -841,6 +1026,20 @@ fn calculate_bounds(layer: &[Entity<SkiaPath, SkiaImage, SkiaLabel>]) -> [f32; 2
                     }
                 }
             }
+            Entity::StrokeLabel(label, stroke_width, _, transform) => {
+                let radius = transform.scale_y * stroke_width;
+                for glyph in label.read().unwrap().glyphs() {
+                    if let Some(path) = &glyph.path {
+                        let transform = transform.pre_translate(glyph.x, glyph.y);
+                        let bounds = path.bounds();
+                        for y in [bounds.top(), bounds.bottom()] {
+                            let transformed_y = transform.transform_y(y);
+                            min_y = min_y.min(transformed_y - radius);
+                            max_y = max_y.max(transformed_y + radius);
+                        }
+                    }
+                }
+            }
         }
     }
     [min_y, max_y]
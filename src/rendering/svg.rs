@@ -17,6 +17,7 @@ use crate::{
 };
 
 use super::{
+    angular_gradient_endpoints,
     default_text_engine::{self, TextEngine},
     resource, Background, Entity, FillShader, FontKind, ResourceAllocator, SceneManager,
     SharedOwnership, Transform,
@@ -26,6 +27,23 @@ type SvgImage = Rc<Image>;
 type SvgFont = default_text_engine::Font;
 type SvgLabel = default_text_engine::Label<SvgPath>;
 
+/// Renders the layout state to an SVG document at the layout's [preferred
+/// size](super::preferred_size). This is a convenience function for one-off
+/// exports, such as embedding a snapshot of the splits in a web page. Use a
+/// [`Renderer`] instead to render the layout repeatedly, as it caches the
+/// resources of the layout between renders. Text is rendered as paths, so the
+/// document doesn't depend on any fonts being available.
+pub fn render(layout_state: &LayoutState, image_cache: &ImageCache) -> String {
+    let mut svg = String::new();
+    let _ = Renderer::new().render(
+        &mut svg,
+        layout_state,
+        image_cache,
+        super::preferred_size(layout_state),
+    );
+    svg
+}
+
 /// The SVG renderer allows rendering layouts to vector images in the SVG
 /// format.
 pub struct Renderer {
@@ -56,6 +74,11 @@ impl Renderer {
         }
     }
 
+    /// Sets whether the layout should be rendered horizontally mirrored.
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.scene_manager.set_mirrored(mirrored);
+    }
+
     /// Renders the layout state with the chosen dimensions to the writer
     /// provided. It may detect that the layout got resized. In that case it
     /// returns the new ideal size. This is just a hint and can be ignored
@@ -90,7 +113,7 @@ impl Renderer {
             ],
             |writer| {
                 let background_filter_id = writer.tag("defs", |writer| {
-                    writer.content(|writer| self.write_defs(writer))
+                    writer.content(|writer| self.write_defs(writer, width, height))
                 })?;
                 self.write_scene(writer, width, height, background_filter_id)?;
 
@@ -101,7 +124,12 @@ impl Renderer {
         Ok(new_dims)
     }
 
-    fn write_defs<W: Write>(&self, writer: &mut Writer<W>) -> Result<Option<usize>, fmt::Error> {
+    fn write_defs<W: Write>(
+        &self,
+        writer: &mut Writer<W>,
+        width: f32,
+        height: f32,
+    ) -> Result<Option<usize>, fmt::Error> {
         let current_id = &mut 0;
         let mut background_filter_id = None;
 
@@ -112,7 +140,11 @@ impl Renderer {
 
         if let Some(background) = scene.background() {
             match background {
-                Background::Shader(shader) => visit_shader(current_id, defs, writer, shader)?,
+                Background::Shader(shader) => {
+                    visit_shader(current_id, defs, writer, shader, || {
+                        [[0.0, 0.0], [width, height]]
+                    })?
+                }
                 Background::Image(image, transform) => {
                     visit_image(current_id, defs, writer, &image.image)?;
 
@@ -169,21 +201,29 @@ impl Renderer {
 
         for entity in scene.bottom_layer().iter().chain(scene.top_layer()) {
             match entity {
-                Entity::FillPath(path, shader, _) => {
+                Entity::FillPath(path, shader, transform) => {
                     visit_path(current_id, defs, writer, path)?;
-                    visit_shader(current_id, defs, writer, shader)?;
+                    visit_shader(current_id, defs, writer, shader, || path.bounds(transform))?;
                 }
                 Entity::StrokePath(path, _, _, _) => visit_path(current_id, defs, writer, path)?,
                 Entity::Image(image, _) => visit_image(current_id, defs, writer, image)?,
                 Entity::Label(label, shader, _) => {
-                    for glyph in label.read().unwrap().glyphs() {
+                    let label = label.read().unwrap();
+                    for glyph in label.glyphs() {
                         if glyph.color.is_none() {
-                            visit_shader(current_id, defs, writer, shader)?;
+                            visit_shader(current_id, defs, writer, shader, || {
+                                label_bounds(&label)
+                            })?;
                         }
 
                         visit_path(current_id, defs, writer, &glyph.path)?;
                     }
                 }
+                Entity::StrokeLabel(label, _, _, _) => {
+                    for glyph in label.read().unwrap().glyphs() {
+                        visit_path(current_id, defs, writer, &glyph.path)?;
+                    }
+                }
             }
         }
 
@@ -202,7 +242,11 @@ impl Renderer {
         if let Some(background) = scene.background() {
             match background {
                 Background::Shader(shader) => {
-                    if let Some((fill, opacity)) = convert_shader(shader, &self.allocator.defs) {
+                    if let Some((fill, opacity)) =
+                        convert_shader(shader, &self.allocator.defs, || {
+                            [[0.0, 0.0], [width, height]]
+                        })
+                    {
                         writer.tag("rect", |mut writer| {
                             writer.attribute("width", DisplayAlreadyEscaped(width))?;
                             writer.attribute("height", DisplayAlreadyEscaped(height))?;
@@ -243,7 +287,9 @@ impl Renderer {
         for entity in scene.bottom_layer().iter().chain(scene.top_layer()) {
             match entity {
                 Entity::FillPath(path, shader, transform) => {
-                    let Some((fill, opacity)) = convert_shader(shader, &self.allocator.defs) else {
+                    let Some((fill, opacity)) =
+                        convert_shader(shader, &self.allocator.defs, || path.bounds(transform))
+                    else {
                         continue;
                     };
                     path_with_transform(
@@ -289,7 +335,8 @@ impl Renderer {
                     )?;
                 }
                 Entity::Label(label, shader, transform) => {
-                    for glyph in label.read().unwrap().glyphs() {
+                    let label = label.read().unwrap();
+                    for glyph in label.glyphs() {
                         let (fill, opacity) = if let Some(color) = &glyph.color {
                             let Some((color, opacity)) = convert_color(color) else {
                                 continue;
@@ -297,7 +344,9 @@ impl Renderer {
                             (Fill::Rgb(color), opacity)
                         } else {
                             let Some((fill, opacity)) =
-                                convert_shader(shader, &self.allocator.defs)
+                                convert_shader(shader, &self.allocator.defs, || {
+                                    label_bounds(&label)
+                                })
                             else {
                                 continue;
                             };
@@ -314,6 +363,27 @@ impl Renderer {
                         )?;
                     }
                 }
+                Entity::StrokeLabel(label, stroke_width, color, transform) => {
+                    let Some((color, opacity)) = convert_color(color) else {
+                        continue;
+                    };
+                    for glyph in label.read().unwrap().glyphs() {
+                        path_with_transform(
+                            writer,
+                            &glyph.path,
+                            &transform
+                                .pre_translate(glyph.x, glyph.y)
+                                .pre_scale(glyph.scale, glyph.scale),
+                            [
+                                ("fill", AttrValue::Str("none")),
+                                ("stroke", Fill::Rgb(color).into()),
+                                ("stroke-width", (stroke_width / glyph.scale).into()),
+                                ("stroke-opacity", opacity.into()),
+                                ("stroke-linejoin", AttrValue::Str("round")),
+                            ],
+                        )?;
+                    }
+                }
             }
         }
 
@@ -385,36 +455,54 @@ fn visit_shader<W: Write>(
     defs: &mut Defs,
     writer: &mut Writer<W>,
     shader: &FillShader,
+    calculate_bounds: impl FnOnce() -> [[f32; 2]; 2],
 ) -> fmt::Result {
-    let (vertical, start, end) = match shader {
+    let (direction, start, end) = match shader {
         FillShader::SolidColor(_) => return Ok(()),
-        FillShader::VerticalGradient(top, bottom) => (true, top, bottom),
-        FillShader::HorizontalGradient(left, right) => (false, left, right),
+        FillShader::VerticalGradient(top, bottom) => (GradientDirection::Vertical, top, bottom),
+        FillShader::HorizontalGradient(left, right) => (GradientDirection::Horizontal, left, right),
+        FillShader::AngularGradient {
+            top,
+            bottom,
+            angle_degrees,
+        } => (
+            GradientDirection::angular(*angle_degrees, calculate_bounds()),
+            top,
+            bottom,
+        ),
     };
 
-    let gradient = defs.add_gradient(vertical, start, end);
+    let gradient = defs.add_gradient(direction, start, end);
 
     if defs.ptr_lookup.insert(Rc::as_ptr(&gradient) as usize) {
         gradient.id.set(*current_id);
         *current_id += 1;
 
-        writer.tag_with_content(
-            "linearGradient",
-            [
-                (
-                    "id",
-                    DisplayAlreadyEscaped(format_args!("{}", gradient.id.get())),
-                ),
-                (
-                    "x2",
-                    DisplayAlreadyEscaped(format_args!("{}", if vertical { "0" } else { "1" })),
-                ),
-                (
-                    "y2",
-                    DisplayAlreadyEscaped(format_args!("{}", if vertical { "1" } else { "0" })),
-                ),
-            ],
-            |writer| {
+        // The vertical and horizontal gradients use the default bounding box
+        // units. The angular gradients however can't, as the angle would be
+        // distorted by stretching the unit square over the bounds. So their
+        // end points are in user space instead.
+        let [[x1, y1], [x2, y2]] = match direction {
+            GradientDirection::Vertical => [[0.0, 0.0], [0.0, 1.0]],
+            GradientDirection::Horizontal => [[0.0, 0.0], [1.0, 0.0]],
+            GradientDirection::Angular(endpoints) => endpoints,
+        };
+
+        writer.tag("linearGradient", |mut writer| {
+            writer.attribute(
+                "id",
+                DisplayAlreadyEscaped(format_args!("{}", gradient.id.get())),
+            )?;
+            if let GradientDirection::Angular(_) = direction {
+                writer.attribute("gradientUnits", Text::new_escaped("userSpaceOnUse"))?;
+            }
+            if x1 != 0.0 || y1 != 0.0 {
+                writer.attribute("x1", DisplayAlreadyEscaped(x1))?;
+                writer.attribute("y1", DisplayAlreadyEscaped(y1))?;
+            }
+            writer.attribute("x2", DisplayAlreadyEscaped(x2))?;
+            writer.attribute("y2", DisplayAlreadyEscaped(y2))?;
+            writer.content(|writer| {
                 writer.tag("stop", |mut writer| {
                     let (start_rgb, start_a) = convert_color_or_transparent(start);
                     writer.attribute("stop-color", start_rgb)?;
@@ -432,8 +520,8 @@ fn visit_shader<W: Write>(
                     }
                     Ok(())
                 })
-            },
-        )?;
+            })
+        })?;
     }
 
     Ok(())
@@ -444,16 +532,40 @@ struct SvgAllocator {
     defs: Rc<RefCell<Defs>>,
 }
 
+#[derive(Copy, Clone)]
+enum GradientDirection {
+    Vertical,
+    Horizontal,
+    /// The start and end point of the gradient in user space.
+    Angular([[f32; 2]; 2]),
+}
+
+impl GradientDirection {
+    fn angular(angle_degrees: f32, [min, max]: [[f32; 2]; 2]) -> Self {
+        GradientDirection::Angular(angular_gradient_endpoints(angle_degrees, min, max))
+    }
+
+    fn key(self) -> (u8, [u32; 4]) {
+        match self {
+            GradientDirection::Vertical => (0, [0; 4]),
+            GradientDirection::Horizontal => (1, [0; 4]),
+            GradientDirection::Angular([[x1, y1], [x2, y2]]) => {
+                (2, [x1, y1, x2, y2].map(f32::to_bits))
+            }
+        }
+    }
+}
+
 struct Gradient {
     id: Cell<usize>,
-    vertical: bool,
+    direction: GradientDirection,
     start: [f32; 4],
     end: [f32; 4],
 }
 
 impl core::hash::Hash for Gradient {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        self.vertical.hash(state);
+        self.direction.key().hash(state);
         self.start.map(f32::to_bits).hash(state);
         self.end.map(f32::to_bits).hash(state);
     }
@@ -461,7 +573,7 @@ impl core::hash::Hash for Gradient {
 
 impl PartialEq for Gradient {
     fn eq(&self, other: &Self) -> bool {
-        self.vertical == other.vertical
+        self.direction.key() == other.direction.key()
             && self.start.map(f32::to_bits) == other.start.map(f32::to_bits)
             && self.end.map(f32::to_bits) == other.end.map(f32::to_bits)
     }
@@ -475,12 +587,17 @@ struct Defs {
 }
 
 impl Defs {
-    fn add_gradient(&mut self, vertical: bool, start: &[f32; 4], end: &[f32; 4]) -> Rc<Gradient> {
+    fn add_gradient(
+        &mut self,
+        direction: GradientDirection,
+        start: &[f32; 4],
+        end: &[f32; 4],
+    ) -> Rc<Gradient> {
         let hasher = foldhash::fast::FixedState::default();
         let hasher = |val: &Gradient| hasher.hash_one(val);
         let gradient = Gradient {
             id: Cell::new(0),
-            vertical,
+            direction,
             start: *start,
             end: *end,
         };
@@ -516,6 +633,7 @@ struct PathData {
     id: Cell<usize>,
     kind: PathKind,
     data: String,
+    bounds: [[f32; 2]; 2],
 }
 
 #[derive(Clone)]
@@ -559,6 +677,36 @@ enum PathSegment {
     Close,
 }
 
+impl PathBuilder {
+    fn bounds(&self) -> [[f32; 2]; 2] {
+        let (mut min, mut max) = ([f32::INFINITY; 2], [f32::NEG_INFINITY; 2]);
+        let mut add = |point: &Point| {
+            min = [min[0].min(point.x), min[1].min(point.y)];
+            max = [max[0].max(point.x), max[1].max(point.y)];
+        };
+        for segment in &self.segments {
+            match segment {
+                PathSegment::MoveTo(point) | PathSegment::LineTo(point) => add(point),
+                PathSegment::QuadTo(point1, point2) => {
+                    add(point1);
+                    add(point2);
+                }
+                PathSegment::CurveTo(point1, point2, point3) => {
+                    add(point1);
+                    add(point2);
+                    add(point3);
+                }
+                PathSegment::Close => {}
+            }
+        }
+        if max[0] < min[0] {
+            [[0.0; 2]; 2]
+        } else {
+            [min, max]
+        }
+    }
+}
+
 impl super::PathBuilder for PathBuilder {
     type Path = SvgPath;
 
@@ -588,6 +736,8 @@ impl super::PathBuilder for PathBuilder {
     }
 
     fn finish(self) -> Self::Path {
+        let bounds = self.bounds();
+
         if let [outer_rem @ .., PathSegment::Close] = &*self.segments {
             if let [PathSegment::MoveTo(_), rem @ ..] = outer_rem {
                 if rem
@@ -610,6 +760,7 @@ impl super::PathBuilder for PathBuilder {
                         id: Cell::new(0),
                         kind: PathKind::Polygon,
                         data,
+                        bounds,
                     }));
                 }
             }
@@ -639,6 +790,7 @@ impl super::PathBuilder for PathBuilder {
                     id: Cell::new(0),
                     kind: PathKind::Polyline,
                     data,
+                    bounds,
                 }));
             }
         }
@@ -676,6 +828,7 @@ impl super::PathBuilder for PathBuilder {
             id: Cell::new(0),
             kind: PathKind::Path,
             data,
+            bounds,
         }))
     }
 }
@@ -786,6 +939,7 @@ enum AttrValue {
     Fill(Fill),
     F32(f32),
     OptionF32(Option<f32>),
+    Str(&'static str),
 }
 
 impl From<Fill> for AttrValue {
@@ -822,6 +976,7 @@ fn path_with_transform<'a, W: fmt::Write>(
                         writer.attribute(key, DisplayAlreadyEscaped(value))?;
                     }
                 }
+                AttrValue::Str(value) => writer.attribute(key, Text::new_escaped(value))?,
             }
         }
         Ok(())
@@ -893,6 +1048,59 @@ impl Point {
     }
 }
 
+impl SvgPath {
+    /// Calculates the bounds of the path in its own coordinate system.
+    fn local_bounds(&self) -> [[f32; 2]; 2] {
+        match self {
+            SvgPath::Rectangle => [[0.0, 0.0], [1.0, 1.0]],
+            SvgPath::Circle(x, y, r) => [[x - r, y - r], [x + r, y + r]],
+            SvgPath::Line(start, end) => [
+                [start.x.min(end.x), start.y.min(end.y)],
+                [start.x.max(end.x), start.y.max(end.y)],
+            ],
+            SvgPath::Path(path) => path.bounds,
+        }
+    }
+
+    /// Calculates the bounds of the path in the user space of the element
+    /// that [`path_with_transform`] emits for it. Only the paths that are
+    /// referenced through a `use` element keep their own coordinate system.
+    /// All the others are emitted with the transform already applied.
+    fn bounds(&self, transform: &Transform) -> [[f32; 2]; 2] {
+        let [min, max] = self.local_bounds();
+        if let SvgPath::Path(_) = self {
+            return [min, max];
+        }
+        let [x1, y1] = Point {
+            x: min[0],
+            y: min[1],
+        }
+        .transform(transform);
+        let [x2, y2] = Point {
+            x: max[0],
+            y: max[1],
+        }
+        .transform(transform);
+        [[x1.min(x2), y1.min(y2)], [x1.max(x2), y1.max(y2)]]
+    }
+}
+
+/// Calculates the bounds of all the glyphs of the label in the coordinate
+/// system of the individual glyphs, the same way the software renderer does.
+fn label_bounds(label: &default_text_engine::LockedLabel<SvgPath>) -> [[f32; 2]; 2] {
+    let (mut min, mut max) = ([f32::INFINITY; 2], [f32::NEG_INFINITY; 2]);
+    for glyph in label.glyphs() {
+        let [glyph_min, glyph_max] = glyph.path.local_bounds();
+        min = [min[0].min(glyph_min[0]), min[1].min(glyph_min[1])];
+        max = [max[0].max(glyph_max[0]), max[1].max(glyph_max[1])];
+    }
+    if max[0] < min[0] || max[1] < min[1] {
+        [[0.0; 2]; 2]
+    } else {
+        [min, max]
+    }
+}
+
 enum Fill {
     Rgb(Rgb),
     Url(usize),
@@ -911,18 +1119,38 @@ impl Value for Fill {
     }
 }
 
-fn convert_shader(shader: &FillShader, defs: &Rc<RefCell<Defs>>) -> Option<(Fill, Option<f32>)> {
+fn convert_shader(
+    shader: &FillShader,
+    defs: &Rc<RefCell<Defs>>,
+    calculate_bounds: impl FnOnce() -> [[f32; 2]; 2],
+) -> Option<(Fill, Option<f32>)> {
     Some(match shader {
         FillShader::SolidColor(c) => {
             let (rgb, a) = convert_color(c)?;
             (Fill::Rgb(rgb), a)
         }
         FillShader::VerticalGradient(top, bottom) => {
-            let gradient = defs.borrow_mut().add_gradient(true, top, bottom);
+            let gradient = defs
+                .borrow_mut()
+                .add_gradient(GradientDirection::Vertical, top, bottom);
             (Fill::Url(gradient.id.get()), None)
         }
         FillShader::HorizontalGradient(left, right) => {
-            let gradient = defs.borrow_mut().add_gradient(false, left, right);
+            let gradient =
+                defs.borrow_mut()
+                    .add_gradient(GradientDirection::Horizontal, left, right);
+            (Fill::Url(gradient.id.get()), None)
+        }
+        FillShader::AngularGradient {
+            top,
+            bottom,
+            angle_degrees,
+        } => {
+            let gradient = defs.borrow_mut().add_gradient(
+                GradientDirection::angular(*angle_degrees, calculate_bounds()),
+                top,
+                bottom,
+            );
             (Fill::Url(gradient.id.get()), None)
         }
     })
@@ -939,6 +1167,7 @@ fn convert_color_or_transparent(&[r, g, b, a]: &[f32; 4]) -> (Rgb, Option<f32>)
     ))
 }
 
+#[derive(Copy, Clone)]
 struct Rgb {
     r: u8,
     g: u8,
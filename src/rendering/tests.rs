@@ -0,0 +1,600 @@
+use super::{
+    angular_gradient_endpoints, decode_gradient, Entity, FillShader, FontKind, Image, Label,
+    PathBuilder, ResourceAllocator, SceneManager, SharedOwnership, SplitAnimation,
+    SplitAnimationKind, Transform,
+};
+use crate::{
+    layout::{Component, Layout},
+    platform::prelude::*,
+    settings::{self, Color, Font, Gradient, ImageCache},
+    util::tests_helper::create_run,
+    Timer,
+};
+
+struct Dummy;
+
+impl PathBuilder for Dummy {
+    type Path = ();
+
+    fn move_to(&mut self, _: f32, _: f32) {}
+    fn line_to(&mut self, _: f32, _: f32) {}
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+    fn close(&mut self) {}
+    fn finish(self) -> Self::Path {}
+}
+
+#[derive(Clone)]
+struct DummyImage;
+
+impl Image for DummyImage {
+    fn aspect_ratio(&self) -> f32 {
+        1.0
+    }
+}
+
+impl SharedOwnership for DummyImage {
+    fn share(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[derive(Clone)]
+struct DummyLabel(usize);
+
+impl Label for DummyLabel {
+    fn width(&self, scale: f32) -> f32 {
+        0.5 * self.0 as f32 * scale
+    }
+
+    fn width_without_max_width(&self, scale: f32) -> f32 {
+        self.width(scale)
+    }
+}
+
+impl SharedOwnership for DummyLabel {
+    fn share(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl ResourceAllocator for Dummy {
+    type PathBuilder = Dummy;
+    type Path = ();
+    type Image = DummyImage;
+    type Font = ();
+    type Label = DummyLabel;
+
+    fn path_builder(&mut self) -> Self::PathBuilder {
+        Dummy
+    }
+
+    fn create_image(&mut self, data: &[u8]) -> Option<Self::Image> {
+        if data.is_empty() {
+            None
+        } else {
+            Some(DummyImage)
+        }
+    }
+
+    fn create_font(&mut self, _: Option<&Font>, _: FontKind) -> Self::Font {}
+
+    fn create_label(&mut self, text: &str, _: &mut Self::Font, _: Option<f32>) -> Self::Label {
+        DummyLabel(text.chars().count())
+    }
+
+    fn update_label(
+        &mut self,
+        label: &mut Self::Label,
+        text: &str,
+        _: &mut Self::Font,
+        _: Option<f32>,
+    ) {
+        label.0 = text.chars().count();
+    }
+}
+
+const WIDTH: f32 = 300.0;
+
+enum Kind {
+    Path,
+    Image,
+    Label { width: f32 },
+}
+
+fn render(mirrored: bool) -> Vec<(Kind, Transform)> {
+    let mut run = create_run(&["A", "B", "C"]);
+    run.set_game_name("Game");
+    run.set_category_name("Category");
+    run.set_game_icon(settings::Image::new([1, 2, 3].as_slice().into(), 64));
+    let timer = Timer::new(run).unwrap();
+
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut manager = SceneManager::new(Dummy);
+    manager.set_mirrored(mirrored);
+    manager.update_scene(Dummy, [WIDTH, 500.0], &state, &image_cache);
+
+    let scene = manager.scene();
+    scene
+        .bottom_layer()
+        .iter()
+        .chain(scene.top_layer())
+        .map(|entity| match entity {
+            Entity::FillPath(_, _, transform) | Entity::StrokePath(_, _, _, transform) => {
+                (Kind::Path, *transform)
+            }
+            Entity::Image(_, transform) => (Kind::Image, *transform),
+            Entity::Label(label, _, transform) | Entity::StrokeLabel(label, _, _, transform) => (
+                Kind::Label {
+                    width: label.width(1.0),
+                },
+                *transform,
+            ),
+        })
+        .collect()
+}
+
+#[track_caller]
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 1e-3, "{a} != {b}");
+}
+
+#[test]
+fn mirroring_flips_positions() {
+    let normal = render(false);
+    let mirrored = render(true);
+
+    assert_eq!(normal.len(), mirrored.len());
+
+    for ((kind, normal), (_, mirrored)) in normal.iter().zip(&mirrored) {
+        assert_close(mirrored.y, normal.y);
+        assert_close(mirrored.scale_y, normal.scale_y);
+
+        let width = match *kind {
+            Kind::Path => {
+                assert_close(mirrored.scale_x, -normal.scale_x);
+                assert_close(mirrored.x, WIDTH - normal.x);
+                continue;
+            }
+            Kind::Image => 1.0,
+            Kind::Label { width } => width,
+        };
+
+        // Images and labels are not flipped, only their position is.
+        assert!(mirrored.scale_x > 0.0);
+        assert_close(mirrored.scale_x, normal.scale_x);
+        assert_close(mirrored.x, WIDTH - normal.x - normal.scale_x * width);
+    }
+}
+
+#[test]
+fn mirroring_does_not_flip_icons() {
+    let icons = render(true)
+        .into_iter()
+        .filter(|(kind, _)| matches!(kind, Kind::Image))
+        .collect::<Vec<_>>();
+
+    assert!(!icons.is_empty());
+
+    for (_, transform) in icons {
+        assert!(transform.scale_x > 0.0);
+        // The icon is on the right side now.
+        assert!(transform.x > 0.5 * WIDTH);
+    }
+}
+
+const HIGHLIGHT: Color = Color::rgba(1.0, 0.0, 1.0, 1.0);
+
+fn splits_layout() -> (Timer, Layout) {
+    let run = create_run(&["A", "B", "C"]);
+    let timer = Timer::new(run).unwrap();
+
+    let mut layout = Layout::default_layout();
+    for component in &mut layout.components {
+        if let Component::Splits(splits) = component {
+            splits.settings_mut().current_split_gradient = Gradient::Plain(HIGHLIGHT);
+        }
+    }
+
+    (timer, layout)
+}
+
+fn update(
+    manager: &mut SceneManager<(), DummyImage, (), DummyLabel>,
+    layout: &mut Layout,
+    timer: &Timer,
+) {
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    manager.update_scene(Dummy, [WIDTH, 500.0], &state, &image_cache);
+}
+
+/// Returns the transforms and opacities of the highlights of the current split.
+fn highlights(manager: &SceneManager<(), DummyImage, (), DummyLabel>) -> Vec<(Transform, f32)> {
+    manager
+        .scene()
+        .bottom_layer()
+        .iter()
+        .filter_map(|entity| match entity {
+            Entity::FillPath(_, FillShader::SolidColor([r, g, b, a]), transform)
+                if [*r, *g, *b] == [HIGHLIGHT.red, HIGHLIGHT.green, HIGHLIGHT.blue] =>
+            {
+                Some((*transform, *a))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn final_highlight(layout: &mut Layout, timer: &Timer) -> Transform {
+    let mut manager = SceneManager::new(Dummy);
+    update(&mut manager, layout, timer);
+    match highlights(&manager)[..] {
+        [(transform, _)] => transform,
+        ref highlights => panic!("Expected a single highlight, got {}", highlights.len()),
+    }
+}
+
+#[track_caller]
+fn assert_transform(a: Transform, b: Transform) {
+    assert_close(a.x, b.x);
+    assert_close(a.y, b.y);
+    assert_close(a.scale_x, b.scale_x);
+    assert_close(a.scale_y, b.scale_y);
+}
+
+fn outlined_scene(
+    text_outline_color: Option<Color>,
+) -> SceneManager<(), DummyImage, (), DummyLabel> {
+    let timer = Timer::new(create_run(&["A", "B", "C"])).unwrap();
+    let mut layout = Layout::default_layout();
+    let general = layout.general_settings_mut();
+    general.text_outline_color = text_outline_color;
+    general.text_outline_width = 10;
+
+    let mut manager = SceneManager::new(Dummy);
+    update(&mut manager, &mut layout, &timer);
+    manager
+}
+
+#[test]
+fn text_outline_strokes_every_label() {
+    let outline = Color::rgba(0.0, 0.0, 0.0, 0.5);
+    let manager = outlined_scene(Some(outline));
+    let scene = manager.scene();
+
+    let mut labels = 0;
+    for layer in [scene.bottom_layer(), scene.top_layer()] {
+        for (index, entity) in layer.iter().enumerate() {
+            let Entity::Label(_, _, transform) = entity else {
+                continue;
+            };
+            labels += 1;
+
+            let Some(Entity::StrokeLabel(_, width, color, stroke_transform)) =
+                index.checked_sub(1).map(|index| &layer[index])
+            else {
+                panic!("The label isn't preceded by its outline");
+            };
+            // The stroke is twice as wide as the visible outline, as half of
+            // it is covered by the label itself.
+            assert_close(*width, 0.2);
+            assert_eq!(*color, outline.to_array());
+            assert_transform(*stroke_transform, *transform);
+        }
+    }
+    assert!(labels > 0);
+}
+
+#[test]
+fn text_outline_is_not_stroked_when_disabled() {
+    let manager = outlined_scene(None);
+    let scene = manager.scene();
+    let mut entities = scene.bottom_layer().iter().chain(scene.top_layer());
+
+    assert!(entities
+        .clone()
+        .any(|entity| matches!(entity, Entity::Label(..))));
+    assert!(!entities.any(|entity| matches!(entity, Entity::StrokeLabel(..))));
+}
+
+#[test]
+fn split_animation_slides_the_highlight() {
+    let (mut timer, mut layout) = splits_layout();
+    let mut manager = SceneManager::new(Dummy);
+    manager.set_split_animation(Some(SplitAnimation {
+        kind: SplitAnimationKind::Slide,
+        duration: 1.0,
+    }));
+
+    timer.start().unwrap();
+    update(&mut manager, &mut layout, &timer);
+    let before = final_highlight(&mut layout, &timer);
+    assert!(!manager.is_animating());
+
+    timer.split().unwrap();
+    update(&mut manager, &mut layout, &timer);
+    assert!(manager.is_animating());
+
+    manager.advance_animations(0.5);
+    update(&mut manager, &mut layout, &timer);
+    assert!(manager.is_animating());
+
+    let [(old, _), (new, _)] = highlights(&manager)[..] else {
+        panic!("Expected the highlight to span two splits");
+    };
+    let row_height = before.scale_y;
+    assert_close(old.y, before.y + 0.5 * row_height);
+    assert_close(old.scale_y, 0.5 * row_height);
+    assert_close(new.y, before.y + row_height);
+    assert_close(new.scale_y, 0.5 * row_height);
+
+    manager.advance_animations(0.5);
+    update(&mut manager, &mut layout, &timer);
+    assert!(!manager.is_animating());
+
+    let [(after, _)] = highlights(&manager)[..] else {
+        panic!("Expected a single highlight");
+    };
+    assert_transform(after, final_highlight(&mut layout, &timer));
+}
+
+#[test]
+fn split_animation_fades_the_highlight() {
+    let (mut timer, mut layout) = splits_layout();
+    let mut manager = SceneManager::new(Dummy);
+    manager.set_split_animation(Some(SplitAnimation {
+        kind: SplitAnimationKind::Fade,
+        duration: 2.0,
+    }));
+
+    timer.start().unwrap();
+    update(&mut manager, &mut layout, &timer);
+    let before = final_highlight(&mut layout, &timer);
+
+    timer.split().unwrap();
+    update(&mut manager, &mut layout, &timer);
+    manager.advance_animations(1.0);
+    update(&mut manager, &mut layout, &timer);
+
+    let [(old, old_opacity), (new, new_opacity)] = highlights(&manager)[..] else {
+        panic!("Expected both highlights to be visible");
+    };
+    assert_transform(old, before);
+    assert_close(old_opacity, 0.5);
+    assert_close(new.y, before.y + before.scale_y);
+    assert_close(new_opacity, 0.5);
+
+    manager.advance_animations(1.0);
+    update(&mut manager, &mut layout, &timer);
+    assert!(!manager.is_animating());
+
+    let [(after, opacity)] = highlights(&manager)[..] else {
+        panic!("Expected a single highlight");
+    };
+    assert_close(opacity, 1.0);
+    assert_transform(after, final_highlight(&mut layout, &timer));
+}
+
+/// Returns the vertical position of the label with the amount of characters
+/// provided.
+fn label_y(manager: &SceneManager<(), DummyImage, (), DummyLabel>, chars: usize) -> f32 {
+    let scene = manager.scene();
+    let mut positions = scene
+        .bottom_layer()
+        .iter()
+        .chain(scene.top_layer())
+        .filter_map(|entity| match entity {
+            Entity::Label(label, _, transform) if label.0 == chars => Some(transform.y),
+            _ => None,
+        });
+    let y = positions.next().expect("The label isn't shown");
+    assert!(positions.next().is_none(), "The label isn't unique");
+    y
+}
+
+#[test]
+fn split_animation_slides_the_splits_when_they_scroll() {
+    // The names are longer than any other text, so their labels can be told
+    // apart by their length.
+    let names = ["A", "BB", "CCC", "DDDD"].map(|name| name.repeat(7));
+    let [_, b, _, d] = names.each_ref().map(String::len);
+    let mut timer = Timer::new(create_run(&names.each_ref().map(String::as_str))).unwrap();
+
+    let mut layout = Layout::default_layout();
+    for component in &mut layout.components {
+        if let Component::Splits(splits) = component {
+            let settings = splits.settings_mut();
+            settings.visual_split_count = 3;
+            settings.split_preview_count = 1;
+            settings.always_show_last_split = false;
+        }
+    }
+
+    let mut manager = SceneManager::new(Dummy);
+    manager.set_split_animation(Some(SplitAnimation {
+        kind: SplitAnimationKind::Slide,
+        duration: 1.0,
+    }));
+
+    timer.start().unwrap();
+    timer.split().unwrap();
+    update(&mut manager, &mut layout, &timer);
+    manager.advance_animations(1.0);
+    update(&mut manager, &mut layout, &timer);
+    assert!(!manager.is_animating());
+    let b_before = label_y(&manager, b);
+
+    // The third split needs the fourth one to be previewed, so the splits
+    // scroll by one.
+    timer.split().unwrap();
+    update(&mut manager, &mut layout, &timer);
+    assert!(manager.is_animating());
+    assert_close(label_y(&manager, b), b_before);
+
+    let mut target = SceneManager::new(Dummy);
+    update(&mut target, &mut layout, &timer);
+    let (b_after, d_after) = (label_y(&target, b), label_y(&target, d));
+    let row_height = b_before - b_after;
+    assert!(row_height > 0.0);
+
+    manager.advance_animations(0.5);
+    update(&mut manager, &mut layout, &timer);
+    assert_close(label_y(&manager, b), b_after + 0.5 * row_height);
+    // The split that wasn't shown before slides in along with the others.
+    assert_close(label_y(&manager, d), d_after + 0.5 * row_height);
+
+    manager.advance_animations(0.5);
+    update(&mut manager, &mut layout, &timer);
+    assert!(!manager.is_animating());
+    assert_close(label_y(&manager, b), b_after);
+    assert_close(label_y(&manager, d), d_after);
+}
+
+#[test]
+fn split_animation_is_disabled_by_default() {
+    let (mut timer, mut layout) = splits_layout();
+    let mut manager = SceneManager::new(Dummy);
+
+    timer.start().unwrap();
+    update(&mut manager, &mut layout, &timer);
+    timer.split().unwrap();
+    update(&mut manager, &mut layout, &timer);
+
+    assert!(!manager.is_animating());
+    let [(highlight, _)] = highlights(&manager)[..] else {
+        panic!("Expected a single highlight");
+    };
+    assert_transform(highlight, final_highlight(&mut layout, &timer));
+}
+
+#[track_caller]
+fn assert_endpoints(actual: [[f32; 2]; 2], expected: [[f32; 2]; 2]) {
+    for (a, b) in actual.iter().flatten().zip(expected.iter().flatten()) {
+        assert_close(*a, *b);
+    }
+}
+
+#[test]
+fn angular_gradient_endpoints_match_the_axis_aligned_gradients() {
+    let (min, max) = ([10.0, 20.0], [50.0, 40.0]);
+    assert_endpoints(
+        angular_gradient_endpoints(0.0, min, max),
+        [[30.0, 20.0], [30.0, 40.0]],
+    );
+    assert_endpoints(
+        angular_gradient_endpoints(90.0, min, max),
+        [[10.0, 30.0], [50.0, 30.0]],
+    );
+}
+
+#[test]
+fn angular_gradient_endpoints_reach_the_corners() {
+    assert_endpoints(
+        angular_gradient_endpoints(45.0, [0.0, 0.0], [1.0, 1.0]),
+        [[0.0, 0.0], [1.0, 1.0]],
+    );
+    assert_endpoints(
+        angular_gradient_endpoints(-45.0, [0.0, 0.0], [1.0, 1.0]),
+        [[1.0, 0.0], [0.0, 1.0]],
+    );
+}
+
+#[test]
+fn angular_gradient_setting_becomes_an_angular_shader() {
+    let (top, bottom) = (
+        Color::rgba(1.0, 0.0, 0.0, 1.0),
+        Color::rgba(0.0, 0.0, 1.0, 1.0),
+    );
+    assert!(
+        decode_gradient(&Gradient::Angular(top, bottom, 45.0))
+            == Some(FillShader::AngularGradient {
+                top: [1.0, 0.0, 0.0, 1.0],
+                bottom: [0.0, 0.0, 1.0, 1.0],
+                angle_degrees: 45.0,
+            })
+    );
+}
+
+#[cfg(feature = "svg-rendering")]
+fn render_svg(layout: &mut Layout, timer: &Timer) -> String {
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    super::svg::render(&state, &image_cache)
+}
+
+/// Parses the SVG document and returns the name of its root element and the
+/// number of `path` elements in it.
+#[cfg(feature = "svg-rendering")]
+#[track_caller]
+fn parse_svg(svg: &str) -> (Option<&str>, usize) {
+    use crate::util::xml::{Event, Reader};
+
+    let mut reader = Reader::new(svg);
+    let mut open_tags = Vec::new();
+    let mut root = None;
+    let mut paths = 0;
+
+    loop {
+        match reader.read_event().expect("The SVG is not well-formed") {
+            Event::Start(tag) => {
+                let (name, _) = tag.name_and_attributes();
+                root.get_or_insert(name.name());
+                if name.name() == "path" {
+                    paths += 1;
+                }
+                open_tags.push(name.name());
+            }
+            Event::End(name) => assert_eq!(open_tags.pop(), Some(name.name())),
+            Event::Ended => break,
+            _ => {}
+        }
+    }
+
+    assert!(open_tags.is_empty());
+    (root, paths)
+}
+
+#[cfg(feature = "svg-rendering")]
+#[test]
+fn svg_export_is_well_formed() {
+    use crate::component::{splits, timer};
+
+    let mut timer = Timer::new(create_run(&["A", "B", "C"])).unwrap();
+    timer.start().unwrap();
+    timer.split().unwrap();
+
+    let mut layout = Layout::new();
+    layout.push(timer::Component::new());
+    layout.push(splits::Component::new());
+
+    let svg = render_svg(&mut layout, &timer);
+    let (root, paths) = parse_svg(&svg);
+    assert_eq!(root, Some("svg"));
+    assert!(paths > 0);
+}
+
+#[cfg(feature = "svg-rendering")]
+#[test]
+fn svg_export_emits_a_path_per_distinct_glyph() {
+    use crate::component::text;
+
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+
+    // Each of these glyphs consists of curves, so each of them is emitted as
+    // a `path` element. Repeated glyphs refer to the same element, and the
+    // rest of the text component is drawn without any paths.
+    let mut layout = Layout::new();
+    layout.push(text::Component::with_settings(text::Settings {
+        text: text::Text::Center("SOCS OS".into()),
+        ..Default::default()
+    }));
+
+    let svg = render_svg(&mut layout, &timer);
+    let (root, paths) = parse_svg(&svg);
+    assert_eq!(root, Some("svg"));
+    assert_eq!(paths, 3);
+}
@@ -24,8 +24,8 @@ use crate::{
 use self::bindings::CanvasRenderingContext2d;
 
 use super::{
-    Background, Entity, FillShader, FontKind, Label, PathBuilder, ResourceAllocator, SceneManager,
-    SharedOwnership, Transform,
+    angular_gradient_endpoints, Background, Entity, FillShader, FontKind, Label, PathBuilder,
+    ResourceAllocator, SceneManager, SharedOwnership, Transform,
 };
 
 mod bindings;
@@ -558,6 +558,11 @@ impl Renderer {
         &self.div
     }
 
+    /// Sets whether the layout should be rendered horizontally mirrored.
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.manager.set_mirrored(mirrored);
+    }
+
     /// Renders the layout state into the canvas. The image cache is used to
     /// retrieve images that are used in the layout state.
     pub fn render(&mut self, state: &LayoutState, image_cache: &ImageCache) -> Option<[f32; 2]> {
@@ -709,6 +714,7 @@ enum HashShader {
     SolidColor([u32; 4]),
     VerticalGradient([u32; 4], [u32; 4], [u32; 2]),
     HorizontalGradient([u32; 4], [u32; 4], [u32; 2]),
+    AngularGradient([u32; 4], [u32; 4], u32, [u32; 4]),
 }
 
 trait HasBounds {
@@ -766,6 +772,19 @@ fn set_fill_style(
         FillShader::HorizontalGradient(l, r) => {
             HashShader::HorizontalGradient(cast(l), cast(r), cast(handle.bounds_x()))
         }
+        FillShader::AngularGradient {
+            top,
+            bottom,
+            angle_degrees,
+        } => {
+            let ([min_x, max_x], [min_y, max_y]) = (handle.bounds_x(), handle.bounds_y());
+            HashShader::AngularGradient(
+                cast(top),
+                cast(bottom),
+                angle_degrees.to_bits(),
+                cast([min_x, min_y, max_x, max_y]),
+            )
+        }
     };
     let style = cache.entry(hash_shader).or_insert_with(|| match shader {
         FillShader::SolidColor(c) => JsValue::from_str(color(str_buf, c)),
@@ -783,6 +802,20 @@ fn set_fill_style(
             let _ = gradient.add_color_stop(1.0, color(str_buf, r));
             gradient.unchecked_into()
         }
+        FillShader::AngularGradient {
+            top,
+            bottom,
+            angle_degrees,
+        } => {
+            let ([min_x, max_x], [min_y, max_y]) = (handle.bounds_x(), handle.bounds_y());
+            let [[start_x, start_y], [end_x, end_y]] =
+                angular_gradient_endpoints(*angle_degrees, [min_x, min_y], [max_x, max_y]);
+            let gradient =
+                ctx.create_linear_gradient(start_x as _, start_y as _, end_x as _, end_y as _);
+            let _ = gradient.add_color_stop(0.0, color(str_buf, top));
+            let _ = gradient.add_color_stop(1.0, color(str_buf, bottom));
+            gradient.unchecked_into()
+        }
     });
     ctx.set_fill_style(style);
 }
@@ -860,6 +893,43 @@ fn render_layer(
                     }
                 }
             }
+            Entity::StrokeLabel(label, stroke_width, color, transform) => {
+                let label = label.0.borrow();
+                let label = &*label;
+                set_font(ctx, &label.font);
+                ctx.set_line_width((*stroke_width / FONT_SCALE_FACTOR) as f64);
+                ctx.set_line_join("round");
+                set_stroke_style(color, ctx, str_buf, cache);
+
+                set_transform(
+                    ctx,
+                    &transform.pre_scale(FONT_SCALE_FACTOR, FONT_SCALE_FACTOR),
+                );
+
+                match &label.shape {
+                    LabelShape::Normal(text) => {
+                        let _ = ctx.stroke_text(text, 0.0, 0.0);
+                    }
+                    LabelShape::MonospaceEmulation(pieces) => {
+                        for piece in pieces {
+                            match piece {
+                                MonospacePiece::Digit { digit, offset } => {
+                                    let _ = ctx.stroke_text(
+                                        &digits[*digit as usize],
+                                        *offset as f64,
+                                        0.0,
+                                    );
+                                }
+                                MonospacePiece::Chunk { chunk, offset } => {
+                                    let _ = ctx.stroke_text(chunk, *offset as f64, 0.0);
+                                }
+                            }
+                        }
+                    }
+                }
+
+                ctx.set_line_join("miter");
+            }
         }
     }
 }
@@ -4,7 +4,7 @@
 //! current state of the editor as state objects that can be visualized by any
 //! kind of User Interface.
 
-use super::{AddComparisonError, CopyComparisonError, LinkedLayout};
+use super::{AddComparisonError, CopyComparisonError, GameTimeEndIndexError, LinkedLayout};
 use crate::{
     comparison,
     platform::prelude::*,
@@ -13,7 +13,8 @@ use crate::{
     util::{caseless, PopulateString},
     Run, Segment, Time, TimeSpan, TimingMethod,
 };
-use core::{mem::swap, num::ParseIntError};
+use alloc::collections::VecDeque;
+use core::{cmp::Ordering, mem::swap, num::ParseIntError};
 use snafu::{OptionExt, ResultExt};
 
 pub mod cleaning;
@@ -43,6 +44,16 @@ pub enum ParseError {
     NegativeTimeNotAllowed,
     /// Empty times are not allowed here.
     EmptyTimeNotAllowed,
+    /// Couldn't parse the frame count.
+    ParseFrameCount {
+        /// The underlying error.
+        source: ParseIntError,
+    },
+    /// There is no valid frame rate to convert the frame count with.
+    MissingFrameRate,
+    /// The frame count is too large to be represented as a time at the frame
+    /// rate used.
+    FrameCountOutOfRange,
 }
 
 /// Describes an Error that occurred while opening the Run Editor.
@@ -67,16 +78,40 @@ pub enum RenameError {
     },
 }
 
+/// Error type for failing to set the segment names from lines of text.
+#[derive(PartialEq, Eq, Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum SegmentNamesError {
+    /// The text doesn't contain any segment names, but a Run needs at least
+    /// one segment.
+    NoSegmentNames,
+}
+
+/// The number of edits that can be undone by default.
+pub const DEFAULT_UNDO_LIMIT: usize = 100;
+
 /// The Run Editor allows modifying Runs while ensuring that all the different
 /// invariants of the Run objects are upheld no matter what kind of operations
 /// are being applied to the Run. It provides the current state of the editor as
-/// state objects that can be visualized by any kind of User Interface.
+/// state objects that can be visualized by any kind of User Interface. All the
+/// edits can be undone and redone.
 pub struct Editor {
     run: Run,
     selected_method: TimingMethod,
     selected_segments: Vec<usize>,
     previous_personal_best_time: Time,
     segment_times: Vec<Option<TimeSpan>>,
+    undo_stack: VecDeque<UndoState>,
+    redo_stack: Vec<UndoState>,
+    undo_limit: usize,
+}
+
+/// The state of the editor before or after an edit that is restored when
+/// undoing or redoing it.
+struct UndoState {
+    run: Run,
+    selected_segments: Vec<usize>,
+    previous_personal_best_time: Time,
 }
 
 impl Editor {
@@ -97,6 +132,9 @@ impl Editor {
             selected_segments: vec![0],
             previous_personal_best_time: personal_best_time,
             segment_times: Vec::with_capacity(len),
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            undo_limit: DEFAULT_UNDO_LIMIT,
         };
 
         editor.update_segment_list();
@@ -208,6 +246,82 @@ impl Editor {
         self.run.mark_as_modified();
     }
 
+    fn undo_state(&self) -> UndoState {
+        UndoState {
+            run: self.run.clone(),
+            selected_segments: self.selected_segments.clone(),
+            previous_personal_best_time: self.previous_personal_best_time,
+        }
+    }
+
+    fn restore(&mut self, state: UndoState) {
+        self.run = state.run;
+        self.selected_segments = state.selected_segments;
+        self.previous_personal_best_time = state.previous_personal_best_time;
+        self.update_segment_list();
+        self.raise_run_edited();
+    }
+
+    /// Stores the current state before an edit is applied, so that the edit
+    /// can be undone. Any edits that were undone can't be redone anymore.
+    fn save_undo_state(&mut self) {
+        self.redo_stack.clear();
+        if self.undo_limit == 0 {
+            return;
+        }
+        if self.undo_stack.len() >= self.undo_limit {
+            self.undo_stack.pop_front();
+        }
+        let state = self.undo_state();
+        self.undo_stack.push_back(state);
+    }
+
+    /// Checks if there is an edit that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Checks if there is an undone edit that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Undoes the most recent edit. The segments that were selected before the
+    /// edit are selected again. If there is no edit to undo, nothing happens.
+    pub fn undo(&mut self) {
+        if let Some(state) = self.undo_stack.pop_back() {
+            self.redo_stack.push(self.undo_state());
+            self.restore(state);
+        }
+    }
+
+    /// Redoes the most recently undone edit. Doing any other edit in the
+    /// meantime discards the edits that can be redone. If there is no edit to
+    /// redo, nothing happens.
+    pub fn redo(&mut self) {
+        if let Some(state) = self.redo_stack.pop() {
+            self.undo_stack.push_back(self.undo_state());
+            self.restore(state);
+        }
+    }
+
+    /// Accesses the maximum number of edits that can be undone.
+    pub const fn undo_limit(&self) -> usize {
+        self.undo_limit
+    }
+
+    /// Sets the maximum number of edits that can be undone. Every edit stores a
+    /// copy of the whole Run, so this bounds the memory used for undoing. If
+    /// more edits are stored than the new limit allows, the oldest ones are
+    /// discarded. The default limit is [`DEFAULT_UNDO_LIMIT`].
+    pub fn set_undo_limit(&mut self, limit: usize) {
+        self.undo_limit = limit;
+        let excess = self.undo_stack.len().saturating_sub(limit);
+        self.undo_stack.drain(..excess);
+        let excess = self.redo_stack.len().saturating_sub(limit);
+        self.redo_stack.drain(..excess);
+    }
+
     /// Accesses the name of the game.
     pub fn game_name(&self) -> &str {
         self.run.game_name()
@@ -218,6 +332,7 @@ impl Editor {
     where
         S: PopulateString,
     {
+        self.save_undo_state();
         self.run.set_game_name(name);
         self.raise_run_edited();
         self.run.clear_run_id();
@@ -233,6 +348,7 @@ impl Editor {
     where
         S: PopulateString,
     {
+        self.save_undo_state();
         self.run.set_category_name(name);
         self.raise_run_edited();
         self.run.clear_run_id();
@@ -247,6 +363,7 @@ impl Editor {
     /// Sets the timer offset. The timer offset specifies the time, the timer
     /// starts at when starting a new attempt.
     pub fn set_offset(&mut self, offset: TimeSpan) {
+        self.save_undo_state();
         self.run.set_offset(offset);
         self.raise_run_edited();
     }
@@ -268,6 +385,7 @@ impl Editor {
     /// history or the segment history. This number is mostly just a visual
     /// number for the runner.
     pub fn set_attempt_count(&mut self, attempts: u32) {
+        self.save_undo_state();
         self.run.set_attempt_count(attempts);
         self.raise_run_edited();
     }
@@ -280,6 +398,29 @@ impl Editor {
         Ok(())
     }
 
+    /// Accesses the index of the segment at which the game time stops. Once
+    /// this segment is split, the game time is frozen for the remainder of the
+    /// attempt, while the real time keeps running.
+    pub const fn game_time_end_index(&self) -> Option<usize> {
+        self.run.game_time_end_index()
+    }
+
+    /// Sets the index of the segment at which the game time stops. Specify
+    /// [`None`] if the game time should keep running until the final split. An
+    /// index that doesn't refer to any of the segments is rejected.
+    pub fn set_game_time_end_index(
+        &mut self,
+        index: Option<usize>,
+    ) -> Result<(), GameTimeEndIndexError> {
+        if index.is_some_and(|index| index >= self.run.len()) {
+            return Err(GameTimeEndIndexError::OutOfBounds);
+        }
+        self.save_undo_state();
+        self.run.set_game_time_end_index(index)?;
+        self.raise_run_edited();
+        Ok(())
+    }
+
     /// Accesses the game's icon.
     pub const fn game_icon(&self) -> &Image {
         self.run.game_icon()
@@ -287,12 +428,14 @@ impl Editor {
 
     /// Sets the game's icon.
     pub fn set_game_icon(&mut self, image: Image) {
+        self.save_undo_state();
         self.run.set_game_icon(image);
         self.raise_run_edited();
     }
 
     /// Removes the game's icon.
     pub fn remove_game_icon(&mut self) {
+        self.save_undo_state();
         self.run.set_game_icon(Image::EMPTY.clone());
         self.raise_run_edited();
     }
@@ -300,6 +443,7 @@ impl Editor {
     /// Sets the [`LinkedLayout`] of the [`Run`]. If a [`Layout`](crate::Layout)
     /// is linked, it is supposed to be loaded to visualize the [`Run`].
     pub fn set_linked_layout(&mut self, linked_layout: Option<LinkedLayout>) {
+        self.save_undo_state();
         self.run.set_linked_layout(linked_layout);
         self.raise_run_edited();
     }
@@ -316,6 +460,7 @@ impl Editor {
     where
         S: PopulateString,
     {
+        self.save_undo_state();
         self.run.metadata_mut().set_run_id(id);
         self.raise_run_edited();
     }
@@ -326,6 +471,7 @@ impl Editor {
     where
         S: PopulateString,
     {
+        self.save_undo_state();
         self.run.metadata_mut().set_region_name(name);
         self.metadata_modified();
     }
@@ -336,6 +482,7 @@ impl Editor {
     where
         S: PopulateString,
     {
+        self.save_undo_state();
         self.run.metadata_mut().set_platform_name(name);
         self.metadata_modified();
     }
@@ -343,10 +490,36 @@ impl Editor {
     /// Specifies whether this speedrun is done on an emulator. Keep in mind
     /// that `false` may also mean that this information is simply not known.
     pub fn set_emulator_usage(&mut self, uses_emulator: bool) {
+        self.save_undo_state();
         self.run.metadata_mut().set_emulator_usage(uses_emulator);
         self.metadata_modified();
     }
 
+    /// Sets the frame rate of the game in frames per second. Frame rates that
+    /// are not positive finite numbers are treated as not specified.
+    pub fn set_frame_rate(&mut self, frame_rate: Option<f64>) {
+        self.save_undo_state();
+        self.run.metadata_mut().set_frame_rate(frame_rate);
+        self.raise_run_edited();
+    }
+
+    /// Parses a frame count and converts it to a time. If a frame rate is
+    /// provided, that frame rate is used. Otherwise the frame rate stored in
+    /// the Run's metadata is used. The frame rate needs to be a positive finite
+    /// number.
+    pub fn parse_frame_count(
+        &self,
+        frames: &str,
+        frame_rate: Option<f64>,
+    ) -> Result<TimeSpan, ParseError> {
+        let frames = frames.trim().parse().context(ParseFrameCount)?;
+        let frame_rate = frame_rate
+            .or(self.run.metadata().frame_rate())
+            .filter(|&fps| fps.is_finite() && fps > 0.0)
+            .context(MissingFrameRate)?;
+        TimeSpan::from_frames(frames, frame_rate).context(FrameCountOutOfRange)
+    }
+
     /// Sets the speedrun.com variable with the name specified to the value
     /// specified. A variable is an arbitrary key value pair storing additional
     /// information about the category. An example of this may be whether
@@ -357,6 +530,7 @@ impl Editor {
         N: PopulateString,
         V: PopulateString,
     {
+        self.save_undo_state();
         self.run
             .metadata_mut()
             .set_speedrun_com_variable(name, value);
@@ -365,6 +539,7 @@ impl Editor {
 
     /// Removes the speedrun.com variable with the name specified.
     pub fn remove_speedrun_com_variable(&mut self, name: &str) {
+        self.save_undo_state();
         self.run.metadata_mut().remove_speedrun_com_variable(name);
         self.metadata_modified();
     }
@@ -377,6 +552,7 @@ impl Editor {
     where
         N: PopulateString,
     {
+        self.save_undo_state();
         self.run
             .metadata_mut()
             .custom_variable_mut(name)
@@ -392,14 +568,18 @@ impl Editor {
         N: PopulateString,
         V: PopulateString,
     {
-        if self.run.metadata().custom_variable(name.as_str()).is_none() {
+        if !self
+            .run
+            .metadata()
+            .custom_variable(name.as_str())
+            .is_some_and(|variable| variable.is_permanent)
+        {
             return;
         }
+        self.save_undo_state();
         let variable = self.run.metadata_mut().custom_variable_mut(name);
-        if variable.is_permanent {
-            value.populate(&mut variable.value);
-            self.raise_run_edited();
-        }
+        value.populate(&mut variable.value);
+        self.raise_run_edited();
     }
 
     /// Removes the custom variable with the name specified. If the custom
@@ -408,6 +588,7 @@ impl Editor {
     pub fn remove_custom_variable(&mut self, name: &str) {
         if let Some(variable) = self.run.metadata().custom_variable(name) {
             if variable.is_permanent {
+                self.save_undo_state();
                 self.run.metadata_mut().remove_custom_variable(name);
                 self.raise_run_edited();
             }
@@ -416,6 +597,7 @@ impl Editor {
 
     /// Resets all the Metadata Information.
     pub fn clear_metadata(&mut self) {
+        self.save_undo_state();
         self.run.metadata_mut().clear();
         self.raise_run_edited();
     }
@@ -483,6 +665,7 @@ impl Editor {
     /// Run's history information accordingly. The newly created segment is then
     /// the only selected segment and also the active segment.
     pub fn insert_segment_above(&mut self) {
+        self.save_undo_state();
         let active_segment = self.active_segment_index();
 
         let mut segment = Segment::new("");
@@ -494,6 +677,7 @@ impl Editor {
             segment.segment_history_mut().insert(x, Default::default());
         }
         self.run.segments_mut().insert(active_segment, segment);
+        self.game_time_end_segment_inserted(active_segment);
 
         self.select_only(active_segment);
 
@@ -505,6 +689,7 @@ impl Editor {
     /// Run's history information accordingly. The newly created segment is then
     /// the only selected segment and also the active segment.
     pub fn insert_segment_below(&mut self) {
+        self.save_undo_state();
         let active_segment = self.active_segment_index();
         let next_segment = active_segment + 1;
 
@@ -519,6 +704,7 @@ impl Editor {
             segment.segment_history_mut().insert(x, Default::default());
         }
         self.run.segments_mut().insert(next_segment, segment);
+        self.game_time_end_segment_inserted(next_segment);
 
         self.select_only(next_segment);
 
@@ -526,6 +712,66 @@ impl Editor {
         self.fix();
     }
 
+    /// Inserts a copy of the active segment below it and adjusts the Run's
+    /// history information accordingly. Only the name and the icon are
+    /// copied. Just like with a newly inserted segment, the comparison times,
+    /// the best segment time and the segment history of the copy are empty,
+    /// so the times of the active segment stay with the active segment. The
+    /// newly created segment is then the only selected segment and also the
+    /// active segment.
+    pub fn duplicate_active_segment(&mut self) {
+        self.save_undo_state();
+        let active_segment = self.active_segment_index();
+        let next_segment = active_segment + 1;
+
+        let original = self.run.segment(active_segment);
+        let mut segment = Segment::new(original.name());
+        segment.set_icon(original.icon().clone());
+        if next_segment < self.run.len() {
+            self.run.import_best_segment(next_segment);
+        }
+
+        let max_index = self.run.max_attempt_history_index().unwrap_or(0);
+        let min_index = self.run.min_segment_history_index().unwrap();
+        for x in min_index..=max_index {
+            segment.segment_history_mut().insert(x, Default::default());
+        }
+        self.run.segments_mut().insert(next_segment, segment);
+        self.game_time_end_segment_inserted(next_segment);
+
+        self.select_only(next_segment);
+
+        self.times_modified();
+        self.fix();
+    }
+
+    /// Keeps the game time end pointing at the same segment after a segment got
+    /// inserted at the index provided.
+    fn game_time_end_segment_inserted(&mut self, index: usize) {
+        if let Some(end_index) = self.run.game_time_end_index() {
+            if end_index >= index {
+                let _ = self.run.set_game_time_end_index(Some(end_index + 1));
+            }
+        }
+    }
+
+    /// Keeps the game time end pointing at the same segment after the segment
+    /// at the index provided got removed. If the game time end itself got
+    /// removed, the next segment, which its times got merged into, becomes the
+    /// game time end. If there is no next segment, the game time ends at the
+    /// final split, as it does by default.
+    fn game_time_end_segment_removed(&mut self, index: usize) {
+        if let Some(end_index) = self.run.game_time_end_index() {
+            let end_index = if end_index > index {
+                end_index - 1
+            } else {
+                end_index
+            };
+            let end_index = Some(end_index).filter(|&i| i < self.run.len());
+            let _ = self.run.set_game_time_end_index(end_index);
+        }
+    }
+
     fn fix_after_deletion(&mut self, index: usize) {
         self.fix_with_timing_method(index, TimingMethod::RealTime);
         self.fix_with_timing_method(index, TimingMethod::GameTime);
@@ -612,12 +858,15 @@ impl Editor {
             return;
         }
 
+        self.save_undo_state();
+
         let mut removed = 0;
         for i in 0..self.run.len() {
             if self.selected_segments.contains(&i) {
                 let segment_index = i - removed;
                 self.fix_after_deletion(segment_index);
                 self.run.segments_mut().remove(segment_index);
+                self.game_time_end_segment_removed(segment_index);
                 removed += 1;
             }
         }
@@ -681,6 +930,14 @@ impl Editor {
         }
 
         swap(first, second);
+
+        // The game time end moves along with its segment.
+        let end_index = self.run.game_time_end_index();
+        if end_index == Some(index) {
+            let _ = self.run.set_game_time_end_index(Some(index + 1));
+        } else if end_index == Some(index + 1) {
+            let _ = self.run.set_game_time_end_index(Some(index));
+        }
     }
 
     /// Checks if the currently selected segments can be moved up. If any one of
@@ -697,6 +954,8 @@ impl Editor {
             return;
         }
 
+        self.save_undo_state();
+
         for i in 0..self.run.len() - 1 {
             if self.selected_segments.contains(&(i + 1)) {
                 self.switch_segments(i);
@@ -726,6 +985,8 @@ impl Editor {
             return;
         }
 
+        self.save_undo_state();
+
         for i in (0..self.run.len() - 1).rev() {
             if self.selected_segments.contains(&i) {
                 self.switch_segments(i);
@@ -742,12 +1003,65 @@ impl Editor {
         self.fix();
     }
 
+    /// Sets the names of all the segments from the text provided, where each
+    /// line is the name of a segment. The names are trimmed and trailing empty
+    /// lines are ignored. Segments are added at the end or removed from the end
+    /// to match the number of names, while the times of the segments that
+    /// remain are kept. If the text doesn't contain any names, the Run is left
+    /// untouched and an error is returned.
+    pub fn set_segment_names_from_lines(&mut self, text: &str) -> Result<(), SegmentNamesError> {
+        let mut names: Vec<&str> = text.split('\n').map(str::trim).collect();
+        while names.last().is_some_and(|name| name.is_empty()) {
+            names.pop();
+        }
+        if names.is_empty() {
+            return Err(SegmentNamesError::NoSegmentNames);
+        }
+
+        self.save_undo_state();
+
+        self.run.segments_mut().truncate(names.len());
+        if self
+            .run
+            .game_time_end_index()
+            .is_some_and(|index| index >= names.len())
+        {
+            let _ = self.run.set_game_time_end_index(None);
+        }
+        while self.run.len() < names.len() {
+            let mut segment = Segment::new("");
+            let max_index = self.run.max_attempt_history_index().unwrap_or(0);
+            let min_index = self.run.min_segment_history_index().unwrap();
+            for x in min_index..=max_index {
+                segment.segment_history_mut().insert(x, Default::default());
+            }
+            self.run.push_segment(segment);
+        }
+
+        for (segment, name) in self.run.segments_mut().iter_mut().zip(names) {
+            segment.set_name(name);
+        }
+
+        let len = self.run.len();
+        self.selected_segments.retain(|&i| i < len);
+        if self.selected_segments.is_empty() {
+            self.selected_segments.push(len - 1);
+        }
+
+        self.times_modified();
+        self.fix();
+
+        Ok(())
+    }
+
     /// Adds a new custom comparison. It can't be added if it starts with
     /// `[Race]` or it already exists.
     pub fn add_comparison<S: PopulateString>(
         &mut self,
         comparison: S,
     ) -> Result<(), AddComparisonError> {
+        self.run.validate_comparison_name(comparison.as_str())?;
+        self.save_undo_state();
         self.run.add_custom_comparison(comparison)?;
         self.fix();
         Ok(())
@@ -761,6 +1075,8 @@ impl Editor {
         run: &Run,
         comparison: &str,
     ) -> Result<(), AddComparisonError> {
+        self.run.validate_comparison_name(comparison)?;
+        self.save_undo_state();
         self.run.add_custom_comparison(comparison)?;
 
         let mut remaining_segments = self.run.segments_mut().as_mut_slice();
@@ -793,6 +1109,8 @@ impl Editor {
             return;
         }
 
+        self.save_undo_state();
+
         self.run
             .custom_comparisons_mut()
             .retain(|c| c != comparison);
@@ -808,6 +1126,88 @@ impl Editor {
         self.fix();
     }
 
+    /// Removes all the custom comparisons that don't store any times. A
+    /// comparison is considered empty if none of the segments have a time for
+    /// either timing method. The Personal Best is never removed, even if it is
+    /// empty.
+    pub fn remove_empty_comparisons(&mut self) {
+        let segments = self.run.segments();
+        let empty_comparisons = self
+            .run
+            .custom_comparisons()
+            .iter()
+            .filter(|&c| {
+                c != comparison::personal_best::NAME
+                    && segments.iter().all(|s| {
+                        let time = s.comparison(c);
+                        time.real_time.is_none() && time.game_time.is_none()
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if empty_comparisons.is_empty() {
+            return;
+        }
+
+        self.save_undo_state();
+
+        self.run
+            .custom_comparisons_mut()
+            .retain(|c| !empty_comparisons.contains(c));
+
+        for segment in self.run.segments_mut() {
+            for comparison in &empty_comparisons {
+                segment.comparisons_mut().remove(comparison);
+            }
+        }
+
+        self.fix();
+    }
+
+    /// Merges the custom comparisons whose names only differ in their casing or
+    /// in leading and trailing whitespace, which commonly happens when
+    /// importing comparisons. The first of these comparisons is kept and for
+    /// every segment and timing method it doesn't have a time for, it receives
+    /// the time of the comparisons merged into it. The Personal Best is never
+    /// merged with any other comparison.
+    pub fn deduplicate_comparisons(&mut self) {
+        let comparisons = self.run.custom_comparisons();
+        let duplicates = comparisons
+            .iter()
+            .enumerate()
+            .filter(|&(_, c)| c != comparison::personal_best::NAME)
+            .filter_map(|(index, duplicate)| {
+                let kept = comparisons[..index].iter().find(|&c| {
+                    c != comparison::personal_best::NAME && caseless::eq(c.trim(), duplicate.trim())
+                })?;
+                Some((kept.clone(), duplicate.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        if duplicates.is_empty() {
+            return;
+        }
+
+        self.save_undo_state();
+
+        self.run
+            .custom_comparisons_mut()
+            .retain(|c| !duplicates.iter().any(|(_, duplicate)| duplicate == c));
+
+        for segment in self.run.segments_mut() {
+            for (kept, duplicate) in &duplicates {
+                if let Some(time) = segment.comparisons_mut().remove(duplicate) {
+                    let kept = segment.comparison_mut(kept);
+                    kept.real_time = kept.real_time.or(time.real_time);
+                    kept.game_time = kept.game_time.or(time.game_time);
+                }
+            }
+        }
+
+        self.fix();
+    }
+
     /// Renames a comparison. The comparison can't be renamed if the new name of
     /// the comparison starts with `[Race]` or it already exists.
     pub fn rename_comparison(&mut self, old: &str, new: &str) -> Result<(), RenameError> {
@@ -819,14 +1219,17 @@ impl Editor {
             .validate_comparison_name(new)
             .context(InvalidName)?;
 
-        {
-            let comparison_name = self
-                .run
-                .custom_comparisons_mut()
-                .iter_mut()
-                .find(|c| *c == old)
-                .context(OldNameNotFound)?;
+        let index = self
+            .run
+            .custom_comparisons()
+            .iter()
+            .position(|c| c == old)
+            .context(OldNameNotFound)?;
+
+        self.save_undo_state();
 
+        {
+            let comparison_name = &mut self.run.custom_comparisons_mut()[index];
             comparison_name.clear();
             comparison_name.push_str(new);
         }
@@ -847,15 +1250,19 @@ impl Editor {
     /// one of the indices is invalid. The indices are based on the
     /// `comparison_names` field of the Run Editor's `State`.
     pub fn move_comparison(&mut self, src_index: usize, dst_index: usize) -> Result<(), ()> {
-        let comparisons = self.run.custom_comparisons_mut();
+        let len = self.run.custom_comparisons().len();
         let (src_index, dst_index) = (src_index + 1, dst_index + 1);
-        if src_index >= comparisons.len() || dst_index >= comparisons.len() {
+        if src_index >= len || dst_index >= len {
             return Err(());
         }
         if src_index == dst_index {
             return Ok(());
         }
 
+        self.save_undo_state();
+
+        let comparisons = self.run.custom_comparisons_mut();
+
         if src_index > dst_index {
             comparisons[dst_index..=src_index].rotate_left(src_index - dst_index);
         } else {
@@ -877,23 +1284,11 @@ impl Editor {
     /// modified by this, so you can call this again with the other timing
     /// method to generate the comparison times for both timing methods.
     pub fn generate_goal_comparison(&mut self, time: TimeSpan) {
-        if !self
-            .run
-            .custom_comparisons()
-            .iter()
-            .any(|c| c == comparison::goal::NAME)
-        {
+        self.save_undo_state();
+        // The name of the goal comparison is never invalid.
+        let _ =
             self.run
-                .custom_comparisons_mut()
-                .push(String::from(comparison::goal::NAME));
-        }
-
-        comparison::goal::generate_for_timing_method(
-            self.run.segments_mut(),
-            self.selected_method,
-            time,
-            comparison::goal::NAME,
-        );
+                .generate_goal_comparison(self.selected_method, time, comparison::goal::NAME);
 
         self.raise_run_edited();
     }
@@ -927,6 +1322,12 @@ impl Editor {
             return Err(CopyComparisonError::NoSuchComparison);
         }
 
+        self.run
+            .validate_comparison_name(new_name)
+            .map_err(|source| CopyComparisonError::AddComparison { source })?;
+
+        self.save_undo_state();
+
         self.run
             .add_custom_comparison(new_name)
             .map_err(|source| CopyComparisonError::AddComparison { source })?;
@@ -940,9 +1341,35 @@ impl Editor {
         Ok(())
     }
 
+    /// Imports all the Best Segment Times of the provided run for the timing
+    /// method specified that are faster than the ones of the Run being edited.
+    /// The segments are aligned by their position, just like with
+    /// [`Run::compare_best_segments`]. This allows combining the golds of
+    /// multiple splits files. The Personal Best is not modified.
+    pub fn merge_best_segments_from(&mut self, other: &Run, method: TimingMethod) {
+        self.save_undo_state();
+        let orderings = self.run.compare_best_segments(other, method);
+
+        for ((segment, other_segment), ordering) in self
+            .run
+            .segments_mut()
+            .iter_mut()
+            .zip(other.segments())
+            .zip(orderings)
+        {
+            if ordering == Ordering::Greater {
+                segment.best_segment_time_mut()[method] = other_segment.best_segment_time()[method];
+            }
+        }
+
+        self.times_modified();
+        self.fix();
+    }
+
     /// Clears out the Attempt History and the Segment Histories of all the
     /// segments.
     pub fn clear_history(&mut self) {
+        self.save_undo_state();
         self.run.clear_history();
         self.fix();
     }
@@ -952,6 +1379,7 @@ impl Editor {
     /// association. All Custom Comparisons other than `Personal Best` are
     /// deleted as well.
     pub fn clear_times(&mut self) {
+        self.save_undo_state();
         self.run.clear_times();
         self.fix();
     }
@@ -963,6 +1391,7 @@ impl Editor {
     /// best segments. The Sum of Best Cleaner will point out all of these and
     /// allows you to delete them individually if any of them seem wrong.
     pub fn clean_sum_of_best(&mut self) -> SumOfBestCleaner<'_> {
+        self.save_undo_state();
         SumOfBestCleaner::new(&mut self.run)
     }
 }
@@ -65,12 +65,14 @@ impl<'a> SegmentRow<&'a mut Editor> {
 
     /// Sets the icon of the segment.
     pub fn set_icon(&mut self, image: Image) {
+        self.editor.save_undo_state();
         self.editor.run.segment_mut(self.index).set_icon(image);
         self.editor.raise_run_edited();
     }
 
     /// Removes the icon of the segment.
     pub fn remove_icon(&mut self) {
+        self.editor.save_undo_state();
         self.editor
             .run
             .segment_mut(self.index)
@@ -83,12 +85,14 @@ impl<'a> SegmentRow<&'a mut Editor> {
     where
         S: PopulateString,
     {
+        self.editor.save_undo_state();
         self.editor.run.segment_mut(self.index).set_name(name);
         self.editor.raise_run_edited();
     }
 
     /// Sets the split time of the segment for the active timing method.
     pub fn set_split_time(&mut self, time: Option<TimeSpan>) {
+        self.editor.save_undo_state();
         let method = self.editor.selected_method;
         self.editor
             .run
@@ -107,6 +111,7 @@ impl<'a> SegmentRow<&'a mut Editor> {
 
     /// Sets the segment time of the segment for the active timing method.
     pub fn set_segment_time(&mut self, time: Option<TimeSpan>) {
+        self.editor.save_undo_state();
         self.editor.segment_times[self.index] = time;
         self.editor.fix_splits_from_segments();
         self.editor.times_modified();
@@ -122,6 +127,7 @@ impl<'a> SegmentRow<&'a mut Editor> {
 
     /// Sets the best segment time of the segment for the active timing method.
     pub fn set_best_segment_time(&mut self, time: Option<TimeSpan>) {
+        self.editor.save_undo_state();
         let method = self.editor.selected_method;
         self.editor
             .run
@@ -140,6 +146,7 @@ impl<'a> SegmentRow<&'a mut Editor> {
 
     /// Sets the provided comparison's time of the segment for the active timing method.
     pub fn set_comparison_time(&mut self, comparison: &str, time: Option<TimeSpan>) {
+        self.editor.save_undo_state();
         let method = self.editor.selected_method;
         self.editor
             .run
@@ -26,6 +26,9 @@ pub struct State {
     /// The number of times this Run has been attempted by the runner. This
     /// is mostly just a visual number and has no effect on any history.
     pub attempts: u32,
+    /// The index of the segment at which the game time stops. If this is
+    /// [`None`], the game time keeps running until the final split.
+    pub game_time_end_index: Option<usize>,
     /// The timing method that is currently selected to be visualized and
     /// edited.
     pub timing_method: TimingMethod,
@@ -56,6 +59,10 @@ pub struct Buttons {
     /// any one of the selected segments is the last segment, then they can't be
     /// moved.
     pub can_move_down: bool,
+    /// Describes whether there is an edit that can be undone.
+    pub can_undo: bool,
+    /// Describes whether there is an undone edit that can be redone.
+    pub can_redo: bool,
 }
 
 /// Describes the current state of a segment.
@@ -127,6 +134,7 @@ impl Editor {
         let category = self.category_name().to_string();
         let offset = formatter.format(self.offset()).to_string();
         let attempts = self.attempt_count();
+        let game_time_end_index = self.game_time_end_index();
         let timing_method = self.selected_timing_method();
         let comparison_names = self
             .custom_comparisons()
@@ -139,6 +147,8 @@ impl Editor {
             can_remove: self.can_remove_segments(),
             can_move_up: self.can_move_segments_up(),
             can_move_down: self.can_move_segments_down(),
+            can_undo: self.can_undo(),
+            can_redo: self.can_redo(),
         };
         let mut segments = Vec::with_capacity(self.run.len());
 
@@ -184,6 +194,7 @@ impl Editor {
             category,
             offset,
             attempts,
+            game_time_end_index,
             timing_method,
             segments,
             comparison_names,
@@ -1,7 +1,7 @@
 use crate::{
     comparison::{best_segments, personal_best},
     run::{AddComparisonError, CopyComparisonError, Editor, RenameError},
-    Run, Segment,
+    Run, Segment, Time, TimeSpan,
 };
 
 #[test]
@@ -156,3 +156,113 @@ fn reordering_works() {
         ["D", "A", "C", "B"]
     );
 }
+
+#[test]
+fn removing_empty_comparisons_works() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    run.push_segment(Segment::new("t"));
+    let mut editor = Editor::new(run).unwrap();
+
+    editor.add_comparison("Empty").unwrap();
+    editor.add_comparison("Populated").unwrap();
+    editor.add_comparison("Partially Populated").unwrap();
+    *editor.run.segment_mut(0).comparison_mut("Populated") = Time::new()
+        .with_real_time(Some(TimeSpan::from_seconds(5.0)))
+        .with_game_time(Some(TimeSpan::from_seconds(4.0)));
+    *editor
+        .run
+        .segment_mut(1)
+        .comparison_mut("Partially Populated") =
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(4.0)));
+
+    editor.remove_empty_comparisons();
+
+    assert_eq!(
+        editor.custom_comparisons(),
+        [personal_best::NAME, "Populated", "Partially Populated"]
+    );
+    assert!(editor
+        .run
+        .segment_mut(0)
+        .comparisons_mut()
+        .get("Empty")
+        .is_none());
+}
+
+#[test]
+fn removing_empty_comparisons_keeps_the_personal_best() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    let mut editor = Editor::new(run).unwrap();
+
+    editor.add_comparison("Empty").unwrap();
+
+    editor.remove_empty_comparisons();
+
+    assert_eq!(editor.custom_comparisons(), [personal_best::NAME]);
+}
+
+#[test]
+fn deduplicating_merges_comparisons_with_similar_names() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    run.push_segment(Segment::new("t"));
+    let mut editor = Editor::new(run).unwrap();
+
+    editor.add_comparison("Average").unwrap();
+    editor.add_comparison("Other").unwrap();
+    editor.add_comparison("average ").unwrap();
+    *editor.run.segment_mut(0).comparison_mut("average ") =
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(5.0)));
+    *editor.run.segment_mut(1).comparison_mut("Average") =
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(10.0)));
+    *editor.run.segment_mut(1).comparison_mut("average ") = Time::new()
+        .with_real_time(Some(TimeSpan::from_seconds(12.0)))
+        .with_game_time(Some(TimeSpan::from_seconds(9.0)));
+
+    editor.deduplicate_comparisons();
+
+    assert_eq!(
+        editor.custom_comparisons(),
+        [personal_best::NAME, "Average", "Other"]
+    );
+    assert_eq!(
+        editor.run.segment(0).comparison("Average"),
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(5.0)))
+    );
+    assert_eq!(
+        editor.run.segment(1).comparison("Average"),
+        Time::new()
+            .with_real_time(Some(TimeSpan::from_seconds(10.0)))
+            .with_game_time(Some(TimeSpan::from_seconds(9.0)))
+    );
+    assert!(editor
+        .run
+        .segment_mut(1)
+        .comparisons_mut()
+        .get("average ")
+        .is_none());
+}
+
+#[test]
+fn deduplicating_keeps_the_personal_best() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    let mut editor = Editor::new(run).unwrap();
+
+    editor.add_comparison("personal best ").unwrap();
+    *editor.run.segment_mut(0).comparison_mut("personal best ") =
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(5.0)));
+
+    editor.deduplicate_comparisons();
+
+    assert_eq!(
+        editor.custom_comparisons(),
+        [personal_best::NAME, "personal best "]
+    );
+    assert_eq!(
+        editor.run.segment(0).personal_best_split_time(),
+        Time::default()
+    );
+}
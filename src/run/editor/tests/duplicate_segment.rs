@@ -0,0 +1,49 @@
+use crate::{
+    run::Editor,
+    util::tests_helper::{create_timer, run_with_splits},
+    Run, Time, TimeSpan,
+};
+
+fn run() -> Run {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+    run_with_splits(&mut timer, &[12.0, 24.0]);
+    let mut run = timer.into_run(true);
+    run.add_custom_comparison("Goal").unwrap();
+    *run.segment_mut(0).comparison_mut("Goal") =
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(9.0)));
+    *run.segment_mut(1).comparison_mut("Goal") =
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(20.0)));
+    run
+}
+
+#[test]
+fn copies_the_active_segment_below_it() {
+    let mut editor = Editor::new(run()).unwrap();
+    editor.duplicate_active_segment();
+
+    assert_eq!(editor.active_segment().name(), "A");
+
+    let run = editor.close();
+    assert_eq!(run.len(), 3);
+
+    let (original, copy) = (run.segment(0), run.segment(1));
+    assert_eq!(copy.name(), "A");
+    for comparison in run.custom_comparisons() {
+        assert_eq!(copy.comparison(comparison), Time::default());
+        assert_ne!(original.comparison(comparison), Time::default());
+    }
+    assert_eq!(copy.best_segment_time(), Time::default());
+    assert_eq!(
+        original.best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(10.0))
+    );
+    assert_eq!(run.segment(2).name(), "B");
+
+    assert!(copy.segment_history().iter().count() > 0);
+    assert!(copy
+        .segment_history()
+        .iter()
+        .all(|(_, time)| time.real_time.is_none() && time.game_time.is_none()));
+    assert!(original.segment_history().iter().count() > 0);
+}
@@ -0,0 +1,57 @@
+use super::super::{Editor, ParseError};
+use crate::{util::tests_helper::create_run, TimeSpan};
+
+#[test]
+fn uses_stored_frame_rate_by_default() {
+    let mut editor = Editor::new(create_run(&["A"])).unwrap();
+    editor.set_frame_rate(Some(60.0));
+
+    assert_eq!(
+        editor.parse_frame_count("90", None).unwrap(),
+        TimeSpan::from_seconds(1.5),
+    );
+}
+
+#[test]
+fn explicit_frame_rate_overrides_stored_one() {
+    let mut editor = Editor::new(create_run(&["A"])).unwrap();
+    editor.set_frame_rate(Some(60.0));
+
+    assert_eq!(
+        editor.parse_frame_count("90", Some(30.0)).unwrap(),
+        TimeSpan::from_seconds(3.0),
+    );
+}
+
+#[test]
+fn requires_frame_rate() {
+    let editor = Editor::new(create_run(&["A"])).unwrap();
+
+    assert!(matches!(
+        editor.parse_frame_count("90", None),
+        Err(ParseError::MissingFrameRate),
+    ));
+    assert!(matches!(
+        editor.parse_frame_count("90", Some(0.0)),
+        Err(ParseError::MissingFrameRate),
+    ));
+}
+
+#[test]
+fn tiny_frame_rates_are_out_of_range() {
+    let editor = Editor::new(create_run(&["A"])).unwrap();
+
+    assert!(matches!(
+        editor.parse_frame_count("90", Some(f64::MIN_POSITIVE)),
+        Err(ParseError::FrameCountOutOfRange),
+    ));
+}
+
+#[test]
+fn invalid_frame_rates_are_not_stored() {
+    let mut editor = Editor::new(create_run(&["A"])).unwrap();
+    editor.set_frame_rate(Some(f64::NAN));
+    assert_eq!(editor.run().metadata().frame_rate(), None);
+    editor.set_frame_rate(Some(-60.0));
+    assert_eq!(editor.run().metadata().frame_rate(), None);
+}
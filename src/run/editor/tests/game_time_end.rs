@@ -0,0 +1,78 @@
+use super::super::Editor;
+use crate::util::tests_helper::create_run;
+
+fn editor_with_end(end_index: usize) -> Editor {
+    let mut editor = Editor::new(create_run(&["A", "B", "C", "D"])).unwrap();
+    editor.set_game_time_end_index(Some(end_index)).unwrap();
+    editor
+}
+
+#[test]
+fn indices_outside_of_the_segments_are_rejected() {
+    let mut editor = editor_with_end(3);
+    assert_eq!(editor.game_time_end_index(), Some(3));
+
+    assert!(editor.set_game_time_end_index(Some(4)).is_err());
+    assert_eq!(editor.game_time_end_index(), Some(3));
+}
+
+#[test]
+fn follows_its_segment_when_inserting() {
+    let mut editor = editor_with_end(2);
+
+    editor.select_only(3);
+    editor.insert_segment_above();
+    assert_eq!(editor.game_time_end_index(), Some(2));
+
+    editor.select_only(1);
+    editor.insert_segment_below();
+    assert_eq!(editor.game_time_end_index(), Some(3));
+
+    editor.select_only(0);
+    editor.duplicate_active_segment();
+    assert_eq!(editor.game_time_end_index(), Some(4));
+}
+
+#[test]
+fn follows_its_segment_when_moving() {
+    let mut editor = editor_with_end(1);
+
+    editor.select_only(1);
+    editor.move_segments_down();
+    assert_eq!(editor.game_time_end_index(), Some(2));
+
+    editor.select_only(3);
+    editor.move_segments_up();
+    assert_eq!(editor.game_time_end_index(), Some(3));
+}
+
+#[test]
+fn moves_to_the_next_segment_when_removed() {
+    let mut editor = editor_with_end(1);
+
+    editor.select_only(0);
+    editor.remove_segments();
+    assert_eq!(editor.game_time_end_index(), Some(0));
+
+    editor.select_only(0);
+    editor.remove_segments();
+    assert_eq!(editor.game_time_end_index(), Some(0));
+    assert_eq!(editor.run().segment(0).name(), "C");
+
+    editor.select_only(1);
+    editor.remove_segments();
+    assert_eq!(editor.game_time_end_index(), Some(0));
+}
+
+#[test]
+fn is_removed_along_with_the_last_segment() {
+    let mut editor = editor_with_end(3);
+
+    editor.select_only(3);
+    editor.remove_segments();
+    assert_eq!(editor.game_time_end_index(), None);
+
+    let mut editor = editor_with_end(2);
+    editor.set_segment_names_from_lines("A\nB").unwrap();
+    assert_eq!(editor.game_time_end_index(), None);
+}
@@ -0,0 +1,68 @@
+use crate::{run::Editor, Run, Segment, Time, TimeSpan, TimingMethod};
+
+fn run(segments: &[(f64, Option<f64>)]) -> Run {
+    let mut run = Run::new();
+    let mut split_time = 0.0;
+    for (index, &(segment_time, best)) in segments.iter().enumerate() {
+        split_time += segment_time;
+        let mut segment = Segment::new(format!("{index}"));
+        segment.set_personal_best_split_time(
+            Time::new().with_real_time(Some(TimeSpan::from_seconds(split_time))),
+        );
+        segment.set_best_segment_time(Time::new().with_real_time(best.map(TimeSpan::from_seconds)));
+        run.push_segment(segment);
+    }
+    run
+}
+
+fn best_segments(run: &Run) -> Vec<Option<TimeSpan>> {
+    run.segments()
+        .iter()
+        .map(|s| s.best_segment_time().real_time)
+        .collect()
+}
+
+fn pb_splits(run: &Run) -> Vec<Option<TimeSpan>> {
+    run.segments()
+        .iter()
+        .map(|s| s.personal_best_split_time().real_time)
+        .collect()
+}
+
+#[test]
+fn only_faster_best_segments_get_imported() {
+    let original = run(&[(10.0, Some(8.0)), (10.0, Some(9.0)), (10.0, Some(7.0))]);
+    let other = run(&[(20.0, Some(6.0)), (20.0, Some(9.5)), (20.0, Some(5.0))]);
+
+    let mut editor = Editor::new(original.clone()).unwrap();
+    editor.merge_best_segments_from(&other, TimingMethod::RealTime);
+    let merged = editor.close();
+
+    assert_eq!(
+        best_segments(&merged),
+        [
+            Some(TimeSpan::from_seconds(6.0)),
+            Some(TimeSpan::from_seconds(9.0)),
+            Some(TimeSpan::from_seconds(5.0)),
+        ]
+    );
+    assert_eq!(pb_splits(&merged), pb_splits(&original));
+}
+
+#[test]
+fn missing_best_segments_get_filled_in() {
+    let original = run(&[(10.0, None), (10.0, Some(9.0))]);
+    let other = run(&[(20.0, Some(6.0))]);
+
+    let mut editor = Editor::new(original).unwrap();
+    editor.merge_best_segments_from(&other, TimingMethod::RealTime);
+    let merged = editor.close();
+
+    assert_eq!(
+        best_segments(&merged),
+        [
+            Some(TimeSpan::from_seconds(6.0)),
+            Some(TimeSpan::from_seconds(9.0)),
+        ]
+    );
+}
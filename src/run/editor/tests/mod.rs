@@ -7,7 +7,13 @@ use crate::{
 mod comparison;
 mod custom_variables;
 mod dissociate_run;
+mod duplicate_segment;
+mod frame_rate;
+mod game_time_end;
 mod mark_as_modified;
+mod merge_best_segments;
+mod segment_names;
+mod undo;
 
 #[test]
 fn new_best_segment() {
@@ -0,0 +1,91 @@
+use crate::{
+    run::{editor::SegmentNamesError, Editor},
+    Run, Segment, TimeSpan,
+};
+
+fn editor() -> Editor {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    let mut editor = Editor::new(run).unwrap();
+    for (index, time) in ["1:00", "2:00", "3:00"].into_iter().enumerate() {
+        editor.select_only(index);
+        editor
+            .active_segment()
+            .parse_and_set_split_time(time)
+            .unwrap();
+    }
+    editor
+}
+
+fn names(editor: &Editor) -> Vec<&str> {
+    editor.run().segments().iter().map(|s| s.name()).collect()
+}
+
+fn split_time(editor: &Editor, index: usize) -> Option<TimeSpan> {
+    editor
+        .run()
+        .segment(index)
+        .personal_best_split_time()
+        .real_time
+}
+
+#[test]
+fn renames_the_segments() {
+    let mut editor = editor();
+    editor
+        .set_segment_names_from_lines("Forest\r\n  Fire \nWater")
+        .unwrap();
+
+    assert_eq!(names(&editor), ["Forest", "Fire", "Water"]);
+    assert_eq!(split_time(&editor, 2), Some(TimeSpan::from_seconds(180.0)));
+    assert!(editor.run().has_been_modified());
+}
+
+#[test]
+fn grows_the_segments() {
+    let mut editor = editor();
+    editor
+        .set_segment_names_from_lines("Forest\nFire\nWater\nShadow\nSpirit\n\n")
+        .unwrap();
+
+    assert_eq!(
+        names(&editor),
+        ["Forest", "Fire", "Water", "Shadow", "Spirit"]
+    );
+    assert_eq!(split_time(&editor, 1), Some(TimeSpan::from_seconds(120.0)));
+    assert_eq!(split_time(&editor, 4), None);
+}
+
+#[test]
+fn shrinks_the_segments() {
+    let mut editor = editor();
+    editor.set_segment_names_from_lines("Forest\n").unwrap();
+
+    assert_eq!(names(&editor), ["Forest"]);
+    assert_eq!(split_time(&editor, 0), Some(TimeSpan::from_seconds(60.0)));
+
+    // The selected segment got removed, so the last one is selected instead.
+    assert_eq!(editor.active_segment().name(), "Forest");
+}
+
+#[test]
+fn keeps_empty_lines_in_between() {
+    let mut editor = editor();
+    editor
+        .set_segment_names_from_lines("Forest\n\nWater")
+        .unwrap();
+
+    assert_eq!(names(&editor), ["Forest", "", "Water"]);
+}
+
+#[test]
+fn rejects_text_without_names() {
+    let mut editor = editor();
+    assert_eq!(
+        editor.set_segment_names_from_lines(" \n\n"),
+        Err(SegmentNamesError::NoSegmentNames),
+    );
+    assert_eq!(names(&editor), ["A", "B", "C"]);
+}
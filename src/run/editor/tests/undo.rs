@@ -0,0 +1,139 @@
+use crate::{
+    run::{editor::SelectionState, Editor},
+    settings::ImageCache,
+    Run, Segment,
+};
+
+fn editor() -> Editor {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    run.add_custom_comparison("Old").unwrap();
+    Editor::new(run).unwrap()
+}
+
+fn selection(editor: &Editor) -> Vec<&'static str> {
+    editor
+        .state(&mut ImageCache::new())
+        .segments
+        .iter()
+        .map(|segment| match segment.selected {
+            SelectionState::NotSelected => "",
+            SelectionState::Selected => "selected",
+            SelectionState::Active => "active",
+        })
+        .collect()
+}
+
+#[test]
+fn nothing_to_undo_or_redo_initially() {
+    let mut editor = editor();
+    assert!(!editor.can_undo());
+    assert!(!editor.can_redo());
+
+    editor.undo();
+    editor.redo();
+    assert_eq!(editor.custom_comparisons(), ["Personal Best", "Old"]);
+}
+
+#[test]
+fn undoing_a_rename_restores_the_old_name() {
+    let mut editor = editor();
+    editor.select_only(2);
+    editor.rename_comparison("Old", "New").unwrap();
+    editor.select_only(1);
+
+    editor.undo();
+    assert_eq!(editor.custom_comparisons(), ["Personal Best", "Old"]);
+    assert!(!editor.can_undo());
+    assert!(editor.can_redo());
+
+    assert_eq!(selection(&editor), ["", "", "active"]);
+
+    editor.redo();
+    assert_eq!(editor.custom_comparisons(), ["Personal Best", "New"]);
+    assert!(editor.can_undo());
+    assert!(!editor.can_redo());
+}
+
+#[test]
+fn failed_edits_are_not_recorded() {
+    let mut editor = editor();
+    editor.rename_comparison("Missing", "New").unwrap_err();
+    editor.add_comparison("Old").unwrap_err();
+    assert!(!editor.can_undo());
+}
+
+#[test]
+fn undoing_a_removal_restores_the_segments() {
+    let mut editor = editor();
+    editor.select_only(0);
+    editor.select_additionally(2);
+    editor.remove_segments();
+    assert_eq!(editor.run().len(), 1);
+
+    editor.undo();
+    assert_eq!(editor.run().len(), 3);
+    assert_eq!(editor.run().segment(2).name(), "C");
+
+    assert_eq!(selection(&editor), ["selected", "", "active"]);
+}
+
+#[test]
+fn undoing_a_segment_time_edit_restores_the_times() {
+    let mut editor = editor();
+    editor
+        .active_segment()
+        .parse_and_set_split_time("1:00")
+        .unwrap();
+    editor
+        .active_segment()
+        .parse_and_set_split_time("2:00")
+        .unwrap();
+
+    editor.undo();
+    assert_eq!(
+        editor.active_segment().split_time(),
+        Some("1:00".parse().unwrap()),
+    );
+    assert_eq!(
+        editor.active_segment().segment_time(),
+        Some("1:00".parse().unwrap()),
+    );
+
+    editor.undo();
+    assert_eq!(editor.active_segment().split_time(), None);
+}
+
+#[test]
+fn new_edits_discard_the_redo_history() {
+    let mut editor = editor();
+    editor.set_game_name("First");
+    editor.undo();
+    assert!(editor.can_redo());
+
+    editor.set_game_name("Second");
+    assert!(!editor.can_redo());
+    editor.undo();
+    assert_eq!(editor.game_name(), "");
+}
+
+#[test]
+fn the_undo_limit_is_respected() {
+    let mut editor = editor();
+    editor.set_undo_limit(2);
+    editor.set_attempt_count(1);
+    editor.set_attempt_count(2);
+    editor.set_attempt_count(3);
+
+    editor.undo();
+    editor.undo();
+    assert!(!editor.can_undo());
+    assert_eq!(editor.attempt_count(), 1);
+
+    editor.set_undo_limit(0);
+    editor.set_attempt_count(4);
+    assert!(!editor.can_undo());
+    assert!(!editor.can_redo());
+}
@@ -0,0 +1,48 @@
+//! The export module provides ways to export data of a Run into formats that
+//! are meant to be consumed by other tools, rather than being loaded back into
+//! a timer.
+//!
+//! # Examples
+//!
+//! Exporting the Personal Best pacing of a Run as a CSV file.
+//!
+//! ```no_run
+//! use livesplit_core::run::export::pace_chart;
+//! use livesplit_core::run::saver::livesplit::IoWrite;
+//! use livesplit_core::{Run, Segment, TimingMethod};
+//! use std::fs::File;
+//! use std::io::BufWriter;
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! let file = File::create("path/to/pace_chart.csv");
+//! let writer = BufWriter::new(file.expect("Failed creating the file"));
+//!
+//! pace_chart::export(&run, "Personal Best", TimingMethod::RealTime, IoWrite(writer))
+//!     .expect("Couldn't export the pace chart");
+//! ```
+
+pub mod pace_chart;
+pub mod splits;
+
+#[cfg(test)]
+mod tests;
+
+use core::fmt;
+
+/// Writes a single CSV field, quoting it if necessary.
+fn csv_field<W: fmt::Write>(writer: &mut W, value: &str) -> fmt::Result {
+    if value.contains([',', '"', '\n', '\r']) {
+        writer.write_char('"')?;
+        for (i, part) in value.split('"').enumerate() {
+            if i != 0 {
+                writer.write_str("\"\"")?;
+            }
+            writer.write_str(part)?;
+        }
+        writer.write_char('"')
+    } else {
+        writer.write_str(value)
+    }
+}
@@ -0,0 +1,52 @@
+//! The pace chart exporter writes the pacing of a single comparison of a Run
+//! as a CSV file. Each row describes a segment with its index, its name, the
+//! cumulative split time and the segment time, both in seconds. This is meant
+//! for plotting the comparison in external tools and is distinct from the
+//! attempt history of the Run.
+
+use super::csv_field;
+use crate::{Run, TimeSpan, TimingMethod};
+use core::fmt;
+
+/// The header row of the CSV file.
+pub const HEADER: &str = "Segment Index,Segment Name,Split Time,Segment Time";
+
+/// Exports the pacing of the comparison provided as a CSV file for the timing
+/// method specified. Segments without a split time in the comparison have both
+/// their split time and segment time left blank. The segment time of a
+/// segment following such a missing split is measured from the last split
+/// that has a time.
+pub fn export<W: fmt::Write>(
+    run: &Run,
+    comparison: &str,
+    method: TimingMethod,
+    mut writer: W,
+) -> fmt::Result {
+    writer.write_str(HEADER)?;
+    writer.write_str("\r\n")?;
+
+    let mut previous_split_time = TimeSpan::zero();
+
+    for (index, segment) in run.segments().iter().enumerate() {
+        write!(writer, "{index},")?;
+        csv_field(&mut writer, segment.name())?;
+        writer.write_char(',')?;
+
+        if let Some(split_time) = segment.comparison_timing_method(comparison, method) {
+            let segment_time = split_time - previous_split_time;
+            write!(
+                writer,
+                "{},{}",
+                split_time.total_seconds(),
+                segment_time.total_seconds(),
+            )?;
+            previous_split_time = split_time;
+        } else {
+            writer.write_char(',')?;
+        }
+
+        writer.write_str("\r\n")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! The splits exporter writes the Personal Best of a Run as a CSV file, so it
+//! can be inspected in spreadsheet applications. Each row describes a segment
+//! with its name, the Personal Best split times, the Best Segment Times and the
+//! Personal Best segment times, each for both timing methods. The times are
+//! formatted the same way as in LiveSplit splits files.
+
+use super::csv_field;
+use crate::{
+    timing::formatter::{Complete, TimeFormatter},
+    Run, TimeSpan, TimingMethod,
+};
+use core::fmt;
+
+/// The header row of the CSV file.
+pub const HEADER: &str = "Segment Name,\
+    Split Time (Real Time),Split Time (Game Time),\
+    Best Segment (Real Time),Best Segment (Game Time),\
+    Segment Time (Real Time),Segment Time (Game Time)";
+
+fn time_field<W: fmt::Write>(writer: &mut W, time: Option<TimeSpan>) -> fmt::Result {
+    writer.write_char(',')?;
+    if let Some(time) = time {
+        write!(writer, "{}", Complete.format(time))?;
+    }
+    Ok(())
+}
+
+/// Exports the Personal Best of the Run provided as a CSV file. Times that are
+/// not available are left blank. The segment time of a segment following a
+/// missing split is measured from the last split that has a time.
+pub fn export<W: fmt::Write>(run: &Run, mut writer: W) -> fmt::Result {
+    writer.write_str(HEADER)?;
+    writer.write_str("\r\n")?;
+
+    let mut previous_split_times = [TimeSpan::zero(); 2];
+
+    for segment in run.segments() {
+        csv_field(&mut writer, segment.name())?;
+
+        let split_time = segment.personal_best_split_time();
+        for method in TimingMethod::all() {
+            time_field(&mut writer, split_time[method])?;
+        }
+
+        let best_segment_time = segment.best_segment_time();
+        for method in TimingMethod::all() {
+            time_field(&mut writer, best_segment_time[method])?;
+        }
+
+        for (method, previous_split_time) in TimingMethod::all()
+            .into_iter()
+            .zip(&mut previous_split_times)
+        {
+            let segment_time = split_time[method].map(|split_time| {
+                let segment_time = split_time - *previous_split_time;
+                *previous_split_time = split_time;
+                segment_time
+            });
+            time_field(&mut writer, segment_time)?;
+        }
+
+        writer.write_str("\r\n")?;
+    }
+
+    Ok(())
+}
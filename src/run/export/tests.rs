@@ -0,0 +1,139 @@
+use super::{pace_chart, splits};
+use crate::{
+    comparison::personal_best, run::Segment, util::tests_helper::create_run, Run, Time, TimeSpan,
+    TimingMethod,
+};
+
+fn run_with_pb(times: &[Option<f64>]) -> Run {
+    let names: Vec<String> = (0..times.len()).map(|i| format!("Split {i}")).collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let mut run = create_run(&names);
+    for (segment, time) in run.segments_mut().iter_mut().zip(times) {
+        *segment.comparison_mut(personal_best::NAME) =
+            Time::new().with_real_time(time.map(TimeSpan::from_seconds));
+    }
+    run
+}
+
+fn export(run: &Run) -> String {
+    let mut buf = String::new();
+    pace_chart::export(run, personal_best::NAME, TimingMethod::RealTime, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn row_count_equals_segment_count() {
+    let run = run_with_pb(&[Some(10.0), None, Some(30.0), Some(45.5)]);
+    let csv = export(&run);
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some(pace_chart::HEADER));
+    assert_eq!(lines.count(), run.len());
+}
+
+#[test]
+fn cumulative_times_are_non_decreasing() {
+    let run = run_with_pb(&[Some(10.0), None, Some(30.0), Some(45.5)]);
+    let csv = export(&run);
+
+    let mut previous = 0.0;
+    for line in csv.lines().skip(1) {
+        let split_time = line.split(',').nth(2).unwrap();
+        if split_time.is_empty() {
+            continue;
+        }
+        let split_time: f64 = split_time.parse().unwrap();
+        assert!(split_time >= previous);
+        previous = split_time;
+    }
+}
+
+#[test]
+fn missing_splits_are_blank() {
+    let run = run_with_pb(&[Some(10.0), None, Some(30.0)]);
+    let csv = export(&run);
+    let rows: Vec<&str> = csv.lines().skip(1).collect();
+    assert_eq!(rows, ["0,Split 0,10,10", "1,Split 1,,", "2,Split 2,30,20"]);
+}
+
+#[test]
+fn segment_names_are_escaped() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new(r#"Cap, "Kingdom""#));
+    let csv = export(&run);
+    assert_eq!(csv.lines().nth(1), Some(r#"0,"Cap, ""Kingdom""",,"#));
+}
+
+/// A minimal CSV reader that supports quoted fields with escaped quotes, but
+/// no line breaks within fields.
+fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+    csv.lines()
+        .map(|line| {
+            let mut fields = vec![String::new()];
+            let mut chars = line.chars().peekable();
+            let mut quoted = false;
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if quoted && chars.peek() == Some(&'"') => {
+                        chars.next();
+                        fields.last_mut().unwrap().push('"');
+                    }
+                    '"' => quoted = !quoted,
+                    ',' if !quoted => fields.push(String::new()),
+                    c => fields.last_mut().unwrap().push(c),
+                }
+            }
+            fields
+        })
+        .collect()
+}
+
+#[test]
+fn splits_round_trip() {
+    let mut run = Run::new();
+    let mut segment = Segment::new(r#"Cap, "Kingdom""#);
+    segment.set_personal_best_split_time(
+        Time::new()
+            .with_real_time(Some(TimeSpan::from_seconds(95.5)))
+            .with_game_time(Some(TimeSpan::from_seconds(90.0))),
+    );
+    segment.set_best_segment_time(Time::new().with_real_time(Some(TimeSpan::from_seconds(93.0))));
+    run.push_segment(segment);
+    run.push_segment(Segment::new("Cascade Kingdom"));
+    let mut segment = Segment::new("Sand Kingdom");
+    segment.set_personal_best_split_time(
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(400.25))),
+    );
+    run.push_segment(segment);
+
+    let mut buf = String::new();
+    splits::export(&run, &mut buf).unwrap();
+    let rows = parse_csv(&buf);
+
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[0].join(","), splits::HEADER);
+    assert_eq!(
+        rows[1],
+        [
+            r#"Cap, "Kingdom""#,
+            "00:01:35.500000000",
+            "00:01:30.000000000",
+            "00:01:33.000000000",
+            "",
+            "00:01:35.500000000",
+            "00:01:30.000000000",
+        ],
+    );
+    assert_eq!(rows[2], ["Cascade Kingdom", "", "", "", "", "", ""]);
+    assert_eq!(
+        rows[3],
+        [
+            "Sand Kingdom",
+            "00:06:40.250000000",
+            "",
+            "",
+            "",
+            "00:05:04.750000000",
+            "",
+        ],
+    );
+}
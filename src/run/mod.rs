@@ -17,6 +17,7 @@
 mod attempt;
 mod comparisons;
 pub mod editor;
+pub mod export;
 mod linked_layout;
 pub mod parser;
 mod run_metadata;
@@ -36,14 +37,19 @@ pub use segment::Segment;
 pub use segment_history::SegmentHistory;
 
 use crate::{
-    comparison::{default_generators, personal_best, ComparisonGenerator, RACE_COMPARISON_PREFIX},
+    comparison::{
+        default_generators, goal, personal_best, ComparisonGenerator, RACE_COMPARISON_PREFIX,
+    },
     platform::prelude::*,
-    settings::Image,
+    settings::{Image, ImageId},
     util::{caseless::matches_ascii_key, PopulateString},
     AtomicDateTime, Time, TimeSpan, TimingMethod,
 };
 use alloc::borrow::Cow;
-use core::{cmp::max, fmt};
+use core::{
+    cmp::{max, Ordering},
+    fmt,
+};
 use hashbrown::HashSet;
 
 /// A Run stores the split times for a specific game and category of a runner.
@@ -76,6 +82,7 @@ pub struct Run {
     comparison_generators: ComparisonGenerators,
     auto_splitter_settings: String,
     linked_layout: Option<LinkedLayout>,
+    game_time_end_index: Option<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -111,6 +118,55 @@ pub enum CopyComparisonError {
     },
 }
 
+/// Error type for an invalid index of the segment at which the game time
+/// stops.
+#[derive(PartialEq, Eq, Debug, snafu::Snafu)]
+pub enum GameTimeEndIndexError {
+    /// There is no segment at the index.
+    OutOfBounds,
+}
+
+/// Describes the repairs that [`Run::repair_history`] applied to the Segment
+/// Histories.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct RepairReport {
+    /// The segment history elements that didn't belong to any attempt and got
+    /// reattached with a new index. The first index is the old one, the second
+    /// one is the new one.
+    pub reattached: Vec<(i32, i32)>,
+    /// The indices of the segment history elements that didn't belong to any
+    /// attempt and didn't store any times, so they got removed.
+    pub removed: Vec<i32>,
+}
+
+impl RepairReport {
+    /// Returns whether no repairs were necessary.
+    pub fn is_empty(&self) -> bool {
+        self.reattached.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// A potential problem with a Run that may cause confusion when using it for a
+/// race. These are reported by [`Run::race_readiness_check`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RaceReadinessWarning {
+    /// The Run has fewer than two segments.
+    TooFewSegments,
+    /// The segment at the index provided has an empty name.
+    EmptySegmentName {
+        /// The index of the segment.
+        index: usize,
+    },
+    /// The Personal Best has no final time for either timing method.
+    MissingFinalSplitTime,
+    /// The Personal Best is faster than the Sum of Best Segments for the
+    /// timing method provided, which should be impossible.
+    PersonalBestFasterThanSumOfBest {
+        /// The timing method for which the times are inconsistent.
+        method: TimingMethod,
+    },
+}
+
 impl Run {
     /// Creates a new Run object with no segments.
     #[inline]
@@ -129,6 +185,7 @@ impl Run {
             comparison_generators: ComparisonGenerators(default_generators()),
             auto_splitter_settings: String::new(),
             linked_layout: None,
+            game_time_end_index: None,
         }
     }
 
@@ -159,6 +216,17 @@ impl Run {
         self.game_icon = image;
     }
 
+    /// Iterates over the IDs of all the images that are referenced by the Run.
+    /// These are the game icon and the icons of all the segments. Empty images
+    /// are skipped. An ID may be yielded multiple times if the same image is
+    /// used in multiple places.
+    pub fn referenced_image_ids(&self) -> impl Iterator<Item = &ImageId> + use<'_> {
+        core::iter::once(&self.game_icon)
+            .chain(self.segments.iter().map(Segment::icon))
+            .map(Image::id)
+            .filter(|id| !id.is_empty())
+    }
+
     /// Accesses the name of the category this Run is for.
     #[inline]
     pub fn category_name(&self) -> &str {
@@ -370,6 +438,31 @@ impl Run {
         self.linked_layout = linked_layout;
     }
 
+    /// Accesses the index of the segment at which the game time stops. Some
+    /// rulesets stop the in-game timer at a split that isn't the final one,
+    /// while the real time keeps running until the end of the attempt. Once
+    /// this segment is split, the game time is frozen for the remainder of the
+    /// attempt and the splits that follow don't store a game time. If the
+    /// segment gets skipped, the game time stops at the next split instead.
+    #[inline]
+    pub const fn game_time_end_index(&self) -> Option<usize> {
+        self.game_time_end_index
+    }
+
+    /// Sets the index of the segment at which the game time stops. Specify
+    /// [`None`] if the game time should keep running until the final split. An
+    /// index that doesn't refer to any of the segments is rejected.
+    pub fn set_game_time_end_index(
+        &mut self,
+        index: Option<usize>,
+    ) -> Result<(), GameTimeEndIndexError> {
+        if index.is_some_and(|index| index >= self.len()) {
+            return Err(GameTimeEndIndexError::OutOfBounds);
+        }
+        self.game_time_end_index = index;
+        Ok(())
+    }
+
     /// Returns the amount of segments stored in this Run.
     #[inline]
     pub fn len(&self) -> usize {
@@ -461,6 +554,60 @@ impl Run {
         Ok(())
     }
 
+    /// Generates a custom goal comparison with the name provided based on the
+    /// goal time provided. If there is no custom comparison with that name
+    /// yet, it is added. The comparison's times are automatically balanced
+    /// based on the runner's history such that it roughly represents what
+    /// split times for the goal time would roughly look like. Since it is
+    /// populated by the runner's history, only goal times within the sum of the
+    /// best segments and the sum of the worst segments are supported.
+    /// Everything else is automatically capped by that range. The comparison
+    /// is only populated for the timing method provided. The other timing
+    /// method's comparison times are not modified by this, so you can call this
+    /// again with the other timing method to generate the comparison times for
+    /// both timing methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use livesplit_core::{Run, Segment, Time, TimeSpan, TimingMethod};
+    ///
+    /// let mut run = Run::new();
+    ///
+    /// for (name, times) in [("Cap Kingdom", [95.0, 110.0]), ("Cascade Kingdom", [290.0, 320.0])] {
+    ///     let mut segment = Segment::new(name);
+    ///     for (index, seconds) in (1..).zip(times) {
+    ///         let time = Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)));
+    ///         segment.segment_history_mut().insert(index, time);
+    ///     }
+    ///     run.push_segment(segment);
+    /// }
+    ///
+    /// run.generate_goal_comparison(
+    ///     TimingMethod::RealTime,
+    ///     TimeSpan::from_seconds(400.0),
+    ///     "Sub 6:40",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(run.custom_comparisons().iter().any(|c| c == "Sub 6:40"));
+    /// assert!(run.segment(1).comparison("Sub 6:40").real_time.is_some());
+    /// ```
+    pub fn generate_goal_comparison(
+        &mut self,
+        method: TimingMethod,
+        time: TimeSpan,
+        comparison: &str,
+    ) -> Result<(), AddComparisonError> {
+        if !self.custom_comparisons.iter().any(|c| c == comparison) {
+            self.add_custom_comparison(comparison)?;
+        }
+
+        goal::generate_for_timing_method(&mut self.segments, method, time, comparison);
+
+        Ok(())
+    }
+
     /// Recalculates all the comparison times the Comparison Generators provide.
     #[inline]
     pub fn regenerate_comparisons(&mut self) {
@@ -550,6 +697,95 @@ impl Run {
         self.reattach_unattached_segment_history_elements();
     }
 
+    /// Checks the Run for problems that may cause confusion when using it for a
+    /// race. This is not a complete validation of the Run. An empty list is
+    /// returned if there are no problems.
+    pub fn race_readiness_check(&self) -> Vec<RaceReadinessWarning> {
+        let mut warnings = Vec::new();
+
+        if self.segments.len() < 2 {
+            warnings.push(RaceReadinessWarning::TooFewSegments);
+        }
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            if segment.name().trim().is_empty() {
+                warnings.push(RaceReadinessWarning::EmptySegmentName { index });
+            }
+        }
+
+        let final_time = self
+            .segments
+            .last()
+            .map(Segment::personal_best_split_time)
+            .unwrap_or_default();
+
+        if final_time.real_time.is_none() && final_time.game_time.is_none() {
+            warnings.push(RaceReadinessWarning::MissingFinalSplitTime);
+        }
+
+        for method in TimingMethod::all() {
+            // The regular Sum of Best includes the Personal Best's split times,
+            // so it can never be slower than the Personal Best. Only the best
+            // segment times themselves can reveal this inconsistency.
+            let sum_of_best_segments = self
+                .segments
+                .iter()
+                .try_fold(TimeSpan::zero(), |sum, segment| {
+                    Some(sum + segment.best_segment_time()[method]?)
+                });
+            if let (Some(pb), Some(sum_of_best)) = (final_time[method], sum_of_best_segments) {
+                if pb < sum_of_best {
+                    warnings.push(RaceReadinessWarning::PersonalBestFasterThanSumOfBest { method });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Checks the Segment Histories for elements that don't belong to any
+    /// attempt in the Attempt History. This may happen when the splits were
+    /// edited by hand or merged from multiple files. Elements that still store
+    /// times are reattached with new negative indices, so they are kept as
+    /// history that isn't associated with an actual attempt. Elements without
+    /// any times are removed. A report of all the repairs is returned.
+    pub fn repair_history(&mut self) -> RepairReport {
+        let attempt_indices: HashSet<i32> =
+            self.attempt_history.iter().map(Attempt::index).collect();
+
+        let mut orphaned: Vec<i32> = self
+            .segments
+            .iter()
+            .flat_map(|s| s.segment_history().iter().map(|&(index, _)| index))
+            .filter(|index| *index >= 1 && !attempt_indices.contains(index))
+            .collect();
+        orphaned.sort_unstable();
+        orphaned.dedup();
+
+        let mut report = RepairReport::default();
+        let mut min_id = self.min_segment_history_index().unwrap_or(1);
+
+        for &orphaned_id in orphaned.iter().rev() {
+            let has_times = self.segments.iter().any(|s| {
+                s.segment_history()
+                    .get(orphaned_id)
+                    .is_some_and(|t| t.real_time.is_some() || t.game_time.is_some())
+            });
+
+            if has_times {
+                let reassign_id = self.reattach_segment_history_elements(orphaned_id, &mut min_id);
+                report.reattached.push((orphaned_id, reassign_id));
+            } else {
+                for segment in self.segments_mut() {
+                    segment.segment_history_mut().remove(orphaned_id);
+                }
+                report.removed.push(orphaned_id);
+            }
+        }
+
+        report
+    }
+
     /// Clears out the Attempt History and the Segment Histories of all the segments.
     pub fn clear_history(&mut self) {
         self.attempt_history.clear();
@@ -762,6 +998,196 @@ impl Run {
         }
     }
 
+    /// Compares the Best Segment Times of this Run with the ones of another
+    /// Run for the timing method specified. The segments are aligned by their
+    /// position. There is one [`Ordering`] per segment of this Run. It is
+    /// [`Less`](Ordering::Less) if this Run has the faster Best Segment Time,
+    /// [`Greater`](Ordering::Greater) if the other Run has the faster one and
+    /// [`Equal`](Ordering::Equal) if they are the same. A Best Segment Time
+    /// that exists is always considered better than a missing one. Segments
+    /// that don't exist in the other Run are treated as missing.
+    pub fn compare_best_segments(&self, other: &Run, method: TimingMethod) -> Vec<Ordering> {
+        self.segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                let this = segment.best_segment_time()[method];
+                let other = other
+                    .segments
+                    .get(index)
+                    .and_then(|s| s.best_segment_time()[method]);
+
+                match (this, other) {
+                    (Some(this), Some(other)) => this.cmp(&other),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            })
+            .collect()
+    }
+
+    /// Creates a copy of this Run with all of its times scaled by the factor
+    /// provided. This is useful for comparing runs of different regions of a
+    /// game that run at different framerates. A PAL version running at 50Hz
+    /// can for example be converted to the times of an NTSC version running at
+    /// 60Hz by using a factor of `50.0 / 60.0`. All the comparisons, the Best
+    /// Segment Times, the Segment History and the times in the Attempt History
+    /// are scaled for both timing methods. The offset is left as is, as it
+    /// doesn't depend on the framerate of the game. The Run itself is not
+    /// modified.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the factor is not a positive finite number.
+    pub fn with_scaled_times(&self, factor: f64) -> Run {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "The factor needs to be a positive finite number."
+        );
+
+        let scale = |time: Time| {
+            let mut scaled = Time::new();
+            for method in TimingMethod::all() {
+                scaled[method] =
+                    time[method].map(|t| TimeSpan::from_seconds(t.total_seconds() * factor));
+            }
+            scaled
+        };
+
+        let mut run = self.clone();
+
+        for segment in &mut run.segments {
+            for (_, time) in segment.comparisons_mut().iter_mut() {
+                *time = scale(*time);
+            }
+            let best_segment_time = scale(segment.best_segment_time());
+            segment.set_best_segment_time(best_segment_time);
+            for (_, time) in segment.segment_history_mut().iter_mut() {
+                *time = scale(*time);
+            }
+        }
+
+        for attempt in &mut run.attempt_history {
+            *attempt = Attempt::new(
+                attempt.index(),
+                scale(attempt.time()),
+                attempt.started(),
+                attempt.ended(),
+                attempt.pause_time(),
+            );
+        }
+
+        run
+    }
+
+    /// Extracts the segment at the index provided into a Run of its own, which
+    /// can be used for practicing the segment as an individual level. The new
+    /// Run consists of a single segment, whose times in the custom comparisons
+    /// are the segment times of the segment in those comparisons. The Best
+    /// Segment Time and the Segment History are taken over, except for segment
+    /// times that follow a skipped split, as those span multiple segments.
+    /// Each attempt in the Segment History becomes an attempt of the new Run,
+    /// with the segment time as its final time and the dates of the original
+    /// attempt. The attempt that is the fastest in the timing method provided
+    /// becomes the Personal Best. If there is no such attempt, the segment
+    /// time in the Personal Best is used instead. The category is named after
+    /// the segment, followed by the original category name in parentheses.
+    /// The game and the metadata are kept, except for the speedrun.com Run ID.
+    /// The Run itself is not modified.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the index is out of bounds.
+    pub fn extract_segment_as_il(&self, index: usize, method: TimingMethod) -> Run {
+        let segment = self.segment(index);
+
+        let segment_time = |comparison: &str| {
+            let mut time = Time::new();
+            for method in TimingMethod::all() {
+                time[method] = catch! {
+                    let split_time = segment.comparison(comparison)[method]?;
+                    match index.checked_sub(1) {
+                        Some(previous) => {
+                            split_time - self.segment(previous).comparison(comparison)[method]?
+                        }
+                        None => split_time,
+                    }
+                };
+            }
+            time
+        };
+
+        let mut run = Run::new();
+        run.set_game_name(self.game_name());
+        run.set_game_icon(self.game_icon().clone());
+        if self.category_name().is_empty() {
+            run.set_category_name(segment.name());
+        } else {
+            run.set_category_name(format!("{} ({})", segment.name(), self.category_name()));
+        }
+        run.metadata = self.metadata.clone();
+        run.clear_run_id();
+        run.custom_comparisons = self.custom_comparisons.clone();
+
+        let mut il_segment = Segment::new(segment.name());
+        il_segment.set_icon(segment.icon().clone());
+        for comparison in &self.custom_comparisons {
+            *il_segment.comparison_mut(comparison) = segment_time(comparison);
+        }
+        il_segment.set_best_segment_time(segment.best_segment_time());
+
+        let segment_history = il_segment.segment_history_mut();
+        *segment_history = segment.segment_history().clone();
+        if let Some(previous) = index.checked_sub(1) {
+            let previous = self.segment(previous).segment_history();
+            for (attempt, time) in segment_history.iter_mut() {
+                // If the previous split got skipped, the segment time also
+                // spans the previous segment, so it's not a time of this
+                // segment alone.
+                if let Some(previous_time) = previous.get(*attempt) {
+                    for method in TimingMethod::all() {
+                        if previous_time[method].is_none() {
+                            time[method] = None;
+                        }
+                    }
+                }
+            }
+        }
+
+        for attempt in &self.attempt_history {
+            if let Some(time) = il_segment.segment_history().get(attempt.index()) {
+                // The pause time of the attempt isn't necessarily spent in this
+                // segment, so only the dates are kept.
+                run.add_attempt_with_index(
+                    time,
+                    attempt.index(),
+                    attempt.started(),
+                    attempt.ended(),
+                    None,
+                );
+            }
+        }
+
+        // The segment time in the full-game Personal Best isn't necessarily
+        // the fastest time of the segment, so the fastest attempt becomes the
+        // Personal Best instead.
+        if let Some((_, time)) = run
+            .attempt_history
+            .iter()
+            .filter_map(|attempt| Some((attempt.time()[method]?, attempt.time())))
+            .min_by_key(|&(time, _)| time)
+        {
+            *il_segment.personal_best_split_time_mut() = time;
+        }
+
+        run.push_segment(il_segment);
+        run.attempt_count = run.attempt_history.len() as u32;
+
+        run.regenerate_comparisons();
+        run
+    }
+
     /// Checks a given name against the current comparisons in the Run to
     /// ensure that it is valid for use.
     pub fn validate_comparison_name(&self, new: &str) -> Result<(), AddComparisonError> {
@@ -785,17 +1211,25 @@ impl Run {
             .filter(|&i| i > max_id)
             .max()
         {
-            let reassign_id = min_id - 1;
+            self.reattach_segment_history_elements(unattached_id, &mut min_id);
+        }
+    }
 
-            for segment in self.segments_mut() {
-                let history = segment.segment_history_mut();
-                if let Some(time) = history.remove(unattached_id) {
-                    history.insert(reassign_id, time);
-                }
-            }
+    /// Moves the Segment History elements with the index provided to the index
+    /// right below the minimum index. The minimum index is updated to the new
+    /// index, which is also returned.
+    fn reattach_segment_history_elements(&mut self, index: i32, min_index: &mut i32) -> i32 {
+        let reassign_id = *min_index - 1;
 
-            min_id = reassign_id;
+        for segment in self.segments_mut() {
+            let history = segment.segment_history_mut();
+            if let Some(time) = history.remove(index) {
+                history.insert(reassign_id, time);
+            }
         }
+
+        *min_index = reassign_id;
+        reassign_id
     }
 }
 
@@ -0,0 +1,243 @@
+//! Provides an importer that reconstructs a Run from a collection of CSV files,
+//! where each file logs a single attempt.
+//!
+//! Every file starts with a header line that is skipped. Each of the following
+//! lines then contains the name of a segment and the real time split time of
+//! that segment, separated by a comma. The split time may be left empty if the
+//! segment was skipped. An attempt that got reset early simply contains fewer
+//! lines.
+//!
+//! ```csv
+//! Segment,Split Time
+//! Forsaken City,2:04.52
+//! Old Site,
+//! Celestial Resort,9:41.20
+//! ```
+
+use crate::{platform::prelude::*, timing, RealTime, Run, Segment, TimeSpan};
+use core::result::Result as StdResult;
+use snafu::ResultExt;
+
+/// The Error type for attempt logs that couldn't be imported.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Expected the split time of a segment, but didn't find it.
+    ExpectedSplitTime {
+        /// The index of the attempt log that is missing the split time.
+        attempt: usize,
+    },
+    /// Failed to parse the split time of a segment.
+    ParseSplitTime {
+        /// The index of the attempt log that contains the invalid split time.
+        attempt: usize,
+        /// The underlying error.
+        source: timing::ParseError,
+    },
+}
+
+/// The Result type for the attempt log importer.
+pub type Result<T> = StdResult<T, Error>;
+
+struct AttemptLog<'a> {
+    rows: Vec<(&'a str, Option<TimeSpan>)>,
+}
+
+fn parse_attempt(attempt: usize, source: &str) -> Result<AttemptLog<'_>> {
+    let mut rows = Vec::new();
+
+    for line in source.lines().skip(1) {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (name, time) = line
+            .rsplit_once(',')
+            .ok_or(Error::ExpectedSplitTime { attempt })?;
+
+        let time = time.trim();
+        let time = if time.is_empty() {
+            None
+        } else {
+            Some(time.parse().context(ParseSplitTime { attempt })?)
+        };
+
+        rows.push((name.trim(), time));
+    }
+
+    Ok(AttemptLog { rows })
+}
+
+/// Reconstructs a Run from the CSV logs of the individual attempts. The logs
+/// are expected to be in chronological order. The segments of the Run are
+/// formed by unioning the segments of all the attempts by their position,
+/// where the first attempt that reaches a segment determines its name. An
+/// attempt counts as finished if it has a split time for the last segment.
+/// The Segment History, the Best Segments and the Personal Best are all
+/// derived from the attempts.
+pub fn parse<'a, I>(sources: I) -> Result<Run>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let attempts = sources
+        .into_iter()
+        .enumerate()
+        .map(|(attempt, source)| parse_attempt(attempt, source))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut run = Run::new();
+
+    for attempt in &attempts {
+        for &(name, _) in attempt.rows.get(run.len()..).unwrap_or_default() {
+            run.push_segment(Segment::new(name));
+        }
+    }
+
+    let segment_count = run.len();
+    let mut personal_best = None::<(TimeSpan, &AttemptLog<'_>)>;
+
+    for (attempt, index) in attempts.iter().zip(1..) {
+        let final_time = segment_count
+            .checked_sub(1)
+            .and_then(|last| attempt.rows.get(last)?.1);
+
+        run.add_attempt_with_index(RealTime(final_time).into(), index, None, None, None);
+
+        let mut previous_split_time = TimeSpan::zero();
+        let mut previous_split_exists = true;
+        for (segment, &(_, split_time)) in run.segments_mut().iter_mut().zip(&attempt.rows) {
+            let segment_time = split_time.map(|split_time| split_time - previous_split_time);
+
+            segment
+                .segment_history_mut()
+                .insert(index, RealTime(segment_time).into());
+
+            // If the previous segment was skipped, the segment time covers
+            // both segments, so it can't be a best segment.
+            if let Some(segment_time) = segment_time.filter(|_| previous_split_exists) {
+                if segment
+                    .best_segment_time()
+                    .real_time
+                    .is_none_or(|best| segment_time < best)
+                {
+                    segment.set_best_segment_time(RealTime(Some(segment_time)).into());
+                }
+            }
+
+            if let Some(split_time) = split_time {
+                previous_split_time = split_time;
+            }
+            previous_split_exists = split_time.is_some();
+        }
+
+        if let Some(final_time) = final_time {
+            if personal_best.is_none_or(|(pb_time, _)| final_time < pb_time) {
+                personal_best = Some((final_time, attempt));
+            }
+        }
+    }
+
+    if let Some((_, attempt)) = personal_best {
+        for (segment, &(_, split_time)) in run.segments_mut().iter_mut().zip(&attempt.rows) {
+            segment.set_personal_best_split_time(RealTime(split_time).into());
+        }
+    }
+
+    run.set_attempt_count(attempts.len() as u32);
+
+    Ok(run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Time;
+
+    const FIRST: &str = "Segment,Split Time
+Forsaken City,2:10.00
+Old Site,5:00.00
+Celestial Resort,9:00.00
+";
+
+    const SECOND: &str = "Segment,Split Time
+Forsaken City,2:00.00
+Old Site,
+Celestial Resort,5:30.00
+";
+
+    const THIRD: &str = "Segment,Split Time
+Forsaken City,2:05.00
+Old Site,4:50.00
+Celestial Resort,8:30.00
+Golden Ridge,12:00.00
+";
+
+    fn time(text: &str) -> Time {
+        RealTime(Some(text.parse().unwrap())).into()
+    }
+
+    #[test]
+    fn reconstructs_run() {
+        let run = parse([FIRST, SECOND, THIRD]).unwrap();
+
+        let names: Vec<_> = run.segments().iter().map(|s| s.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "Forsaken City",
+                "Old Site",
+                "Celestial Resort",
+                "Golden Ridge"
+            ],
+        );
+        assert_eq!(run.attempt_count(), 3);
+
+        let history = run.attempt_history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].time(), Time::default());
+        assert_eq!(history[1].time(), Time::default());
+        assert_eq!(history[2].time(), time("12:00"));
+
+        let segment_history =
+            |segment: usize, index: i32| run.segment(segment).segment_history().get(index);
+        assert_eq!(segment_history(0, 1), Some(time("2:10")));
+        assert_eq!(segment_history(1, 1), Some(time("2:50")));
+        assert_eq!(segment_history(2, 1), Some(time("4:00")));
+        assert_eq!(segment_history(3, 1), None);
+        assert_eq!(segment_history(0, 2), Some(time("2:00")));
+        assert_eq!(segment_history(1, 2), Some(Time::default()));
+        assert_eq!(segment_history(2, 2), Some(time("3:30")));
+        assert_eq!(segment_history(3, 2), None);
+        assert_eq!(segment_history(2, 3), Some(time("3:40")));
+        assert_eq!(segment_history(3, 3), Some(time("3:30")));
+
+        let best_segments: Vec<_> = run
+            .segments()
+            .iter()
+            .map(|s| s.best_segment_time())
+            .collect();
+        assert_eq!(
+            best_segments,
+            [time("2:00"), time("2:45"), time("3:40"), time("3:30")],
+        );
+
+        let personal_best: Vec<_> = run
+            .segments()
+            .iter()
+            .map(|s| s.personal_best_split_time())
+            .collect();
+        assert_eq!(
+            personal_best,
+            [time("2:05"), time("4:50"), time("8:30"), time("12:00")],
+        );
+    }
+
+    #[test]
+    fn invalid_split_time() {
+        assert!(matches!(
+            parse([FIRST, "Segment,Split Time\nForsaken City,abc\n"]),
+            Err(Error::ParseSplitTime { attempt: 1, .. }),
+        ));
+    }
+}
@@ -97,7 +97,7 @@ pub fn parse<'source>(
     load_files_path: Option<&Path>,
 ) -> Result<ParsedRun<'source>> {
     if let Ok(source) = simdutf8::basic::from_utf8(source) {
-        if let Ok(run) = livesplit::parse(source) {
+        if let Ok(run) = livesplit::parse_and_load_files(source, load_files_path) {
             return Ok(parsed(run, TimerKind::LiveSplit));
         }
 
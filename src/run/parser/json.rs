@@ -0,0 +1,252 @@
+//! Provides the parser for the JSON files written by the
+//! [JSON Saver](crate::run::saver::json). The format is documented there.
+
+use super::livesplit::parse_time_span;
+use crate::{
+    platform::prelude::*,
+    run::{AddComparisonError, GameTimeEndIndexError, LinkedLayout},
+    settings::Image,
+    util::ordered_map::Map,
+    AtomicDateTime, DateTime, Run, Segment as LiveSplitSegment, Time, TimeSpan,
+};
+use core::result::Result as StdResult;
+use serde::{de, Deserializer};
+use serde_derive::Deserialize;
+use serde_json::Error as JsonError;
+use time::{Date, PrimitiveDateTime};
+
+/// The Error type for JSON files that couldn't be parsed by the JSON Parser.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to parse JSON.
+    Json {
+        /// The underlying error.
+        #[cfg_attr(not(feature = "std"), snafu(source(false)))]
+        source: JsonError,
+    },
+    /// Parsed comparison has an invalid name.
+    InvalidComparisonName {
+        /// The underlying error.
+        source: AddComparisonError,
+    },
+    /// The index of the segment at which the game time stops is invalid.
+    InvalidGameTimeEndIndex {
+        /// The underlying error.
+        source: GameTimeEndIndexError,
+    },
+}
+
+/// The Result type for the JSON Parser.
+pub type Result<T> = StdResult<T, Error>;
+
+#[derive(Copy, Clone)]
+struct JsonTimeSpan(TimeSpan);
+
+impl<'de> serde::Deserialize<'de> for JsonTimeSpan {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text: String = serde::Deserialize::deserialize(deserializer)?;
+        parse_time_span(&text).map(Self).map_err(de::Error::custom)
+    }
+}
+
+fn parse_date_time(text: &str) -> Option<DateTime> {
+    let (date, time) = text.strip_suffix('Z')?.split_once('T')?;
+    let (year, rem) = date.split_once('-')?;
+    let (month, day) = rem.split_once('-')?;
+    let (hour, rem) = time.split_once(':')?;
+    let (minute, rem) = rem.split_once(':')?;
+    let (second, fraction) = rem.split_once('.').unwrap_or((rem, ""));
+
+    let nanosecond = if fraction.is_empty() {
+        0
+    } else if fraction.len() <= 9 && fraction.bytes().all(|b| b.is_ascii_digit()) {
+        fraction.parse::<u32>().ok()? * 10u32.pow(9 - fraction.len() as u32)
+    } else {
+        return None;
+    };
+
+    Some(
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(
+                year.parse().ok()?,
+                month.parse::<u8>().ok()?.try_into().ok()?,
+                day.parse().ok()?,
+            )
+            .ok()?,
+            time::Time::from_hms_nano(
+                hour.parse().ok()?,
+                minute.parse().ok()?,
+                second.parse().ok()?,
+                nanosecond,
+            )
+            .ok()?,
+        )
+        .assume_utc(),
+    )
+}
+
+struct JsonDateTime(AtomicDateTime);
+
+impl<'de> serde::Deserialize<'de> for JsonDateTime {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Inner {
+            time: String,
+            synced_with_atomic_clock: bool,
+        }
+
+        let inner: Inner = serde::Deserialize::deserialize(deserializer)?;
+        let time = parse_date_time(&inner.time)
+            .ok_or_else(|| de::Error::custom(format!("Not a valid date time: {:?}", inner.time)))?;
+        Ok(Self(AtomicDateTime::new(
+            time,
+            inner.synced_with_atomic_clock,
+        )))
+    }
+}
+
+#[derive(Copy, Clone, Deserialize)]
+struct JsonTime {
+    real_time: Option<JsonTimeSpan>,
+    game_time: Option<JsonTimeSpan>,
+}
+
+impl From<JsonTime> for Time {
+    fn from(time: JsonTime) -> Self {
+        Time::new()
+            .with_real_time(time.real_time.map(|t| t.0))
+            .with_game_time(time.game_time.map(|t| t.0))
+    }
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    run_id: String,
+    platform_name: String,
+    uses_emulator: bool,
+    region_name: String,
+    speedrun_com_variables: Map<String>,
+    custom_variables: Map<String>,
+    runners: Vec<String>,
+    frame_rate: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct Attempt {
+    index: i32,
+    time: JsonTime,
+    started: Option<JsonDateTime>,
+    ended: Option<JsonDateTime>,
+    pause_time: Option<JsonTimeSpan>,
+}
+
+#[derive(Deserialize)]
+struct HistoryElement {
+    index: i32,
+    time: JsonTime,
+}
+
+#[derive(Deserialize)]
+struct Segment {
+    name: String,
+    icon: Image,
+    split_times: Map<JsonTime>,
+    best_segment_time: JsonTime,
+    segment_history: Vec<HistoryElement>,
+    variables: Map<String>,
+}
+
+#[derive(Deserialize)]
+struct JsonRun {
+    game_icon: Image,
+    game_name: String,
+    category_name: String,
+    metadata: Metadata,
+    linked_layout: Option<LinkedLayout>,
+    offset: JsonTimeSpan,
+    attempt_count: u32,
+    attempt_history: Vec<Attempt>,
+    custom_comparisons: Vec<String>,
+    segments: Vec<Segment>,
+    game_time_end_index: Option<usize>,
+    auto_splitter_settings: String,
+}
+
+/// Attempts to parse a JSON file written by the
+/// [JSON Saver](crate::run::saver::json).
+pub fn parse(source: &str) -> Result<Run> {
+    let json: JsonRun = serde_json::from_str(source).map_err(|source| Error::Json { source })?;
+
+    let mut run = Run::new();
+
+    run.set_game_icon(json.game_icon);
+    run.set_game_name(json.game_name);
+    run.set_category_name(json.category_name);
+
+    let metadata = run.metadata_mut();
+    metadata.set_run_id(json.metadata.run_id);
+    metadata.set_platform_name(json.metadata.platform_name);
+    metadata.set_emulator_usage(json.metadata.uses_emulator);
+    metadata.set_region_name(json.metadata.region_name);
+    metadata.speedrun_com_variables = json.metadata.speedrun_com_variables;
+    for (name, value) in json.metadata.custom_variables.iter() {
+        metadata
+            .custom_variable_mut(name)
+            .permanent()
+            .set_value(value.as_str());
+    }
+    metadata.runners = json.metadata.runners;
+    metadata.set_frame_rate(json.metadata.frame_rate);
+
+    run.set_linked_layout(json.linked_layout);
+    run.set_offset(json.offset.0);
+    run.set_attempt_count(json.attempt_count);
+
+    for attempt in json.attempt_history {
+        run.add_attempt_with_index(
+            attempt.time.into(),
+            attempt.index,
+            attempt.started.map(|d| d.0),
+            attempt.ended.map(|d| d.0),
+            attempt.pause_time.map(|t| t.0),
+        );
+    }
+
+    for comparison in json.custom_comparisons {
+        match run.add_custom_comparison(comparison) {
+            Ok(()) | Err(AddComparisonError::DuplicateName) => {}
+            Err(source) => return Err(Error::InvalidComparisonName { source }),
+        }
+    }
+
+    for json_segment in json.segments {
+        let mut segment = LiveSplitSegment::new(json_segment.name);
+        segment.set_icon(json_segment.icon);
+        for (comparison, &time) in json_segment.split_times.iter() {
+            segment.comparisons_mut().set(comparison, time.into());
+        }
+        segment.set_best_segment_time(json_segment.best_segment_time.into());
+        for element in json_segment.segment_history {
+            segment
+                .segment_history_mut()
+                .insert(element.index, element.time.into());
+        }
+        for (name, value) in json_segment.variables.iter() {
+            segment.variables_mut().insert(name.into(), value.clone());
+        }
+        run.push_segment(segment);
+    }
+
+    run.set_game_time_end_index(json.game_time_end_index)
+        .map_err(|source| Error::InvalidGameTimeEndIndex { source })?;
+    *run.auto_splitter_settings_mut() = json.auto_splitter_settings;
+
+    Ok(run)
+}
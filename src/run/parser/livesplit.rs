@@ -1,8 +1,8 @@
 //! Provides the parser for LiveSplit splits files.
 
 use crate::{
-    platform::prelude::*,
-    run::{AddComparisonError, LinkedLayout},
+    platform::{path::Path, prelude::*},
+    run::{AddComparisonError, GameTimeEndIndexError, LinkedLayout},
     settings::Image,
     util::{
         ascii_char::AsciiChar,
@@ -12,7 +12,7 @@ use crate::{
                 parse_attributes, parse_base, parse_children, reencode_children, text,
                 text_as_escaped_string_err, text_parsed, Error as XmlError,
             },
-            Reader,
+            Attributes, Reader,
         },
     },
     AtomicDateTime, DateTime, Run, RunMetadata, Segment, Time, TimeSpan,
@@ -56,6 +56,11 @@ pub enum Error {
     },
     /// Failed to parse a boolean.
     ParseBool,
+    /// The index of the segment at which the game time stops is invalid.
+    InvalidGameTimeEndIndex {
+        /// The underlying error.
+        source: GameTimeEndIndexError,
+    },
 }
 
 impl From<XmlError> for Error {
@@ -163,7 +168,7 @@ where
     })
 }
 
-fn parse_time_span(text: &str) -> Result<TimeSpan> {
+pub(super) fn parse_time_span(text: &str) -> Result<TimeSpan> {
     if let Some((before_dot, after_dot)) = AsciiChar::DOT.split_once(text) {
         if AsciiChar::COLON.contains(after_dot) {
             const SECS_PER_DAY: i64 = 24 * 60 * 60;
@@ -264,6 +269,10 @@ fn parse_metadata(
                 type_hint(text(reader, |t| var.set_value(t)))?;
                 Ok(())
             }),
+            "Runners" => parse_children(reader, |reader, _, _| {
+                text(reader, |t| metadata.add_runner(t))
+            }),
+            "FrameRate" => text_parsed(reader, |t| metadata.set_frame_rate(Some(t))),
             _ => end_tag(reader),
         })
     } else {
@@ -271,18 +280,100 @@ fn parse_metadata(
     }
 }
 
+fn icon<F>(
+    reader: &mut Reader<'_>,
+    attributes: Attributes<'_>,
+    image_buf: &mut Vec<MaybeUninit<u8>>,
+    load_files_path: Option<&Path>,
+    f: F,
+) -> Result<()>
+where
+    F: FnOnce(Image),
+{
+    let mut file = None;
+    type_hint(parse_attributes(attributes, |k, v| {
+        if k == "file" {
+            file = Some(v.unescape_cow());
+            Ok(false)
+        } else {
+            Ok(true)
+        }
+    }))?;
+
+    if let Some(file) = file {
+        // The icon is stored in a separate file. If that file can't be loaded,
+        // we fall back to an empty icon rather than failing to parse the
+        // splits.
+        f(load_icon_file(load_files_path, &file).unwrap_or_default());
+        end_tag(reader)
+    } else {
+        image(reader, image_buf, |i| f(Image::new(i.into(), Image::ICON)))
+    }
+}
+
+/// Icon files that are larger than this are not loaded.
+#[cfg(feature = "std")]
+const MAX_ICON_FILE_SIZE: u64 = 16 << 20;
+
+#[cfg(feature = "std")]
+fn load_icon_file(load_files_path: Option<&Path>, file: &str) -> Option<Image> {
+    use std::{io::Read, path::Component};
+
+    // The splits file may come from an untrusted source, so the icon file may
+    // only be referred to by a relative path that stays within the directory
+    // of the splits file.
+    let file = Path::new(file);
+    if file.as_os_str().is_empty()
+        || !file
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return None;
+    }
+
+    let directory = match load_files_path?.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    // Symbolic links may still point outside of the directory, so we compare
+    // the resolved paths.
+    let directory = directory.canonicalize().ok()?;
+    let path = directory.join(file).canonicalize().ok()?;
+    if !path.starts_with(&directory) {
+        return None;
+    }
+
+    let mut buf = Vec::new();
+    std::fs::File::open(path)
+        .ok()?
+        .take(MAX_ICON_FILE_SIZE + 1)
+        .read_to_end(&mut buf)
+        .ok()?;
+    if buf.len() as u64 > MAX_ICON_FILE_SIZE {
+        return None;
+    }
+
+    Some(Image::new(buf.as_slice().into(), Image::ICON))
+}
+
+#[cfg(not(feature = "std"))]
+const fn load_icon_file(_load_files_path: Option<&Path>, _file: &str) -> Option<Image> {
+    None
+}
+
 fn parse_segment(
     version: Version,
     reader: &mut Reader<'_>,
     image_buf: &mut Vec<MaybeUninit<u8>>,
+    load_files_path: Option<&Path>,
     run: &mut Run,
 ) -> Result<Segment> {
     let mut segment = Segment::new("");
 
-    parse_children(reader, |reader, tag, _| match tag.name() {
+    parse_children(reader, |reader, tag, attributes| match tag.name() {
         "Name" => text(reader, |t| segment.set_name(t)),
-        "Icon" => image(reader, image_buf, |i| {
-            segment.set_icon(Image::new(i.into(), Image::ICON))
+        "Icon" => icon(reader, attributes, image_buf, load_files_path, |i| {
+            segment.set_icon(i)
         }),
         "SplitTimes" => {
             if version >= Version(1, 3, 0, 0) {
@@ -421,6 +512,16 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
 
 /// Attempts to parse a LiveSplit splits file.
 pub fn parse(source: &str) -> Result<Run> {
+    parse_and_load_files(source, None)
+}
+
+/// Attempts to parse a LiveSplit splits file. In addition to the source to
+/// parse, you can specify the path of the splits file, which is then used to
+/// load icons that are stored as separate files next to the splits file. Only
+/// files within the directory of the splits file are loaded. Icons that can't
+/// be loaded are replaced by empty icons. This is only supported when the
+/// `std` feature is enabled.
+pub fn parse_and_load_files(source: &str, load_files_path: Option<&Path>) -> Result<Run> {
     let mut reader = Reader::new(source);
 
     let mut image_buf = Vec::new();
@@ -428,6 +529,7 @@ pub fn parse(source: &str) -> Result<Run> {
     let mut run = Run::new();
 
     let mut required_flags = 0u8;
+    let mut game_time_end_index = None;
 
     parse_base(&mut reader, "Run", |reader, attributes| {
         let mut version = Version(1, 0, 0, 0);
@@ -436,11 +538,11 @@ pub fn parse(source: &str) -> Result<Run> {
             Ok(())
         }))?;
 
-        parse_children(reader, |reader, tag, _| match tag.name() {
+        parse_children(reader, |reader, tag, attributes| match tag.name() {
             "GameIcon" => {
                 required_flags |= 1;
-                image(reader, &mut image_buf, |i| {
-                    run.set_game_icon(Image::new(i.into(), Image::ICON))
+                icon(reader, attributes, &mut image_buf, load_files_path, |i| {
+                    run.set_game_icon(i)
                 })
             }
             "GameName" => {
@@ -459,6 +561,7 @@ pub fn parse(source: &str) -> Result<Run> {
                 required_flags |= 1 << 4;
                 text_parsed(reader, |t| run.set_attempt_count(t))
             }
+            "GameTimeEndIndex" => text_parsed(reader, |t| game_time_end_index = Some(t)),
             "AttemptHistory" => parse_attempt_history(version, reader, &mut run),
             "RunHistory" => parse_run_history(version, reader, &mut run),
             "Metadata" => parse_metadata(version, reader, run.metadata_mut()),
@@ -466,7 +569,13 @@ pub fn parse(source: &str) -> Result<Run> {
                 required_flags |= 1 << 5;
                 parse_children(reader, |reader, tag, _| {
                     if tag.name() == "Segment" {
-                        let segment = parse_segment(version, reader, &mut image_buf, &mut run)?;
+                        let segment = parse_segment(
+                            version,
+                            reader,
+                            &mut image_buf,
+                            load_files_path,
+                            &mut run,
+                        )?;
                         run.push_segment(segment);
                         Ok(())
                     } else {
@@ -497,6 +606,10 @@ pub fn parse(source: &str) -> Result<Run> {
         });
     }
 
+    // The segments are only known once the whole file is parsed.
+    run.set_game_time_end_index(game_time_end_index)
+        .map_err(|source| Error::InvalidGameTimeEndIndex { source })?;
+
     Ok(run)
 }
 
@@ -28,9 +28,11 @@
 //! let run = parsed.run;
 //! ```
 
+pub mod attempt_logs;
 pub mod composite;
 pub mod face_split;
 pub mod flitter;
+pub mod json;
 pub mod livesplit;
 pub mod llanfair;
 pub mod llanfair_gered;
@@ -332,43 +332,46 @@ pub fn parse(source: &str) -> Result<(Run, Cow<'_, str>)> {
         }
     }
 
-    if let Some(runner) = splits
-        .runners
-        .and_then(|runners| runners.into_iter().next())
-    {
+    for (index, runner) in splits.runners.into_iter().flatten().enumerate() {
         let name = runner.longname.unwrap_or(runner.shortname);
-        if !name.trim_start().is_empty() {
-            run.metadata_mut()
-                .custom_variable_mut("Runner")
-                .permanent()
-                .set_value(name);
-        }
-        if let Some(links) = runner.links {
-            if let Some(twitter_id) = links.twitter_id {
-                run.metadata_mut()
-                    .custom_variable_mut("Twitter")
-                    .permanent()
-                    .set_value(twitter_id);
-            }
-            if let Some(twitch_id) = links.twitch_id {
-                run.metadata_mut()
-                    .custom_variable_mut("Twitch")
-                    .permanent()
-                    .set_value(twitch_id);
-            }
-            if let Some(speedruncom_id) = links.speedruncom_id {
+        let has_name = !name.trim_start().is_empty();
+        if index == 0 {
+            if has_name {
                 run.metadata_mut()
-                    .custom_variable_mut("speedrun.com")
+                    .custom_variable_mut("Runner")
                     .permanent()
-                    .set_value(speedruncom_id);
+                    .set_value(&*name);
             }
-            if let Some(splitsio_id) = links.splitsio_id {
-                run.metadata_mut()
-                    .custom_variable_mut("Splits I/O")
-                    .permanent()
-                    .set_value(splitsio_id);
+            if let Some(links) = runner.links {
+                if let Some(twitter_id) = links.twitter_id {
+                    run.metadata_mut()
+                        .custom_variable_mut("Twitter")
+                        .permanent()
+                        .set_value(twitter_id);
+                }
+                if let Some(twitch_id) = links.twitch_id {
+                    run.metadata_mut()
+                        .custom_variable_mut("Twitch")
+                        .permanent()
+                        .set_value(twitch_id);
+                }
+                if let Some(speedruncom_id) = links.speedruncom_id {
+                    run.metadata_mut()
+                        .custom_variable_mut("speedrun.com")
+                        .permanent()
+                        .set_value(speedruncom_id);
+                }
+                if let Some(splitsio_id) = links.splitsio_id {
+                    run.metadata_mut()
+                        .custom_variable_mut("Splits I/O")
+                        .permanent()
+                        .set_value(splitsio_id);
+                }
             }
         }
+        if has_name {
+            run.metadata_mut().add_runner(name);
+        }
     }
 
     if let Some(segments) = splits.segments {
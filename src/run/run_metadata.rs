@@ -49,7 +49,7 @@ impl CustomVariable {
 
 /// The `RunMetadata` struct stores optional information about a run, like the
 /// platform and region of the game.
-#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunMetadata {
     /// The speedrun.com Run ID of the run. You need to ensure that the record
     /// on speedrun.com matches up with the Personal Best of this run. This may
@@ -77,6 +77,17 @@ pub struct RunMetadata {
     /// the runner. Additionally auto splitters or other sources may provide
     /// temporary custom variables that are not stored in the splits files.
     pub custom_variables: Map<CustomVariable>,
+    /// The names of all the runners participating in the run. This is mostly
+    /// useful for relay and co-op categories where more than a single runner
+    /// is involved. This may be empty if it's not specified.
+    #[serde(default)]
+    pub runners: Vec<String>,
+    /// The frame rate of the game in frames per second. This is used for
+    /// converting between frame counts and times, so the frame rate doesn't
+    /// need to be specified every time. This may be [`None`] if it's not
+    /// specified.
+    #[serde(default)]
+    pub frame_rate: Option<f64>,
 }
 
 impl RunMetadata {
@@ -211,6 +222,52 @@ impl RunMetadata {
         self.custom_variables.iter()
     }
 
+    /// Accesses the names of all the runners participating in the run. This
+    /// may be empty if it's not specified.
+    #[inline]
+    pub fn runners(&self) -> &[String] {
+        &self.runners
+    }
+
+    /// Adds a runner with the name specified to the list of runners
+    /// participating in the run.
+    pub fn add_runner<S>(&mut self, name: S)
+    where
+        S: PopulateString,
+    {
+        let mut runner = String::new();
+        name.populate(&mut runner);
+        self.runners.push(runner);
+    }
+
+    /// Removes the runner at the index specified. Nothing happens if the index
+    /// is out of bounds.
+    pub fn remove_runner(&mut self, index: usize) {
+        if index < self.runners.len() {
+            self.runners.remove(index);
+        }
+    }
+
+    /// Removes all the runners from the list of runners participating in the
+    /// run.
+    pub fn clear_runners(&mut self) {
+        self.runners.clear();
+    }
+
+    /// Accesses the frame rate of the game in frames per second. This may be
+    /// [`None`] if it's not specified.
+    #[inline]
+    pub const fn frame_rate(&self) -> Option<f64> {
+        self.frame_rate
+    }
+
+    /// Sets the frame rate of the game in frames per second. Frame rates that
+    /// are not positive finite numbers are treated as not specified.
+    #[inline]
+    pub fn set_frame_rate(&mut self, frame_rate: Option<f64>) {
+        self.frame_rate = frame_rate.filter(|&fps| fps.is_finite() && fps > 0.0);
+    }
+
     /// Resets all the Metadata Information.
     pub fn clear(&mut self) {
         self.run_id.clear();
@@ -219,5 +276,7 @@ impl RunMetadata {
         self.uses_emulator = false;
         self.speedrun_com_variables.clear();
         self.custom_variables.clear();
+        self.runners.clear();
+        self.frame_rate = None;
     }
 }
@@ -0,0 +1,273 @@
+//! The JSON Saver saves Runs as JSON files. Unlike the LiveSplit splits files,
+//! this format is meant to be easily consumed by other tools and web clients.
+//! It can be parsed again with the [JSON Parser](crate::run::parser::json)
+//! without losing any information.
+//!
+//! # Schema
+//!
+//! The root of the file is an object with the following fields:
+//!
+//! - `game_icon`: The game's icon as a base64 encoded string. Empty if there
+//!   is no icon.
+//! - `game_name`, `category_name`: Strings.
+//! - `metadata`: An object with `run_id`, `platform_name` and `region_name`
+//!   strings, a `uses_emulator` boolean, `speedrun_com_variables` and
+//!   `custom_variables` objects mapping the names of the variables to their
+//!   values, a `runners` array of strings and a `frame_rate` number, which is
+//!   `null` if it's not specified. Only permanent custom variables are stored.
+//! - `linked_layout`: `null`, `"Default"` or `{ "Path": "<path>" }`.
+//! - `offset`: A time.
+//! - `attempt_count`: An integer.
+//! - `attempt_history`: An array of objects with an `index` integer, a `time`,
+//!   the `started` and `ended` date times and the `pause_time`, where all but
+//!   the index and the time may be `null`.
+//! - `custom_comparisons`: An array with the names of all the custom
+//!   comparisons, including `Personal Best`.
+//! - `segments`: An array of objects with a `name` string, an `icon` like the
+//!   game's icon, a `split_times` object mapping the names of the custom
+//!   comparisons to their split time, the `best_segment_time`, a
+//!   `segment_history` array of objects with an `index` integer and a `time`,
+//!   and a `variables` object mapping the names of the segment's variables to
+//!   their values.
+//! - `game_time_end_index`: The index of the segment at which the game time
+//!   ends, or `null`.
+//! - `auto_splitter_settings`: The auto splitter settings as an XML string.
+//!
+//! A time is an object with a `real_time` and a `game_time`, each of which is
+//! either `null` or a string of the form `[-][d.]hh:mm:ss.fffffffff`. The
+//! `offset` and the `pause_time` only consist of such a string. A date time is
+//! an object with a `time` string in the RFC 3339 form
+//! `yyyy-mm-ddThh:mm:ss.fffffffffZ` and a `synced_with_atomic_clock` boolean.
+//!
+//! # Examples
+//!
+//! Using the JSON Saver to save a Run as a JSON file.
+//!
+//! ```no_run
+//! use livesplit_core::run::saver::{json, livesplit::IoWrite};
+//! use livesplit_core::{Run, Segment};
+//! use std::fs::File;
+//! use std::io::BufWriter;
+//!
+//! // Create a run object that we can use.
+//! let mut run = Run::new();
+//! run.set_game_name("Super Mario Odyssey");
+//! run.set_category_name("Any%");
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! // Create the file.
+//! let file = File::create("path/to/splits_file.json");
+//! let writer = BufWriter::new(file.expect("Failed creating the file"));
+//!
+//! // Save the run as a JSON file.
+//! json::save_run(&run, IoWrite(writer)).expect("Couldn't save the splits file");
+//! ```
+
+use crate::{
+    platform::prelude::*,
+    run::LinkedLayout,
+    settings::Image,
+    timing::formatter::{Complete, TimeFormatter},
+    util::ordered_map::Map,
+    AtomicDateTime, Run, Time, TimeSpan,
+};
+use core::fmt;
+use serde::Serializer;
+use serde_derive::Serialize;
+use time::UtcOffset;
+
+struct JsonTimeSpan(TimeSpan);
+
+impl serde::Serialize for JsonTimeSpan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&Complete.format(self.0))
+    }
+}
+
+struct JsonDateTime(AtomicDateTime);
+
+impl serde::Serialize for JsonDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Inner<'a> {
+            time: &'a str,
+            synced_with_atomic_clock: bool,
+        }
+
+        let date = self.0.time.to_offset(UtcOffset::UTC);
+        let (year, month, day) = date.to_calendar_date();
+        let month = month as u8;
+        let (hour, minute, second, nanosecond) = date.to_hms_nano();
+
+        serde::Serialize::serialize(
+            &Inner {
+                time: &format!(
+                    "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{nanosecond:09}Z"
+                ),
+                synced_with_atomic_clock: self.0.synced_with_atomic_clock,
+            },
+            serializer,
+        )
+    }
+}
+
+#[derive(Default, Serialize)]
+struct JsonTime {
+    real_time: Option<JsonTimeSpan>,
+    game_time: Option<JsonTimeSpan>,
+}
+
+impl From<Time> for JsonTime {
+    fn from(time: Time) -> Self {
+        Self {
+            real_time: time.real_time.map(JsonTimeSpan),
+            game_time: time.game_time.map(JsonTimeSpan),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Metadata<'a> {
+    run_id: &'a str,
+    platform_name: &'a str,
+    uses_emulator: bool,
+    region_name: &'a str,
+    speedrun_com_variables: &'a Map<String>,
+    custom_variables: Map<&'a str>,
+    runners: &'a [String],
+    frame_rate: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct Attempt {
+    index: i32,
+    time: JsonTime,
+    started: Option<JsonDateTime>,
+    ended: Option<JsonDateTime>,
+    pause_time: Option<JsonTimeSpan>,
+}
+
+#[derive(Serialize)]
+struct HistoryElement {
+    index: i32,
+    time: JsonTime,
+}
+
+#[derive(Serialize)]
+struct Segment<'a> {
+    name: &'a str,
+    icon: &'a Image,
+    split_times: Map<JsonTime>,
+    best_segment_time: JsonTime,
+    segment_history: Vec<HistoryElement>,
+    variables: Map<&'a str>,
+}
+
+#[derive(Serialize)]
+struct JsonRun<'a> {
+    game_icon: &'a Image,
+    game_name: &'a str,
+    category_name: &'a str,
+    metadata: Metadata<'a>,
+    linked_layout: Option<&'a LinkedLayout>,
+    offset: JsonTimeSpan,
+    attempt_count: u32,
+    attempt_history: Vec<Attempt>,
+    custom_comparisons: &'a [String],
+    segments: Vec<Segment<'a>>,
+    game_time_end_index: Option<usize>,
+    auto_splitter_settings: &'a str,
+}
+
+/// Saves the Run provided as a JSON file. Only permanent custom variables are
+/// stored and out of the comparison times, only the ones of the custom
+/// comparisons are stored, as the others are generated.
+pub fn save_run<W: fmt::Write>(run: &Run, mut writer: W) -> fmt::Result {
+    let metadata = run.metadata();
+
+    let mut custom_variables = Map::default();
+    for (name, variable) in metadata.custom_variables() {
+        if variable.is_permanent {
+            custom_variables.insert(name, variable.value.as_str());
+        }
+    }
+
+    let json_run = JsonRun {
+        game_icon: run.game_icon(),
+        game_name: run.game_name(),
+        category_name: run.category_name(),
+        metadata: Metadata {
+            run_id: metadata.run_id(),
+            platform_name: metadata.platform_name(),
+            uses_emulator: metadata.uses_emulator(),
+            region_name: metadata.region_name(),
+            speedrun_com_variables: &metadata.speedrun_com_variables,
+            custom_variables,
+            runners: metadata.runners(),
+            frame_rate: metadata.frame_rate(),
+        },
+        linked_layout: run.linked_layout(),
+        offset: JsonTimeSpan(run.offset()),
+        attempt_count: run.attempt_count(),
+        attempt_history: run
+            .attempt_history()
+            .iter()
+            .map(|attempt| Attempt {
+                index: attempt.index(),
+                time: attempt.time().into(),
+                started: attempt.started().map(JsonDateTime),
+                ended: attempt.ended().map(JsonDateTime),
+                pause_time: attempt.pause_time().map(JsonTimeSpan),
+            })
+            .collect(),
+        custom_comparisons: run.custom_comparisons(),
+        segments: run
+            .segments()
+            .iter()
+            .map(|segment| {
+                let mut split_times = Map::default();
+                for (comparison, time) in segment.comparisons().iter() {
+                    if run.custom_comparisons().iter().any(|c| **c == **comparison) {
+                        split_times.insert(&**comparison, JsonTime::from(*time));
+                    }
+                }
+
+                // The variables are stored in a hash map, so they are sorted to
+                // keep the output stable.
+                let mut variables: Vec<_> = segment.variables().iter().collect();
+                variables.sort_unstable();
+                let mut variables_map = Map::default();
+                for (name, value) in variables {
+                    variables_map.insert(name.as_str(), value.as_str());
+                }
+
+                Segment {
+                    name: segment.name(),
+                    icon: segment.icon(),
+                    split_times,
+                    best_segment_time: segment.best_segment_time().into(),
+                    segment_history: segment
+                        .segment_history()
+                        .iter()
+                        .map(|&(index, time)| HistoryElement {
+                            index,
+                            time: time.into(),
+                        })
+                        .collect(),
+                    variables: variables_map,
+                }
+            })
+            .collect(),
+        game_time_end_index: run.game_time_end_index(),
+        auto_splitter_settings: run.auto_splitter_settings(),
+    };
+
+    let json = serde_json::to_string(&json_run).map_err(|_| fmt::Error)?;
+    writer.write_str(&json)
+}
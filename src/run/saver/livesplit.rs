@@ -62,19 +62,26 @@ where
     })
 }
 
+type StoreImage<'a> = dyn FnMut(&Image) -> Option<String> + 'a;
+
 fn image<W: fmt::Write>(
     writer: &mut Writer<W>,
     tag: &str,
     image: &Image,
     base64_buf: &mut Vec<MaybeUninit<u8>>,
     image_buf: &mut Cow<'_, [u8]>,
+    store_image: &mut StoreImage<'_>,
 ) -> fmt::Result {
-    writer.tag(tag, |tag| {
+    writer.tag(tag, |mut tag| {
         let image_data = image.data();
         if image_data.is_empty() {
             return Ok(());
         }
 
+        if let Some(file) = store_image(image) {
+            return tag.attribute("file", file.as_str());
+        }
+
         let len = image_data.len();
         let image_buf = image_buf.to_mut();
         image_buf.truncate(LSS_IMAGE_HEADER.len());
@@ -169,13 +176,107 @@ pub fn save_timer<W: fmt::Write>(timer: &Timer, writer: W) -> fmt::Result {
 /// function if the Run is in use by a timer in order to properly save the
 /// current attempt as well.
 pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
+    save_run_inner(run, writer, &mut |_| None)
+}
+
+/// Describes an error that happened when saving a Run with its icons stored as
+/// separate files.
+#[cfg(feature = "std")]
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum ExternalIconsError {
+    /// The directory for the icons is not a relative path that stays within
+    /// the directory of the splits file. The icons would not be loaded from
+    /// any other directory.
+    InvalidDirectory,
+    /// The path of the directory for the icons refers to something other than
+    /// a directory.
+    NotADirectory,
+    /// Failed to create the directory for the icons.
+    CreateDirectory {
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// Failed to write an icon file.
+    WriteIcon {
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// Failed to write the splits file.
+    Format {
+        /// The underlying error.
+        source: fmt::Error,
+    },
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss), but instead of embedding
+/// the icons into the splits file, they are written as separate files into the
+/// directory provided, which is relative to the splits file at the path
+/// provided. The splits file then only refers to these files. This keeps the
+/// splits file small and works better when storing it in version control. The
+/// files are named after the [`ImageId`](crate::settings::ImageId) of the
+/// icons, so the same icon is only stored once. Use the LiveSplit parser's
+/// [`parse_and_load_files`](crate::run::parser::livesplit::parse_and_load_files)
+/// to load such a splits file again. The directory needs to be a relative path
+/// that stays within the directory of the splits file, as the icons are not
+/// loaded from anywhere else.
+#[cfg(feature = "std")]
+pub fn save_run_with_external_icons<W: fmt::Write>(
+    run: &Run,
+    writer: W,
+    splits_path: &std::path::Path,
+    icons_directory: &str,
+) -> Result<(), ExternalIconsError> {
+    use snafu::{ensure, ResultExt};
+    use std::path::{Component, Path};
+
+    let relative_directory = Path::new(icons_directory);
+    ensure!(
+        !relative_directory.as_os_str().is_empty()
+            && relative_directory
+                .components()
+                .all(|component| matches!(component, Component::Normal(_))),
+        InvalidDirectory,
+    );
+
+    let directory = splits_path.with_file_name(relative_directory);
+    ensure!(!directory.exists() || directory.is_dir(), NotADirectory);
+    std::fs::create_dir_all(&directory).context(CreateDirectory)?;
+
+    let icons =
+        core::iter::once(run.game_icon()).chain(run.segments().iter().map(crate::Segment::icon));
+    for icon in icons.filter(|icon| !icon.is_empty()) {
+        let path = directory.join(icon.id().to_string());
+        if !path.exists() {
+            std::fs::write(path, icon.data()).context(WriteIcon)?;
+        }
+    }
+
+    save_run_inner(run, writer, &mut |image| {
+        Some(format!("{icons_directory}/{}", image.id()))
+    })
+    .context(Format)
+}
+
+fn save_run_inner<W: fmt::Write>(
+    run: &Run,
+    writer: W,
+    store_image: &mut StoreImage<'_>,
+) -> fmt::Result {
     let writer = &mut Writer::new_with_default_header(writer)?;
 
     let base64_buf = &mut Vec::new();
     let image_buf = &mut Cow::Borrowed(&LSS_IMAGE_HEADER[..]);
 
     writer.tag_with_content("Run", [("version", Text::new_escaped("1.8.0"))], |writer| {
-        image(writer, "GameIcon", run.game_icon(), base64_buf, image_buf)?;
+        image(
+            writer,
+            "GameIcon",
+            run.game_icon(),
+            base64_buf,
+            image_buf,
+            store_image,
+        )?;
         writer.tag_with_text_content("GameName", NO_ATTRIBUTES, run.game_name())?;
         writer.tag_with_text_content("CategoryName", NO_ATTRIBUTES, run.category_name())?;
 
@@ -205,7 +306,20 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                 |writer, (name, var)| {
                     writer.tag_with_text_content("Variable", [("name", name)], var.value.as_str())
                 },
-            )
+            )?;
+            if !metadata.runners().is_empty() {
+                scoped_iter(writer, "Runners", metadata.runners(), |writer, runner| {
+                    writer.tag_with_text_content("Runner", NO_ATTRIBUTES, runner.as_str())
+                })?;
+            }
+            if let Some(frame_rate) = metadata.frame_rate() {
+                writer.tag_with_text_content(
+                    "FrameRate",
+                    NO_ATTRIBUTES,
+                    DisplayAlreadyEscaped(frame_rate),
+                )?;
+            }
+            Ok(())
         })?;
 
         writer.tag_with_text_content(
@@ -228,6 +342,13 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
             NO_ATTRIBUTES,
             DisplayAlreadyEscaped(run.attempt_count()),
         )?;
+        if let Some(index) = run.game_time_end_index() {
+            writer.tag_with_text_content(
+                "GameTimeEndIndex",
+                NO_ATTRIBUTES,
+                DisplayAlreadyEscaped(index),
+            )?;
+        }
 
         scoped_iter(
             writer,
@@ -274,7 +395,14 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
         scoped_iter(writer, "Segments", run.segments(), |writer, segment| {
             writer.tag_with_content("Segment", NO_ATTRIBUTES, |writer| {
                 writer.tag_with_text_content("Name", NO_ATTRIBUTES, segment.name())?;
-                image(writer, "Icon", segment.icon(), base64_buf, image_buf)?;
+                image(
+                    writer,
+                    "Icon",
+                    segment.icon(),
+                    base64_buf,
+                    image_buf,
+                    store_image,
+                )?;
 
                 scoped_iter(
                     writer,
@@ -25,4 +25,5 @@
 //! livesplit::save_run(&run, IoWrite(writer)).expect("Couldn't save the splits file");
 //! ```
 
+pub mod json;
 pub mod livesplit;
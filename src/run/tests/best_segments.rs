@@ -0,0 +1,64 @@
+use crate::{Run, Segment, Time, TimeSpan, TimingMethod};
+use core::cmp::Ordering;
+
+fn run(best_segments: &[Option<f64>]) -> Run {
+    let mut run = Run::new();
+    for (index, &best) in best_segments.iter().enumerate() {
+        let mut segment = Segment::new(format!("{index}"));
+        segment.set_best_segment_time(Time::new().with_real_time(best.map(TimeSpan::from_seconds)));
+        run.push_segment(segment);
+    }
+    run
+}
+
+#[test]
+fn each_run_wins_some_segments() {
+    let a = run(&[Some(5.0), Some(10.0), Some(7.0)]);
+    let b = run(&[Some(6.0), Some(9.0), Some(7.0)]);
+
+    assert_eq!(
+        a.compare_best_segments(&b, TimingMethod::RealTime),
+        [Ordering::Less, Ordering::Greater, Ordering::Equal]
+    );
+    assert_eq!(
+        b.compare_best_segments(&a, TimingMethod::RealTime),
+        [Ordering::Greater, Ordering::Less, Ordering::Equal]
+    );
+}
+
+#[test]
+fn missing_times_lose() {
+    let a = run(&[None, Some(10.0), None]);
+    let b = run(&[Some(6.0), None, None]);
+
+    assert_eq!(
+        a.compare_best_segments(&b, TimingMethod::RealTime),
+        [Ordering::Greater, Ordering::Less, Ordering::Equal]
+    );
+}
+
+#[test]
+fn unequal_segment_counts() {
+    let a = run(&[Some(5.0), Some(10.0), Some(7.0)]);
+    let b = run(&[Some(4.0)]);
+
+    assert_eq!(
+        a.compare_best_segments(&b, TimingMethod::RealTime),
+        [Ordering::Greater, Ordering::Less, Ordering::Less]
+    );
+    assert_eq!(
+        b.compare_best_segments(&a, TimingMethod::RealTime),
+        [Ordering::Less]
+    );
+}
+
+#[test]
+fn only_the_timing_method_specified_is_compared() {
+    let a = run(&[Some(5.0)]);
+    let b = run(&[Some(6.0)]);
+
+    assert_eq!(
+        a.compare_best_segments(&b, TimingMethod::GameTime),
+        [Ordering::Equal]
+    );
+}
@@ -1,6 +1,7 @@
 use crate::{
-    util::tests_helper::{create_timer, run_with_splits},
-    Timer,
+    run::RepairReport,
+    util::tests_helper::{create_timer, run_with_splits, span},
+    Time, Timer,
 };
 
 #[test]
@@ -25,6 +26,48 @@ fn reattaches_unattached_segment_history_elements_by_using_negative_ids() {
     assert_eq!(segments[1].segment_history().try_get_max_index(), Some(1));
 }
 
+#[test]
+fn repair_history_reattaches_and_removes_orphaned_elements() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 4.0]);
+    run_with_splits(&mut timer, &[2.5, 5.0]);
+    let mut run = timer.into_run(true);
+
+    // We remove the second attempt from the history, but keep it in the
+    // segment history. Additionally there's an element without any times that
+    // doesn't belong to any attempt either.
+    run.attempt_history.remove(1);
+    for segment in run.segments_mut() {
+        segment.segment_history_mut().insert(7, Time::default());
+    }
+
+    let report = run.repair_history();
+    assert_eq!(
+        report,
+        RepairReport {
+            reattached: vec![(2, 0)],
+            removed: vec![7],
+        },
+    );
+
+    for segment in run.segments() {
+        let history = segment.segment_history();
+        let indices: Vec<_> = history.iter().map(|&(index, _)| index).collect();
+        assert_eq!(indices, [0, 1, 3]);
+    }
+    assert_eq!(
+        run.segments()[1]
+            .segment_history()
+            .get(0)
+            .unwrap()
+            .game_time,
+        Some(span(2.0)),
+    );
+
+    assert!(run.repair_history().is_empty());
+}
+
 // The below tests should be in crate::timing::timer::tests, but we ended up
 // having to put them here due to run.attempt_history being private.
 
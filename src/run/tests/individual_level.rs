@@ -0,0 +1,107 @@
+use crate::{
+    comparison::personal_best,
+    util::tests_helper::{create_run, run_with_splits, run_with_splits_opt, span},
+    Run, Timer, TimingMethod,
+};
+
+fn run() -> Run {
+    let mut run = create_run(&["A", "B", "C"]);
+    run.set_game_name("Game");
+    run.set_category_name("Any%");
+    let mut timer = Timer::new(run).unwrap();
+    run_with_splits(&mut timer, &[10.0, 25.0, 40.0]);
+    run_with_splits(&mut timer, &[12.0, 24.0, 41.0]);
+    run_with_splits_opt(&mut timer, &[None, Some(26.0), Some(45.0)]);
+    timer.into_run(true)
+}
+
+#[test]
+fn extracts_a_middle_segment() {
+    let run = run();
+    let il = run.extract_segment_as_il(1, TimingMethod::GameTime);
+
+    assert_eq!(il.len(), 1);
+    assert_eq!(il.game_name(), "Game");
+    assert_eq!(il.category_name(), "B (Any%)");
+
+    let segment = il.segment(0);
+    assert_eq!(segment.name(), "B");
+    assert_eq!(segment.best_segment_time().game_time, Some(span(12.0)));
+
+    // The first attempt is the full-game Personal Best, but the second attempt
+    // is faster in B, so it's the Personal Best of the individual level.
+    assert_eq!(
+        segment.personal_best_split_time().game_time,
+        Some(span(12.0)),
+    );
+    assert_eq!(
+        segment.comparison(personal_best::NAME),
+        il.attempt_history()[1].time(),
+    );
+
+    assert_eq!(run.len(), 3);
+}
+
+#[test]
+fn attempts_are_the_segment_times() {
+    let il = run().extract_segment_as_il(1, TimingMethod::GameTime);
+
+    let times = il
+        .attempt_history()
+        .iter()
+        .map(|attempt| attempt.time().game_time)
+        .collect::<Vec<_>>();
+
+    // The segment time of the third attempt also contains the skipped segment
+    // A, so it's not a time of B alone.
+    assert_eq!(times, [Some(span(15.0)), Some(span(12.0)), None]);
+    assert_eq!(il.attempt_count(), 3);
+    assert_eq!(
+        il.segment(0)
+            .segment_history()
+            .iter()
+            .map(|&(_, time)| time[TimingMethod::GameTime])
+            .collect::<Vec<_>>(),
+        times,
+    );
+}
+
+#[test]
+fn attempts_keep_their_dates() {
+    let run = run();
+    let il = run.extract_segment_as_il(1, TimingMethod::GameTime);
+
+    for (il_attempt, attempt) in il.attempt_history().iter().zip(run.attempt_history()) {
+        assert_eq!(il_attempt.index(), attempt.index());
+        assert!(il_attempt.started().is_some());
+        assert_eq!(il_attempt.started(), attempt.started());
+        assert_eq!(il_attempt.ended(), attempt.ended());
+    }
+}
+
+#[test]
+fn extracts_the_first_segment() {
+    let il = run().extract_segment_as_il(0, TimingMethod::GameTime);
+
+    assert_eq!(il.category_name(), "A (Any%)");
+    assert_eq!(
+        il.segment(0).personal_best_split_time().game_time,
+        Some(span(10.0)),
+    );
+    assert_eq!(
+        il.segment(0).best_segment_time().game_time,
+        Some(span(10.0))
+    );
+}
+
+#[test]
+fn uses_the_segment_name_without_a_category() {
+    let mut run = run();
+    run.set_category_name("");
+
+    assert_eq!(
+        run.extract_segment_as_il(2, TimingMethod::GameTime)
+            .category_name(),
+        "C"
+    );
+}
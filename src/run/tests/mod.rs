@@ -1,6 +1,10 @@
+mod best_segments;
 mod comparison;
 mod empty_run;
 mod extended_category_name;
 mod fixing;
+mod individual_level;
 mod linked_layout;
 mod metadata;
+mod race_readiness;
+mod scaling;
@@ -0,0 +1,60 @@
+use crate::{
+    run::RaceReadinessWarning,
+    util::tests_helper::{create_run, create_timer, run_with_splits, span},
+    Run, Time, TimingMethod,
+};
+
+fn clean_run() -> Run {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 5.0]);
+    timer.into_run(true)
+}
+
+#[test]
+fn clean_run_has_no_warnings() {
+    assert_eq!(clean_run().race_readiness_check(), []);
+}
+
+#[test]
+fn too_few_segments() {
+    let mut run = clean_run();
+    run.segments_mut().remove(0);
+    assert!(run
+        .race_readiness_check()
+        .contains(&RaceReadinessWarning::TooFewSegments));
+}
+
+#[test]
+fn empty_segment_name() {
+    let mut run = clean_run();
+    run.segment_mut(1).set_name(" ");
+    assert_eq!(
+        run.race_readiness_check(),
+        [RaceReadinessWarning::EmptySegmentName { index: 1 }],
+    );
+}
+
+#[test]
+fn missing_final_split_time() {
+    let mut run = create_run(&["A", "B"]);
+    run.segment_mut(0)
+        .set_personal_best_split_time(Time::new().with_game_time(Some(span(3.0))));
+    assert_eq!(
+        run.race_readiness_check(),
+        [RaceReadinessWarning::MissingFinalSplitTime],
+    );
+}
+
+#[test]
+fn personal_best_faster_than_sum_of_best() {
+    let mut run = clean_run();
+    run.segment_mut(1)
+        .set_personal_best_split_time(Time::new().with_game_time(Some(span(4.0))));
+    assert_eq!(
+        run.race_readiness_check(),
+        [RaceReadinessWarning::PersonalBestFasterThanSumOfBest {
+            method: TimingMethod::GameTime,
+        }],
+    );
+}
@@ -0,0 +1,95 @@
+use crate::{
+    comparison::personal_best,
+    run::Attempt,
+    util::tests_helper::{create_timer, run_with_splits, span},
+    Run, Time, TimeSpan, TimingMethod,
+};
+
+const PAL_TO_NTSC: f64 = 50.0 / 60.0;
+
+fn pal_run() -> Run {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[60.0, 120.0, 180.0]);
+    run_with_splits(&mut timer, &[54.0, 126.0, 186.0]);
+    timer.into_run(true)
+}
+
+#[track_caller]
+fn assert_close(actual: Option<TimeSpan>, expected: f64) {
+    let actual = actual.unwrap().total_seconds();
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn scales_split_times() {
+    let run = pal_run().with_scaled_times(PAL_TO_NTSC);
+
+    for (segment, expected) in run.segments().iter().zip([50.0, 100.0, 150.0]) {
+        assert_close(segment.comparison(personal_best::NAME).game_time, expected);
+    }
+}
+
+#[test]
+fn scales_best_segments_and_history() {
+    let run = pal_run().with_scaled_times(PAL_TO_NTSC);
+
+    for (segment, expected) in run.segments().iter().zip([45.0, 50.0, 50.0]) {
+        assert_close(segment.best_segment_time().game_time, expected);
+    }
+
+    let history = run.segments()[1]
+        .segment_history()
+        .iter()
+        .map(|&(_, time)| time.game_time)
+        .collect::<Vec<_>>();
+    assert_eq!(history.len(), 2);
+    assert_close(history[0], 50.0);
+    assert_close(history[1], 60.0);
+
+    let finished = run
+        .attempt_history()
+        .iter()
+        .map(|a| a.time().game_time.unwrap().total_seconds())
+        .collect::<Vec<_>>();
+    assert!((finished[0] - 150.0).abs() < 1e-6);
+    assert!((finished[1] - 155.0).abs() < 1e-6);
+}
+
+#[test]
+fn preserves_monotonicity() {
+    let run = pal_run().with_scaled_times(PAL_TO_NTSC);
+
+    let splits = run
+        .segments()
+        .iter()
+        .filter_map(|s| s.personal_best_split_time()[TimingMethod::GameTime])
+        .collect::<Vec<_>>();
+    assert_eq!(splits.len(), 3);
+    assert!(splits.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn leaves_the_original_untouched() {
+    let mut run = pal_run();
+    run.set_offset(span(-1.5));
+    run.add_attempt_with_index(Time::new(), 10, None, None, None);
+    let original = run.clone();
+
+    let scaled = run.with_scaled_times(PAL_TO_NTSC);
+
+    assert_eq!(run, original);
+    assert_eq!(scaled.offset(), span(-1.5));
+    assert_eq!(
+        scaled.attempt_history().last(),
+        Some(&Attempt::new(10, Time::new(), None, None, None))
+    );
+}
+
+#[test]
+#[should_panic]
+fn rejects_non_positive_factors() {
+    pal_run().with_scaled_times(0.0);
+}
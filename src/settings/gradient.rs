@@ -14,6 +14,10 @@ pub enum Gradient {
     Vertical(Color, Color),
     /// Use a horizontal gradient (Left, Right).
     Horizontal(Color, Color),
+    /// Use a gradient rotated by the angle in degrees (Top, Bottom, Angle). An
+    /// angle of 0° is the same as a vertical gradient, while an angle of 90°
+    /// is the same as a horizontal gradient.
+    Angular(Color, Color, f32),
 }
 
 /// Describes an extended form of a gradient, specifically made for use with
@@ -1,7 +1,7 @@
 use core::mem;
 
-use crate::platform::prelude::*;
-use hashbrown::{hash_table::Entry, HashTable};
+use crate::{platform::prelude::*, Run};
+use hashbrown::{hash_table::Entry, HashSet, HashTable};
 use slab::Slab;
 
 use super::{Image, ImageId};
@@ -189,6 +189,61 @@ impl<T: HasImageId> ImageCache<T> {
         total_collect_count
     }
 
+    /// Removes all the images from the cache for which the predicate returns
+    /// [`false`]. Unlike [`collect`](Self::collect), this removes the images
+    /// regardless of whether they have been visited or not. Returns the amount
+    /// of images that got removed.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) -> usize {
+        let mut removed_count = 0;
+        let mut current = self.oldest;
+
+        while current != KEY_NONE {
+            let element = &self.elements[current];
+            let next = element.newer;
+
+            if !f(&element.value) {
+                let removed = self.elements.remove(current);
+
+                if removed.older != KEY_NONE {
+                    self.elements[removed.older].newer = removed.newer;
+                } else {
+                    self.oldest = removed.newer;
+                }
+
+                if removed.newer != KEY_NONE {
+                    self.elements[removed.newer].older = removed.older;
+                } else {
+                    self.newest = removed.older;
+                }
+
+                self.table
+                    .find_entry(removed.value.image_id().hash(), |&image_key| {
+                        image_key == current
+                    })
+                    .unwrap()
+                    .remove();
+
+                self.bitvec_visited[current / 64] &= !(1 << (current % 64));
+                removed_count += 1;
+            }
+
+            current = next;
+        }
+
+        removed_count
+    }
+
+    /// Removes all the images from the cache that are not referenced by the
+    /// [`Run`], i.e. that are neither its game icon nor the icon of any of its
+    /// segments. This is useful for removing the images that are orphaned
+    /// after editing the [`Run`]. Keep in mind that this also removes images
+    /// that are cached for other reasons, such as images that are part of the
+    /// layout. Returns the amount of images that got removed.
+    pub fn retain_referenced(&mut self, run: &Run) -> usize {
+        let referenced = run.referenced_image_ids().collect::<HashSet<_>>();
+        self.retain(|image| referenced.contains(image.image_id()))
+    }
+
     fn visited_count(&self) -> usize {
         self.bitvec_visited
             .iter()
@@ -277,6 +332,33 @@ mod tests {
         assert_eq!(image_cache.table.len(), 5);
     }
 
+    #[test]
+    fn retain() {
+        let mut image_cache = ImageCache::new();
+
+        for i in 0..100 {
+            let id = ImageId([i; 32]);
+            image_cache.cache(&id, || MyImage(id));
+        }
+        image_cache.collect();
+        for i in 0..10 {
+            let id = ImageId([i; 32]);
+            image_cache.cache(&id, || MyImage(id));
+        }
+
+        assert_eq!(image_cache.retain(|image| image.0 .0[0] % 2 == 0), 50);
+        assert_consistency(&image_cache);
+        assert_eq!(image_cache.table.len(), 50);
+        assert_eq!(image_cache.visited_count(), 5);
+        assert!(image_cache.lookup(&ImageId([1; 32])).is_none());
+        assert!(image_cache.lookup(&ImageId([2; 32])).is_some());
+
+        assert_eq!(image_cache.retain(|_| false), 50);
+        assert_consistency(&image_cache);
+        assert_eq!(image_cache.table.len(), 0);
+        assert_eq!(image_cache.visited_count(), 0);
+    }
+
     #[test]
     fn single() {
         let mut image_cache = ImageCache::new();
@@ -1,7 +1,43 @@
-use super::Image;
+use super::{Image, ImageCache};
+use crate::{Run, Segment};
 
 #[test]
 fn serializes_to_json_as_data_url() {
     let json = serde_json::to_string(&Image::new([1, 2, 3].into(), Image::ICON)).unwrap();
     assert_eq!(r#""AQID""#, json);
 }
+
+#[test]
+fn retaining_referenced_images_only_removes_orphans() {
+    let game_icon = Image::new([1].into(), Image::ICON);
+    let shared_icon = Image::new([2].into(), Image::ICON);
+    let removed_icon = Image::new([3].into(), Image::ICON);
+
+    let mut run = Run::new();
+    run.set_game_icon(game_icon.clone());
+    for icon in [&shared_icon, &removed_icon, &shared_icon] {
+        let mut segment = Segment::new("");
+        segment.set_icon(icon.clone());
+        run.push_segment(segment);
+    }
+    run.push_segment(Segment::new(""));
+
+    let mut cache = ImageCache::new();
+    for image in [&game_icon, &shared_icon, &removed_icon] {
+        cache.cache(image.id(), || image.clone());
+    }
+
+    assert_eq!(cache.retain_referenced(&run), 0);
+
+    run.segments_mut()[1].set_icon(Image::default());
+    let referenced = run.referenced_image_ids().collect::<Vec<_>>();
+    assert_eq!(
+        referenced,
+        [game_icon.id(), shared_icon.id(), shared_icon.id()]
+    );
+
+    assert_eq!(cache.retain_referenced(&run), 1);
+    assert!(cache.lookup(game_icon.id()).is_some());
+    assert!(cache.lookup(shared_icon.id()).is_some());
+    assert!(cache.lookup(removed_icon.id()).is_none());
+}
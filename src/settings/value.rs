@@ -7,7 +7,7 @@ use crate::{
     layout::LayoutDirection,
     platform::prelude::*,
     settings::{Alignment, Color, Font, Gradient, ImageId, LayoutBackground, ListGradient},
-    timing::formatter::{Accuracy, DigitsFormat},
+    timing::formatter::{Accuracy, DeltaSigns, DigitsFormat},
     TimingMethod,
 };
 use core::result::Result as StdResult;
@@ -42,6 +42,8 @@ pub enum Value {
     /// A digits format, describing how many digits to show for the main part of
     /// a time.
     DigitsFormat(DigitsFormat),
+    /// A value describing which signs to show in front of deltas.
+    DeltaSigns(DeltaSigns),
     /// An optional timing method.
     OptionalTimingMethod(Option<TimingMethod>),
     /// A color.
@@ -120,6 +122,12 @@ impl From<DigitsFormat> for Value {
     }
 }
 
+impl From<DeltaSigns> for Value {
+    fn from(x: DeltaSigns) -> Self {
+        Value::DeltaSigns(x)
+    }
+}
+
 impl From<Option<TimingMethod>> for Value {
     fn from(x: Option<TimingMethod>) -> Self {
         Value::OptionalTimingMethod(x)
@@ -278,6 +286,14 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into the signs to show in front of deltas.
+    pub fn into_delta_signs(self) -> Result<DeltaSigns> {
+        match self {
+            Value::DeltaSigns(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into an optional timing method.
     pub fn into_optional_timing_method(self) -> Result<Option<TimingMethod>> {
         match self {
@@ -454,6 +470,12 @@ impl From<Value> for DigitsFormat {
     }
 }
 
+impl From<Value> for DeltaSigns {
+    fn from(value: Value) -> Self {
+        value.into_delta_signs().unwrap()
+    }
+}
+
 impl From<Value> for Option<TimingMethod> {
     fn from(value: Value) -> Self {
         value.into_optional_timing_method().unwrap()
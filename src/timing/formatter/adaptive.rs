@@ -0,0 +1,147 @@
+use super::{segment_time, Accuracy, SegmentTime, TimeFormatter};
+use crate::TimeSpan;
+
+/// The Adaptive Time Formatter formats a [`TimeSpan`] the same way as the
+/// [`SegmentTime`] Time Formatter, but chooses the accuracy based on the
+/// magnitude of the time. Short times are shown with more digits of the
+/// fractional part than long times, which keeps the times compact while still
+/// being precise where it matters. By default times below a minute are shown
+/// with milliseconds, times below an hour with hundredths and all longer times
+/// with tenths. Both the thresholds and the accuracies can be configured.
+///
+/// # Example Formatting
+///
+/// * Empty Time `—`
+/// * Seconds `23.123`
+/// * Minutes `12:34.98`
+/// * Hours `12:34:56.1`
+/// * Negative Times `−23.123`
+pub struct Adaptive {
+    short_threshold: TimeSpan,
+    long_threshold: TimeSpan,
+    short_accuracy: Accuracy,
+    medium_accuracy: Accuracy,
+    long_accuracy: Accuracy,
+}
+
+impl Adaptive {
+    /// Creates a new Adaptive Time Formatter that uses milliseconds below a
+    /// minute, hundredths below an hour and tenths for all longer times.
+    pub fn new() -> Self {
+        Self {
+            short_threshold: TimeSpan::from_seconds(60.0),
+            long_threshold: TimeSpan::from_seconds(60.0 * 60.0),
+            short_accuracy: Accuracy::Milliseconds,
+            medium_accuracy: Accuracy::Hundredths,
+            long_accuracy: Accuracy::Tenths,
+        }
+    }
+
+    /// Configures the thresholds at which the accuracy changes. Times shorter
+    /// than the short threshold are considered short, while times at least as
+    /// long as the long threshold are considered long. Everything in between
+    /// is considered medium.
+    pub const fn with_thresholds(mut self, short: TimeSpan, long: TimeSpan) -> Self {
+        self.short_threshold = short;
+        self.long_threshold = long;
+        self
+    }
+
+    /// Configures the accuracies to use for short, medium and long times.
+    pub const fn with_accuracies(
+        mut self,
+        short: Accuracy,
+        medium: Accuracy,
+        long: Accuracy,
+    ) -> Self {
+        self.short_accuracy = short;
+        self.medium_accuracy = medium;
+        self.long_accuracy = long;
+        self
+    }
+
+    fn accuracy_for(&self, time: TimeSpan) -> Accuracy {
+        let magnitude = time.to_duration().abs();
+        if magnitude < self.short_threshold.to_duration() {
+            self.short_accuracy
+        } else if magnitude < self.long_threshold.to_duration() {
+            self.medium_accuracy
+        } else {
+            self.long_accuracy
+        }
+    }
+}
+
+impl Default for Adaptive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeFormatter<'_> for Adaptive {
+    type Inner = segment_time::Inner;
+
+    fn format<T>(&self, time: T) -> Self::Inner
+    where
+        T: Into<Option<TimeSpan>>,
+    {
+        let time = time.into();
+        let accuracy = time.map_or(self.medium_accuracy, |time| self.accuracy_for(time));
+        SegmentTime::with_accuracy(accuracy).format(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use super::*;
+
+    fn format(formatter: &Adaptive, time: &str) -> String {
+        formatter
+            .format(TimeSpan::from_str(time).unwrap())
+            .to_string()
+    }
+
+    #[test]
+    fn default_thresholds() {
+        let formatter = Adaptive::new();
+        assert_eq!(format(&formatter, "0.123"), "0.123");
+        assert_eq!(format(&formatter, "1:23.4"), "1:23.40");
+        assert_eq!(format(&formatter, "2:03:04"), "2:03:04.0");
+    }
+
+    #[test]
+    fn negative() {
+        let formatter = Adaptive::new();
+        assert_eq!(format(&formatter, "-0.123"), "−0.123");
+        assert_eq!(format(&formatter, "-2:03:04"), "−2:03:04.0");
+    }
+
+    #[test]
+    fn configured_thresholds() {
+        let formatter = Adaptive::new()
+            .with_thresholds(
+                TimeSpan::from_seconds(1.0),
+                TimeSpan::from_seconds(2.0 * 60.0),
+            )
+            .with_accuracies(Accuracy::Hundredths, Accuracy::Tenths, Accuracy::Seconds);
+        assert_eq!(format(&formatter, "0.123"), "0.12");
+        assert_eq!(format(&formatter, "1:23.4"), "1:23.4");
+        assert_eq!(format(&formatter, "2:03:04"), "2:03:04");
+    }
+
+    #[test]
+    fn min() {
+        // This verifies that determining the magnitude of the minimum value
+        // doesn't cause a panic.
+        let time = TimeSpan::from(crate::platform::Duration::MIN);
+        let inner = Adaptive::new().format(Some(time));
+        assert_eq!(inner.to_string(), "−2562047788015215:30:08.9");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Adaptive::new().format(None).to_string(), "—");
+    }
+}
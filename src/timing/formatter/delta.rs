@@ -3,11 +3,35 @@ use super::{
 };
 use crate::TimeSpan;
 use core::fmt::{Display, Formatter, Result};
+use serde_derive::{Deserialize, Serialize};
+
+/// Describes which signs the Delta Time Formatter shows in front of the
+/// deltas. A positive delta means that time was lost compared to the
+/// comparison, while a negative delta means that time was saved.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum DeltaSigns {
+    /// Both positive and negative deltas are shown with their sign.
+    ///
+    /// `+1.2` and `−0.8`
+    #[default]
+    Both,
+    /// Only positive deltas are shown with their sign. Negative deltas are
+    /// shown without the minus sign.
+    ///
+    /// `+1.2` and `0.8`
+    OnlyPlus,
+    /// Only negative deltas are shown with their sign. Positive deltas are
+    /// shown without the plus sign.
+    ///
+    /// `1.2` and `−0.8`
+    OnlyMinus,
+}
 
 pub struct Inner {
     time: Option<TimeSpan>,
     drop_decimals: bool,
     accuracy: Accuracy,
+    signs: DeltaSigns,
 }
 
 /// The Delta Time Formatter formats a [`TimeSpan`] as a comparison of two
@@ -27,32 +51,40 @@ pub struct Inner {
 /// * Hours with Decimal Dropping `+12:34:56`
 /// * Negative Times `−23.1`
 /// * Exactly zero `0.0`
-pub struct Delta(bool, Accuracy);
+///
+/// Either of the signs can be hidden by choosing the [`DeltaSigns`] to show.
+pub struct Delta(bool, Accuracy, DeltaSigns);
 
 impl Delta {
     /// Creates a new default Delta Time Formatter that drops the fractional
     /// part and uses tenths when showing the fractional part.
     pub const fn new() -> Self {
-        Delta(true, Accuracy::Tenths)
+        Delta(true, Accuracy::Tenths, DeltaSigns::Both)
     }
 
     /// Creates a new custom Delta Time Formatter where you can specify whether
     /// the fractional part should be dropped for deltas that are larger than 1
     /// minute and how many digits to show for the fractional part.
     pub const fn custom(drop_decimals: bool, accuracy: Accuracy) -> Self {
-        Delta(drop_decimals, accuracy)
+        Delta(drop_decimals, accuracy, DeltaSigns::Both)
     }
 
     /// Creates a new Delta Time Formatter that drops the fractional part and
     /// uses tenths when showing the fractional part.
     pub const fn with_decimal_dropping() -> Self {
-        Delta(true, Accuracy::Tenths)
+        Delta(true, Accuracy::Tenths, DeltaSigns::Both)
     }
 
     /// Creates a new Delta Time Formatter that does not drop the fractional
     /// part and uses tenths when showing the fractional part.
     pub const fn without_decimal_dropping() -> Self {
-        Delta(false, Accuracy::Tenths)
+        Delta(false, Accuracy::Tenths, DeltaSigns::Both)
+    }
+
+    /// Changes which signs the Delta Time Formatter shows in front of the
+    /// deltas. By default both signs are shown.
+    pub const fn with_signs(self, signs: DeltaSigns) -> Self {
+        Delta(self.0, self.1, signs)
     }
 }
 
@@ -73,6 +105,7 @@ impl TimeFormatter<'_> for Delta {
             time: time.into(),
             drop_decimals: self.0,
             accuracy: self.1,
+            signs: self.2,
         }
     }
 }
@@ -83,10 +116,12 @@ impl Display for Inner {
             let (total_seconds, nanoseconds) = time.to_seconds_and_subsec_nanoseconds();
             let bit_or = total_seconds | nanoseconds as i64;
             let (total_seconds, nanoseconds) = if bit_or < 0 {
-                f.write_str(MINUS)?;
+                if self.signs != DeltaSigns::OnlyPlus {
+                    f.write_str(MINUS)?;
+                }
                 (total_seconds.wrapping_neg() as u64, (-nanoseconds) as u32)
             } else {
-                if bit_or > 0 {
+                if bit_or > 0 && self.signs != DeltaSigns::OnlyMinus {
                     f.write_str(PLUS)?;
                 }
                 (total_seconds as u64, nanoseconds as u32)
@@ -239,6 +274,34 @@ mod tests {
         assert_eq!(inner.to_string(), "−12:34:56");
     }
 
+    #[test]
+    fn only_plus() {
+        let formatter = Delta::new().with_signs(DeltaSigns::OnlyPlus);
+        assert_eq!(
+            formatter.format(TimeSpan::from_seconds(1.2)).to_string(),
+            "+1.2"
+        );
+        assert_eq!(
+            formatter.format(TimeSpan::from_seconds(-0.8)).to_string(),
+            "0.8"
+        );
+        assert_eq!(formatter.format(TimeSpan::zero()).to_string(), "0.0");
+    }
+
+    #[test]
+    fn only_minus() {
+        let formatter = Delta::new().with_signs(DeltaSigns::OnlyMinus);
+        assert_eq!(
+            formatter.format(TimeSpan::from_seconds(1.2)).to_string(),
+            "1.2"
+        );
+        assert_eq!(
+            formatter.format(TimeSpan::from_seconds(-0.8)).to_string(),
+            "−0.8"
+        );
+        assert_eq!(formatter.format(TimeSpan::zero()).to_string(), "0.0");
+    }
+
     #[test]
     fn days() {
         let time = TimeSpan::from_str("2148:34:56.123456789").unwrap();
@@ -21,6 +21,7 @@
 //! ```
 
 mod accuracy;
+mod adaptive;
 mod complete;
 mod days;
 mod delta;
@@ -31,8 +32,14 @@ mod segment_time;
 pub mod timer;
 
 pub use self::{
-    accuracy::Accuracy, complete::Complete, days::Days, delta::Delta, digits_format::DigitsFormat,
-    regular::Regular, segment_time::SegmentTime,
+    accuracy::Accuracy,
+    adaptive::Adaptive,
+    complete::Complete,
+    days::Days,
+    delta::{Delta, DeltaSigns},
+    digits_format::DigitsFormat,
+    regular::Regular,
+    segment_time::SegmentTime,
 };
 
 use crate::TimeSpan;
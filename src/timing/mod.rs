@@ -11,13 +11,16 @@ mod timer_phase;
 mod timing_method;
 
 #[cfg(feature = "std")]
-pub use self::timer::SharedTimer;
+pub use self::timer::{SharedTimer, TimerObserver};
 pub use self::{
     atomic_date_time::AtomicDateTime,
     time::{GameTime, RealTime, Time},
     time_span::{ParseError, TimeSpan},
     time_stamp::TimeStamp,
-    timer::{CreationError as TimerCreationError, Snapshot, Timer},
+    timer::{
+        Bookmark, CreationError as TimerCreationError, FirstSplitUndoPolicy, GameTimeAheadPolicy,
+        Snapshot, Timer,
+    },
     timer_phase::TimerPhase,
     timing_method::TimingMethod,
 };
@@ -30,6 +30,13 @@ impl TimeSpan {
         Self(Duration::seconds_f64(0.001 * milliseconds))
     }
 
+    /// Creates a new `TimeSpan` from a given amount of frames at the frame
+    /// rate provided in frames per second. Returns [`None`] if the resulting
+    /// time can't be represented, which may happen for very small frame rates.
+    pub fn from_frames(frames: u64, frame_rate: f64) -> Option<Self> {
+        Duration::checked_seconds_f64(frames as f64 / frame_rate).map(Self)
+    }
+
     /// Converts the `TimeSpan` to a `Duration` from the `time` crate.
     pub const fn to_duration(&self) -> Duration {
         self.0
@@ -1,6 +1,8 @@
+use super::Bookmark;
 use crate::{
     event::{Error, Event, Result},
-    AtomicDateTime, Run, Time, TimeSpan, TimeStamp, TimingMethod,
+    platform::prelude::*,
+    AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp, TimingMethod,
 };
 
 #[derive(Debug, Clone)]
@@ -16,6 +18,13 @@ pub struct ActiveAttempt {
     pub adjusted_offset: TimeSpan,
     pub game_time_paused_at: Option<TimeSpan>,
     pub loading_times: Option<TimeSpan>,
+    pub bookmarks: Vec<Bookmark>,
+    /// Whether the game time being ahead of the real time was already
+    /// reported during this attempt.
+    pub warned_about_game_time_ahead: bool,
+    /// Whether the Run had unsaved changes before the attempt started, so
+    /// that this can be restored when the start is undone.
+    pub run_was_modified: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -45,25 +54,33 @@ impl From<TimerTime> for Time {
 
 impl ActiveAttempt {
     pub fn current_time(&self, run: &Run) -> TimerTime {
-        let real_time = match self.state {
+        let (real_time, current_split_index) = match self.state {
             State::Ended { .. } => {
                 let Time {
                     real_time,
                     game_time,
-                } = run.segments().last().unwrap().split_time();
+                } = final_time(run, Segment::split_time);
 
                 return TimerTime {
                     real_time: real_time.unwrap_or_default(),
                     game_time,
                 };
             }
-            State::NotEnded { time_paused_at, .. } => time_paused_at
-                .unwrap_or_else(|| TimeStamp::now() - self.start_time + self.adjusted_offset),
+            State::NotEnded {
+                time_paused_at,
+                current_split_index,
+            } => (
+                time_paused_at
+                    .unwrap_or_else(|| TimeStamp::now() - self.start_time + self.adjusted_offset),
+                current_split_index,
+            ),
         };
 
-        let game_time = self
-            .game_time_paused_at
-            .or_else(|| Some(real_time - self.loading_times?));
+        let game_time =
+            game_time_end(run, current_split_index, Segment::split_time).or_else(|| {
+                self.game_time_paused_at
+                    .or_else(|| Some(real_time - self.loading_times?))
+            });
 
         TimerTime {
             real_time,
@@ -113,9 +130,14 @@ impl ActiveAttempt {
             return Err(Error::NegativeTime);
         }
 
-        let game_time = self
-            .game_time_paused_at
-            .or_else(|| Some(real_time - self.loading_times?));
+        // Once the game time has stopped, the remaining splits don't store a
+        // game time anymore.
+        let game_time = if game_time_end(run, *current_split_index, Segment::split_time).is_some() {
+            None
+        } else {
+            self.game_time_paused_at
+                .or_else(|| Some(real_time - self.loading_times?))
+        };
 
         let previous_split_index = *current_split_index;
         *current_split_index += 1;
@@ -179,9 +201,7 @@ impl ActiveAttempt {
     pub fn update_attempt_history(&self, run: &mut Run) {
         let (attempt_ended, time) = match self.state {
             State::NotEnded { .. } => (AtomicDateTime::now(), Time::new()),
-            State::Ended { attempt_ended } => {
-                (attempt_ended, run.segments().last().unwrap().split_time())
-            }
+            State::Ended { attempt_ended } => (attempt_ended, final_time(run, Segment::split_time)),
         };
 
         let pause_time = self.get_pause_time();
@@ -195,6 +215,31 @@ impl ActiveAttempt {
     }
 }
 
+/// Returns the game time at which the game time stopped, if the segment at
+/// which it stops has already been split. If that segment got skipped, the game
+/// time stops at the next split that has a game time instead.
+fn game_time_end(
+    run: &Run,
+    current_split_index: usize,
+    split_time: fn(&Segment) -> Time,
+) -> Option<TimeSpan> {
+    let end_index = run.game_time_end_index()?;
+    run.segments()
+        .get(end_index..current_split_index)?
+        .iter()
+        .find_map(|segment| split_time(segment).game_time)
+}
+
+/// Returns the final time of the run, with the game time taken from where the
+/// game time stopped.
+pub fn final_time(run: &Run, split_time: fn(&Segment) -> Time) -> Time {
+    let mut time = split_time(run.segments().last().unwrap());
+    if let Some(game_time) = game_time_end(run, run.len(), split_time) {
+        time.game_time = Some(game_time);
+    }
+    time
+}
+
 fn update_best_segments(run: &mut Run) {
     let mut previous_split_time_rta = Some(TimeSpan::zero());
     let mut previous_split_time_game_time = Some(TimeSpan::zero());
@@ -229,13 +274,8 @@ fn update_best_segments(run: &mut Run) {
 }
 
 fn update_pb_splits(run: &mut Run, method: TimingMethod) {
-    let (split_time, pb_split_time) = {
-        let last_segment = run.segments().last().unwrap();
-        (
-            last_segment.split_time()[method],
-            last_segment.personal_best_split_time()[method],
-        )
-    };
+    let split_time = final_time(run, Segment::split_time)[method];
+    let pb_split_time = final_time(run, Segment::personal_best_split_time)[method];
     if split_time.is_some_and(|s| pb_split_time.is_none_or(|pb| s < pb)) {
         super::set_run_as_pb(run);
     }
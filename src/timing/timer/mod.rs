@@ -1,5 +1,5 @@
 use crate::{
-    analysis::check_best_segment,
+    analysis::{self, check_best_segment},
     comparison::personal_best,
     event::{Error, Event},
     platform::prelude::*,
@@ -14,7 +14,14 @@ use core::{mem, ops::Deref};
 mod tests;
 
 mod active_attempt;
-use active_attempt::{ActiveAttempt, State};
+use active_attempt::{final_time, ActiveAttempt, State};
+
+#[cfg(feature = "std")]
+mod observer;
+#[cfg(feature = "std")]
+use observer::Observers;
+#[cfg(feature = "std")]
+pub use observer::TimerObserver;
 
 /// A `Timer` provides all the capabilities necessary for doing speedrun attempts.
 ///
@@ -54,6 +61,62 @@ pub struct Timer {
     current_comparison: String,
     current_timing_method: TimingMethod,
     active_attempt: Option<ActiveAttempt>,
+    undo_reset: Option<Box<UndoReset>>,
+    game_time_ahead_policy: GameTimeAheadPolicy,
+    first_split_undo_policy: FirstSplitUndoPolicy,
+    #[cfg(feature = "std")]
+    observers: Observers,
+}
+
+/// The state of the Timer right before the most recent reset, so that the
+/// reset can be undone.
+#[derive(Debug, Clone)]
+struct UndoReset {
+    run: Run,
+    active_attempt: ActiveAttempt,
+}
+
+/// A bookmark marks a point in time of the current attempt that the runner
+/// may want to review after the attempt. Unlike splits, bookmarks don't
+/// influence the attempt in any way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    /// The time of the attempt at which the bookmark was added.
+    pub time: Time,
+    /// The note the runner attached to the bookmark.
+    pub note: String,
+}
+
+/// Describes how the [`Timer`] handles game times that are ahead of the real
+/// time. A game time like that is impossible, but bad reads of the game's
+/// memory may cause one to be forwarded to the timer, which would result in
+/// negative loading times.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GameTimeAheadPolicy {
+    /// The game time is clamped to the real time. If logging is enabled, a
+    /// warning is logged.
+    #[default]
+    ClampWithWarning,
+    /// The game time is silently clamped to the real time.
+    Clamp,
+    /// The game time is used as is, even if it's ahead of the real time.
+    Allow,
+}
+
+/// Describes what the [`Timer`] does when a split is to be undone while the
+/// attempt is still on the first split, so there is no split to undo.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FirstSplitUndoPolicy {
+    /// Nothing happens and [`CantUndoFirstSplit`](Error::CantUndoFirstSplit)
+    /// is returned.
+    #[default]
+    Ignore = 0,
+    /// The start of the attempt is undone. The attempt is discarded without
+    /// storing it in the Run's history and the attempt count is reverted, as
+    /// if the Timer had never been started. If the Run had no unsaved changes
+    /// before the attempt started, it is marked as unmodified again.
+    UndoStart = 1,
 }
 
 /// A snapshot represents a specific point in time that the timer was observed
@@ -70,6 +133,62 @@ impl Snapshot<'_> {
     pub const fn current_time(&self) -> Time {
         self.time
     }
+
+    /// Returns the projected final time of the current attempt for the
+    /// comparison and timing method provided, based on the time the snapshot
+    /// was taken at. See [`Timer::projected_final_time`] for details.
+    pub fn projected_final_time(
+        &self,
+        comparison: &str,
+        timing_method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        self.projection(comparison, timing_method).0
+    }
+
+    /// Calculates the projected final time and whether it is based on the live
+    /// delta of the current segment, which means that it keeps changing.
+    pub(crate) fn projection(
+        &self,
+        comparison: &str,
+        timing_method: TimingMethod,
+    ) -> (Option<TimeSpan>, bool) {
+        let last_segment = self.run().segments().last().unwrap();
+
+        match self.current_phase() {
+            Running | Paused => {
+                let mut delta = analysis::last_delta(
+                    self.run(),
+                    self.current_split_index().unwrap(),
+                    comparison,
+                    timing_method,
+                )
+                .unwrap_or_default();
+
+                let mut is_live = false;
+
+                catch! {
+                    let live_delta = self.current_time()[timing_method]?
+                        - self.current_split().unwrap().comparison(comparison)[timing_method]?;
+
+                    if live_delta > delta {
+                        delta = live_delta;
+                        is_live = true;
+                    }
+                };
+
+                let value = catch! {
+                    last_segment.comparison(comparison)[timing_method]? + delta
+                };
+
+                (value, is_live && value.is_some())
+            }
+            Ended => (
+                final_time(self.run(), Segment::split_time)[timing_method],
+                false,
+            ),
+            NotRunning => (None, false),
+        }
+    }
 }
 
 impl Deref for Snapshot<'_> {
@@ -111,6 +230,11 @@ impl Timer {
             current_comparison: personal_best::NAME.into(),
             current_timing_method: TimingMethod::RealTime,
             active_attempt: None,
+            undo_reset: None,
+            game_time_ahead_policy: GameTimeAheadPolicy::default(),
+            first_split_undo_policy: FirstSplitUndoPolicy::default(),
+            #[cfg(feature = "std")]
+            observers: Observers::default(),
         })
     }
 
@@ -121,6 +245,14 @@ impl Timer {
         alloc::sync::Arc::new(std::sync::RwLock::new(self))
     }
 
+    /// Registers an observer that gets notified about the state transitions
+    /// of the Timer, such as starting, splitting or resetting. Observers are
+    /// not carried over when the Timer is cloned.
+    #[cfg(feature = "std")]
+    pub fn add_observer(&mut self, observer: Box<dyn TimerObserver>) {
+        self.observers.push(observer);
+    }
+
     /// Takes out the Run from the Timer and resets the current attempt if there
     /// is one in progress. If the splits are to be updated, all the information
     /// of the current attempt is stored in the Run's history. Otherwise the
@@ -150,6 +282,8 @@ impl Timer {
         run.fix_splits();
         run.regenerate_comparisons();
 
+        self.undo_reset = None;
+
         Ok(mem::replace(&mut self.run, run))
     }
 
@@ -253,6 +387,23 @@ impl Timer {
         }
     }
 
+    /// Returns the projected final time of the current attempt for the
+    /// comparison and timing method provided. This is the final time of the
+    /// comparison, adjusted by how far ahead or behind the attempt is. While
+    /// the current segment takes longer than in the comparison, this is the
+    /// current time plus the comparison's remaining segment times. Otherwise
+    /// the delta of the last split is used. Once the attempt is finished, the
+    /// final time of the attempt is returned. If there's no attempt in
+    /// progress, [`None`] is returned.
+    pub fn projected_final_time(
+        &self,
+        comparison: &str,
+        timing_method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        self.snapshot()
+            .projected_final_time(comparison, timing_method)
+    }
+
     /// Accesses the split the attempt is currently on. If there's no attempt in
     /// progress or the run finished, `None` is returned instead.
     pub fn current_split(&self) -> Option<&Segment> {
@@ -276,6 +427,20 @@ impl Timer {
         )
     }
 
+    /// Returns the time that has passed in the current segment so far. This is
+    /// the current time minus the split time of the previous segment. If
+    /// there's no attempt in progress, the attempt has ended or the previous
+    /// segment has no split time for the timing method, `None` is returned
+    /// instead.
+    pub fn current_segment_time(&self, method: TimingMethod) -> Option<TimeSpan> {
+        let split_index = self.active_attempt.as_ref()?.current_split_index()?;
+        let previous_split_time = match split_index.checked_sub(1) {
+            Some(previous_index) => self.run.segment(previous_index).split_time()[method]?,
+            None => TimeSpan::zero(),
+        };
+        Some(self.snapshot().current_time()[method]? - previous_split_time)
+    }
+
     /// Starts the Timer if there is no attempt in progress. If that's not the
     /// case, nothing happens.
     pub fn start(&mut self) -> Result {
@@ -295,9 +460,16 @@ impl Timer {
                 adjusted_offset: offset,
                 game_time_paused_at: None,
                 loading_times: None,
+                bookmarks: Vec::new(),
+                warned_about_game_time_ahead: false,
+                run_was_modified: self.run.has_been_modified(),
             });
+            self.undo_reset = None;
             self.run.start_next_run();
 
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_start());
+
             Ok(Event::Started)
         } else {
             Err(Error::RunAlreadyInProgress)
@@ -325,6 +497,9 @@ impl Timer {
 
         self.run.mark_as_modified();
 
+        #[cfg(feature = "std")]
+        self.observers.notify(|o| o.on_split(split_index));
+
         Ok(event)
     }
 
@@ -356,6 +531,9 @@ impl Timer {
 
             self.run.mark_as_modified();
 
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_skip());
+
             Ok(Event::SplitSkipped)
         } else {
             Err(Error::CantSkipLastSplit)
@@ -364,7 +542,8 @@ impl Timer {
 
     /// Removes the split time from the last split if an attempt is in progress
     /// and there is a previous split. The Timer Phase also switches to
-    /// [`Running`] if it previously was [`Ended`].
+    /// [`Running`] if it previously was [`Ended`]. If the attempt is still on
+    /// the first split, the [`FirstSplitUndoPolicy`] decides what happens.
     pub fn undo_split(&mut self) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
@@ -388,7 +567,25 @@ impl Timer {
 
             self.run.mark_as_modified();
 
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_undo());
+
             Ok(Event::SplitUndone)
+        } else if self.first_split_undo_policy == FirstSplitUndoPolicy::UndoStart {
+            // There are no split times to clear yet, as we are still on the
+            // first split.
+            let run_was_modified = active_attempt.run_was_modified;
+            self.active_attempt = None;
+            self.run
+                .set_attempt_count(self.run.attempt_count().saturating_sub(1));
+            if !run_was_modified {
+                self.run.mark_as_unmodified();
+            }
+
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_undo_start());
+
+            Ok(Event::StartUndone)
         } else {
             Err(Error::CantUndoFirstSplit)
         }
@@ -401,12 +598,10 @@ impl Timer {
             return false;
         }
 
-        let last_segment = self.run.segments().last().unwrap();
+        let pb_time = final_time(&self.run, Segment::personal_best_split_time)[timing_method];
 
-        if let Some(final_time) = last_segment.split_time()[timing_method] {
-            if last_segment.personal_best_split_time()[timing_method]
-                .is_none_or(|pb| final_time < pb)
-            {
+        if let Some(final_time) = final_time(&self.run, Segment::split_time)[timing_method] {
+            if pb_time.is_none_or(|pb| final_time < pb) {
                 return true;
             }
         }
@@ -448,6 +643,8 @@ impl Timer {
         if self.active_attempt.is_some() {
             self.reset_state(update_splits);
             self.reset_splits();
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_reset(update_splits));
             Ok(Event::Reset)
         } else {
             Err(Error::NoRunInProgress)
@@ -462,17 +659,51 @@ impl Timer {
             self.reset_state(true);
             set_run_as_pb(&mut self.run);
             self.reset_splits();
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_reset(true));
             Ok(Event::Reset)
         } else {
             Err(Error::NoRunInProgress)
         }
     }
 
+    /// Undoes the most recent reset, restoring the attempt that was in
+    /// progress, including its split times and the current split. This only
+    /// works right after the reset. Once a new attempt is started or the Run
+    /// is replaced, the reset can't be undone anymore. Only a single reset can
+    /// be undone. Any changes to the Run since the reset, such as the attempt
+    /// being stored in its history, are reverted.
+    pub fn undo_reset(&mut self) -> Result {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+
+        let undo_reset = self.undo_reset.take().ok_or(Error::NoResetToUndo)?;
+        self.run = undo_reset.run;
+        self.active_attempt = Some(undo_reset.active_attempt);
+
+        #[cfg(feature = "std")]
+        self.observers.notify(|o| o.on_undo_reset());
+
+        Ok(Event::ResetUndone)
+    }
+
+    /// Returns whether the most recent reset can be undone with
+    /// [`undo_reset`](Self::undo_reset).
+    pub const fn can_undo_reset(&self) -> bool {
+        self.active_attempt.is_none() && self.undo_reset.is_some()
+    }
+
     fn reset_state(&mut self, update_times: bool) {
         let Some(active_attempt) = self.active_attempt.take() else {
             return;
         };
 
+        self.undo_reset = Some(Box::new(UndoReset {
+            run: self.run.clone(),
+            active_attempt: active_attempt.clone(),
+        }));
+
         if update_times {
             active_attempt.update_times(&mut self.run, self.current_timing_method);
         }
@@ -499,6 +730,8 @@ impl Timer {
         if time_paused_at.is_none() {
             *time_paused_at =
                 Some(TimeStamp::now() - active_attempt.start_time + active_attempt.adjusted_offset);
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_pause());
             Ok(Event::Paused)
         } else {
             Err(Error::AlreadyPaused)
@@ -517,6 +750,8 @@ impl Timer {
             active_attempt.adjusted_offset =
                 pause_time - (TimeStamp::now() - active_attempt.start_time);
             *time_paused_at = None;
+            #[cfg(feature = "std")]
+            self.observers.notify(|o| o.on_resume());
             Ok(Event::Resumed)
         } else {
             Err(Error::NotPaused)
@@ -720,19 +955,56 @@ impl Timer {
         }
     }
 
+    /// Accesses how game times that are ahead of the real time are handled.
+    #[inline]
+    pub const fn game_time_ahead_policy(&self) -> GameTimeAheadPolicy {
+        self.game_time_ahead_policy
+    }
+
+    /// Sets how game times that are ahead of the real time are handled.
+    #[inline]
+    pub fn set_game_time_ahead_policy(&mut self, policy: GameTimeAheadPolicy) {
+        self.game_time_ahead_policy = policy;
+    }
+
+    /// Accesses what happens when undoing a split while the attempt is still
+    /// on the first split.
+    #[inline]
+    pub const fn first_split_undo_policy(&self) -> FirstSplitUndoPolicy {
+        self.first_split_undo_policy
+    }
+
+    /// Sets what happens when undoing a split while the attempt is still on
+    /// the first split.
+    #[inline]
+    pub fn set_first_split_undo_policy(&mut self, policy: FirstSplitUndoPolicy) {
+        self.first_split_undo_policy = policy;
+    }
+
     /// Sets the Game Time to the time specified. This also works if the Game
     /// Time is paused, which can be used as a way of updating the Game Timer
     /// periodically without it automatically moving forward. This ensures that
     /// the Game Timer never shows any time that is not coming from the game.
+    /// Game times that are ahead of the Real Time are handled according to the
+    /// [`GameTimeAheadPolicy`]. The loading times get recalculated from the
+    /// Game Time provided, so it takes precedence over any loading times
+    /// accumulated with [`add_loading_time`](Self::add_loading_time) so far.
     #[inline]
     pub fn set_game_time(&mut self, game_time: TimeSpan) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
+        let real_time = active_attempt.current_time(&self.run).real_time;
+        let loading_times = clamp_loading_times(
+            self.game_time_ahead_policy,
+            real_time,
+            real_time - game_time,
+            &mut active_attempt.warned_about_game_time_ahead,
+        );
+
         if active_attempt.game_time_paused_at.is_some() {
-            active_attempt.game_time_paused_at = Some(game_time);
+            active_attempt.game_time_paused_at = Some(real_time - loading_times);
         }
-        active_attempt.loading_times =
-            Some(active_attempt.current_time(&self.run).real_time - game_time);
+        active_attempt.loading_times = Some(loading_times);
 
         Ok(Event::GameTimeSet)
     }
@@ -748,10 +1020,18 @@ impl Timer {
 
     /// Instead of setting the game time directly, this method can be used to
     /// just specify the amount of time the game has been loading. The game time
-    /// is then automatically determined by Real Time - Loading Times.
+    /// is then automatically determined by Real Time - Loading Times. Negative
+    /// loading times are handled according to the [`GameTimeAheadPolicy`].
     #[inline]
     pub fn set_loading_times(&mut self, time: TimeSpan) -> Result {
         if let Some(active_attempt) = &mut self.active_attempt {
+            let real_time = active_attempt.current_time(&self.run).real_time;
+            let time = clamp_loading_times(
+                self.game_time_ahead_policy,
+                real_time,
+                time,
+                &mut active_attempt.warned_about_game_time_ahead,
+            );
             active_attempt.set_loading_times(time, &self.run);
             Ok(Event::LoadingTimesSet)
         } else {
@@ -759,6 +1039,39 @@ impl Timer {
         }
     }
 
+    /// Adds the duration of a single load to the loading times of the current
+    /// attempt, such that the Game Time is Real Time - the total of all the
+    /// loads. This initializes the Game Time if necessary. Setting the Game
+    /// Time explicitly replaces the accumulated loading times, with further
+    /// loads being added on top of that. The loading times start out empty
+    /// for every new attempt.
+    pub fn add_loading_time(&mut self, time: TimeSpan) -> Result {
+        self.set_loading_times(self.loading_times() + time)
+    }
+
+    /// Adds a bookmark with the note provided at the current time of the
+    /// attempt. The bookmarks only live as long as the attempt and are cleared
+    /// when the attempt gets reset.
+    pub fn add_bookmark<S: PopulateString>(&mut self, note: S) -> Result {
+        let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
+
+        let time = active_attempt.current_time(&self.run).into();
+        active_attempt.bookmarks.push(Bookmark {
+            time,
+            note: note.into_string(),
+        });
+
+        Ok(Event::BookmarkAdded)
+    }
+
+    /// Accesses the bookmarks of the current attempt in the order they were
+    /// added.
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        self.active_attempt
+            .as_ref()
+            .map_or(&[], |active_attempt| &active_attempt.bookmarks)
+    }
+
     /// Sets the value of a custom variable with the name specified. If the
     /// variable does not exist, a temporary variable gets created that will not
     /// be stored in the splits file.
@@ -790,6 +1103,38 @@ impl Timer {
     }
 }
 
+/// Clamps the loading times such that the game time isn't ahead of the real
+/// time, unless the policy allows it. While the real time is still negative
+/// because of the run's offset, the game time may be ahead of it up to zero.
+/// The warning is only logged once per attempt, as the game time is usually
+/// set many times a second.
+fn clamp_loading_times(
+    policy: GameTimeAheadPolicy,
+    real_time: TimeSpan,
+    loading_times: TimeSpan,
+    _warned: &mut bool,
+) -> TimeSpan {
+    let min_loading_times = if real_time < TimeSpan::zero() {
+        real_time
+    } else {
+        TimeSpan::zero()
+    };
+
+    if loading_times >= min_loading_times || policy == GameTimeAheadPolicy::Allow {
+        return loading_times;
+    }
+
+    #[cfg(feature = "log")]
+    if policy == GameTimeAheadPolicy::ClampWithWarning && !core::mem::replace(_warned, true) {
+        log::warn!(
+            "The game time is {} seconds ahead of the real time. It is clamped to the real time.",
+            (min_loading_times - loading_times).total_seconds(),
+        );
+    }
+
+    min_loading_times
+}
+
 fn set_run_as_pb(run: &mut Run) {
     run.import_pb_into_segment_history();
     run.fix_splits();
@@ -0,0 +1,60 @@
+use core::fmt;
+
+/// A timer observer gets notified about the state transitions of the
+/// [`Timer`](super::Timer) it is registered on. The notifications happen right
+/// after the transition has been committed, so the timer already reflects the
+/// new state. All the methods do nothing by default, so only the transitions
+/// of interest need to be implemented.
+pub trait TimerObserver: Send + Sync {
+    /// The timer has been started.
+    fn on_start(&mut self) {}
+    /// The segment at the index provided has been split. This also happens for
+    /// the final split that finishes the attempt.
+    fn on_split(&mut self, _index: usize) {}
+    /// The current split has been skipped.
+    fn on_skip(&mut self) {}
+    /// The previous split has been undone.
+    fn on_undo(&mut self) {}
+    /// The start of the attempt has been undone, because the first split was
+    /// undone.
+    fn on_undo_start(&mut self) {}
+    /// The timer has been reset. `update` indicates whether the attempt has
+    /// been stored in the run's history.
+    fn on_reset(&mut self, _update: bool) {}
+    /// The most recent reset has been undone, so the attempt that was in
+    /// progress is restored.
+    fn on_undo_reset(&mut self) {}
+    /// The timer has been paused.
+    fn on_pause(&mut self) {}
+    /// The timer has been resumed.
+    fn on_resume(&mut self) {}
+}
+
+/// The observers registered on a timer. They are bound to the timer they were
+/// registered on, so cloning the timer does not carry them over.
+#[derive(Default)]
+pub struct Observers(Vec<Box<dyn TimerObserver>>);
+
+impl Observers {
+    pub fn push(&mut self, observer: Box<dyn TimerObserver>) {
+        self.0.push(observer);
+    }
+
+    pub fn notify(&mut self, mut f: impl FnMut(&mut dyn TimerObserver)) {
+        for observer in &mut self.0 {
+            f(&mut **observer);
+        }
+    }
+}
+
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Observers").field(&self.0.len()).finish()
+    }
+}
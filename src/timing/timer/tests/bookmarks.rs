@@ -0,0 +1,55 @@
+use crate::{
+    event::{Error, Event},
+    util::tests_helper::{create_timer, start_run},
+    TimeSpan,
+};
+
+#[test]
+fn can_only_add_bookmarks_during_an_attempt() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    assert_eq!(timer.add_bookmark("Too early"), Err(Error::NoRunInProgress));
+    assert!(timer.bookmarks().is_empty());
+}
+
+#[test]
+fn bookmarks_carry_the_time_they_were_added_at() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(3.0)).unwrap();
+    assert_eq!(timer.add_bookmark("Lag spike"), Ok(Event::BookmarkAdded));
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(7.5)).unwrap();
+    timer.add_bookmark("Weird clip").unwrap();
+
+    let bookmarks = timer.bookmarks();
+    assert_eq!(bookmarks.len(), 2);
+    assert_eq!(bookmarks[0].note, "Lag spike");
+    assert_eq!(
+        bookmarks[0].time.game_time,
+        Some(TimeSpan::from_seconds(3.0))
+    );
+    assert_eq!(bookmarks[1].note, "Weird clip");
+    assert_eq!(
+        bookmarks[1].time.game_time,
+        Some(TimeSpan::from_seconds(7.5))
+    );
+}
+
+#[test]
+fn bookmarks_are_cleared_on_reset() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+
+    timer.add_bookmark("Something happened").unwrap();
+    assert_eq!(timer.bookmarks().len(), 1);
+
+    timer.reset(true).unwrap();
+    assert!(timer.bookmarks().is_empty());
+
+    start_run(&mut timer);
+    assert!(timer.bookmarks().is_empty());
+}
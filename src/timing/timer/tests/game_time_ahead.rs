@@ -0,0 +1,65 @@
+use crate::{
+    timing::GameTimeAheadPolicy,
+    util::tests_helper::{create_timer, span},
+    TimeSpan, Timer,
+};
+
+fn timer(policy: GameTimeAheadPolicy) -> Timer {
+    let mut timer = create_timer(&["A"]);
+    timer.set_game_time_ahead_policy(policy);
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    timer.pause_game_time().unwrap();
+    timer
+}
+
+#[test]
+fn clamps_with_warning_by_default() {
+    let timer = create_timer(&["A"]);
+    assert_eq!(
+        timer.game_time_ahead_policy(),
+        GameTimeAheadPolicy::ClampWithWarning,
+    );
+}
+
+#[test]
+fn game_time_is_clamped_to_real_time() {
+    for policy in [
+        GameTimeAheadPolicy::ClampWithWarning,
+        GameTimeAheadPolicy::Clamp,
+    ] {
+        let mut timer = timer(policy);
+        timer.set_game_time(span(100.0)).unwrap();
+
+        let time = timer.snapshot().current_time();
+        assert!(time.game_time.unwrap() <= time.real_time.unwrap());
+        assert!(time.game_time.unwrap() < span(100.0));
+        assert_eq!(timer.loading_times(), TimeSpan::zero());
+    }
+}
+
+#[test]
+fn negative_loading_times_are_clamped() {
+    let mut timer = timer(GameTimeAheadPolicy::default());
+    timer.set_loading_times(span(-100.0)).unwrap();
+
+    let time = timer.snapshot().current_time();
+    assert!(time.game_time.unwrap() <= time.real_time.unwrap());
+    assert_eq!(timer.loading_times(), TimeSpan::zero());
+}
+
+#[test]
+fn game_time_ahead_of_real_time_can_be_allowed() {
+    let mut timer = timer(GameTimeAheadPolicy::Allow);
+    timer.set_game_time(span(100.0)).unwrap();
+
+    assert_eq!(timer.snapshot().current_time().game_time, Some(span(100.0)));
+}
+
+#[test]
+fn game_time_behind_real_time_is_kept() {
+    let mut timer = timer(GameTimeAheadPolicy::default());
+    timer.set_game_time(span(-5.0)).unwrap();
+
+    assert_eq!(timer.snapshot().current_time().game_time, Some(span(-5.0)));
+}
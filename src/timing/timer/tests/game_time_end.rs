@@ -0,0 +1,100 @@
+use crate::{
+    util::tests_helper::{create_run, start_run},
+    TimeSpan, Timer,
+};
+
+fn timer() -> Timer {
+    let mut run = create_run(&["A", "B", "C"]);
+    run.set_game_time_end_index(Some(1)).unwrap();
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn game_time_freezes_after_the_game_time_end_split() {
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(10.0)).unwrap();
+    timer.split().unwrap();
+
+    timer.set_game_time(TimeSpan::from_seconds(20.0)).unwrap();
+    let current_time = timer.snapshot().current_time();
+    assert_eq!(current_time.game_time, Some(TimeSpan::from_seconds(10.0)));
+    assert!(current_time.real_time.is_some());
+
+    timer.split().unwrap();
+    let final_time = timer.run().segment(2).split_time();
+    assert_eq!(final_time.game_time, None);
+    assert!(
+        final_time.real_time.unwrap() >= timer.run().segment(1).split_time().real_time.unwrap()
+    );
+    assert_eq!(
+        timer.snapshot().current_time().game_time,
+        Some(TimeSpan::from_seconds(10.0))
+    );
+
+    timer.reset(true).unwrap();
+    let attempt = timer.run().attempt_history().last().unwrap();
+    assert_eq!(attempt.time().game_time, Some(TimeSpan::from_seconds(10.0)));
+    assert_eq!(timer.run().segment(2).best_segment_time().game_time, None);
+}
+
+#[test]
+fn game_time_stops_at_the_next_split_if_the_game_time_end_split_is_skipped() {
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    timer.split().unwrap();
+    timer.skip_split().unwrap();
+
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+    assert_eq!(
+        timer.snapshot().current_time().game_time,
+        Some(TimeSpan::from_seconds(12.0))
+    );
+
+    timer.split().unwrap();
+    assert_eq!(
+        timer.run().segment(2).split_time().game_time,
+        Some(TimeSpan::from_seconds(12.0))
+    );
+    assert_eq!(
+        timer.snapshot().current_time().game_time,
+        Some(TimeSpan::from_seconds(12.0))
+    );
+}
+
+#[test]
+fn game_time_keeps_running_before_the_game_time_end_split() {
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(7.0)).unwrap();
+
+    assert_eq!(
+        timer.snapshot().current_time().game_time,
+        Some(TimeSpan::from_seconds(7.0))
+    );
+}
+
+#[test]
+fn undoing_the_game_time_end_split_unfreezes_game_time() {
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    timer.split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(10.0)).unwrap();
+    timer.split().unwrap();
+    timer.undo_split().unwrap();
+
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+    assert_eq!(
+        timer.snapshot().current_time().game_time,
+        Some(TimeSpan::from_seconds(12.0))
+    );
+}
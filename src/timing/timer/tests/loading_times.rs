@@ -0,0 +1,57 @@
+use super::timer;
+use crate::TimeSpan;
+
+fn secs(seconds: f64) -> TimeSpan {
+    TimeSpan::from_seconds(seconds)
+}
+
+#[test]
+fn accumulates_multiple_loads() {
+    let mut timer = timer();
+    timer.start().unwrap();
+
+    timer.add_loading_time(secs(1.5)).unwrap();
+    timer.add_loading_time(secs(2.0)).unwrap();
+    timer.add_loading_time(secs(0.25)).unwrap();
+
+    assert!(timer.is_game_time_initialized());
+    assert_eq!(timer.loading_times(), secs(3.75));
+}
+
+#[test]
+fn adding_a_load_without_an_attempt_fails() {
+    let mut timer = timer();
+    timer.add_loading_time(secs(1.0)).unwrap_err();
+    assert_eq!(timer.loading_times(), TimeSpan::zero());
+}
+
+#[test]
+fn reset_clears_the_loading_times() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.add_loading_time(secs(4.0)).unwrap();
+    timer.reset(true).unwrap();
+
+    assert_eq!(timer.loading_times(), TimeSpan::zero());
+
+    timer.start().unwrap();
+    assert!(!timer.is_game_time_initialized());
+    timer.add_loading_time(secs(1.0)).unwrap();
+    assert_eq!(timer.loading_times(), secs(1.0));
+}
+
+#[test]
+fn explicitly_set_game_time_replaces_the_loads() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.add_loading_time(secs(10.0)).unwrap();
+
+    // The attempt just started, so a Game Time of zero means the loading
+    // times are just the tiny bit of Real Time that passed so far.
+    timer.set_game_time(TimeSpan::zero()).unwrap();
+    assert!(timer.loading_times() < secs(10.0));
+
+    timer.add_loading_time(secs(1.0)).unwrap();
+    assert!(timer.loading_times() >= secs(1.0));
+    assert!(timer.loading_times() < secs(10.0));
+}
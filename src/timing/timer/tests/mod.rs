@@ -3,11 +3,21 @@ use crate::{
     util::tests_helper::{
         make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, start_run,
     },
-    Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod,
+    Run, Segment, TimeSpan, TimeStamp, Timer, TimerPhase, TimingMethod,
 };
 
+mod bookmarks;
 mod events;
+mod game_time_ahead;
+mod game_time_end;
+mod loading_times;
 mod mark_as_modified;
+#[cfg(feature = "std")]
+mod observer;
+mod projected_final_time;
+mod segment_time;
+mod undo_first_split;
+mod undo_reset;
 mod variables;
 
 fn run() -> Run {
@@ -689,3 +699,23 @@ fn paused_then_resumed_game_time_lags_behind_real_time() {
         .current_time(timer.run());
     assert!(time.game_time.unwrap() < time.real_time);
 }
+
+#[test]
+fn undoing_all_pauses_restores_the_unpaused_elapsed_time() {
+    let mut timer = timer();
+
+    timer.start().unwrap();
+    timer.pause().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    timer.resume().unwrap();
+    assert!(timer.get_pause_time().unwrap() >= TimeSpan::from_milliseconds(20.0));
+
+    timer.undo_all_pauses().unwrap();
+    assert_eq!(timer.get_pause_time(), None);
+
+    let active_attempt = timer.active_attempt.as_ref().unwrap();
+    let time = active_attempt.current_time(timer.run());
+    let elapsed = TimeStamp::now() - active_attempt.start_time;
+    assert!(time.real_time >= TimeSpan::from_milliseconds(20.0));
+    assert!(time.real_time <= elapsed);
+}
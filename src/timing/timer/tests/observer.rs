@@ -0,0 +1,131 @@
+use super::timer;
+use crate::timing::TimerObserver;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, PartialEq)]
+enum Recorded {
+    Start,
+    Split(usize),
+    Skip,
+    Undo,
+    Reset(bool),
+    UndoReset,
+    Pause,
+    Resume,
+}
+
+struct Recorder(Arc<Mutex<Vec<Recorded>>>);
+
+impl TimerObserver for Recorder {
+    fn on_start(&mut self) {
+        self.0.lock().unwrap().push(Recorded::Start);
+    }
+    fn on_split(&mut self, index: usize) {
+        self.0.lock().unwrap().push(Recorded::Split(index));
+    }
+    fn on_skip(&mut self) {
+        self.0.lock().unwrap().push(Recorded::Skip);
+    }
+    fn on_undo(&mut self) {
+        self.0.lock().unwrap().push(Recorded::Undo);
+    }
+    fn on_reset(&mut self, update: bool) {
+        self.0.lock().unwrap().push(Recorded::Reset(update));
+    }
+    fn on_undo_reset(&mut self) {
+        self.0.lock().unwrap().push(Recorded::UndoReset);
+    }
+    fn on_pause(&mut self) {
+        self.0.lock().unwrap().push(Recorded::Pause);
+    }
+    fn on_resume(&mut self) {
+        self.0.lock().unwrap().push(Recorded::Resume);
+    }
+}
+
+#[test]
+fn records_start_split_and_reset() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut timer = timer();
+    timer.add_observer(Box::new(Recorder(events.clone())));
+
+    timer.start().unwrap();
+    timer.split().unwrap();
+    timer.reset(true).unwrap();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [Recorded::Start, Recorded::Split(0), Recorded::Reset(true)],
+    );
+}
+
+#[test]
+fn records_every_transition() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut timer = timer();
+    timer.add_observer(Box::new(Recorder(events.clone())));
+
+    timer.start().unwrap();
+    timer.pause().unwrap();
+    timer.resume().unwrap();
+    timer.skip_split().unwrap();
+    timer.undo_split().unwrap();
+    timer.split().unwrap();
+    timer.reset(false).unwrap();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            Recorded::Start,
+            Recorded::Pause,
+            Recorded::Resume,
+            Recorded::Skip,
+            Recorded::Undo,
+            Recorded::Split(0),
+            Recorded::Reset(false),
+        ],
+    );
+}
+
+#[test]
+fn records_undoing_a_reset() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut timer = timer();
+    timer.add_observer(Box::new(Recorder(events.clone())));
+
+    timer.start().unwrap();
+    timer.reset(true).unwrap();
+    timer.undo_reset().unwrap();
+    timer.undo_reset().unwrap_err();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [Recorded::Start, Recorded::Reset(true), Recorded::UndoReset],
+    );
+}
+
+#[test]
+fn failed_commands_are_not_observed() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut timer = timer();
+    timer.add_observer(Box::new(Recorder(events.clone())));
+
+    timer.split().unwrap_err();
+    timer.reset(true).unwrap_err();
+    timer.start().unwrap();
+    timer.start().unwrap_err();
+
+    assert_eq!(*events.lock().unwrap(), [Recorded::Start]);
+}
+
+#[test]
+fn clones_do_not_carry_observers() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut timer = timer();
+    timer.add_observer(Box::new(Recorder(events.clone())));
+
+    let mut clone = timer.clone();
+    clone.start().unwrap();
+
+    assert!(events.lock().unwrap().is_empty());
+}
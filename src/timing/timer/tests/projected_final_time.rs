@@ -0,0 +1,76 @@
+use crate::{
+    comparison::personal_best,
+    util::tests_helper::{create_timer, run_with_splits, span, start_run},
+    TimeSpan, Timer, TimingMethod,
+};
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    timer
+}
+
+#[test]
+fn is_none_without_an_active_attempt() {
+    let timer = timer();
+    assert_eq!(
+        timer.projected_final_time(personal_best::NAME, TimingMethod::GameTime),
+        None,
+    );
+}
+
+#[test]
+fn adds_the_remaining_segments_to_the_current_time() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(span(12.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(span(25.0)).unwrap();
+
+    let run = timer.run();
+    let split_time = |i: usize| run.segment(i).personal_best_split_time().game_time.unwrap();
+    let remaining_segments = (2..run.len()).fold(TimeSpan::zero(), |sum, i| {
+        sum + (split_time(i) - split_time(i - 1))
+    });
+    assert_eq!(remaining_segments, span(10.0));
+
+    let snapshot = timer.snapshot();
+    let current_time = snapshot.current_time().game_time.unwrap();
+    assert_eq!(
+        snapshot.projected_final_time(personal_best::NAME, TimingMethod::GameTime),
+        Some(current_time + remaining_segments),
+    );
+    assert_eq!(
+        timer.projected_final_time(personal_best::NAME, TimingMethod::GameTime),
+        Some(span(35.0)),
+    );
+}
+
+#[test]
+fn uses_the_last_delta_while_the_current_segment_is_faster() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(span(12.0)).unwrap();
+    timer.split().unwrap();
+    timer.set_game_time(span(13.0)).unwrap();
+
+    assert_eq!(
+        timer.projected_final_time(personal_best::NAME, TimingMethod::GameTime),
+        Some(span(32.0)),
+    );
+}
+
+#[test]
+fn is_the_final_time_once_finished() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    for split in [9.0, 19.0, 28.0] {
+        timer.set_game_time(span(split)).unwrap();
+        timer.split().unwrap();
+    }
+
+    assert_eq!(
+        timer.projected_final_time(personal_best::NAME, TimingMethod::GameTime),
+        Some(span(28.0)),
+    );
+}
@@ -0,0 +1,65 @@
+use crate::{
+    util::tests_helper::{create_timer, start_run},
+    TimeSpan, TimingMethod,
+};
+
+#[test]
+fn is_none_without_an_attempt() {
+    let timer = create_timer(&["A", "B"]);
+    assert_eq!(timer.current_segment_time(TimingMethod::GameTime), None);
+}
+
+#[test]
+fn starts_from_the_previous_split() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(2.0)).unwrap();
+    assert_eq!(
+        timer.current_segment_time(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(2.0))
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    timer.split().unwrap();
+    assert_eq!(
+        timer.current_segment_time(TimingMethod::GameTime),
+        Some(TimeSpan::zero())
+    );
+
+    let mut previous = TimeSpan::zero();
+    for seconds in [5.5, 7.0, 12.25] {
+        timer
+            .set_game_time(TimeSpan::from_seconds(seconds))
+            .unwrap();
+        let segment_time = timer.current_segment_time(TimingMethod::GameTime).unwrap();
+        let current_time = timer.snapshot().current_time().game_time.unwrap();
+        let split_time = timer.run().segment(0).split_time().game_time.unwrap();
+
+        assert!(segment_time > previous);
+        assert_eq!(segment_time, current_time - split_time);
+        previous = segment_time;
+    }
+}
+
+#[test]
+fn is_none_after_a_skipped_split() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+
+    timer.skip_split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(3.0)).unwrap();
+
+    assert_eq!(timer.current_segment_time(TimingMethod::GameTime), None);
+}
+
+#[test]
+fn is_none_once_the_attempt_ended() {
+    let mut timer = create_timer(&["A"]);
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(3.0)).unwrap();
+    timer.split().unwrap();
+
+    assert_eq!(timer.current_segment_time(TimingMethod::GameTime), None);
+}
@@ -0,0 +1,116 @@
+use crate::{
+    comparison::personal_best,
+    event::{Error, Event},
+    timing::FirstSplitUndoPolicy,
+    util::tests_helper::{create_timer, run_with_splits, span, start_run},
+    Timer, TimerPhase, TimingMethod,
+};
+
+fn timer(policy: FirstSplitUndoPolicy) -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    timer.set_first_split_undo_policy(policy);
+    timer
+}
+
+#[track_caller]
+fn assert_splits_untouched(timer: &Timer) {
+    for (segment, pb) in timer.run().segments().iter().zip([1.0, 2.0, 3.0]) {
+        assert_eq!(segment.split_time()[TimingMethod::GameTime], None);
+        assert_eq!(
+            segment.personal_best_split_time()[TimingMethod::GameTime],
+            Some(span(pb)),
+        );
+        assert_eq!(
+            segment.comparison(personal_best::NAME)[TimingMethod::GameTime],
+            Some(span(pb)),
+        );
+    }
+    assert_eq!(timer.run().attempt_history().len(), 1);
+}
+
+#[test]
+fn is_ignored_by_default() {
+    let timer = create_timer(&["A"]);
+    assert_eq!(
+        timer.first_split_undo_policy(),
+        FirstSplitUndoPolicy::Ignore,
+    );
+}
+
+#[test]
+fn ignoring_keeps_the_attempt_going() {
+    let mut timer = timer(FirstSplitUndoPolicy::Ignore);
+    start_run(&mut timer);
+
+    assert_eq!(timer.undo_split(), Err(Error::CantUndoFirstSplit));
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(0));
+    assert_eq!(timer.run().attempt_count(), 2);
+    assert_splits_untouched(&timer);
+}
+
+#[test]
+fn undoing_the_start_discards_the_attempt() {
+    let mut timer = timer(FirstSplitUndoPolicy::UndoStart);
+    start_run(&mut timer);
+
+    assert_eq!(timer.undo_split(), Ok(Event::StartUndone));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.current_split_index(), None);
+    assert_eq!(timer.run().attempt_count(), 1);
+    assert!(!timer.can_undo_reset());
+    assert_splits_untouched(&timer);
+
+    assert_eq!(timer.undo_split(), Err(Error::NoRunInProgress));
+
+    start_run(&mut timer);
+    timer.set_game_time(span(1.5)).unwrap();
+    timer.split().unwrap();
+    assert_eq!(
+        timer.run().segment(0).split_time()[TimingMethod::GameTime],
+        Some(span(1.5)),
+    );
+}
+
+#[test]
+fn splits_are_undone_before_the_start() {
+    let mut timer = timer(FirstSplitUndoPolicy::UndoStart);
+    start_run(&mut timer);
+    timer.set_game_time(span(0.5)).unwrap();
+    timer.split().unwrap();
+
+    assert_eq!(timer.undo_split(), Ok(Event::SplitUndone));
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(0));
+
+    assert_eq!(timer.undo_split(), Ok(Event::StartUndone));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_splits_untouched(&timer);
+}
+
+#[test]
+fn undoing_the_start_restores_the_modified_flag() {
+    let mut timer = timer(FirstSplitUndoPolicy::UndoStart);
+    timer.mark_as_unmodified();
+
+    start_run(&mut timer);
+    assert!(timer.run().has_been_modified());
+    timer.set_game_time(span(0.5)).unwrap();
+    timer.split().unwrap();
+    timer.undo_split().unwrap();
+
+    assert_eq!(timer.undo_split(), Ok(Event::StartUndone));
+    assert!(!timer.run().has_been_modified());
+}
+
+#[test]
+fn undoing_the_start_keeps_earlier_modifications() {
+    let mut timer = timer(FirstSplitUndoPolicy::UndoStart);
+    assert!(timer.run().has_been_modified());
+
+    start_run(&mut timer);
+
+    assert_eq!(timer.undo_split(), Ok(Event::StartUndone));
+    assert!(timer.run().has_been_modified());
+}
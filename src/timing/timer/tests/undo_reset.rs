@@ -0,0 +1,77 @@
+use crate::{
+    event::{Error, Event},
+    util::tests_helper::{create_timer, make_progress_run_with_splits_opt, start_run},
+    TimeSpan, TimerPhase, TimingMethod,
+};
+
+#[test]
+fn undoing_a_reset_restores_the_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None]);
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+
+    let attempt_history_len = timer.run().attempt_history().len();
+
+    timer.reset(true).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert!(timer.can_undo_reset());
+
+    assert_eq!(timer.undo_reset(), Ok(Event::ResetUndone));
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(2));
+    assert_eq!(
+        timer.run().segment(0).split_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(5.0))
+    );
+    assert_eq!(
+        timer.run().segment(1).split_time()[TimingMethod::GameTime],
+        None
+    );
+    assert_eq!(
+        timer.snapshot().current_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(12.0))
+    );
+    assert_eq!(timer.run().attempt_history().len(), attempt_history_len);
+
+    timer.split().unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+}
+
+#[test]
+fn only_a_single_reset_can_be_undone() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+    timer.split().unwrap();
+    timer.reset(false).unwrap();
+
+    timer.undo_reset().unwrap();
+    timer.reset(false).unwrap();
+    timer.undo_reset().unwrap();
+    timer.reset(false).unwrap();
+    timer.undo_reset().unwrap();
+
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert!(!timer.can_undo_reset());
+    assert_eq!(timer.undo_reset(), Err(Error::RunAlreadyInProgress));
+}
+
+#[test]
+fn reset_cant_be_undone_after_starting_a_new_attempt_or_replacing_the_run() {
+    let mut timer = create_timer(&["A", "B"]);
+    assert_eq!(timer.undo_reset(), Err(Error::NoResetToUndo));
+
+    start_run(&mut timer);
+    timer.split().unwrap();
+    timer.reset(true).unwrap();
+
+    start_run(&mut timer);
+    assert!(!timer.can_undo_reset());
+    assert_eq!(timer.current_split_index(), Some(0));
+    timer.reset(true).unwrap();
+    assert!(timer.can_undo_reset());
+
+    let run = timer.run().clone();
+    timer.set_run(run).unwrap();
+    assert_eq!(timer.undo_reset(), Err(Error::NoResetToUndo));
+}
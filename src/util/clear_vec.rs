@@ -103,6 +103,13 @@ impl<T: Clear> Default for ClearVec<T> {
     }
 }
 
+impl<T: Clear + PartialEq> PartialEq for ClearVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // The unused elements are not part of the ClearVec's contents.
+        **self == **other
+    }
+}
+
 impl<T: Clear> FromIterator<T> for ClearVec<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Vec::from_iter(iter).into()
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use crate::{Run, Segment, TimeSpan, Timer, TimingMethod};
+use crate::{timing::GameTimeAheadPolicy, Run, Segment, TimeSpan, Timer, TimingMethod};
 
 #[track_caller]
 pub fn create_run(names: &[&str]) -> Run {
@@ -18,6 +18,9 @@ pub fn create_timer(names: &[&str]) -> Timer {
 
 #[track_caller]
 pub fn start_run(timer: &mut Timer) {
+    // The tests forward arbitrary game times without waiting for the real time
+    // to catch up.
+    timer.set_game_time_ahead_policy(GameTimeAheadPolicy::Allow);
     timer.set_current_timing_method(TimingMethod::GameTime);
     timer.start().unwrap();
     timer.initialize_game_time().unwrap();
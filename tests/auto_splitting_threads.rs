@@ -0,0 +1,66 @@
+#![cfg(feature = "auto-splitting")]
+
+use livesplit_core::{
+    auto_splitting::{Config, Runtime, ThreadPriority},
+    SharedTimer,
+};
+
+fn config(thread_name_prefix: &str, thread_priority: ThreadPriority) -> Config {
+    let mut config = Config::default();
+    config.thread_name_prefix = thread_name_prefix.into();
+    config.thread_priority = thread_priority;
+    config
+}
+
+#[test]
+fn default_config() {
+    let config = Config::default();
+    assert_eq!(config.thread_name_prefix, "Auto Splitting");
+    assert_eq!(config.thread_priority, ThreadPriority::Low);
+}
+
+#[test]
+fn construction_succeeds_with_any_priority() {
+    // Lowering the priority is not supported everywhere, which must not
+    // prevent the runtime from being created.
+    for priority in [ThreadPriority::Low, ThreadPriority::Normal] {
+        let runtime = Runtime::<SharedTimer>::with_config(config("asr-prio", priority));
+        assert!(runtime.settings_map().is_none());
+        runtime.unload().unwrap();
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn thread_name_prefix_is_applied() {
+    use std::{
+        collections::HashSet,
+        fs, thread,
+        time::{Duration, Instant},
+    };
+
+    fn thread_names() -> HashSet<String> {
+        fs::read_dir("/proc/self/task")
+            .unwrap()
+            .filter_map(|task| {
+                let comm = fs::read_to_string(task.ok()?.path().join("comm")).ok()?;
+                Some(comm.trim_end().to_owned())
+            })
+            .collect()
+    }
+
+    // Linux truncates thread names to 15 bytes, so the prefix is kept short.
+    let _runtime = Runtime::<SharedTimer>::with_config(config("asr-x", ThreadPriority::Normal));
+
+    // The threads name themselves once they are running, so this may take a
+    // moment.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let names = thread_names();
+        if names.contains("asr-x Runtime") && names.contains("asr-x Watchdog") {
+            break;
+        }
+        assert!(Instant::now() < deadline, "Threads not found: {names:?}");
+        thread::sleep(Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "auto-splitting")]
+
+use livesplit_core::{
+    auto_splitting::{settings, Runtime, TickSummary},
+    Run, Segment, SharedTimer, Timer,
+};
+use std::{
+    env, fs, process,
+    sync::mpsc::{self, Receiver},
+    time::{Duration, Instant},
+};
+
+/// A module that exports its memory and an `update` function that does
+/// nothing:
+///
+/// ```wat
+/// (module
+///   (memory (export "memory") 1)
+///   (func (export "update")))
+/// ```
+const EMPTY_UPDATE: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00, // Header
+    0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // Type Section
+    0x03, 0x02, 0x01, 0x00, // Function Section
+    0x05, 0x03, 0x01, 0x00, 0x01, // Memory Section
+    0x07, 0x13, 0x02, // Export Section
+    0x06, b'm', b'e', b'm', b'o', b'r', b'y', 0x02, 0x00, // "memory"
+    0x06, b'u', b'p', b'd', b'a', b't', b'e', 0x00, 0x00, // "update"
+    0x0A, 0x04, 0x01, 0x02, 0x00, 0x0B, // Code Section
+];
+
+const TIMEOUT: Duration = Duration::from_secs(3);
+
+fn shared_timer() -> SharedTimer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Split"));
+    Timer::new(run).unwrap().into_shared()
+}
+
+fn load(name: &str) -> (Runtime<SharedTimer>, Receiver<TickSummary>) {
+    let path = env::temp_dir().join(format!(
+        "livesplit-core-tick-callback-{name}-{}.wasm",
+        process::id(),
+    ));
+    fs::write(&path, EMPTY_UPDATE).unwrap();
+
+    let runtime = Runtime::new();
+    let (sender, receiver) = mpsc::channel();
+    runtime.set_tick_callback(move |summary| {
+        let _ = sender.send(summary);
+    });
+
+    let result = runtime.load(path.clone(), shared_timer());
+    fs::remove_file(&path).ok();
+    result.unwrap();
+
+    (runtime, receiver)
+}
+
+#[test]
+fn called_once_per_tick() {
+    let (runtime, receiver) = load("ticks");
+
+    let first = receiver.recv_timeout(TIMEOUT).unwrap();
+    assert!(first.settings_map_changed);
+    assert!(first.settings_widgets_changed);
+    assert!(first.duration < TIMEOUT);
+
+    let start = Instant::now();
+    let mut ticks = 0;
+    while start.elapsed() < Duration::from_millis(500) {
+        let summary = receiver.recv_timeout(TIMEOUT).unwrap();
+        assert!(!summary.settings_map_changed);
+        assert!(!summary.settings_widgets_changed);
+        assert!(summary.duration < TIMEOUT);
+        ticks += 1;
+    }
+
+    // The auto splitter runs at 120 ticks per second by default. Being called
+    // more often than that would mean that the callback got called more than
+    // once per tick.
+    assert!(ticks > 1);
+    assert!(ticks <= 62, "{ticks} callbacks within half a second");
+
+    runtime.remove_tick_callback();
+}
+
+#[test]
+fn reports_settings_changes() {
+    let (runtime, receiver) = load("settings");
+
+    receiver.recv_timeout(TIMEOUT).unwrap();
+
+    let mut map = settings::Map::new();
+    map.insert("key".into(), settings::Value::Bool(true));
+    runtime.set_settings_map(map).unwrap();
+
+    let deadline = Instant::now() + TIMEOUT;
+    while !receiver.recv_timeout(TIMEOUT).unwrap().settings_map_changed {
+        assert!(
+            Instant::now() < deadline,
+            "The settings change was not reported."
+        );
+    }
+
+    assert!(!receiver.recv_timeout(TIMEOUT).unwrap().settings_map_changed);
+}
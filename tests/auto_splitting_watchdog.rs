@@ -0,0 +1,64 @@
+#![cfg(feature = "auto-splitting")]
+
+use livesplit_core::{auto_splitting::Runtime, Run, Segment, SharedTimer, Timer};
+use std::{
+    env, fs, process, thread,
+    time::{Duration, Instant},
+};
+
+/// A module that exports its memory and an `update` function that loops
+/// forever:
+///
+/// ```wat
+/// (module
+///   (memory (export "memory") 1)
+///   (func (export "update") (loop (br 0))))
+/// ```
+const INFINITE_LOOP: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00, // Header
+    0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // Type Section
+    0x03, 0x02, 0x01, 0x00, // Function Section
+    0x05, 0x03, 0x01, 0x00, 0x01, // Memory Section
+    0x07, 0x13, 0x02, // Export Section
+    0x06, b'm', b'e', b'm', b'o', b'r', b'y', 0x02, 0x00, // "memory"
+    0x06, b'u', b'p', b'd', b'a', b't', b'e', 0x00, 0x00, // "update"
+    0x0A, 0x09, 0x01, 0x07, 0x00, 0x03, 0x40, 0x0C, 0x00, 0x0B, 0x0B, // Code Section
+];
+
+fn shared_timer() -> SharedTimer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Split"));
+    Timer::new(run).unwrap().into_shared()
+}
+
+#[test]
+fn default_timeout() {
+    let runtime = Runtime::<SharedTimer>::new();
+    assert_eq!(runtime.watchdog_timeout(), Duration::from_secs(5));
+
+    runtime.set_watchdog_timeout(Duration::from_millis(250));
+    assert_eq!(runtime.watchdog_timeout(), Duration::from_millis(250));
+}
+
+#[test]
+fn interrupts_after_configured_timeout() {
+    let path = env::temp_dir().join(format!("livesplit-core-watchdog-{}.wasm", process::id()));
+    fs::write(&path, INFINITE_LOOP).unwrap();
+
+    let runtime = Runtime::new();
+    runtime.set_watchdog_timeout(Duration::from_millis(100));
+    let result = runtime.load(path.clone(), shared_timer());
+    fs::remove_file(&path).ok();
+    result.unwrap();
+
+    // The auto splitter gets unloaded once it is interrupted. This needs to
+    // happen well before the default timeout of 5 seconds would've passed.
+    let deadline = Instant::now() + Duration::from_secs(3);
+    while runtime.settings_map().is_some() {
+        assert!(
+            Instant::now() < deadline,
+            "The auto splitter did not get interrupted."
+        );
+        thread::sleep(Duration::from_millis(10));
+    }
+}
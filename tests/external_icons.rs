@@ -0,0 +1,114 @@
+#![cfg(feature = "std")]
+use livesplit_core::{
+    run::{parser, saver, saver::livesplit::ExternalIconsError},
+    settings::Image,
+    Run, Segment,
+};
+use std::{fs, path::PathBuf};
+
+fn splits_path(test_name: &str) -> PathBuf {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("livesplit-core-external-icons-{test_name}"));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    dir.push("splits.lss");
+    dir
+}
+
+fn run_with_icons() -> Run {
+    let mut run = Run::new();
+    run.set_game_icon(Image::new([1, 2, 3][..].into(), Image::ICON));
+    let mut segment = Segment::new("A");
+    segment.set_icon(Image::new([4, 5, 6][..].into(), Image::ICON));
+    run.push_segment(segment);
+    run.push_segment(Segment::new("B"));
+    run
+}
+
+#[test]
+fn external_icons_round_trip() {
+    let path = splits_path("round-trip");
+    let run = run_with_icons();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run_with_external_icons(&run, &mut buf, &path, "icons").unwrap();
+    assert!(!buf.contains("CDATA"));
+    assert!(buf.contains(&format!("icons/{}", run.segment(0).icon().id())));
+
+    let parsed = parser::livesplit::parse_and_load_files(&buf, Some(&path)).unwrap();
+    assert_eq!(parsed.game_icon(), run.game_icon());
+    assert_eq!(parsed.segment(0).icon(), run.segment(0).icon());
+    assert!(parsed.segment(1).icon().is_empty());
+}
+
+#[test]
+fn missing_external_icon_is_empty() {
+    let path = splits_path("missing");
+    let run = run_with_icons();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run_with_external_icons(&run, &mut buf, &path, "icons").unwrap();
+    fs::remove_file(
+        path.with_file_name("icons")
+            .join(run.segment(0).icon().id().to_string()),
+    )
+    .unwrap();
+
+    let parsed = parser::livesplit::parse_and_load_files(&buf, Some(&path)).unwrap();
+    assert_eq!(parsed.game_icon(), run.game_icon());
+    assert!(parsed.segment(0).icon().is_empty());
+
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    assert!(parsed.game_icon().is_empty());
+}
+
+#[test]
+fn external_icons_outside_of_the_directory_are_not_loaded() {
+    let path = splits_path("outside");
+    let run = run_with_icons();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run_with_external_icons(&run, &mut buf, &path, "icons").unwrap();
+    let icon_file = format!("icons/{}", run.game_icon().id());
+    let outside = path
+        .parent()
+        .unwrap()
+        .with_file_name("livesplit-core-outside-icon");
+    fs::write(&outside, run.game_icon().data()).unwrap();
+
+    for file in [
+        outside.to_str().unwrap(),
+        "../livesplit-core-outside-icon",
+        "icons/../../livesplit-core-outside-icon",
+    ] {
+        let buf = buf.replace(&icon_file, file);
+        let parsed = parser::livesplit::parse_and_load_files(&buf, Some(&path)).unwrap();
+        assert!(parsed.game_icon().is_empty());
+    }
+}
+
+#[test]
+fn icons_directory_needs_to_stay_within_the_splits_directory() {
+    let path = splits_path("invalid-directory");
+    let run = run_with_icons();
+
+    for directory in ["", "..", "../icons", "icons/../..", "/icons"] {
+        let result =
+            saver::livesplit::save_run_with_external_icons(&run, String::new(), &path, directory);
+        assert!(
+            matches!(result, Err(ExternalIconsError::InvalidDirectory)),
+            "{directory:?}",
+        );
+    }
+}
+
+#[test]
+fn icons_directory_needs_to_be_a_directory() {
+    let path = splits_path("not-a-directory");
+    let run = run_with_icons();
+    fs::write(path.with_file_name("icons"), []).unwrap();
+
+    let result =
+        saver::livesplit::save_run_with_external_icons(&run, String::new(), &path, "icons");
+    assert!(matches!(result, Err(ExternalIconsError::NotADirectory)));
+}
@@ -0,0 +1,49 @@
+use livesplit_core::{
+    run::{parser, saver},
+    Run, Segment,
+};
+
+fn save_and_parse(run: &Run) -> (String, Run) {
+    let mut buf = String::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    (buf, parsed)
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    run
+}
+
+#[test]
+fn game_time_end_index_round_trips() {
+    let mut run = run();
+    run.set_game_time_end_index(Some(1)).unwrap();
+
+    let (buf, parsed) = save_and_parse(&run);
+    assert!(buf.contains("<GameTimeEndIndex>1</GameTimeEndIndex>"));
+    assert_eq!(parsed.game_time_end_index(), Some(1));
+}
+
+#[test]
+fn game_time_end_index_is_omitted_by_default() {
+    let (buf, parsed) = save_and_parse(&run());
+    assert!(!buf.contains("GameTimeEndIndex"));
+    assert_eq!(parsed.game_time_end_index(), None);
+}
+
+#[test]
+fn game_time_end_index_outside_of_the_segments_is_rejected() {
+    let mut run = run();
+    run.set_game_time_end_index(Some(2)).unwrap();
+
+    let (buf, _) = save_and_parse(&run);
+    let buf = buf.replace(
+        "<GameTimeEndIndex>2</GameTimeEndIndex>",
+        "<GameTimeEndIndex>3</GameTimeEndIndex>",
+    );
+    assert!(parser::livesplit::parse(&buf).is_err());
+}
@@ -0,0 +1,95 @@
+mod run_files;
+
+use livesplit_core::{
+    run::{
+        parser::{json, livesplit},
+        saver, LinkedLayout,
+    },
+    AtomicDateTime, DateTime, Run, Time, TimeSpan,
+};
+
+fn save(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::json::save_run(run, &mut buf).unwrap();
+    buf
+}
+
+fn fully_populated_run() -> Run {
+    let mut run = livesplit::parse(run_files::CELESTE).unwrap();
+
+    run.set_offset(TimeSpan::from_seconds(-1.5));
+    run.set_linked_layout(Some(LinkedLayout::Path("layouts/celeste.ls1l".into())));
+    run.set_game_time_end_index(Some(run.len() - 2)).unwrap();
+    *run.auto_splitter_settings_mut() = r#"<Setting id="chapters">True</Setting>"#.into();
+
+    let metadata = run.metadata_mut();
+    metadata.set_run_id("yz1w9e3m");
+    metadata.set_platform_name("PC");
+    metadata.set_region_name("USA");
+    metadata.set_emulator_usage(true);
+    metadata.set_speedrun_com_variable("Version", "1.2.1.5");
+    metadata
+        .custom_variable_mut("Controller")
+        .permanent()
+        .set_value("Keyboard");
+    metadata.add_runner("Alice");
+    metadata.add_runner("Bob");
+    metadata.set_frame_rate(Some(60.0));
+
+    run.add_custom_comparison("Race Goal").unwrap();
+    let icon = run.game_icon().clone();
+    let segment = run.segment_mut(1);
+    segment.set_icon(icon);
+    *segment.comparison_mut("Race Goal") =
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(100_000.25)));
+    segment
+        .variables_mut()
+        .insert("Berries".into(), "20".into());
+
+    let index = run.attempt_history().last().unwrap().index() + 1;
+    let started = DateTime::from_unix_timestamp_nanos(1_700_000_000_123_456_789).unwrap();
+    let ended = DateTime::from_unix_timestamp_nanos(1_700_003_600_987_654_321).unwrap();
+    run.add_attempt_with_index(
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(3599.5))),
+        index,
+        Some(AtomicDateTime::new(started, true)),
+        Some(AtomicDateTime::new(ended, false)),
+        Some(TimeSpan::from_seconds(0.25)),
+    );
+
+    run
+}
+
+#[test]
+fn round_trips_losslessly() {
+    let run = fully_populated_run();
+    let reparsed = json::parse(&save(&run)).unwrap();
+    assert_eq!(run, reparsed);
+}
+
+#[test]
+fn output_is_stable() {
+    let run = fully_populated_run();
+    let json = save(&run);
+    assert_eq!(save(&json::parse(&json).unwrap()), json);
+    assert!(json.contains(r#""time":"2023-11-14T22:13:20.123456789Z""#));
+}
+
+#[test]
+fn temporary_custom_variables_are_not_stored() {
+    let mut run = Run::new();
+    run.metadata_mut()
+        .custom_variable_mut("Permanent")
+        .permanent()
+        .set_value("A");
+    run.metadata_mut()
+        .custom_variable_mut("Temporary")
+        .set_value("B");
+
+    let reparsed = json::parse(&save(&run)).unwrap();
+    assert_eq!(
+        reparsed.metadata().custom_variable_value("Permanent"),
+        Some("A"),
+    );
+    assert!(reparsed.metadata().custom_variable("Temporary").is_none());
+}
@@ -14,7 +14,7 @@ use livesplit_core::{
     rendering,
     run::parser::{livesplit, llanfair, wsplit},
     settings::ImageCache,
-    Run, Segment, TimeSpan, Timer, TimingMethod,
+    timing, Run, Segment, TimeSpan, Timer, TimingMethod,
 };
 use std::{fs, path::PathBuf};
 
@@ -256,8 +256,8 @@ fn all_components() {
         &state,
         &image_cache,
         [300, 800],
-        "7e7aa83a3b80e1da",
-        "fa3c0357219389d8",
+        "4aed58603a669907",
+        "ca2c4c50f53e4218",
         "all_components",
     );
 
@@ -265,8 +265,8 @@ fn all_components() {
         &state,
         &image_cache,
         [150, 800],
-        "97afa51bfd8a8597",
-        "eda169eec3995eeb",
+        "3dc628e76456d776",
+        "87e9cad1e4e34124",
         "all_components_thin",
     );
 }
@@ -391,6 +391,29 @@ fn display_two_rows() {
     );
 }
 
+#[test]
+fn splits_comparison_line() {
+    let mut timer = tests_helper::create_timer(&["A", "B"]);
+    tests_helper::run_with_splits(&mut timer, &[5.0, 123.0]);
+    let mut layout = Layout::new();
+    let mut component = component::splits::Component::new();
+    let settings = component.settings_mut();
+    settings.visual_split_count = 0;
+    settings.show_comparison_line = true;
+    layout.push(component);
+
+    let mut image_cache = ImageCache::new();
+
+    check_dims(
+        &layout.state(&mut image_cache, &timer.snapshot()),
+        &image_cache,
+        [300, 80],
+        "1cf8527bbbb79115",
+        "1373e3a1a7008a71",
+        "splits_comparison_line",
+    );
+}
+
 #[test]
 fn single_line_title() {
     let mut run = tests_helper::create_run(&["A"]);
@@ -456,6 +479,98 @@ fn horizontal() {
     );
 }
 
+fn preferred_size_with_splits(names: &[&str]) -> [f32; 2] {
+    let timer = Timer::new(tests_helper::create_run(names)).unwrap();
+    let mut layout = Layout::default_layout();
+    match &mut layout.components[1] {
+        Component::Splits(splits) => splits.settings_mut().visual_split_count = 0,
+        _ => unreachable!("We wanted to configure the splits"),
+    }
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    rendering::preferred_size(&state)
+}
+
+#[test]
+fn preferred_size_grows_with_splits() {
+    let [few_width, few_height] = preferred_size_with_splits(&["A", "B"]);
+    let [many_width, many_height] =
+        preferred_size_with_splits(&["A", "B", "C", "D", "E", "F", "G", "H"]);
+
+    assert_eq!(few_width, many_width);
+    assert!(many_height > few_height);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn preferred_size_renders_without_resizing() {
+    let timer = Timer::new(tests_helper::create_run(&["A", "B", "C", "D"])).unwrap();
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let [width, height] = rendering::preferred_size(&state);
+    let dims = [width.ceil() as u32, height.ceil() as u32];
+
+    let mut renderer = rendering::software::Renderer::new();
+    // The renderer doesn't suggest any other resolution when rendering at the
+    // preferred size.
+    assert_eq!(renderer.render(&state, &image_cache, dims), None);
+    assert_eq!(renderer.render(&state, &image_cache, dims), None);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn render_to_png() {
+    use rendering::software::image;
+
+    let timer = Timer::new(tests_helper::create_run(&["A", "B", "C", "D"])).unwrap();
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    let png = renderer.render_to_png(&state, &image_cache, [120, 200]);
+    assert!(!png.is_empty());
+
+    let image = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+        .unwrap()
+        .to_rgba8();
+    assert_eq!(image.dimensions(), (120, 200));
+    assert!(image.pixels().any(|pixel| pixel.0 != [0; 4]));
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn render_if_changed_skips_unchanged_frames() {
+    let mut timer = Timer::new(tests_helper::create_run(&["A", "B", "C", "D"])).unwrap();
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let mut renderer = rendering::software::Renderer::new();
+
+    tests_helper::start_run(&mut timer);
+    let mut state = layout.state(&mut image_cache, &timer.snapshot());
+    assert!(renderer
+        .render_if_changed(&state, &image_cache, [300, 500])
+        .is_some());
+
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    assert!(renderer
+        .render_if_changed(&state, &image_cache, [300, 500])
+        .is_none());
+
+    timer.set_game_time(TimeSpan::from_seconds(1.5)).unwrap();
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    assert!(renderer
+        .render_if_changed(&state, &image_cache, [300, 500])
+        .is_some());
+
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    assert!(renderer
+        .render_if_changed(&state, &image_cache, [300, 400])
+        .is_some());
+}
+
 #[track_caller]
 fn check(
     state: &LayoutState,
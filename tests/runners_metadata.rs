@@ -0,0 +1,96 @@
+mod run_files;
+
+use livesplit_core::{
+    run::{parser, saver},
+    Run, Segment,
+};
+
+fn save_and_parse(run: &Run) -> (String, Run) {
+    let mut buf = String::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    (buf, parsed)
+}
+
+#[test]
+fn multiple_runners_round_trip() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.metadata_mut().add_runner("Alice");
+    run.metadata_mut().add_runner("Bob & Carol");
+
+    let (buf, parsed) = save_and_parse(&run);
+    assert!(buf.contains("<Runners>"));
+    assert_eq!(parsed.metadata().runners(), ["Alice", "Bob & Carol"]);
+}
+
+#[test]
+fn runners_are_omitted_when_empty() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    let (buf, parsed) = save_and_parse(&run);
+    assert!(!buf.contains("Runners"));
+    assert!(parsed.metadata().runners().is_empty());
+}
+
+#[test]
+fn existing_files_parse_without_runners() {
+    let run = parser::livesplit::parse(run_files::LIVESPLIT_1_6).unwrap();
+    assert!(run.metadata().runners().is_empty());
+
+    let run = parser::livesplit::parse(run_files::CELESTE).unwrap();
+    assert!(run.metadata().runners().is_empty());
+}
+
+#[test]
+fn single_runner_round_trips() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.metadata_mut().add_runner("Alice");
+
+    let (_, parsed) = save_and_parse(&run);
+    assert_eq!(parsed.metadata().runners(), ["Alice"]);
+}
+
+#[test]
+fn frame_rate_round_trips() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.metadata_mut().set_frame_rate(Some(60.0988));
+
+    let (buf, parsed) = save_and_parse(&run);
+    assert!(buf.contains("<FrameRate>60.0988</FrameRate>"));
+    assert_eq!(parsed.metadata().frame_rate(), Some(60.0988));
+}
+
+#[test]
+fn frame_rate_is_omitted_when_not_specified() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    let (buf, parsed) = save_and_parse(&run);
+    assert!(!buf.contains("FrameRate"));
+    assert_eq!(parsed.metadata().frame_rate(), None);
+}
+
+#[test]
+fn splits_io_imports_all_runners() {
+    let (run, _) = parser::splits_io::parse(
+        r#"{
+            "_schemaVersion": "v1.0.1",
+            "timer": { "shortname": "livesplit", "longname": "LiveSplit", "version": "1.7.0" },
+            "runners": [
+                { "shortname": "alice", "longname": "Alice" },
+                { "shortname": "bob" }
+            ],
+            "segments": [{ "name": "A", "endedAt": { "realtimeMS": 1000 } }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(run.metadata().runners(), ["Alice", "bob"]);
+    assert_eq!(
+        run.metadata().custom_variable_value("Runner"),
+        Some("Alice")
+    );
+}
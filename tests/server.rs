@@ -0,0 +1,108 @@
+#![cfg(feature = "std")]
+
+use livesplit_core::{
+    networking::server::{Server, MAX_CONNECTIONS},
+    Run, Segment, Timer, TimerPhase,
+};
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpStream,
+    thread,
+};
+
+#[test]
+fn start_and_split_over_loopback() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    let timer = Timer::new(run).unwrap().into_shared();
+
+    let server = Server::bind("127.0.0.1:0", timer.clone()).unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream
+        .write_all(b"starttimer\r\nsplit\r\ngetcurrenttimerphase\r\ngetcurrentsplitname\r\n")
+        .unwrap();
+
+    let mut lines = BufReader::new(stream).lines();
+    assert_eq!(lines.next().unwrap().unwrap(), "Running");
+    assert_eq!(lines.next().unwrap().unwrap(), "Second");
+
+    let timer = timer.read().unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+}
+
+#[test]
+fn overly_long_lines_close_the_connection() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    let timer = Timer::new(run).unwrap().into_shared();
+
+    let server = Server::bind("127.0.0.1:0", timer.clone()).unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(&[b'a'; 8192]).unwrap();
+    stream.write_all(b"\r\nping\r\n").ok();
+
+    let mut lines = BufReader::new(stream).lines();
+    assert!(lines.next().is_none_or(|line| line.is_err()));
+}
+
+fn ping(stream: &mut TcpStream) -> Option<String> {
+    stream.write_all(b"ping\r\n").ok()?;
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).ok()?;
+    (!line.is_empty()).then(|| line.trim_end().to_owned())
+}
+
+#[test]
+fn stopping_closes_the_server_and_its_connections() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    let timer = Timer::new(run).unwrap().into_shared();
+
+    let server = Server::bind("127.0.0.1:0", timer).unwrap();
+    let addr = server.local_addr().unwrap();
+    let stop_handle = server.stop_handle().unwrap();
+    let server = thread::spawn(move || server.run());
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    assert_eq!(ping(&mut stream).as_deref(), Some("pong"));
+
+    stop_handle.stop();
+    server.join().unwrap();
+
+    assert_eq!(ping(&mut stream), None);
+    assert!(TcpStream::connect(addr).is_err());
+}
+
+#[test]
+fn connections_beyond_the_limit_are_closed() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    let timer = Timer::new(run).unwrap().into_shared();
+
+    let server = Server::bind("127.0.0.1:0", timer).unwrap();
+    let addr = server.local_addr().unwrap();
+    let stop_handle = server.stop_handle().unwrap();
+    thread::spawn(move || server.run());
+
+    // The server answers a connection only once it got registered, so all of
+    // these are registered before the next connection is made.
+    let mut streams = (0..MAX_CONNECTIONS)
+        .map(|_| TcpStream::connect(addr).unwrap())
+        .collect::<Vec<_>>();
+    for stream in &mut streams {
+        assert_eq!(ping(stream).as_deref(), Some("pong"));
+    }
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    assert_eq!(ping(&mut stream), None);
+
+    stop_handle.stop();
+}
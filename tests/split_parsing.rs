@@ -140,6 +140,25 @@ mod parse {
         urn::parse(run_files::URN).unwrap();
     }
 
+    #[test]
+    fn urn_segments() {
+        let run = urn::parse(run_files::URN).unwrap();
+        assert_eq!(run.category_name(), "SotN Any% NSC");
+        assert_eq!(run.attempt_count(), 69);
+
+        let names: Vec<_> = run.segments().iter().map(|s| s.name()).collect();
+        assert_eq!(names, ["Mist", "Bat", "Reverse", "Dracula"]);
+
+        assert_eq!(
+            run.segment(1).personal_best_split_time().real_time,
+            Some("14:36.934210".parse::<TimeSpan>().unwrap()),
+        );
+        assert_eq!(
+            run.segment(3).best_segment_time().real_time,
+            Some("5:54.446978".parse::<TimeSpan>().unwrap()),
+        );
+    }
+
     #[test]
     fn flitter() {
         flitter::parse(run_files::FLITTER).unwrap();
@@ -216,4 +235,66 @@ mod parse {
         let run = composite::parse(run_files::FLITTER.as_bytes(), None).unwrap();
         assert_eq!(run.kind, TimerKind::Flitter);
     }
+
+    #[track_caller]
+    fn detected_kind(source: &[u8]) -> TimerKind<'_> {
+        composite::parse(source, None).unwrap().kind
+    }
+
+    #[test]
+    fn livesplit_is_detected() {
+        assert_eq!(
+            detected_kind(run_files::LIVESPLIT_1_6.as_bytes()),
+            TimerKind::LiveSplit,
+        );
+    }
+
+    #[test]
+    fn wsplit_is_detected() {
+        assert_eq!(
+            detected_kind(run_files::WSPLIT.as_bytes()),
+            TimerKind::WSplit,
+        );
+    }
+
+    #[test]
+    fn splitterz_is_detected() {
+        assert_eq!(
+            detected_kind(run_files::SPLITTERZ.as_bytes()),
+            TimerKind::SplitterZ,
+        );
+    }
+
+    #[test]
+    fn time_split_tracker_is_detected() {
+        assert_eq!(
+            detected_kind(run_files::TIME_SPLIT_TRACKER.as_bytes()),
+            TimerKind::TimeSplitTracker,
+        );
+    }
+
+    #[test]
+    fn llanfair_gered_is_detected() {
+        assert_eq!(
+            detected_kind(run_files::LLANFAIR_GERED.as_bytes()),
+            TimerKind::LlanfairGered,
+        );
+    }
+
+    #[test]
+    fn llanfair_is_detected() {
+        assert_eq!(detected_kind(run_files::LLANFAIR), TimerKind::Llanfair);
+    }
+
+    #[test]
+    fn detected_kind_has_a_display_name() {
+        assert_eq!(
+            detected_kind(run_files::LLANFAIR_GERED.as_bytes()).to_string(),
+            "Llanfair (Gered's fork)",
+        );
+        assert_eq!(
+            detected_kind(run_files::GENERIC_SPLITS_IO.as_bytes()).to_string(),
+            "LiveSplit",
+        );
+    }
 }